@@ -1,4 +1,5 @@
 import { useState, useEffect, lazy, Suspense, useMemo, useCallback } from "react";
+import { listen } from "@tauri-apps/api/event";
 import { AnimatePresence, motion } from "framer-motion";
 import { Sidebar } from "./components/Sidebar";
 import { Header } from "./components/Header";
@@ -25,9 +26,11 @@ const Console = lazy(() => import("./components/Console").then(module => ({ defa
 const BudgetManagement = lazy(() => import("./components/BudgetManagement").then(module => ({ default: module.BudgetManagement })));
 import type { Printer, Settings, Filament, Offer, Customer, ThemeName, Project, Task } from "./types";
 import { defaultSettings } from "./types";
-import { savePrinters, loadPrinters, saveFilaments, loadFilaments, saveSettings, loadSettings, saveOffers, loadOffers, saveCustomers, loadCustomers, loadProjects, loadTasks, resetStoreInstance } from "./utils/store";
-import { createAutomaticBackup, cleanupOldBackups } from "./utils/backup";
+import { savePrinters, loadPrinters, saveFilaments, loadFilaments, saveSettings, loadSettings, saveOffers, loadOffers, saveCustomers, loadCustomers, loadProjects, loadTasks, resetStoreInstance, reloadStoreFromDisk, storeFileExists } from "./utils/store";
+import { createAutomaticBackup, cleanupOldBackups, applyBackupRetention, type BackupReplicationResult } from "./utils/backup";
 import { cleanupOldLogs } from "./utils/logCleanup";
+import { consumeLaunchRequests, OPEN_SLICER_FILE_EVENT } from "./utils/launchRequests";
+import { onBeforeWorkspaceSwitch } from "./utils/workspaces";
 import { cleanupOldAuditLogs } from "./utils/auditLogCleanup";
 import { getThemeStyles, resolveTheme } from "./utils/themes";
 import { defaultAnimationSettings } from "./types";
@@ -87,10 +90,16 @@ export default function App() {
   useEffect(() => {
     const checkFirstLaunch = async () => {
       try {
+        // Titkosított adattároló: zárolt állapotban nincs data.json, először fel kell oldani
+        // (különben első indításnak látszana és a nyelvválasztó felülírná az adatokat)
+        const { ensureVaultUnlocked } = await import("./utils/vault");
+        if (!(await ensureVaultUnlocked())) {
+          console.warn("⚠️ Az adattároló zárolva maradt, az adatok nem töltődnek be");
+          return;
+        }
+
         // Ellenőrizzük, hogy létezik-e a data.json fájl, anélkül hogy betöltjük a Store-t
-        const { exists } = await import("@tauri-apps/plugin-fs");
-        const { BaseDirectory } = await import("@tauri-apps/plugin-fs");
-        const dataJsonExists = await exists("data.json", { baseDir: BaseDirectory.AppConfig });
+        const dataJsonExists = await storeFileExists();
         
         if (!dataJsonExists) {
           // Ha nincs data.json, akkor első indítás - mutassuk a nyelvválasztót
@@ -228,6 +237,89 @@ export default function App() {
     }
   }, []);
 
+  // 🔹 A backend átírta a data.json-t (pl. visszaállítás) - a Store cache-t és az állapotot újratöltjük
+  useEffect(() => {
+    const unlisten = listen("data-store-changed", async () => {
+      try {
+        await reloadStoreFromDisk();
+        await reloadData();
+      } catch (error) {
+        console.error("❌ Hiba a Store újratöltésekor:", error);
+      }
+    });
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, [reloadData]);
+
+  // 🔹 A backend backup ütemező eseményei - a lastBackupDate frissítése és a hibák naplózása
+  useEffect(() => {
+    const unlistenCompleted = listen<{ trigger: string; filePath: string; timestamp: string }>("backup-completed", async (event) => {
+      try {
+        const currentSettings = await loadSettings();
+        if (currentSettings) {
+          const updatedSettings = { ...currentSettings, lastBackupDate: event.payload.timestamp };
+          await saveSettings(updatedSettings);
+          setSettings(updatedSettings);
+        }
+        console.log("✅ Automatikus vészbackup létrehozva:", event.payload.timestamp, `(${event.payload.trigger})`);
+      } catch (error) {
+        console.error("❌ Hiba a backup dátum frissítésekor:", error);
+      }
+    });
+    const unlistenFailed = listen<{ trigger: string; error: string }>("backup-failed", (event) => {
+      console.error("❌ Hiba az automatikus backup létrehozásakor:", event.payload.error);
+    });
+    // A további backup célokra másolás a háttérben fut, az eredménye külön eseményként érkezik
+    const unlistenReplicated = listen<{ filePath: string; results: BackupReplicationResult[] }>("backup-replicated", (event) => {
+      const failed = event.payload.results.filter((result) => !result.success);
+      if (failed.length === 0) {
+        console.log("✅ Backup másolva a további célokra:", event.payload.filePath);
+        return;
+      }
+      for (const result of failed) {
+        console.error(`❌ Backup másolása sikertelen ide: ${result.name} -`, result.error);
+      }
+      writeFrontendLog('WARN', `Backup másolása sikertelen (${failed.map((result) => result.name).join(", ")})`).catch(() => {});
+    });
+    return () => {
+      unlistenCompleted.then((fn) => fn());
+      unlistenFailed.then((fn) => fn());
+      unlistenReplicated.then((fn) => fn());
+    };
+  }, []);
+
+  // 🔹 Megnyitandó fájlok (indítási argumentumok és második indítás) - a backend sorát a betöltés után vesszük át
+  // A második indításnál a backend előtérbe hozta az ablakot, az átadott fájlok a megnyitási sorba kerültek
+  useEffect(() => {
+    if (!isInitialized) return;
+
+    const openLaunchFiles = async () => {
+      try {
+        const { slicerFiles, unsupported } = await consumeLaunchRequests();
+        if (unsupported.length > 0) {
+          writeFrontendLog('WARN', `Nem megnyitható fájlok: ${unsupported.join(", ")}`).catch(() => {});
+        }
+        if (slicerFiles.length > 0) {
+          setActivePage("calculator");
+          window.dispatchEvent(new CustomEvent(OPEN_SLICER_FILE_EVENT));
+        }
+      } catch (error) {
+        console.error("❌ Hiba a megnyitandó fájlok átvételekor:", error);
+      }
+    };
+
+    openLaunchFiles();
+    const unlisten = listen<{ args: string[]; cwd: string; files: string[] }>("second-instance", (event) => {
+      console.log("ℹ️ Az alkalmazás már fut - második indítás átirányítva", event.payload.files);
+      writeFrontendLog('INFO', `Második indítás átirányítva (${event.payload.files.length} fájl)`).catch(() => {});
+      openLaunchFiles();
+    });
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, [isInitialized]);
+
   // 🔹 Betöltés indításkor - Progress tracking-gel (csak ha a nyelv kiválasztva)
   useEffect(() => {
     if (!languageSelected) return; // Várjuk meg a nyelvválasztást
@@ -734,9 +826,13 @@ export default function App() {
           // Frissítjük a settings state-et a legfrissebb beállításokkal
           setSettings(updatedSettings);
           
-          // Töröljük a régi backupokat (max 10 db)
-          const maxBackups = latestSettings.maxAutomaticBackups || 10;
-          await cleanupOldBackups(maxBackups);
+          // Töröljük a régi backupokat: GFS megőrzés, ha be van állítva, különben max 10 db
+          if (latestSettings.backupRetentionPolicy) {
+            await applyBackupRetention(latestSettings.backupRetentionPolicy);
+          } else {
+            const maxBackups = latestSettings.maxAutomaticBackups || 10;
+            await cleanupOldBackups(maxBackups);
+          }
         } else {
           // Már létezett mai backup - NEM írjuk felül a beállításokat, hogy ne veszítsük el a friss értékeket (pl. téma)
           // NE mentjük a beállításokat, ha már létezett mai backup - ez elkerüli, hogy a régi értékek írják felül a friss értékeket
@@ -858,6 +954,22 @@ export default function App() {
     }
   }, [customers, isInitialized, autosaveEnabled]);
 
+  // 🔹 Munkaterület váltás előtt a debounce-ra váró mentések azonnal kiíródnak a jelenlegi munkaterületre
+  useEffect(() => {
+    if (!isInitialized) {
+      return;
+    }
+    return onBeforeWorkspaceSwitch(async () => {
+      await Promise.all([
+        savePrinters(printers),
+        saveFilaments(filaments),
+        saveOffers(offers),
+        saveCustomers(customers),
+        saveSettings(settings),
+      ]);
+    });
+  }, [printers, filaments, offers, customers, settings, isInitialized]);
+
   // 🔹 Autosave újraindítása, amikor be van kapcsolva
   useEffect(() => {
     if (!isInitialized) {
@@ -891,8 +1003,9 @@ export default function App() {
 
   // 🔹 Naponta egyszer automatikus backup ellenőrzés - még akkor is, ha nincs változás
   // Ez biztosítja, hogy naponta egyszer létrejöjjön a backup, még akkor is, ha nincs változás
+  // Ha az automatikus backup be van kapcsolva, a backend ütemező végzi (a webview állapotától függetlenül)
   useEffect(() => {
-    if (!autosaveEnabled || !isInitialized) {
+    if (!autosaveEnabled || !isInitialized || settings.automaticBackupEnabled === true) {
       return;
     }
 
@@ -929,7 +1042,7 @@ export default function App() {
       clearTimeout(initialTimeout);
       clearInterval(intervalId);
     };
-  }, [autosaveEnabled, isInitialized, createAutomaticBackupIfEnabled]);
+  }, [autosaveEnabled, isInitialized, settings.automaticBackupEnabled, createAutomaticBackupIfEnabled]);
 
   // 🔹 Automatikus log rotáció (törlés) - alkalmazás indításakor és naponta
   useEffect(() => {
@@ -8,6 +8,7 @@ import { Tooltip } from "./Tooltip";
 import { saveTemplates, loadTemplates } from "../utils/store";
 import { ConfirmDialog } from "./ConfirmDialog";
 import { SlicerImportModal } from "./SlicerImportModal";
+import { takePendingSlicerFile, OPEN_SLICER_FILE_EVENT } from "../utils/launchRequests";
 import { validatePrintTime, validateUsedGrams, validateDryingTime, validateDryingPower, validateProfitPercentage } from "../utils/validation";
 import { sendNativeNotification } from "../utils/platformFeatures";
 
@@ -61,6 +62,7 @@ export const Calculator: React.FC<Props> = ({ printers, filaments, customers, se
   const [showTemplateList, setShowTemplateList] = useState(false);
   const [deleteTemplateId, setDeleteTemplateId] = useState<number | null>(null);
   const [showSlicerImportModal, setShowSlicerImportModal] = useState(false);
+  const [slicerImportFile, setSlicerImportFile] = useState<string | undefined>(undefined);
 
   const selectedPrinter = useMemo(() => {
     if (selectedPrinterId === "") return null;
@@ -169,6 +171,24 @@ export const Calculator: React.FC<Props> = ({ printers, filaments, customers, se
     loadTemplates().then(setTemplates).catch(console.error);
   }, []);
 
+  // Az alkalmazásnak átadott slicer fájl importja (indításkor vagy második indításból)
+  const openPendingSlicerFile = () => {
+    const filePath = takePendingSlicerFile();
+    if (filePath) {
+      setSlicerImportFile(filePath);
+      setShowSlicerImportModal(true);
+    }
+  };
+
+  useEffect(() => {
+    openPendingSlicerFile();
+    window.addEventListener(OPEN_SLICER_FILE_EVENT, openPendingSlicerFile);
+    return () => {
+      window.removeEventListener(OPEN_SLICER_FILE_EVENT, openPendingSlicerFile);
+    };
+    // eslint-disable-next-line react-hooks/exhaustive-deps
+  }, []);
+
   // Template mentése
   const handleSaveTemplate = async () => {
     if (!templateName.trim()) {
@@ -1348,7 +1368,11 @@ export const Calculator: React.FC<Props> = ({ printers, filaments, customers, se
 
       <SlicerImportModal
         isOpen={showSlicerImportModal}
-        onClose={() => setShowSlicerImportModal(false)}
+        onClose={() => {
+          setShowSlicerImportModal(false);
+          setSlicerImportFile(undefined);
+        }}
+        initialFilePath={slicerImportFile}
         settings={settings}
         theme={theme}
         themeStyles={themeStyles}
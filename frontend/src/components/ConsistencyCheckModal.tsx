@@ -0,0 +1,214 @@
+import React, { useCallback, useEffect, useState } from "react";
+import { AnimatePresence, motion } from "framer-motion";
+import { useToast } from "./Toast";
+import type { Settings } from "../types";
+import type { Theme } from "../utils/themes";
+import {
+  checkDataConsistency,
+  repairDataConsistency,
+  type ConsistencyIssueKind,
+  type ConsistencyReport,
+} from "../utils/consistency";
+
+interface ConsistencyCheckModalProps {
+  isOpen: boolean;
+  onClose: () => void;
+  settings: Settings;
+  theme: Theme;
+  themeStyles: ReturnType<typeof import("../utils/themes").getThemeStyles>;
+}
+
+// Probléma típusok: [magyar, német, angol]
+const KIND_LABELS: Record<ConsistencyIssueKind, [string, string, string]> = {
+  danglingReference: ["Hibás hivatkozás", "Ungültiger Verweis", "Dangling reference"],
+  missingId: ["Hiányzó azonosító", "Fehlende ID", "Missing ID"],
+  duplicateId: ["Duplikált azonosító", "Doppelte ID", "Duplicate ID"],
+  duplicateKey: ["Duplikált filament", "Doppeltes Filament", "Duplicate filament"],
+  unlinkedCustomer: ["Ügyfél kapcsolat", "Kundenzuordnung", "Customer link"],
+  negativeValue: ["Negatív érték", "Negativer Wert", "Negative value"],
+  outOfRange: ["Tartományon kívül", "Außerhalb des Bereichs", "Out of range"],
+  invalidDate: ["Hibás dátum", "Ungültiges Datum", "Invalid date"],
+  impossibleDate: ["Lehetetlen dátum", "Unmögliches Datum", "Impossible date"],
+};
+
+export const ConsistencyCheckModal: React.FC<ConsistencyCheckModalProps> = ({ isOpen, onClose, settings, theme, themeStyles }) => {
+  const { showToast } = useToast();
+  const [report, setReport] = useState<ConsistencyReport | null>(null);
+  const [isBusy, setIsBusy] = useState(false);
+
+  const label = (hu: string, de: string, en: string) =>
+    settings.language === "hu" ? hu : settings.language === "de" ? de : en;
+
+  const runCheck = useCallback(async () => {
+    setIsBusy(true);
+    try {
+      setReport(await checkDataConsistency());
+    } catch (error) {
+      console.error("❌ Adatkonzisztencia ellenőrzés hiba:", error);
+      showToast(String(error), "error");
+    } finally {
+      setIsBusy(false);
+    }
+  }, [showToast]);
+
+  useEffect(() => {
+    if (isOpen) {
+      runCheck();
+    }
+  }, [isOpen, runCheck]);
+
+  const runRepair = async (issueIds?: string[]) => {
+    setIsBusy(true);
+    try {
+      // A backend írja a Store-t, az App a "data-store-changed" eseményre újratölti az adatokat
+      const result = await repairDataConsistency(issueIds);
+      setReport(result.report);
+      showToast(
+        label(`${result.repaired.length} probléma javítva`, `${result.repaired.length} Probleme behoben`, `${result.repaired.length} issues repaired`),
+        "success"
+      );
+    } catch (error) {
+      console.error("❌ Adatkonzisztencia javítás hiba:", error);
+      showToast(String(error), "error");
+    } finally {
+      setIsBusy(false);
+    }
+  };
+
+  const smallButton: React.CSSProperties = { ...themeStyles.button, padding: "6px 12px", fontSize: "12px" };
+
+  return (
+    <AnimatePresence>
+      {isOpen && (
+        <motion.div
+          initial={{ opacity: 0 }}
+          animate={{ opacity: 1 }}
+          exit={{ opacity: 0 }}
+          transition={{ duration: 0.2 }}
+          onClick={onClose}
+          style={{
+            position: "fixed",
+            top: 0,
+            left: 0,
+            right: 0,
+            bottom: 0,
+            backgroundColor: "rgba(15, 23, 42, 0.45)",
+            zIndex: 1100,
+            display: "flex",
+            alignItems: "center",
+            justifyContent: "center",
+            padding: "24px",
+            backdropFilter: "blur(6px)",
+          }}
+        >
+          <motion.div
+            initial={{ opacity: 0, scale: 0.95, y: 20 }}
+            animate={{ opacity: 1, scale: 1, y: 0 }}
+            exit={{ opacity: 0, scale: 0.95, y: 20 }}
+            transition={{ duration: 0.25, ease: "easeOut" }}
+            onClick={event => event.stopPropagation()}
+            style={{
+              ...themeStyles.card,
+              width: "min(1000px, 95vw)",
+              maxHeight: "90vh",
+              overflowY: "auto",
+              position: "relative",
+              padding: "32px",
+              display: "flex",
+              flexDirection: "column",
+              gap: "16px",
+            }}
+          >
+            <button
+              onClick={onClose}
+              style={{
+                position: "absolute",
+                top: "12px",
+                right: "12px",
+                border: "none",
+                background: "transparent",
+                color: theme.colors.text,
+                fontSize: "20px",
+                cursor: "pointer",
+              }}
+              aria-label={label("Bezárás", "Schließen", "Close")}
+            >
+              ✕
+            </button>
+
+            <div>
+              <h3 style={{ margin: "0 0 8px 0", fontSize: "20px", fontWeight: 600, color: theme.colors.text }}>
+                🩺 {label("Adatkonzisztencia", "Datenkonsistenz", "Data consistency")}
+              </h3>
+              {report && (
+                <p style={{ margin: 0, fontSize: "13px", color: theme.colors.textMuted }}>
+                  {label(
+                    `${report.recordsChecked} rekord ellenőrizve: ${report.errors} hiba, ${report.warnings} figyelmeztetés, ${report.repairable} automatikusan javítható.`,
+                    `${report.recordsChecked} Datensätze geprüft: ${report.errors} Fehler, ${report.warnings} Warnungen, ${report.repairable} automatisch behebbar.`,
+                    `${report.recordsChecked} records checked: ${report.errors} errors, ${report.warnings} warnings, ${report.repairable} automatically repairable.`
+                  )}
+                </p>
+              )}
+            </div>
+
+            <div style={{ display: "flex", gap: "12px", flexWrap: "wrap" }}>
+              <button disabled={isBusy} onClick={runCheck} style={{ ...smallButton, ...themeStyles.buttonSecondary }}>
+                🔄 {label("Újraellenőrzés", "Erneut prüfen", "Check again")}
+              </button>
+              <button
+                disabled={isBusy || !report || report.repairable === 0}
+                onClick={() => runRepair()}
+                style={{ ...smallButton, ...themeStyles.buttonPrimary, opacity: report && report.repairable > 0 ? 1 : 0.5 }}
+              >
+                🛠️ {label("Összes javítása", "Alle beheben", "Repair all")}
+              </button>
+            </div>
+
+            {report && report.issues.length === 0 && (
+              <div style={{ fontSize: "13px", color: theme.colors.textMuted, padding: "16px 0" }}>
+                ✅ {label("Nem található probléma.", "Keine Probleme gefunden.", "No issues found.")}
+              </div>
+            )}
+
+            {report && report.issues.length > 0 && (
+              <table style={{ width: "100%", borderCollapse: "collapse", fontSize: "13px", color: theme.colors.text }}>
+                <thead>
+                  <tr style={{ textAlign: "left", borderBottom: `1px solid ${theme.colors.border}` }}>
+                    <th style={{ padding: "6px" }}>{label("Típus", "Typ", "Type")}</th>
+                    <th style={{ padding: "6px" }}>{label("Rekord", "Datensatz", "Record")}</th>
+                    <th style={{ padding: "6px" }}>{label("Leírás", "Beschreibung", "Description")}</th>
+                    <th style={{ padding: "6px" }} />
+                  </tr>
+                </thead>
+                <tbody>
+                  {report.issues.map(issue => (
+                    <tr key={issue.id} style={{ borderBottom: `1px solid ${theme.colors.border}` }}>
+                      <td style={{ padding: "6px", whiteSpace: "nowrap", color: issue.severity === "error" ? theme.colors.danger : theme.colors.text }}>
+                        {issue.severity === "error" ? "⛔" : "⚠️"} {label(...KIND_LABELS[issue.kind])}
+                      </td>
+                      <td style={{ padding: "6px", whiteSpace: "nowrap" }}>
+                        {issue.entityType} #{issue.recordKey}
+                      </td>
+                      <td style={{ padding: "6px" }}>{issue.message}</td>
+                      <td style={{ padding: "6px", textAlign: "right" }}>
+                        {issue.repair && (
+                          <button
+                            disabled={isBusy}
+                            onClick={() => runRepair([issue.id])}
+                            style={{ ...smallButton, ...themeStyles.buttonSecondary }}
+                          >
+                            🛠️ {label("Javítás", "Beheben", "Repair")}
+                          </button>
+                        )}
+                      </td>
+                    </tr>
+                  ))}
+                </tbody>
+              </table>
+            )}
+          </motion.div>
+        </motion.div>
+      )}
+    </AnimatePresence>
+  );
+};
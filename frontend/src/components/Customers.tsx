@@ -10,6 +10,7 @@ import { saveCustomers } from "../utils/store";
 import { useUndoRedo } from "../hooks/useUndoRedo";
 import { useKeyboardShortcut } from "../utils/keyboardShortcuts";
 import { auditCreate, auditUpdate, auditDelete } from "../utils/auditLog";
+import { moveToTrash } from "../utils/trash";
 
 const LANGUAGE_LOCALES: Record<string, string> = {
   hu: "hu-HU",
@@ -250,6 +251,17 @@ export const Customers: React.FC<Props> = ({
     const id = deleteConfirmId;
     const customerToDelete = customers.find(c => c.id === id);
     
+    // Lomtárba helyezés - ha nem sikerül, nem törlünk (a rekord különben visszaállíthatatlanul elveszne)
+    if (customerToDelete) {
+      try {
+        await moveToTrash("customers", [customerToDelete]);
+      } catch {
+        showToast(t("common.trashMoveFailed"), "error");
+        setDeleteConfirmId(null);
+        return;
+      }
+    }
+    
     // Audit log
     try {
       await auditDelete("customer", id, customerToDelete?.name || "Unknown", {
@@ -359,6 +371,13 @@ export const Customers: React.FC<Props> = ({
     if (selectedCustomerIds.size === 0) return;
     
     const idsToDelete = Array.from(selectedCustomerIds);
+    try {
+      await moveToTrash("customers", customersWithHistory.filter(c => idsToDelete.includes(c.id)));
+    } catch {
+      showToast(t("common.trashMoveFailed"), "error");
+      setBulkDeleteConfirm(false);
+      return;
+    }
     const updatedCustomers = customersWithHistory.filter(c => !idsToDelete.includes(c.id));
     
     setCustomersWithHistory(updatedCustomers);
@@ -43,6 +43,7 @@ import { logWithLanguage } from "../utils/languages/global_console";
 import { addPriceHistory, isSignificantPriceChange, getFilamentPriceHistory } from "../utils/priceHistory";
 import type { PriceHistory } from "../types";
 import { auditCreate, auditUpdate, auditDelete } from "../utils/auditLog";
+import { moveToTrash } from "../utils/trash";
 
 const DEFAULT_WEIGHT_UNITS = ["g", "kg"] as const;
 
@@ -1035,6 +1036,17 @@ export const Filaments: React.FC<Props> = ({ filaments, setFilaments, settings,
       type: filamentToDelete?.type,
     });
     
+    // Lomtárba helyezés - ha nem sikerül, nem törlünk (a rekord különben visszaállíthatatlanul elveszne)
+    if (filamentToDelete) {
+      try {
+        await moveToTrash("filaments", [filamentToDelete]);
+      } catch {
+        showToast(t("common.trashMoveFailed"), "error");
+        setDeleteConfirmIndex(null);
+        return;
+      }
+    }
+    
     // Audit log
     try {
       const filamentId = `${filamentToDelete?.brand || "unknown"}_${filamentToDelete?.type || "unknown"}_${filamentToDelete?.color || "no-color"}_${index}`;
@@ -1284,7 +1296,7 @@ export const Filaments: React.FC<Props> = ({ filaments, setFilaments, settings,
     setBulkDeleteConfirm(true);
   };
 
-  const confirmBulkDelete = () => {
+  const confirmBulkDelete = async () => {
     if (selectedFilamentIds.size === 0) return;
     
     const idsToDelete = Array.from(selectedFilamentIds);
@@ -1297,6 +1309,14 @@ export const Filaments: React.FC<Props> = ({ filaments, setFilaments, settings,
       brands: filamentsToDelete.map(f => f.brand).join(", "),
     });
     
+    try {
+      await moveToTrash("filaments", filamentsToDelete);
+    } catch {
+      showToast(t("common.trashMoveFailed"), "error");
+      setBulkDeleteConfirm(false);
+      return;
+    }
+    
     const updatedFilaments = filamentsWithHistory.filter((f, index) => 
       !idsToDelete.includes(getFilamentId(f, index))
     );
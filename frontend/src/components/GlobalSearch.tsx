@@ -1,5 +1,6 @@
 import React, { useState, useEffect, useMemo, useRef } from 'react';
 import { motion, AnimatePresence } from 'framer-motion';
+import { invoke } from '@tauri-apps/api/core';
 import type { Theme } from '../utils/themes';
 import type { Settings, Offer } from '../types';
 import { useTranslation } from '../utils/translations';
@@ -8,7 +9,7 @@ import { getAllLibraryEntries, getLocalizedLibraryColorLabel } from '../utils/fi
 
 interface SearchResult {
   id: string;
-  type: 'page' | 'action' | 'offer' | 'filament';
+  type: 'page' | 'action' | 'offer' | 'filament' | 'customer' | 'project';
   label: string;
   icon: string;
   description?: string;
@@ -20,6 +21,16 @@ interface SearchResult {
   };
 }
 
+// Backend keresési index találat (search command)
+interface BackendSearchHit {
+  entityType: 'offers' | 'customers' | 'filaments' | 'projects';
+  id: number | string;
+  title: string;
+  subtitle?: string;
+  score: number;
+  matchedFields: string[];
+}
+
 interface GlobalSearchProps {
   isOpen: boolean;
   onClose: () => void;
@@ -38,12 +49,12 @@ export const GlobalSearch: React.FC<GlobalSearchProps> = ({
   theme,
   themeStyles,
   settings,
-  offers = [],
   onAddFilamentFromLibrary,
 }) => {
   const t = useTranslation(settings.language);
   const [searchTerm, setSearchTerm] = useState('');
   const [selectedIndex, setSelectedIndex] = useState(0);
+  const [backendHits, setBackendHits] = useState<BackendSearchHit[]>([]);
   const inputRef = useRef<HTMLInputElement>(null);
   const isGradientBg = typeof theme.colors.background === 'string' && theme.colors.background.includes('gradient');
   const isNeon = theme.name === 'neon' || theme.name === 'cyberpunk';
@@ -62,6 +73,37 @@ export const GlobalSearch: React.FC<GlobalSearchProps> = ({
     }
   }, [isOpen]);
 
+  // Ajánlatok, ügyfelek, filamentek és projektek keresése a backend indexben
+  // (ékezet független, prefix és elgépelés tűrő keresés)
+  useEffect(() => {
+    const query = searchTerm.trim();
+    if (!query) {
+      setBackendHits([]);
+      return;
+    }
+
+    let cancelled = false;
+    const timer = setTimeout(() => {
+      invoke<BackendSearchHit[]>('search', { query, limit: 20 })
+        .then(hits => {
+          if (!cancelled) {
+            setBackendHits(hits);
+          }
+        })
+        .catch(error => {
+          console.error('❌ Hiba a keresés során:', error);
+          if (!cancelled) {
+            setBackendHits([]);
+          }
+        });
+    }, 150);
+
+    return () => {
+      cancelled = true;
+      clearTimeout(timer);
+    };
+  }, [searchTerm]);
+
   // Keresési eredmények generálása
   const searchResults = useMemo(() => {
     if (!searchTerm.trim()) {
@@ -133,35 +175,38 @@ export const GlobalSearch: React.FC<GlobalSearchProps> = ({
       }
     });
 
-    // Ajánlatok keresése
-    if (offers.length > 0) {
-      offers.forEach((offer, index) => {
-        const searchableText = [
-          offer.customerName || '',
-          offer.id?.toString() || '',
-          offer.status || '',
-          offer.date || '',
-        ].join(' ').toLowerCase();
+    // Ajánlatok, ügyfelek, mentett filamentek és projektek (backend index, relevancia szerint rendezve)
+    const entityDisplay: Record<BackendSearchHit['entityType'], { type: SearchResult['type']; icon: string; page: string; label: string }> = {
+      offers: { type: 'offer', icon: '📋', page: 'offers', label: t('offers.title') || 'Árajánlat' },
+      customers: { type: 'customer', icon: '👥', page: 'customers', label: t('sidebar.customers') || 'Ügyfél' },
+      filaments: { type: 'filament', icon: '🧵', page: 'filaments', label: t('filaments.title') || 'Filament' },
+      projects: { type: 'project', icon: '📁', page: 'projects', label: t('sidebar.projects') || 'Projekt' },
+    };
 
-        if (searchableText.includes(term)) {
-          results.push({
-            id: `offer-${offer.id || index}`,
-            type: 'offer',
-            label: offer.customerName || t('offers.customerName') || 'Ügyfél',
-            icon: '📋',
-            description: `${t('offers.title') || 'Árajánlat'} #${offer.id || index} - ${offer.status || ''}`,
-            action: () => {
-              onNavigate('offers');
-              onClose();
-              // TODO: Később lehet scrollozni az ajánlathoz vagy kiemelni
-            },
-            metadata: {
-              offerId: offer.id?.toString(),
-            },
-          });
-        }
+    backendHits.forEach(hit => {
+      const display = entityDisplay[hit.entityType];
+      if (!display) {
+        return;
+      }
+      results.push({
+        id: `${hit.entityType}-${hit.id}`,
+        type: display.type,
+        label: hit.title,
+        icon: display.icon,
+        description: hit.entityType === 'offers'
+          ? `${display.label} #${hit.id}${hit.subtitle ? ` - ${hit.subtitle}` : ''}`
+          : hit.subtitle || display.label,
+        action: () => {
+          onNavigate(display.page);
+          onClose();
+        },
+        metadata: hit.entityType === 'offers'
+          ? { offerId: String(hit.id) }
+          : hit.entityType === 'filaments'
+            ? { filamentIndex: Number(hit.id) }
+            : undefined,
       });
-    }
+    });
 
     // Filamentek keresése az adatbázisból (nem a mentett filamentekből)
     if (libraryEntries.length > 0) {
@@ -205,13 +250,15 @@ export const GlobalSearch: React.FC<GlobalSearchProps> = ({
         'page': 0,
         'action': 1,
         'offer': 2,
+        'customer': 2,
+        'project': 2,
         'filament': 3,
       };
       return (typeOrder[a.type] || 99) - (typeOrder[b.type] || 99);
     });
 
     return sortedResults.slice(0, 15); // Maximum 15 eredmény (növelve, mert több típus van)
-  }, [searchTerm, t, onNavigate, onClose, offers, libraryEntries, settings.currency, onAddFilamentFromLibrary]);
+  }, [searchTerm, t, onNavigate, onClose, backendHits, libraryEntries, settings.currency, onAddFilamentFromLibrary]);
 
   // Billentyű kezelés
   useEffect(() => {
@@ -1,6 +1,4 @@
 import React, { useMemo, useState, useEffect, useRef } from "react";
-import { save } from "@tauri-apps/plugin-dialog";
-import { writeTextFile } from "@tauri-apps/plugin-fs";
 import type {
   Offer,
   Settings,
@@ -26,6 +24,9 @@ import { notifyExportComplete, notifyOfferStatusChange } from "../utils/platform
 import { useUndoRedo } from "../hooks/useUndoRedo";
 import { useKeyboardShortcut } from "../utils/keyboardShortcuts";
 import { auditCreate, auditUpdate, auditDelete } from "../utils/auditLog";
+import { moveToTrash } from "../utils/trash";
+import { chooseSpreadsheetPath, exportSpreadsheet } from "../utils/spreadsheetExport";
+import { chooseOfferPdfPath, exportOfferPdf } from "../utils/offerPdf";
 
 const STATUS_ORDER: OfferStatus[] = ["draft", "sent", "accepted", "rejected", "completed"];
 
@@ -288,6 +289,17 @@ export const Offers: React.FC<Props> = ({
       customerName: offerToDelete?.customerName,
     });
     
+    // Lomtárba helyezés - ha nem sikerül, nem törlünk (a rekord különben visszaállíthatatlanul elveszne)
+    if (offerToDelete) {
+      try {
+        await moveToTrash("offers", [offerToDelete]);
+      } catch {
+        showToast(t("common.trashMoveFailed"), "error");
+        setDeleteConfirmId(null);
+        return;
+      }
+    }
+    
     // Audit log
     try {
       await auditDelete("offer", id, offerToDelete?.customerName || "Unknown", {
@@ -752,38 +764,24 @@ export const Offers: React.FC<Props> = ({
       if (import.meta.env.DEV) {
         logWithLanguage(settings.language, "log", "offers.pdf.startedForOffer", { offerId: offer.id });
       }
-      
-      // HTML tartalom generálása
-      const htmlContent = generatePDFContent(offer, t, settings, locale, theme);
-      
-      // Tauri save dialog használata
-      const dateStr = new Date().toISOString().split('T')[0];
-      const fileName = `ajánlat_${offer.id}_${dateStr}.html`;
-      
-      const filePath = await save({
-        defaultPath: fileName,
-        filters: [
-          {
-            name: "HTML",
-            extensions: ["html"]
-          },
-          {
-            name: "All Files",
-            extensions: ["*"]
-          }
-        ]
-      });
 
-      if (filePath) {
-        await writeTextFile(filePath, htmlContent);
-        showToast(`${t("offers.toast.exportHtmlSuccess")} ${fileName}`, "success");
-        // Natív értesítés küldése (ha engedélyezve van)
-        if (settings.notificationEnabled !== false) {
-          try {
-            await notifyExportComplete(fileName);
-          } catch (error) {
-            console.log("Értesítés küldése sikertelen:", error);
-          }
+      // Natív PDF (backend), így minden platformon azonos a kimenet
+      const filePath = await chooseOfferPdfPath(offer);
+      if (!filePath) {
+        return;
+      }
+      const result = await exportOfferPdf(offer, settings, t, locale, filePath);
+      const fileName = result.filePath.split(/[\\/]/).pop() || result.filePath;
+      showToast(`${t("offers.toast.exportPdfSuccess")} ${fileName}`, "success");
+      if (result.missingCharacters) {
+        showToast(`${t("offers.toast.exportPdfMissingGlyphs")} ${result.missingCharacters}`, "error");
+      }
+      // Natív értesítés küldése (ha engedélyezve van)
+      if (settings.notificationEnabled !== false) {
+        try {
+          await notifyExportComplete(fileName);
+        } catch (error) {
+          console.log("Értesítés küldése sikertelen:", error);
         }
       }
     } catch (error) {
@@ -1269,6 +1267,54 @@ export const Offers: React.FC<Props> = ({
     return true;
   });
 
+  // A szűrt árajánlatok és a havi összesítő exportja táblázatba (a szűrést a backend ugyanígy végzi el)
+  const exportFilteredOffersSpreadsheet = async () => {
+    try {
+      const target = await chooseSpreadsheetPath(`offers_${new Date().toISOString().split("T")[0]}`);
+      if (!target) {
+        return;
+      }
+      const parseAmount = (value: string) => {
+        const parsed = Number(value.replace(",", "."));
+        return value && !Number.isNaN(parsed) ? parsed : undefined;
+      };
+      const result = await exportSpreadsheet({
+        ...target,
+        datasets: ["offers", "financialSummary"],
+        filter: {
+          searchTerm: searchTerm || undefined,
+          minPrice: parseAmount(minAmountFilter),
+          maxPrice: parseAmount(maxAmountFilter),
+          currency: settings.currency,
+          startDate: fromDateFilter || undefined,
+          endDate: toDateFilter || undefined,
+          statuses: statusFilter !== "all" ? [statusFilter] : undefined,
+        },
+        language: settings.language,
+        decimalComma: settings.language !== "en",
+      });
+      const fileName = target.filePath.split(/[\\/]/).pop() ?? target.filePath;
+      showToast(
+        settings.language === "hu"
+          ? `${result.offerCount} árajánlat exportálva: ${fileName}`
+          : settings.language === "de"
+          ? `${result.offerCount} Angebote exportiert: ${fileName}`
+          : `${result.offerCount} offers exported: ${fileName}`,
+        "success"
+      );
+      if (settings.notificationEnabled !== false) {
+        try {
+          await notifyExportComplete(fileName);
+        } catch (error) {
+          console.log("Értesítés küldése sikertelen:", error);
+        }
+      }
+    } catch (error) {
+      console.error("❌ Táblázat export hiba:", error);
+      showToast(String(error), "error");
+    }
+  };
+
   type OfferSortKey = "date" | "amount" | "status" | "customer" | "id";
 
   const [offerSortConfig, setOfferSortConfig] = useState<Array<{ key: OfferSortKey; direction: "asc" | "desc" }>>(
@@ -1479,6 +1525,13 @@ export const Offers: React.FC<Props> = ({
       customerName: offersToDelete[0]?.customerName,
     });
     
+    try {
+      await moveToTrash("offers", offersToDelete);
+    } catch {
+      showToast(t("common.trashMoveFailed"), "error");
+      setBulkDeleteConfirm(false);
+      return;
+    }
     const updatedOffers = offersWithHistory.filter(o => !idsToDelete.includes(o.id));
     setOffersWithHistory(updatedOffers);
     
@@ -1754,6 +1807,12 @@ export const Offers: React.FC<Props> = ({
                     ↷ {t("common.redo")}
                   </button>
                 </Tooltip>
+                <button
+                  onClick={exportFilteredOffersSpreadsheet}
+                  style={{ ...themeStyles.button, ...themeStyles.buttonSecondary, padding: "8px 16px", fontSize: "13px" }}
+                >
+                  📊 {settings.language === "hu" ? "Export táblázatba" : settings.language === "de" ? "Tabellenexport" : "Export spreadsheet"}
+                </button>
               </div>
 
               {/* Rendezés – jobb oldal */}
@@ -2621,7 +2680,7 @@ export const Offers: React.FC<Props> = ({
                               {t("offers.print")}
                             </button>
                           </Tooltip>
-                          <Tooltip content={t("offers.tooltip.downloadHtml")}>
+                          <Tooltip content={t("offers.tooltip.downloadPdf")}>
                             <button
                               onClick={() => exportAsPDF(selectedOffer)}
                               onMouseEnter={(e) => Object.assign((e.currentTarget as HTMLButtonElement).style, themeStyles.buttonHover)}
@@ -25,7 +25,12 @@ import {
   themeToCustomDefinition,
   DEFAULT_THEME_NAME,
 } from "../utils/themes";
-import { createBackup, restoreBackup, getAutomaticBackupHistory, getDeletionCountdown, type BackupHistoryItem } from "../utils/backup";
+import { createBackup, restoreBackup, setBackupPassphrase, verifyAllBackups, getAutomaticBackupHistory, getDeletionCountdown, getBackupDestinations, addBackupDestination, addWebDavBackupDestination, saveBackupDestination, removeBackupDestination, replicateLatestBackup, previewBackupRetention, setBackupPinned, getBackupSchedulerStatus, runScheduledBackupNow, DEFAULT_BACKUP_RETENTION_POLICY, type BackupHistoryItem, type BackupDestination, type BackupRetentionPolicy, type BackupSchedulerStatus } from "../utils/backup";
+import { getDataLayout, setDataRoot, chooseDataRoot, type DataLayout } from "../utils/dataLocation";
+import { chooseSpreadsheetPath, exportSpreadsheet, type SpreadsheetDataset, type OfferExportLayout } from "../utils/spreadsheetExport";
+import { getJournalStatus, restoreDataAsOf, type JournalStatus } from "../utils/journal";
+import { getSyncStatus, setSyncConfig, chooseSyncFolder, syncNow, getSyncConflicts, clearSyncConflicts, type SyncStatus, type SyncConflict } from "../utils/sync";
+import { listWorkspaces, createWorkspace, duplicateWorkspace, renameWorkspace, deleteWorkspace, switchWorkspace, workspaceDisplayName, type WorkspaceInfo, type WorkspaceList } from "../utils/workspaces";
 import { getLogHistory, type LogHistoryItem } from "../utils/logHistory";
 import { listAuditLogs, type AuditLogHistoryItem, auditSettingsChange } from "../utils/auditLog";
 import { cleanupOldAuditLogs } from "../utils/auditLogCleanup";
@@ -37,6 +42,12 @@ import { LogViewer } from "./LogViewer";
 import { AuditLogViewer } from "./AuditLogViewer";
 import { FactoryResetProgress } from "./FactoryResetProgress";
 import { SystemDiagnostics } from "./SystemDiagnostics";
+import { TableImportModal } from "./TableImportModal";
+import { TrashBinModal } from "./TrashBinModal";
+import { ConsistencyCheckModal } from "./ConsistencyCheckModal";
+import type { WebDavSettings } from "../utils/webdav";
+import { WebDavSettingsModal } from "./WebDavSettingsModal";
+import { DEFAULT_TRASH_RETENTION_DAYS } from "../utils/trash";
 import type { RawLibraryEntry } from "../utils/filamentLibrary";
 import {
   getLibrarySnapshot,
@@ -106,6 +117,24 @@ export const SettingsPage: React.FC<Props> = ({
   const [hideMacOSWarningTemporarily, setHideMacOSWarningTemporarily] = useState(false); // Csak az aktuális session-re
   const [showAutosaveModal, setShowAutosaveModal] = useState(false);
   const [backupHistory, setBackupHistory] = useState<BackupHistoryItem[]>([]);
+  const [backupDestinations, setBackupDestinations] = useState<BackupDestination[]>([]);
+  const [dataLayout, setDataLayout] = useState<DataLayout | null>(null);
+  const [showTableImport, setShowTableImport] = useState(false);
+  const [showTrashBin, setShowTrashBin] = useState(false);
+  const [showConsistencyCheck, setShowConsistencyCheck] = useState(false);
+  const [spreadsheetDatasets, setSpreadsheetDatasets] = useState<SpreadsheetDataset[]>(["offers", "customers", "financialSummary"]);
+  const [offerExportLayout, setOfferExportLayout] = useState<OfferExportLayout>("perOffer");
+  const [journalStatus, setJournalStatus] = useState<JournalStatus | null>(null);
+  const [journalRestoreAt, setJournalRestoreAt] = useState("");
+  const [workspaceList, setWorkspaceList] = useState<WorkspaceList | null>(null);
+  const [newWorkspaceName, setNewWorkspaceName] = useState("");
+  const [newWorkspaceCopySettings, setNewWorkspaceCopySettings] = useState(true);
+  const [syncStatus, setSyncStatus] = useState<SyncStatus | null>(null);
+  const [syncConflicts, setSyncConflicts] = useState<SyncConflict[]>([]);
+  const [isSyncing, setIsSyncing] = useState(false);
+  // Melyik beállításhoz nyílt meg a WebDAV párbeszédablak (backup cél vagy szinkronizálás)
+  const [webDavDialog, setWebDavDialog] = useState<"backup" | "sync" | null>(null);
+  const [schedulerStatus, setSchedulerStatus] = useState<BackupSchedulerStatus | null>(null);
   const [logHistory, setLogHistory] = useState<LogHistoryItem[]>([]);
   const [auditLogHistory, setAuditLogHistory] = useState<AuditLogHistoryItem[]>([]);
   const [previousAutosaveState, setPreviousAutosaveState] = useState<boolean | undefined>(settings.autosave);
@@ -296,6 +325,63 @@ export const SettingsPage: React.FC<Props> = ({
     }
   }, []);
 
+  // További backup célok (USB, NAS, ...) betöltése állapottal együtt
+  const loadBackupDestinations = useCallback(async () => {
+    try {
+      setBackupDestinations(await getBackupDestinations());
+    } catch (error) {
+      console.error("❌ Hiba a backup célok betöltésekor:", error);
+      setBackupDestinations([]);
+    }
+  }, []);
+
+  useEffect(() => {
+    loadBackupDestinations();
+  }, [loadBackupDestinations]);
+
+  // Adatkönyvtár (alapértelmezett / hordozható / választott / környezeti változó)
+  useEffect(() => {
+    getDataLayout()
+      .then(setDataLayout)
+      .catch(error => console.error("❌ Hiba az adatkönyvtár lekérésekor:", error));
+  }, []);
+
+  // Változásnapló állapota (legkorábbi visszaállítható időpont)
+  useEffect(() => {
+    getJournalStatus()
+      .then(setJournalStatus)
+      .catch(error => console.error("❌ Hiba a változásnapló állapotának lekérésekor:", error));
+  }, []);
+
+  // Munkaterületek (külön adatok, backupok és beállítások profilonként)
+  useEffect(() => {
+    listWorkspaces()
+      .then(setWorkspaceList)
+      .catch(error => console.error("❌ Hiba a munkaterületek lekérésekor:", error));
+  }, []);
+
+  // Mappa alapú szinkronizálás állapota és a legutóbbi ütközések
+  useEffect(() => {
+    getSyncStatus()
+      .then(setSyncStatus)
+      .catch(error => console.error("❌ Hiba a szinkronizálás állapotának lekérésekor:", error));
+    getSyncConflicts(5)
+      .then(setSyncConflicts)
+      .catch(error => console.error("❌ Hiba a szinkronizálási ütközések lekérésekor:", error));
+  }, []);
+
+  // Backend backup ütemező állapota (következő / utolsó backup, utolsó hiba)
+  useEffect(() => {
+    const loadSchedulerStatus = () => {
+      getBackupSchedulerStatus()
+        .then(setSchedulerStatus)
+        .catch(error => console.error("❌ Hiba a backup ütemező állapotának lekérdezésekor:", error));
+    };
+    loadSchedulerStatus();
+    const interval = setInterval(loadSchedulerStatus, 10000);
+    return () => clearInterval(interval);
+  }, []);
+
   // Backup history betöltése - mindig látható, függetlenül az autosave állapotától
   useEffect(() => {
     // Azonnal betöltjük
@@ -1898,12 +1984,9 @@ export const SettingsPage: React.FC<Props> = ({
       type: "warning",
       onConfirm: async () => {
         try {
-          const backupData = await restoreBackup();
-          if (backupData) {
-            if (backupData.printers) setPrinters(backupData.printers);
-            if (backupData.filaments) setFilaments(backupData.filaments);
-            if (backupData.offers) setOffers(backupData.offers);
-            if (backupData.settings) onChange(backupData.settings);
+          // A backend írja a Store-t, az App a "data-store-changed" eseményre újratölti az adatokat
+          const report = await restoreBackup();
+          if (report?.applied) {
             showToast(t("backup.restoreSuccess"), "success");
           }
         } catch (error) {
@@ -1913,6 +1996,194 @@ export const SettingsPage: React.FC<Props> = ({
     });
   };
 
+  const handleJournalRestore = () => {
+    if (!journalRestoreAt) {
+      return;
+    }
+    // A datetime-local mező helyi időt ad, a backend UTC időbélyegeket hasonlít össze
+    const timestamp = new Date(journalRestoreAt).toISOString();
+    const label = new Date(journalRestoreAt).toLocaleString(settings.language);
+    openConfirmDialog({
+      title: settings.language === "hu" ? "Visszaállítás időpontra" : settings.language === "de" ? "Auf Zeitpunkt zurücksetzen" : "Restore to point in time",
+      message:
+        settings.language === "hu"
+          ? `Az összes nyomtató, filament, árajánlat, ügyfél, projekt, feladat és sablon visszaáll erre az állapotra: ${label}. Előtte biztonsági mentés készül.`
+          : settings.language === "de"
+          ? `Alle Drucker, Filamente, Angebote, Kunden, Projekte, Aufgaben und Vorlagen werden auf den Stand vom ${label} zurückgesetzt. Vorher wird eine Sicherung erstellt.`
+          : `All printers, filaments, offers, customers, projects, tasks and templates will be restored to their state at ${label}. A safety backup is created first.`,
+      confirmText: settings.language === "hu" ? "Visszaállítás" : settings.language === "de" ? "Zurücksetzen" : "Restore",
+      cancelText: t("common.cancel"),
+      type: "warning",
+      onConfirm: async () => {
+        try {
+          // A backend írja a Store-t, az App a "data-store-changed" eseményre újratölti az adatokat
+          await restoreDataAsOf(timestamp);
+          setJournalStatus(await getJournalStatus());
+          showToast(
+            settings.language === "hu" ? "Adatok visszaállítva" : settings.language === "de" ? "Daten wiederhergestellt" : "Data restored",
+            "success"
+          );
+        } catch (error) {
+          console.error("❌ Point-in-time visszaállítás hiba:", error);
+          showToast(String(error), "error");
+        }
+      },
+    });
+  };
+
+  const handleCreateWorkspace = async () => {
+    try {
+      setWorkspaceList(await createWorkspace(newWorkspaceName, newWorkspaceCopySettings));
+      setNewWorkspaceName("");
+      showToast(
+        settings.language === "hu" ? "Munkaterület létrehozva" : settings.language === "de" ? "Arbeitsbereich erstellt" : "Workspace created",
+        "success"
+      );
+    } catch (error) {
+      console.error("❌ Munkaterület létrehozás hiba:", error);
+      showToast(String(error), "error");
+    }
+  };
+
+  const handleDuplicateWorkspace = async (workspace: WorkspaceInfo) => {
+    const name = window.prompt(
+      settings.language === "hu" ? "Az új munkaterület neve:" : settings.language === "de" ? "Name des neuen Arbeitsbereichs:" : "Name of the new workspace:",
+      `${workspaceDisplayName(workspace, settings.language)} (2)`
+    );
+    if (!name) {
+      return;
+    }
+    try {
+      setWorkspaceList(await duplicateWorkspace(workspace.id, name));
+      showToast(
+        settings.language === "hu" ? "Munkaterület duplikálva" : settings.language === "de" ? "Arbeitsbereich dupliziert" : "Workspace duplicated",
+        "success"
+      );
+    } catch (error) {
+      console.error("❌ Munkaterület duplikálás hiba:", error);
+      showToast(String(error), "error");
+    }
+  };
+
+  const handleRenameWorkspace = async (workspace: WorkspaceInfo) => {
+    const name = window.prompt(
+      settings.language === "hu" ? "A munkaterület új neve:" : settings.language === "de" ? "Neuer Name des Arbeitsbereichs:" : "New workspace name:",
+      workspace.name
+    );
+    if (!name) {
+      return;
+    }
+    try {
+      setWorkspaceList(await renameWorkspace(workspace.id, name));
+    } catch (error) {
+      console.error("❌ Munkaterület átnevezés hiba:", error);
+      showToast(String(error), "error");
+    }
+  };
+
+  const handleSwitchWorkspace = (workspace: WorkspaceInfo) => {
+    const name = workspaceDisplayName(workspace, settings.language);
+    openConfirmDialog({
+      title: settings.language === "hu" ? "Munkaterület váltás" : settings.language === "de" ? "Arbeitsbereich wechseln" : "Switch workspace",
+      message:
+        settings.language === "hu"
+          ? `Váltás erre a munkaterületre: ${name}. Az alkalmazás újratöltődik.`
+          : settings.language === "de"
+          ? `Zum Arbeitsbereich ${name} wechseln. Die Anwendung wird neu geladen.`
+          : `Switch to workspace ${name}. The application will reload.`,
+      confirmText: settings.language === "hu" ? "Váltás" : settings.language === "de" ? "Wechseln" : "Switch",
+      cancelText: t("common.cancel"),
+      type: "warning",
+      onConfirm: async () => {
+        try {
+          await switchWorkspace(workspace.id);
+        } catch (error) {
+          console.error("❌ Munkaterület váltás hiba:", error);
+          showToast(String(error), "error");
+        }
+      },
+    });
+  };
+
+  const handleDeleteWorkspace = (workspace: WorkspaceInfo) => {
+    const name = workspaceDisplayName(workspace, settings.language);
+    openConfirmDialog({
+      title: settings.language === "hu" ? "Munkaterület törlése" : settings.language === "de" ? "Arbeitsbereich löschen" : "Delete workspace",
+      message:
+        settings.language === "hu"
+          ? `A(z) ${name} munkaterület összes adata, backupja és naplója véglegesen törlődik. Ez nem vonható vissza.`
+          : settings.language === "de"
+          ? `Alle Daten, Sicherungen und Protokolle des Arbeitsbereichs ${name} werden endgültig gelöscht. Dies kann nicht rückgängig gemacht werden.`
+          : `All data, backups and logs of workspace ${name} will be permanently deleted. This cannot be undone.`,
+      confirmText: settings.language === "hu" ? "Törlés" : settings.language === "de" ? "Löschen" : "Delete",
+      cancelText: t("common.cancel"),
+      type: "danger",
+      onConfirm: async () => {
+        try {
+          setWorkspaceList(await deleteWorkspace(workspace.id));
+        } catch (error) {
+          console.error("❌ Munkaterület törlés hiba:", error);
+          showToast(String(error), "error");
+        }
+      },
+    });
+  };
+
+  const handleChooseSyncFolder = async () => {
+    try {
+      const status = await chooseSyncFolder(syncStatus?.intervalMinutes ?? 15);
+      if (status) {
+        setSyncStatus(status);
+      }
+    } catch (error) {
+      console.error("❌ Szinkronizálási mappa beállítás hiba:", error);
+      showToast(String(error), "error");
+    }
+  };
+
+  const handleSyncConfigChange = async (folder: string | null, intervalMinutes: number, webdav: WebDavSettings | null = null) => {
+    try {
+      setSyncStatus(await setSyncConfig(folder, intervalMinutes, undefined, webdav));
+    } catch (error) {
+      console.error("❌ Szinkronizálás beállítás hiba:", error);
+      showToast(String(error), "error");
+    }
+  };
+
+  const handleSyncNow = async () => {
+    setIsSyncing(true);
+    try {
+      // Ha a Store változott, az App a "data-store-changed" eseményre újratölti az adatokat
+      const report = await syncNow();
+      setSyncStatus(await getSyncStatus());
+      setSyncConflicts(await getSyncConflicts(5));
+      showToast(
+        settings.language === "hu"
+          ? `Szinkronizálva: ${report.exported} helyi és ${report.applied} távoli változás, ${report.conflicts} ütközés`
+          : settings.language === "de"
+          ? `Synchronisiert: ${report.exported} lokale und ${report.applied} entfernte Änderungen, ${report.conflicts} Konflikte`
+          : `Synced: ${report.exported} local and ${report.applied} remote changes, ${report.conflicts} conflicts`,
+        "success"
+      );
+    } catch (error) {
+      console.error("❌ Szinkronizálás hiba:", error);
+      showToast(String(error), "error");
+    } finally {
+      setIsSyncing(false);
+    }
+  };
+
+  const handleClearSyncConflicts = async () => {
+    try {
+      await clearSyncConflicts();
+      setSyncConflicts([]);
+      setSyncStatus(await getSyncStatus());
+    } catch (error) {
+      console.error("❌ Ütközésnapló törlés hiba:", error);
+      showToast(String(error), "error");
+    }
+  };
+
   const handleFactoryReset = () => {
     openConfirmDialog({
       title: t("settings.backup.factoryResetTitle"),
@@ -3388,132 +3659,1034 @@ export const SettingsPage: React.FC<Props> = ({
             </div>
           )}
 
-          {/* Backup History - mindig látható, hasonlóan a log history-hoz */}
-          <div data-tutorial="backup-history-section" style={{ marginTop: "24px", marginLeft: "32px" }}>
-            <div style={{ display: "flex", alignItems: "center", gap: "12px", marginBottom: "12px" }}>
-              <h3 style={{ 
-                fontSize: "16px", 
-                fontWeight: 600, 
-                color: theme.colors.text,
-                margin: 0
-              }}>
-                📋 {t("settings.backup.history.title")}
-              </h3>
-              <Tooltip content={t("settings.backup.history.openFolderTooltip")}>
+          {/* Ütemezett automatikus backup - a backend végzi, akkor is, ha a felület épp foglalt */}
+          <div style={{ marginTop: "16px", marginLeft: "32px" }}>
+            <label style={{
+              display: "flex",
+              alignItems: "center",
+              gap: "12px",
+              fontSize: "14px",
+              color: theme.colors.background?.includes('gradient') ? "#1a202c" : theme.colors.text,
+              cursor: "pointer"
+            }}>
+              <input
+                type="checkbox"
+                checked={settings.automaticBackupEnabled === true}
+                onChange={e => onChange({ ...settings, automaticBackupEnabled: e.target.checked })}
+                style={{ width: "18px", height: "18px", cursor: "pointer" }}
+              />
+              <span>
+                ⏰ {settings.language === "hu"
+                  ? "Ütemezett automatikus backup"
+                  : settings.language === "de"
+                  ? "Geplantes automatisches Backup"
+                  : "Scheduled automatic backups"}
+              </span>
+            </label>
+            {settings.automaticBackupEnabled === true && (
+              <div style={{ display: "flex", flexWrap: "wrap", alignItems: "center", gap: "12px", marginTop: "8px", marginLeft: "30px", fontSize: "12px", color: theme.colors.text }}>
+                <label style={{ display: "flex", alignItems: "center", gap: "4px" }}>
+                  {settings.language === "hu" ? "Gyakoriság (óra)" : settings.language === "de" ? "Intervall (Stunden)" : "Interval (hours)"}
+                  <input
+                    type="number"
+                    min={1}
+                    value={settings.automaticBackupIntervalHours ?? 24}
+                    onChange={e => onChange({ ...settings, automaticBackupIntervalHours: Math.max(1, Number(e.target.value) || 1) })}
+                    style={{ ...themeStyles.input, width: "64px", padding: "4px 6px", fontSize: "12px" }}
+                  />
+                </label>
                 <button
+                  disabled={schedulerStatus?.inProgress === true}
                   onClick={async () => {
                     try {
-                      const backupDirPath = await invoke<string>("get_backup_directory_path");
-                      await invoke("open_directory", { path: backupDirPath });
+                      await runScheduledBackupNow();
+                      showToast(
+                        settings.language === "hu" ? "Backup elindítva" : settings.language === "de" ? "Backup gestartet" : "Backup started",
+                        "success"
+                      );
                     } catch (error) {
-                      console.error("Backup mappa megnyitási hiba:", error);
-                      showToast(t("settings.backup.history.openFolderError"), "error");
+                      console.error("❌ Backup indítási hiba:", error);
+                      showToast(String(error), "error");
                     }
                   }}
-                  style={{
-                    ...themeStyles.button,
-                    ...themeStyles.buttonSecondary,
-                    padding: "6px 14px",
-                    fontSize: "12px",
-                  }}
+                  style={{ ...themeStyles.button, ...themeStyles.buttonSecondary, padding: "4px 12px", fontSize: "12px" }}
                 >
-                  📁 {t("settings.backup.history.openFolder")}
+                  ▶️ {settings.language === "hu" ? "Backup most" : settings.language === "de" ? "Jetzt sichern" : "Back up now"}
                 </button>
-              </Tooltip>
-            </div>
-            
-            {/* Információs szöveg - megjelenítés, színezés, törlés magyarázata */}
-            <Tooltip content={t("settings.backup.history.clickToOpen")}>
-              <p style={{ 
-                marginBottom: "12px", 
-                fontSize: "12px", 
-                color: theme.colors.textMuted,
-                lineHeight: "1.5"
-              }}>
-                {t("settings.backup.history.description")}
-              </p>
-            </Tooltip>
-            
-            {/* Színezés magyarázata */}
-            <div style={{
-              marginBottom: "12px",
-              padding: "10px",
-              backgroundColor: `${theme.colors.primary}10`,
-              borderRadius: "8px",
-              border: `1px solid ${theme.colors.primary}30`,
-              fontSize: "11px",
-            }}>
-              <div style={{ 
-                fontWeight: 600, 
-                color: theme.colors.text,
-                marginBottom: "6px"
-              }}>
-                {t("settings.backup.history.colorExplanation")}
-              </div>
-              <div style={{ 
-                display: "flex", 
-                flexDirection: "column", 
-                gap: "4px",
-                color: theme.colors.textMuted 
-              }}>
-                <div>{t("settings.backup.history.colorGreen")}</div>
-                <div>{t("settings.backup.history.colorYellow")}</div>
-                <div>{t("settings.backup.history.colorRed")}</div>
-                <div>{t("settings.backup.history.colorGray")}</div>
-              </div>
-              <div style={{ 
-                marginTop: "8px", 
-                paddingTop: "8px",
-                borderTop: `1px solid ${theme.colors.border}40`,
-                fontSize: "10px",
-                fontStyle: "italic",
-                color: theme.colors.textMuted 
-              }}>
-                {t("settings.backup.history.deletionInfo")}
+                {schedulerStatus?.nextBackupAt && (
+                  <span style={{ color: theme.colors.textMuted }}>
+                    {settings.language === "hu" ? "Következő:" : settings.language === "de" ? "Nächstes:" : "Next:"} {new Date(schedulerStatus.nextBackupAt).toLocaleString()}
+                  </span>
+                )}
+                {schedulerStatus?.lastError && (
+                  <span style={{ color: "#dc3545" }}>⚠️ {schedulerStatus.lastError}</span>
+                )}
               </div>
-            </div>
-            
-            {backupHistory.length > 0 ? (
-              <div style={{
-                display: "flex",
-                flexDirection: "column",
-                gap: "8px",
-                maxHeight: "300px",
-                overflowY: "auto",
-                padding: "8px",
-                backgroundColor: theme.colors.surface,
-                borderRadius: "8px",
-                border: `1px solid ${theme.colors.border}`,
-              }}>
-                {backupHistory.map((item, index) => {
-                  const getStatusColor = () => {
-                    if (item.daysOld === 0) return "#22c55e"; // Zöld - mai
-                    if (item.daysOld === 1) return "#eab308"; // Sárga - tegnap
-                    if (item.daysOld >= 2 && item.daysOld < 5) return "#ef4444"; // Piros - 2-4 nap
-                    return "#6b7280"; // Szürke - 5+ nap (hamarosan törlődik)
-                  };
-
-                  const getStatusText = () => {
-                    if (item.daysOld === 0) {
-                      return t("settings.backup.history.today");
-                    }
-                    if (item.daysOld === 1) {
-                      return t("settings.backup.history.yesterday");
-                    }
-                    if (item.daysOld >= 2 && item.daysOld < 5) {
-                      return t("settings.backup.history.daysAgo", { days: item.daysOld });
-                    }
-                    return t("settings.backup.history.daysAgoWillDelete", { days: item.daysOld });
-                  };
-
-                  const statusColor = getStatusColor();
-                  const dateStr = item.date.toLocaleString(settings.language === "hu" ? "hu-HU" : settings.language === "de" ? "de-DE" : "en-US", {
-                    year: "numeric",
-                    month: "2-digit",
-                    day: "2-digit",
-                    hour: "2-digit",
-                    minute: "2-digit",
-                  });
+            )}
+          </div>
+
+          {/* Inkrementális backup - a változatlan rekordok és képek csak egyszer tárolódnak */}
+          <div style={{ marginTop: "16px", marginLeft: "32px" }}>
+            <label style={{
+              display: "flex",
+              alignItems: "center",
+              gap: "12px",
+              fontSize: "14px",
+              color: theme.colors.background?.includes('gradient') ? "#1a202c" : theme.colors.text,
+              cursor: "pointer"
+            }}>
+              <input
+                type="checkbox"
+                checked={settings.incrementalBackupsEnabled === true}
+                disabled={settings.backupEncryptionEnabled === true}
+                onChange={e => onChange({ ...settings, incrementalBackupsEnabled: e.target.checked })}
+                style={{ width: "18px", height: "18px", cursor: "pointer" }}
+              />
+              <span>
+                🧩 {settings.language === "hu"
+                  ? "Inkrementális (deduplikált) automatikus backup"
+                  : settings.language === "de"
+                  ? "Inkrementelles (dedupliziertes) automatisches Backup"
+                  : "Incremental (deduplicated) automatic backups"}
+              </span>
+            </label>
+            <p style={{ marginTop: "4px", marginLeft: "30px", fontSize: "12px", color: theme.colors.textMuted }}>
+              {settings.language === "hu"
+                ? "Csak a megváltozott rekordok és képek mentődnek; titkosított backup mellett nem érhető el."
+                : settings.language === "de"
+                ? "Nur geänderte Datensätze und Bilder werden gespeichert; nicht mit verschlüsselten Backups verfügbar."
+                : "Only changed records and images are stored; not available with encrypted backups."}
+            </p>
+          </div>
+
+          {/* Backup titkosítás - a jelmondat csak memóriában él, indítás után újra kell adni */}
+          <div style={{ marginTop: "16px", marginLeft: "32px" }}>
+            <label style={{
+              display: "flex",
+              alignItems: "center",
+              gap: "12px",
+              fontSize: "14px",
+              color: theme.colors.background?.includes('gradient') ? "#1a202c" : theme.colors.text,
+              cursor: "pointer"
+            }}>
+              <input
+                type="checkbox"
+                checked={settings.backupEncryptionEnabled === true}
+                onChange={async (e) => {
+                  const newValue = e.target.checked;
+                  try {
+                    if (newValue) {
+                      const passphrase = window.prompt(
+                        settings.language === "hu"
+                          ? "Backup jelmondat (legalább 8 karakter):"
+                          : settings.language === "de"
+                          ? "Backup-Passphrase (mindestens 8 Zeichen):"
+                          : "Backup passphrase (at least 8 characters):"
+                      );
+                      if (passphrase === null) {
+                        return;
+                      }
+                      await setBackupPassphrase(passphrase);
+                    } else {
+                      await setBackupPassphrase(null);
+                    }
+                    onChange({ ...settings, backupEncryptionEnabled: newValue });
+                    try {
+                      await auditSettingsChange("backupEncryptionEnabled", settings.backupEncryptionEnabled, newValue);
+                    } catch (error) {
+                      console.warn("Audit log hiba:", error);
+                    }
+                  } catch (error) {
+                    console.error("❌ Backup jelmondat beállítási hiba:", error);
+                    showToast(String(error), "error");
+                  }
+                }}
+                style={{ width: "18px", height: "18px", cursor: "pointer" }}
+              />
+              <span>
+                🔒 {settings.language === "hu"
+                  ? "Backupok titkosítása jelmondattal"
+                  : settings.language === "de"
+                  ? "Backups mit Passphrase verschlüsseln"
+                  : "Encrypt backups with a passphrase"}
+              </span>
+            </label>
+            <p style={{ marginTop: "4px", marginLeft: "30px", fontSize: "12px", color: theme.colors.textMuted }}>
+              {settings.language === "hu"
+                ? "A jelmondatot nem tároljuk; elvesztése esetén a titkosított backupok nem állíthatók vissza."
+                : settings.language === "de"
+                ? "Die Passphrase wird nicht gespeichert; ohne sie können verschlüsselte Backups nicht wiederhergestellt werden."
+                : "The passphrase is not stored; encrypted backups cannot be restored without it."}
+            </p>
+          </div>
+
+          {/* GFS megőrzés - órás / napi / heti / havi backupok megtartása a darabszám alapú takarítás helyett */}
+          <div style={{ marginTop: "16px", marginLeft: "32px" }}>
+            <label style={{
+              display: "flex",
+              alignItems: "center",
+              gap: "12px",
+              fontSize: "14px",
+              color: theme.colors.background?.includes('gradient') ? "#1a202c" : theme.colors.text,
+              cursor: "pointer"
+            }}>
+              <input
+                type="checkbox"
+                checked={settings.backupRetentionPolicy !== undefined}
+                onChange={e => onChange({
+                  ...settings,
+                  backupRetentionPolicy: e.target.checked ? { ...DEFAULT_BACKUP_RETENTION_POLICY } : undefined,
+                })}
+                style={{ width: "18px", height: "18px", cursor: "pointer" }}
+              />
+              <span>
+                🗓️ {settings.language === "hu"
+                  ? "GFS megőrzés (órás / napi / heti / havi backupok)"
+                  : settings.language === "de"
+                  ? "GFS-Aufbewahrung (stündliche / tägliche / wöchentliche / monatliche Backups)"
+                  : "GFS retention (hourly / daily / weekly / monthly backups)"}
+              </span>
+            </label>
+            {settings.backupRetentionPolicy && (
+              <div style={{ display: "flex", flexWrap: "wrap", alignItems: "center", gap: "12px", marginTop: "8px", marginLeft: "30px", fontSize: "12px", color: theme.colors.text }}>
+                {(["hourly", "daily", "weekly", "monthly"] as (keyof BackupRetentionPolicy)[]).map(tier => (
+                  <label key={tier} style={{ display: "flex", alignItems: "center", gap: "4px" }}>
+                    {settings.language === "hu"
+                      ? { hourly: "Órás", daily: "Napi", weekly: "Heti", monthly: "Havi" }[tier]
+                      : settings.language === "de"
+                      ? { hourly: "Stündlich", daily: "Täglich", weekly: "Wöchentlich", monthly: "Monatlich" }[tier]
+                      : { hourly: "Hourly", daily: "Daily", weekly: "Weekly", monthly: "Monthly" }[tier]}
+                    <input
+                      type="number"
+                      min={0}
+                      value={settings.backupRetentionPolicy?.[tier] ?? 0}
+                      onChange={e => onChange({
+                        ...settings,
+                        backupRetentionPolicy: {
+                          ...(settings.backupRetentionPolicy ?? DEFAULT_BACKUP_RETENTION_POLICY),
+                          [tier]: Math.max(0, Number(e.target.value) || 0),
+                        },
+                      })}
+                      style={{ ...themeStyles.input, width: "64px", padding: "4px 6px", fontSize: "12px" }}
+                    />
+                  </label>
+                ))}
+                <button
+                  onClick={async () => {
+                    try {
+                      const plan = await previewBackupRetention(settings.backupRetentionPolicy ?? DEFAULT_BACKUP_RETENTION_POLICY);
+                      const header = settings.language === "hu"
+                        ? `A következő takarítás ${plan.delete.length} backupot töröl, ${plan.keep.length} marad meg.`
+                        : settings.language === "de"
+                        ? `Die nächste Bereinigung löscht ${plan.delete.length} Backups, ${plan.keep.length} bleiben erhalten.`
+                        : `The next cleanup will delete ${plan.delete.length} backups and keep ${plan.keep.length}.`;
+                      window.alert([header, "", ...plan.delete.map(entry => `🗑️ ${entry.fileName}`)].join("\n"));
+                    } catch (error) {
+                      console.error("❌ GFS előnézet hiba:", error);
+                      showToast(String(error), "error");
+                    }
+                  }}
+                  style={{ ...themeStyles.button, ...themeStyles.buttonSecondary, padding: "4px 12px", fontSize: "12px" }}
+                >
+                  👁️ {settings.language === "hu" ? "Előnézet" : settings.language === "de" ? "Vorschau" : "Preview"}
+                </button>
+              </div>
+            )}
+            <p style={{ marginTop: "4px", marginLeft: "30px", fontSize: "12px", color: theme.colors.textMuted }}>
+              {settings.language === "hu"
+                ? "Időszakonként a legújabb backup marad meg; a 📌 rögzített backupok soha nem törlődnek."
+                : settings.language === "de"
+                ? "Pro Zeitraum bleibt das neueste Backup erhalten; 📌 fixierte Backups werden nie gelöscht."
+                : "The newest backup of each period is kept; 📌 pinned backups are never deleted."}
+            </p>
+          </div>
+
+          {/* További backup célok - második mappa, USB meghajtó, csatolt NAS megosztás */}
+          <div style={{ marginTop: "24px", marginLeft: "32px" }}>
+            <div style={{ display: "flex", alignItems: "center", gap: "12px", marginBottom: "12px" }}>
+              <h3 style={{ fontSize: "16px", fontWeight: 600, color: theme.colors.text, margin: 0 }}>
+                💽 {settings.language === "hu" ? "További backup célok" : settings.language === "de" ? "Weitere Backup-Ziele" : "Additional backup destinations"}
+              </h3>
+              <button
+                onClick={async () => {
+                  try {
+                    const destination = await addBackupDestination({ retentionCount: 10 });
+                    if (destination) {
+                      await loadBackupDestinations();
+                    }
+                  } catch (error) {
+                    console.error("❌ Backup cél hozzáadási hiba:", error);
+                    showToast(String(error), "error");
+                  }
+                }}
+                style={{ ...themeStyles.button, ...themeStyles.buttonSecondary, padding: "6px 14px", fontSize: "12px" }}
+              >
+                ➕ {settings.language === "hu" ? "Mappa hozzáadása" : settings.language === "de" ? "Ordner hinzufügen" : "Add folder"}
+              </button>
+              <button
+                onClick={() => setWebDavDialog("backup")}
+                style={{ ...themeStyles.button, ...themeStyles.buttonSecondary, padding: "6px 14px", fontSize: "12px" }}
+              >
+                ☁️ WebDAV / Nextcloud
+              </button>
+              {backupDestinations.length > 0 && (
+                <button
+                  onClick={async () => {
+                    try {
+                      const results = await replicateLatestBackup();
+                      const failed = results.filter(r => !r.success).length;
+                      showToast(
+                        settings.language === "hu"
+                          ? `Másolás: ${results.length - failed} sikeres, ${failed} sikertelen`
+                          : settings.language === "de"
+                          ? `Kopieren: ${results.length - failed} erfolgreich, ${failed} fehlgeschlagen`
+                          : `Copy: ${results.length - failed} succeeded, ${failed} failed`,
+                        failed > 0 ? "error" : "success"
+                      );
+                    } catch (error) {
+                      console.error("❌ Backup másolási hiba:", error);
+                      showToast(String(error), "error");
+                    }
+                    await loadBackupDestinations();
+                  }}
+                  style={{ ...themeStyles.button, ...themeStyles.buttonSecondary, padding: "6px 14px", fontSize: "12px" }}
+                >
+                  🔁 {settings.language === "hu" ? "Másolás most" : settings.language === "de" ? "Jetzt kopieren" : "Copy now"}
+                </button>
+              )}
+            </div>
+            <p style={{ marginBottom: "12px", fontSize: "12px", color: theme.colors.textMuted }}>
+              {settings.language === "hu"
+                ? "Minden automatikus backup ellenőrzött másolata ide is elkészül (3DPrinterCalcApp_backups alkönyvtár), célonkénti megőrzéssel."
+                : settings.language === "de"
+                ? "Jedes automatische Backup wird zusätzlich verifiziert hierher kopiert (Unterordner 3DPrinterCalcApp_backups), mit eigener Aufbewahrung pro Ziel."
+                : "Every automatic backup is also copied here with verification (3DPrinterCalcApp_backups subfolder), with per-destination retention."}
+            </p>
+            {backupDestinations.map(destination => (
+              <div
+                key={destination.id}
+                style={{
+                  display: "flex",
+                  alignItems: "center",
+                  gap: "12px",
+                  padding: "8px 12px",
+                  marginBottom: "8px",
+                  borderRadius: "8px",
+                  border: `1px solid ${destination.status?.lastError && (destination.status.lastErrorAt ?? "") > (destination.status.lastSuccessAt ?? "") ? "#dc3545" : theme.colors.border}`,
+                  fontSize: "12px",
+                  color: theme.colors.text,
+                }}
+              >
+                <input
+                  type="checkbox"
+                  checked={destination.enabled}
+                  onChange={async e => {
+                    try {
+                      await saveBackupDestination({ ...destination, enabled: e.target.checked });
+                      await loadBackupDestinations();
+                    } catch (error) {
+                      showToast(String(error), "error");
+                    }
+                  }}
+                  style={{ width: "16px", height: "16px", cursor: "pointer" }}
+                />
+                <div style={{ flex: 1, minWidth: 0 }}>
+                  <div style={{ fontWeight: 600 }}>{destination.name}</div>
+                  <div style={{ color: theme.colors.textMuted, overflow: "hidden", textOverflow: "ellipsis" }}>{destination.path}</div>
+                  {destination.status?.lastSuccessAt && (
+                    <div style={{ color: "#22c55e" }}>
+                      ✓ {new Date(destination.status.lastSuccessAt).toLocaleString()} ({destination.status.backupCount})
+                    </div>
+                  )}
+                  {destination.status?.lastError && (
+                    <div style={{ color: "#dc3545" }}>
+                      ⚠️ {destination.status.lastErrorAt ? new Date(destination.status.lastErrorAt).toLocaleString() : ""} {destination.status.lastError}
+                    </div>
+                  )}
+                </div>
+                <label style={{ display: "flex", alignItems: "center", gap: "4px" }}>
+                  {settings.language === "hu" ? "Megőrzés (db)" : settings.language === "de" ? "Aufbewahrung (Anz.)" : "Keep (count)"}
+                  <input
+                    type="number"
+                    min={1}
+                    value={destination.retentionCount ?? ""}
+                    onChange={async e => {
+                      const value = e.target.value === "" ? null : Math.max(1, Number(e.target.value));
+                      try {
+                        await saveBackupDestination({ ...destination, retentionCount: value });
+                        await loadBackupDestinations();
+                      } catch (error) {
+                        showToast(String(error), "error");
+                      }
+                    }}
+                    style={{ ...themeStyles.input, width: "64px", padding: "4px 6px", fontSize: "12px" }}
+                  />
+                </label>
+                <label style={{ display: "flex", alignItems: "center", gap: "4px" }}>
+                  {settings.language === "hu" ? "Max. kor (nap)" : settings.language === "de" ? "Max. Alter (Tage)" : "Max age (days)"}
+                  <input
+                    type="number"
+                    min={1}
+                    value={destination.retentionDays ?? ""}
+                    onChange={async e => {
+                      const value = e.target.value === "" ? null : Math.max(1, Number(e.target.value));
+                      try {
+                        await saveBackupDestination({ ...destination, retentionDays: value });
+                        await loadBackupDestinations();
+                      } catch (error) {
+                        showToast(String(error), "error");
+                      }
+                    }}
+                    style={{ ...themeStyles.input, width: "64px", padding: "4px 6px", fontSize: "12px" }}
+                  />
+                </label>
+                <button
+                  onClick={async () => {
+                    try {
+                      await removeBackupDestination(destination.id);
+                      await loadBackupDestinations();
+                    } catch (error) {
+                      showToast(String(error), "error");
+                    }
+                  }}
+                  style={{ ...themeStyles.button, ...themeStyles.buttonDanger, padding: "4px 10px", fontSize: "12px" }}
+                >
+                  🗑️
+                </button>
+              </div>
+            ))}
+          </div>
+
+          {/* Adatkönyvtár - hol tárolja az alkalmazás az adatokat, backupokat és logokat */}
+          {dataLayout && (
+            <div style={{ marginTop: "24px", marginLeft: "32px" }}>
+              <div style={{ display: "flex", alignItems: "center", gap: "12px", marginBottom: "12px" }}>
+                <h3 style={{ fontSize: "16px", fontWeight: 600, color: theme.colors.text, margin: 0 }}>
+                  🗂️ {settings.language === "hu" ? "Adatkönyvtár" : settings.language === "de" ? "Datenverzeichnis" : "Data location"}
+                </h3>
+                {(dataLayout.mode === "default" || dataLayout.mode === "custom") && (
+                  <button
+                    onClick={async () => {
+                      try {
+                        const copyExisting = window.confirm(
+                          settings.language === "hu"
+                            ? "Átmásoljuk a jelenlegi adatokat (Store, backupok, logok) az új helyre?"
+                            : settings.language === "de"
+                            ? "Aktuelle Daten (Store, Backups, Logs) an den neuen Ort kopieren?"
+                            : "Copy the current data (store, backups, logs) to the new location?"
+                        );
+                        const layout = await chooseDataRoot(copyExisting);
+                        if (layout) {
+                          setDataLayout(layout);
+                        }
+                      } catch (error) {
+                        console.error("❌ Adatkönyvtár beállítási hiba:", error);
+                        showToast(String(error), "error");
+                      }
+                    }}
+                    style={{ ...themeStyles.button, ...themeStyles.buttonSecondary, padding: "6px 14px", fontSize: "12px" }}
+                  >
+                    📂 {settings.language === "hu" ? "Mappa választása" : settings.language === "de" ? "Ordner wählen" : "Choose folder"}
+                  </button>
+                )}
+                {dataLayout.customRoot && (
+                  <button
+                    onClick={async () => {
+                      try {
+                        setDataLayout(await setDataRoot(null, false));
+                      } catch (error) {
+                        showToast(String(error), "error");
+                      }
+                    }}
+                    style={{ ...themeStyles.button, ...themeStyles.buttonSecondary, padding: "6px 14px", fontSize: "12px" }}
+                  >
+                    ↩️ {settings.language === "hu" ? "Alapértelmezett" : settings.language === "de" ? "Standard" : "Default"}
+                  </button>
+                )}
+              </div>
+              <div style={{ fontSize: "12px", color: theme.colors.textMuted, display: "grid", gap: "4px" }}>
+                <div>
+                  {settings.language === "hu" ? "Mód" : settings.language === "de" ? "Modus" : "Mode"}:{" "}
+                  <strong>
+                    {dataLayout.mode === "portable"
+                      ? settings.language === "hu" ? "hordozható" : settings.language === "de" ? "portabel" : "portable"
+                      : dataLayout.mode === "custom"
+                      ? settings.language === "hu" ? "választott mappa" : settings.language === "de" ? "gewählter Ordner" : "custom folder"
+                      : dataLayout.mode === "environment"
+                      ? dataLayout.envVariable
+                      : settings.language === "hu" ? "alapértelmezett" : settings.language === "de" ? "Standard" : "default"}
+                  </strong>
+                </div>
+                <div>📁 {dataLayout.root}</div>
+                <div>💾 {dataLayout.storeFile}</div>
+                {dataLayout.restartRequired && (
+                  <div style={{ color: theme.colors.danger }}>
+                    ⚠️ {settings.language === "hu"
+                      ? `Az új adatkönyvtár (${dataLayout.customRoot ?? "alapértelmezett"}) az alkalmazás újraindítása után lép életbe.`
+                      : settings.language === "de"
+                      ? `Das neue Datenverzeichnis (${dataLayout.customRoot ?? "Standard"}) wird nach einem Neustart verwendet.`
+                      : `The new data location (${dataLayout.customRoot ?? "default"}) takes effect after restarting the app.`}
+                  </div>
+                )}
+                {dataLayout.mode === "default" && (
+                  <div>
+                    {settings.language === "hu"
+                      ? `Hordozható mód: helyezzen egy ${dataLayout.portableMarker} fájlt az alkalmazás mellé.`
+                      : settings.language === "de"
+                      ? `Portabler Modus: legen Sie eine Datei ${dataLayout.portableMarker} neben die Anwendung.`
+                      : `Portable mode: place a ${dataLayout.portableMarker} file next to the application.`}
+                  </div>
+                )}
+              </div>
+            </div>
+          )}
+
+          {/* Tömeges import CSV / XLSX fájlból (filamentek, nyomtatók, ügyfelek) */}
+          <div style={{ marginTop: "24px", marginLeft: "32px" }}>
+            <div style={{ display: "flex", alignItems: "center", gap: "12px", marginBottom: "8px" }}>
+              <h3 style={{ fontSize: "16px", fontWeight: 600, color: theme.colors.text, margin: 0 }}>
+                📥 {settings.language === "hu" ? "Import táblázatból" : settings.language === "de" ? "Import aus Tabelle" : "Import from spreadsheet"}
+              </h3>
+              <button
+                onClick={() => setShowTableImport(true)}
+                style={{ ...themeStyles.button, ...themeStyles.buttonSecondary, padding: "6px 14px", fontSize: "12px" }}
+              >
+                📄 CSV / XLSX
+              </button>
+            </div>
+            <div style={{ fontSize: "12px", color: theme.colors.textMuted }}>
+              {settings.language === "hu"
+                ? "Filamentek, nyomtatók és ügyfelek felvétele CSV, XLSX vagy ODS fájlból, oszlop-hozzárendeléssel és előnézettel."
+                : settings.language === "de"
+                ? "Filamente, Drucker und Kunden aus CSV-, XLSX- oder ODS-Dateien mit Spaltenzuordnung und Vorschau anlegen."
+                : "Create filaments, printers and customers from CSV, XLSX or ODS files with column mapping and a preview."}
+            </div>
+          </div>
+
+          {/* Táblázat export (XLSX / ODS / CSV) - pl. a könyvelő számára */}
+          <div style={{ marginTop: "24px", marginLeft: "32px" }}>
+            <div style={{ display: "flex", alignItems: "center", gap: "12px", marginBottom: "8px", flexWrap: "wrap" }}>
+              <h3 style={{ fontSize: "16px", fontWeight: 600, color: theme.colors.text, margin: 0 }}>
+                📊 {settings.language === "hu" ? "Export táblázatba" : settings.language === "de" ? "Tabellenexport" : "Spreadsheet export"}
+              </h3>
+              <button
+                disabled={spreadsheetDatasets.length === 0}
+                onClick={async () => {
+                  try {
+                    const target = await chooseSpreadsheetPath(`3dprintercalc_${new Date().toISOString().split("T")[0]}`);
+                    if (!target) {
+                      return;
+                    }
+                    const result = await exportSpreadsheet({
+                      ...target,
+                      datasets: spreadsheetDatasets,
+                      offerLayout: offerExportLayout,
+                      language: settings.language,
+                      decimalComma: settings.language !== "en",
+                    });
+                    showToast(
+                      settings.language === "hu"
+                        ? `Export kész: ${result.files.join(", ")}`
+                        : settings.language === "de"
+                        ? `Export abgeschlossen: ${result.files.join(", ")}`
+                        : `Export finished: ${result.files.join(", ")}`,
+                      "success"
+                    );
+                  } catch (error) {
+                    console.error("❌ Táblázat export hiba:", error);
+                    showToast(String(error), "error");
+                  }
+                }}
+                style={{
+                  ...themeStyles.button,
+                  ...themeStyles.buttonSecondary,
+                  padding: "6px 14px",
+                  fontSize: "12px",
+                  opacity: spreadsheetDatasets.length === 0 ? 0.5 : 1,
+                }}
+              >
+                💾 XLSX / ODS / CSV
+              </button>
+            </div>
+            <div style={{ display: "flex", gap: "16px", flexWrap: "wrap", alignItems: "center", fontSize: "12px", color: theme.colors.text }}>
+              {([
+                ["offers", settings.language === "hu" ? "Árajánlatok" : settings.language === "de" ? "Angebote" : "Offers"],
+                ["customers", settings.language === "hu" ? "Ügyfelek" : settings.language === "de" ? "Kunden" : "Customers"],
+                ["projects", settings.language === "hu" ? "Projektek" : settings.language === "de" ? "Projekte" : "Projects"],
+                ["tasks", settings.language === "hu" ? "Feladatok" : settings.language === "de" ? "Aufgaben" : "Tasks"],
+                ["financialSummary", settings.language === "hu" ? "Havi pénzügyi összesítő" : settings.language === "de" ? "Monatliche Finanzübersicht" : "Monthly financial summary"],
+              ] as [SpreadsheetDataset, string][]).map(([dataset, label]) => (
+                <label key={dataset} style={{ display: "flex", alignItems: "center", gap: "6px" }}>
+                  <input
+                    type="checkbox"
+                    checked={spreadsheetDatasets.includes(dataset)}
+                    onChange={e =>
+                      setSpreadsheetDatasets(prev =>
+                        e.target.checked ? [...prev, dataset] : prev.filter(item => item !== dataset)
+                      )
+                    }
+                  />
+                  {label}
+                </label>
+              ))}
+              <select
+                value={offerExportLayout}
+                onChange={e => setOfferExportLayout(e.target.value as OfferExportLayout)}
+                disabled={!spreadsheetDatasets.includes("offers")}
+                style={{ ...themeStyles.select, padding: "6px 10px", fontSize: "12px" }}
+              >
+                <option value="perOffer">
+                  {settings.language === "hu" ? "Egy sor / árajánlat" : settings.language === "de" ? "Eine Zeile pro Angebot" : "One row per offer"}
+                </option>
+                <option value="perFilament">
+                  {settings.language === "hu" ? "Egy sor / filament tétel" : settings.language === "de" ? "Eine Zeile pro Filamentposition" : "One row per filament line"}
+                </option>
+              </select>
+            </div>
+          </div>
+
+          {/* Munkaterületek - külön adatok, backupok, audit log, cégadatok és beállítások profilonként */}
+          {workspaceList && (
+            <div style={{ marginTop: "24px", marginLeft: "32px" }}>
+              <h3 style={{ fontSize: "16px", fontWeight: 600, color: theme.colors.text, margin: "0 0 8px 0" }}>
+                🗂️ {settings.language === "hu" ? "Munkaterületek" : settings.language === "de" ? "Arbeitsbereiche" : "Workspaces"}
+              </h3>
+              <div style={{ display: "flex", flexDirection: "column", gap: "6px", marginBottom: "8px" }}>
+                {workspaceList.workspaces.map(workspace => (
+                  <div key={workspace.id} style={{ display: "flex", alignItems: "center", gap: "8px", flexWrap: "wrap", fontSize: "13px", color: theme.colors.text }}>
+                    <span style={{ fontWeight: workspace.isActive ? 600 : 400, minWidth: "160px" }}>
+                      {workspace.isActive ? "● " : "○ "}
+                      {workspaceDisplayName(workspace, settings.language)}
+                      {workspace.vaultEnabled ? " 🔒" : ""}
+                    </span>
+                    {!workspace.isActive && (
+                      <button
+                        onClick={() => handleSwitchWorkspace(workspace)}
+                        style={{ ...themeStyles.button, ...themeStyles.buttonPrimary, padding: "4px 10px", fontSize: "12px" }}
+                      >
+                        🔀 {settings.language === "hu" ? "Váltás" : settings.language === "de" ? "Wechseln" : "Switch"}
+                      </button>
+                    )}
+                    <button
+                      disabled={workspace.vaultEnabled || !workspace.storeExists}
+                      onClick={() => handleDuplicateWorkspace(workspace)}
+                      style={{
+                        ...themeStyles.button,
+                        ...themeStyles.buttonSecondary,
+                        padding: "4px 10px",
+                        fontSize: "12px",
+                        opacity: workspace.vaultEnabled || !workspace.storeExists ? 0.5 : 1,
+                      }}
+                    >
+                      📄 {settings.language === "hu" ? "Duplikálás" : settings.language === "de" ? "Duplizieren" : "Duplicate"}
+                    </button>
+                    {!workspace.isDefault && (
+                      <button
+                        onClick={() => handleRenameWorkspace(workspace)}
+                        style={{ ...themeStyles.button, ...themeStyles.buttonSecondary, padding: "4px 10px", fontSize: "12px" }}
+                      >
+                        ✏️ {settings.language === "hu" ? "Átnevezés" : settings.language === "de" ? "Umbenennen" : "Rename"}
+                      </button>
+                    )}
+                    {!workspace.isDefault && !workspace.isActive && (
+                      <button
+                        onClick={() => handleDeleteWorkspace(workspace)}
+                        style={{ ...themeStyles.button, ...themeStyles.buttonSecondary, padding: "4px 10px", fontSize: "12px", color: theme.colors.danger }}
+                      >
+                        🗑️ {settings.language === "hu" ? "Törlés" : settings.language === "de" ? "Löschen" : "Delete"}
+                      </button>
+                    )}
+                  </div>
+                ))}
+              </div>
+              <div style={{ display: "flex", alignItems: "center", gap: "12px", flexWrap: "wrap", marginBottom: "8px" }}>
+                <input
+                  type="text"
+                  value={newWorkspaceName}
+                  onChange={e => setNewWorkspaceName(e.target.value)}
+                  placeholder={settings.language === "hu" ? "Új munkaterület neve" : settings.language === "de" ? "Name des neuen Arbeitsbereichs" : "New workspace name"}
+                  style={{ ...themeStyles.input, padding: "6px 10px", fontSize: "12px" }}
+                />
+                <label style={{ display: "flex", alignItems: "center", gap: "6px", fontSize: "12px", color: theme.colors.text }}>
+                  <input type="checkbox" checked={newWorkspaceCopySettings} onChange={e => setNewWorkspaceCopySettings(e.target.checked)} />
+                  {settings.language === "hu" ? "Beállítások átvétele" : settings.language === "de" ? "Einstellungen übernehmen" : "Copy settings"}
+                </label>
+                <button
+                  disabled={!newWorkspaceName.trim()}
+                  onClick={handleCreateWorkspace}
+                  style={{
+                    ...themeStyles.button,
+                    ...themeStyles.buttonSecondary,
+                    padding: "6px 14px",
+                    fontSize: "12px",
+                    opacity: newWorkspaceName.trim() ? 1 : 0.5,
+                  }}
+                >
+                  ➕ {settings.language === "hu" ? "Létrehozás" : settings.language === "de" ? "Erstellen" : "Create"}
+                </button>
+              </div>
+              <div style={{ fontSize: "12px", color: theme.colors.textMuted }}>
+                {settings.language === "hu"
+                  ? "Minden munkaterületnek saját adatai, backupjai, audit logja, cégadatai és beállításai vannak. Az aktív munkaterület újraindítás után is megmarad."
+                  : settings.language === "de"
+                  ? "Jeder Arbeitsbereich hat eigene Daten, Sicherungen, Audit-Protokolle, Firmendaten und Einstellungen. Der aktive Arbeitsbereich bleibt nach einem Neustart erhalten."
+                  : "Each workspace has its own data, backups, audit log, company info and settings. The active workspace is remembered across restarts."}
+              </div>
+            </div>
+          )}
+
+          {/* Szinkronizálás - rekord szintű változáscsomagok egy megosztott mappán keresztül */}
+          {syncStatus && (
+            <div style={{ marginTop: "24px", marginLeft: "32px" }}>
+              <div style={{ display: "flex", alignItems: "center", gap: "12px", marginBottom: "8px", flexWrap: "wrap" }}>
+                <h3 style={{ fontSize: "16px", fontWeight: 600, color: theme.colors.text, margin: 0 }}>
+                  🔄 {settings.language === "hu" ? "Szinkronizálás" : settings.language === "de" ? "Synchronisierung" : "Sync"}
+                </h3>
+                <button
+                  disabled={syncStatus.blockedByVault}
+                  onClick={handleChooseSyncFolder}
+                  style={{ ...themeStyles.button, ...themeStyles.buttonSecondary, padding: "6px 14px", fontSize: "12px", opacity: syncStatus.blockedByVault ? 0.5 : 1 }}
+                >
+                  📁 {settings.language === "hu" ? "Mappa kiválasztása" : settings.language === "de" ? "Ordner wählen" : "Choose folder"}
+                </button>
+                <button
+                  disabled={syncStatus.blockedByVault}
+                  onClick={() => setWebDavDialog("sync")}
+                  style={{ ...themeStyles.button, ...themeStyles.buttonSecondary, padding: "6px 14px", fontSize: "12px", opacity: syncStatus.blockedByVault ? 0.5 : 1 }}
+                >
+                  ☁️ WebDAV / Nextcloud
+                </button>
+                {(syncStatus.folder || syncStatus.webdav) && (
+                  <>
+                    <select
+                      value={syncStatus.intervalMinutes}
+                      onChange={e => handleSyncConfigChange(syncStatus.folder, parseInt(e.target.value) || 0, syncStatus.webdav)}
+                      style={{ ...themeStyles.select, padding: "6px 10px", fontSize: "12px" }}
+                    >
+                      {[5, 15, 60].map(minutes => (
+                        <option key={minutes} value={minutes}>
+                          {settings.language === "hu" ? `${minutes} percenként` : settings.language === "de" ? `Alle ${minutes} Minuten` : `Every ${minutes} minutes`}
+                        </option>
+                      ))}
+                      <option value={0}>{settings.language === "hu" ? "Csak kézzel" : settings.language === "de" ? "Nur manuell" : "Manual only"}</option>
+                    </select>
+                    <button
+                      disabled={isSyncing || syncStatus.blockedByVault}
+                      onClick={handleSyncNow}
+                      style={{ ...themeStyles.button, ...themeStyles.buttonPrimary, padding: "6px 14px", fontSize: "12px", opacity: isSyncing ? 0.5 : 1 }}
+                    >
+                      ⇅ {settings.language === "hu" ? "Szinkronizálás most" : settings.language === "de" ? "Jetzt synchronisieren" : "Sync now"}
+                    </button>
+                    <button
+                      onClick={() => handleSyncConfigChange(null, syncStatus.intervalMinutes)}
+                      style={{ ...themeStyles.button, ...themeStyles.buttonSecondary, padding: "6px 14px", fontSize: "12px" }}
+                    >
+                      ⏹️ {settings.language === "hu" ? "Kikapcsolás" : settings.language === "de" ? "Deaktivieren" : "Disable"}
+                    </button>
+                  </>
+                )}
+              </div>
+              <div style={{ fontSize: "12px", color: theme.colors.textMuted }}>
+                {syncStatus.blockedByVault
+                  ? settings.language === "hu"
+                    ? "Titkosított adattárolóval a szinkronizálás nem érhető el."
+                    : settings.language === "de"
+                    ? "Mit verschlüsseltem Datenspeicher ist die Synchronisierung nicht verfügbar."
+                    : "Sync is not available while the data vault is encrypted."
+                  : syncStatus.folder || syncStatus.webdav
+                  ? `${syncStatus.webdav ? `☁️ ${syncStatus.webdav.url}` : `📁 ${syncStatus.folder}`} · ${syncStatus.deviceName} · ${
+                      settings.language === "hu" ? "Eszközök" : settings.language === "de" ? "Geräte" : "Devices"
+                    }: ${syncStatus.peers.map(peer => peer.deviceName).join(", ") || "-"} · ${
+                      settings.language === "hu" ? "Utolsó szinkronizálás" : settings.language === "de" ? "Letzte Synchronisierung" : "Last sync"
+                    }: ${syncStatus.lastSyncAt ? new Date(syncStatus.lastSyncAt).toLocaleString(settings.language) : "-"}`
+                  : settings.language === "hu"
+                  ? "Válasszon egy Syncthing, Dropbox vagy hálózati megosztás alatti mappát, vagy egy WebDAV (Nextcloud) mappát. Az eszközök csak a módosított rekordokat cserélik, mezőnként a későbbi módosítás nyer."
+                  : settings.language === "de"
+                  ? "Wählen Sie einen Ordner in Syncthing, Dropbox oder einer Netzwerkfreigabe oder einen WebDAV-Ordner (Nextcloud). Geräte tauschen nur geänderte Datensätze aus, pro Feld gewinnt die spätere Änderung."
+                  : "Choose a folder inside Syncthing, Dropbox or a network share, or a WebDAV (Nextcloud) folder. Devices exchange only changed records, and the later change wins per field."}
+              </div>
+              {syncStatus.lastError && (
+                <div style={{ fontSize: "12px", color: theme.colors.danger, marginTop: "4px" }}>⚠️ {syncStatus.lastError}</div>
+              )}
+              {syncStatus.conflictCount > 0 && (
+                <div style={{ fontSize: "12px", color: theme.colors.text, marginTop: "8px" }}>
+                  <div style={{ display: "flex", alignItems: "center", gap: "12px", marginBottom: "4px" }}>
+                    <span>
+                      ⚡ {settings.language === "hu" ? "Ütközések" : settings.language === "de" ? "Konflikte" : "Conflicts"}: {syncStatus.conflictCount}
+                    </span>
+                    <button
+                      onClick={handleClearSyncConflicts}
+                      style={{ ...themeStyles.button, ...themeStyles.buttonSecondary, padding: "4px 10px", fontSize: "12px" }}
+                    >
+                      🧹 {settings.language === "hu" ? "Napló ürítése" : settings.language === "de" ? "Protokoll leeren" : "Clear log"}
+                    </button>
+                  </div>
+                  {syncConflicts.map(conflict => (
+                    <div key={`${conflict.timestamp}-${conflict.entityType}-${conflict.recordKey}-${conflict.field}`} style={{ color: theme.colors.textMuted }}>
+                      {new Date(conflict.timestamp).toLocaleString(settings.language)} · {conflict.entityType} #{conflict.recordKey}
+                      {conflict.field ? `.${conflict.field}` : ""} · {JSON.stringify(conflict.localValue ?? null)} ↔ {JSON.stringify(conflict.remoteValue ?? null)} (
+                      {conflict.remoteDevice}) →{" "}
+                      {conflict.winner === "local"
+                        ? settings.language === "hu" ? "helyi" : settings.language === "de" ? "lokal" : "local"
+                        : settings.language === "hu" ? "távoli" : settings.language === "de" ? "entfernt" : "remote"}
+                    </div>
+                  ))}
+                </div>
+              )}
+            </div>
+          )}
+
+          {/* Változásnapló - az adatok visszaállítása egy korábbi időpontra */}
+          {journalStatus && (
+            <div style={{ marginTop: "24px", marginLeft: "32px" }}>
+              <div style={{ display: "flex", alignItems: "center", gap: "12px", marginBottom: "8px", flexWrap: "wrap" }}>
+                <h3 style={{ fontSize: "16px", fontWeight: 600, color: theme.colors.text, margin: 0 }}>
+                  🕒 {settings.language === "hu" ? "Változásnapló" : settings.language === "de" ? "Änderungsprotokoll" : "Change journal"}
+                </h3>
+                <input
+                  type="datetime-local"
+                  value={journalRestoreAt}
+                  onChange={e => setJournalRestoreAt(e.target.value)}
+                  style={{ ...themeStyles.input, padding: "6px 10px", fontSize: "12px" }}
+                />
+                <button
+                  disabled={!journalRestoreAt}
+                  onClick={handleJournalRestore}
+                  style={{
+                    ...themeStyles.button,
+                    ...themeStyles.buttonSecondary,
+                    padding: "6px 14px",
+                    fontSize: "12px",
+                    opacity: journalRestoreAt ? 1 : 0.5,
+                  }}
+                >
+                  ⏪ {settings.language === "hu" ? "Visszaállítás" : settings.language === "de" ? "Zurücksetzen" : "Restore"}
+                </button>
+              </div>
+              <div style={{ fontSize: "12px", color: theme.colors.textMuted }}>
+                {settings.language === "hu"
+                  ? `${journalStatus.entryCount} rögzített változás. Visszaállítható időpont: ${new Date(journalStatus.checkpointAt).toLocaleString(settings.language)} óta.`
+                  : settings.language === "de"
+                  ? `${journalStatus.entryCount} protokollierte Änderungen. Wiederherstellbar ab ${new Date(journalStatus.checkpointAt).toLocaleString(settings.language)}.`
+                  : `${journalStatus.entryCount} recorded changes. Restorable since ${new Date(journalStatus.checkpointAt).toLocaleString(settings.language)}.`}
+              </div>
+            </div>
+          )}
+
+          {/* Lomtár - törölt árajánlatok, ügyfelek és filamentek visszaállítása */}
+          <div style={{ marginTop: "24px", marginLeft: "32px" }}>
+            <div style={{ display: "flex", alignItems: "center", gap: "12px", marginBottom: "8px", flexWrap: "wrap" }}>
+              <h3 style={{ fontSize: "16px", fontWeight: 600, color: theme.colors.text, margin: 0 }}>
+                🗑️ {settings.language === "hu" ? "Lomtár" : settings.language === "de" ? "Papierkorb" : "Trash"}
+              </h3>
+              <button
+                onClick={() => setShowTrashBin(true)}
+                style={{ ...themeStyles.button, ...themeStyles.buttonSecondary, padding: "6px 14px", fontSize: "12px" }}
+              >
+                📂 {settings.language === "hu" ? "Megnyitás" : settings.language === "de" ? "Öffnen" : "Open"}
+              </button>
+              <select
+                value={settings.trashRetentionDays ?? DEFAULT_TRASH_RETENTION_DAYS}
+                onChange={async e => {
+                  const days = parseInt(e.target.value) || 0;
+                  const newSettings = { ...settings, trashRetentionDays: days };
+                  onChange(newSettings);
+                  await saveSettings(newSettings);
+                }}
+                style={{ ...themeStyles.select, padding: "6px 10px", fontSize: "12px" }}
+              >
+                {[7, 14, 30, 90, 365].map(days => (
+                  <option key={days} value={days}>
+                    {settings.language === "hu" ? `${days} napig` : settings.language === "de" ? `${days} Tage` : `${days} days`}
+                  </option>
+                ))}
+                <option value={0}>
+                  {settings.language === "hu" ? "Kézi ürítésig" : settings.language === "de" ? "Bis zum manuellen Leeren" : "Until emptied manually"}
+                </option>
+              </select>
+            </div>
+            <div style={{ fontSize: "12px", color: theme.colors.textMuted }}>
+              {settings.language === "hu"
+                ? "A törölt árajánlatok, ügyfelek és filamentek a lomtárba kerülnek, a megőrzési idő után véglegesen törlődnek."
+                : settings.language === "de"
+                ? "Gelöschte Angebote, Kunden und Filamente landen im Papierkorb und werden nach Ablauf der Aufbewahrungszeit endgültig gelöscht."
+                : "Deleted offers, customers and filaments go to the trash and are permanently deleted after the retention period."}
+            </div>
+          </div>
+
+          {/* Adatkonzisztencia - hibás hivatkozások, duplikált azonosítók, lehetetlen értékek */}
+          <div style={{ marginTop: "24px", marginLeft: "32px" }}>
+            <div style={{ display: "flex", alignItems: "center", gap: "12px", marginBottom: "8px" }}>
+              <h3 style={{ fontSize: "16px", fontWeight: 600, color: theme.colors.text, margin: 0 }}>
+                🩺 {settings.language === "hu" ? "Adatkonzisztencia" : settings.language === "de" ? "Datenkonsistenz" : "Data consistency"}
+              </h3>
+              <button
+                onClick={() => setShowConsistencyCheck(true)}
+                style={{ ...themeStyles.button, ...themeStyles.buttonSecondary, padding: "6px 14px", fontSize: "12px" }}
+              >
+                🔍 {settings.language === "hu" ? "Ellenőrzés" : settings.language === "de" ? "Prüfen" : "Check"}
+              </button>
+            </div>
+            <div style={{ fontSize: "12px", color: theme.colors.textMuted }}>
+              {settings.language === "hu"
+                ? "Nem létező rekordokra mutató hivatkozások, duplikált azonosítók, negatív súlyok és lehetetlen dátumok keresése, biztonságos javítással."
+                : settings.language === "de"
+                ? "Sucht nach Verweisen auf nicht vorhandene Datensätze, doppelten IDs, negativen Gewichten und unmöglichen Daten und behebt sie sicher."
+                : "Finds references to missing records, duplicate IDs, negative weights and impossible dates, with safe repairs."}
+            </div>
+          </div>
+
+          {/* Backup History - mindig látható, hasonlóan a log history-hoz */}
+          <div data-tutorial="backup-history-section" style={{ marginTop: "24px", marginLeft: "32px" }}>
+            <div style={{ display: "flex", alignItems: "center", gap: "12px", marginBottom: "12px" }}>
+              <h3 style={{ 
+                fontSize: "16px", 
+                fontWeight: 600, 
+                color: theme.colors.text,
+                margin: 0
+              }}>
+                📋 {t("settings.backup.history.title")}
+              </h3>
+              <Tooltip content={t("settings.backup.history.openFolderTooltip")}>
+                <button
+                  onClick={async () => {
+                    try {
+                      const backupDirPath = await invoke<string>("get_backup_directory_path");
+                      await invoke("open_directory", { path: backupDirPath });
+                    } catch (error) {
+                      console.error("Backup mappa megnyitási hiba:", error);
+                      showToast(t("settings.backup.history.openFolderError"), "error");
+                    }
+                  }}
+                  style={{
+                    ...themeStyles.button,
+                    ...themeStyles.buttonSecondary,
+                    padding: "6px 14px",
+                    fontSize: "12px",
+                  }}
+                >
+                  📁 {t("settings.backup.history.openFolder")}
+                </button>
+              </Tooltip>
+              <button
+                onClick={async () => {
+                  try {
+                    const report = await verifyAllBackups();
+                    const summary = settings.language === "hu"
+                      ? `${report.checked} backup ellenőrizve: ${report.ok} rendben, ${report.corrupted} sérült, ${report.unverified} nem ellenőrizhető`
+                      : settings.language === "de"
+                      ? `${report.checked} Backups geprüft: ${report.ok} in Ordnung, ${report.corrupted} beschädigt, ${report.unverified} nicht prüfbar`
+                      : `${report.checked} backups checked: ${report.ok} OK, ${report.corrupted} corrupted, ${report.unverified} unverifiable`;
+                    showToast(summary, report.corrupted > 0 ? "error" : "success");
+                    loadBackupHistory();
+                  } catch (error) {
+                    console.error("❌ Backup ellenőrzési hiba:", error);
+                    showToast(String(error), "error");
+                  }
+                }}
+                style={{
+                  ...themeStyles.button,
+                  ...themeStyles.buttonSecondary,
+                  padding: "6px 14px",
+                  fontSize: "12px",
+                }}
+              >
+                🛡️ {settings.language === "hu" ? "Összes ellenőrzése" : settings.language === "de" ? "Alle prüfen" : "Verify all"}
+              </button>
+            </div>
+            
+            {/* Információs szöveg - megjelenítés, színezés, törlés magyarázata */}
+            <Tooltip content={t("settings.backup.history.clickToOpen")}>
+              <p style={{ 
+                marginBottom: "12px", 
+                fontSize: "12px", 
+                color: theme.colors.textMuted,
+                lineHeight: "1.5"
+              }}>
+                {t("settings.backup.history.description")}
+              </p>
+            </Tooltip>
+            
+            {/* Színezés magyarázata */}
+            <div style={{
+              marginBottom: "12px",
+              padding: "10px",
+              backgroundColor: `${theme.colors.primary}10`,
+              borderRadius: "8px",
+              border: `1px solid ${theme.colors.primary}30`,
+              fontSize: "11px",
+            }}>
+              <div style={{ 
+                fontWeight: 600, 
+                color: theme.colors.text,
+                marginBottom: "6px"
+              }}>
+                {t("settings.backup.history.colorExplanation")}
+              </div>
+              <div style={{ 
+                display: "flex", 
+                flexDirection: "column", 
+                gap: "4px",
+                color: theme.colors.textMuted 
+              }}>
+                <div>{t("settings.backup.history.colorGreen")}</div>
+                <div>{t("settings.backup.history.colorYellow")}</div>
+                <div>{t("settings.backup.history.colorRed")}</div>
+                <div>{t("settings.backup.history.colorGray")}</div>
+              </div>
+              <div style={{ 
+                marginTop: "8px", 
+                paddingTop: "8px",
+                borderTop: `1px solid ${theme.colors.border}40`,
+                fontSize: "10px",
+                fontStyle: "italic",
+                color: theme.colors.textMuted 
+              }}>
+                {t("settings.backup.history.deletionInfo")}
+              </div>
+            </div>
+            
+            {backupHistory.length > 0 ? (
+              <div style={{
+                display: "flex",
+                flexDirection: "column",
+                gap: "8px",
+                maxHeight: "300px",
+                overflowY: "auto",
+                padding: "8px",
+                backgroundColor: theme.colors.surface,
+                borderRadius: "8px",
+                border: `1px solid ${theme.colors.border}`,
+              }}>
+                {backupHistory.map((item, index) => {
+                  const getStatusColor = () => {
+                    if (item.daysOld === 0) return "#22c55e"; // Zöld - mai
+                    if (item.daysOld === 1) return "#eab308"; // Sárga - tegnap
+                    if (item.daysOld >= 2 && item.daysOld < 5) return "#ef4444"; // Piros - 2-4 nap
+                    return "#6b7280"; // Szürke - 5+ nap (hamarosan törlődik)
+                  };
+
+                  const getStatusText = () => {
+                    if (item.daysOld === 0) {
+                      return t("settings.backup.history.today");
+                    }
+                    if (item.daysOld === 1) {
+                      return t("settings.backup.history.yesterday");
+                    }
+                    if (item.daysOld >= 2 && item.daysOld < 5) {
+                      return t("settings.backup.history.daysAgo", { days: item.daysOld });
+                    }
+                    return t("settings.backup.history.daysAgoWillDelete", { days: item.daysOld });
+                  };
+
+                  const statusColor = getStatusColor();
+                  const dateStr = item.date.toLocaleString(settings.language === "hu" ? "hu-HU" : settings.language === "de" ? "de-DE" : "en-US", {
+                    year: "numeric",
+                    month: "2-digit",
+                    day: "2-digit",
+                    hour: "2-digit",
+                    minute: "2-digit",
+                  });
 
                   return (
                     <div
@@ -3568,6 +4741,20 @@ export const SettingsPage: React.FC<Props> = ({
                         }}>
                           {dateStr}
                         </div>
+                        {item.integrity === "corrupted" && (
+                          <div style={{ 
+                            fontSize: "10px", 
+                            color: "#ef4444",
+                            marginTop: "4px",
+                            fontWeight: 600
+                          }}>
+                            ⚠️ {settings.language === "hu"
+                              ? "Sérült backup (ellenőrzőösszeg hiba)"
+                              : settings.language === "de"
+                              ? "Beschädigtes Backup (Prüfsummenfehler)"
+                              : "Corrupted backup (checksum mismatch)"}
+                          </div>
+                        )}
                         {item.willBeDeletedIn > 0 && item.willBeDeletedIn < 5 && (
                           <div style={{ 
                             fontSize: "10px", 
@@ -3579,6 +4766,31 @@ export const SettingsPage: React.FC<Props> = ({
                           </div>
                         )}
                       </div>
+                      <button
+                        title={item.pinned
+                          ? (settings.language === "hu" ? "Rögzítés feloldása" : settings.language === "de" ? "Fixierung aufheben" : "Unpin")
+                          : (settings.language === "hu" ? "Rögzítés (soha ne töröld)" : settings.language === "de" ? "Fixieren (nie löschen)" : "Pin (never delete)")}
+                        onClick={async (e) => {
+                          e.stopPropagation();
+                          try {
+                            await setBackupPinned(item.fileName, !item.pinned);
+                            loadBackupHistory();
+                          } catch (error) {
+                            console.error("❌ Backup rögzítési hiba:", error);
+                            showToast(String(error), "error");
+                          }
+                        }}
+                        style={{
+                          background: "none",
+                          border: "none",
+                          cursor: "pointer",
+                          fontSize: "16px",
+                          opacity: item.pinned ? 1 : 0.35,
+                          marginLeft: "8px",
+                        }}
+                      >
+                        📌
+                      </button>
                       <div style={{ 
                         fontSize: "16px", 
                         color: theme.colors.textMuted,
@@ -4567,6 +5779,31 @@ export const SettingsPage: React.FC<Props> = ({
                           {dateStr} • {item.fileName}
                         </div>
                       </div>
+                      <button
+                        title={item.pinned
+                          ? (settings.language === "hu" ? "Rögzítés feloldása" : settings.language === "de" ? "Fixierung aufheben" : "Unpin")
+                          : (settings.language === "hu" ? "Rögzítés (soha ne töröld)" : settings.language === "de" ? "Fixieren (nie löschen)" : "Pin (never delete)")}
+                        onClick={async (e) => {
+                          e.stopPropagation();
+                          try {
+                            await setBackupPinned(item.fileName, !item.pinned);
+                            loadBackupHistory();
+                          } catch (error) {
+                            console.error("❌ Backup rögzítési hiba:", error);
+                            showToast(String(error), "error");
+                          }
+                        }}
+                        style={{
+                          background: "none",
+                          border: "none",
+                          cursor: "pointer",
+                          fontSize: "16px",
+                          opacity: item.pinned ? 1 : 0.35,
+                          marginLeft: "8px",
+                        }}
+                      >
+                        📌
+                      </button>
                       <div style={{ 
                         fontSize: "16px", 
                         color: theme.colors.textMuted,
@@ -4805,6 +6042,31 @@ export const SettingsPage: React.FC<Props> = ({
                           {dateStr} • {item.fileName} • {item.size}
                         </div>
                       </div>
+                      <button
+                        title={item.pinned
+                          ? (settings.language === "hu" ? "Rögzítés feloldása" : settings.language === "de" ? "Fixierung aufheben" : "Unpin")
+                          : (settings.language === "hu" ? "Rögzítés (soha ne töröld)" : settings.language === "de" ? "Fixieren (nie löschen)" : "Pin (never delete)")}
+                        onClick={async (e) => {
+                          e.stopPropagation();
+                          try {
+                            await setBackupPinned(item.fileName, !item.pinned);
+                            loadBackupHistory();
+                          } catch (error) {
+                            console.error("❌ Backup rögzítési hiba:", error);
+                            showToast(String(error), "error");
+                          }
+                        }}
+                        style={{
+                          background: "none",
+                          border: "none",
+                          cursor: "pointer",
+                          fontSize: "16px",
+                          opacity: item.pinned ? 1 : 0.35,
+                          marginLeft: "8px",
+                        }}
+                      >
+                        📌
+                      </button>
                       <div style={{ 
                         fontSize: "16px", 
                         color: theme.colors.textMuted,
@@ -5878,6 +7140,54 @@ export const SettingsPage: React.FC<Props> = ({
         onComplete={handleFactoryResetComplete}
       />
 
+      {/* CSV / XLSX import */}
+      <TableImportModal
+        isOpen={showTableImport}
+        onClose={() => setShowTableImport(false)}
+        settings={settings}
+        theme={theme}
+        themeStyles={themeStyles}
+      />
+
+      <TrashBinModal
+        isOpen={showTrashBin}
+        onClose={() => setShowTrashBin(false)}
+        settings={settings}
+        theme={theme}
+        themeStyles={themeStyles}
+      />
+
+      <ConsistencyCheckModal
+        isOpen={showConsistencyCheck}
+        onClose={() => setShowConsistencyCheck(false)}
+        settings={settings}
+        theme={theme}
+        themeStyles={themeStyles}
+      />
+
+      <WebDavSettingsModal
+        isOpen={webDavDialog !== null}
+        onClose={() => setWebDavDialog(null)}
+        title={
+          webDavDialog === "sync"
+            ? settings.language === "hu" ? "Szinkronizálás WebDAV-on" : settings.language === "de" ? "Synchronisierung über WebDAV" : "Sync over WebDAV"
+            : settings.language === "hu" ? "WebDAV backup cél" : settings.language === "de" ? "WebDAV-Backup-Ziel" : "WebDAV backup destination"
+        }
+        initial={webDavDialog === "sync" ? syncStatus?.webdav : null}
+        withName={webDavDialog === "backup"}
+        onSave={async (webdav, name) => {
+          if (webDavDialog === "sync") {
+            setSyncStatus(await setSyncConfig(null, syncStatus?.intervalMinutes ?? 15, undefined, webdav));
+          } else {
+            await addWebDavBackupDestination(name, webdav, { retentionCount: 10 });
+            await loadBackupDestinations();
+          }
+        }}
+        settings={settings}
+        theme={theme}
+        themeStyles={themeStyles}
+      />
+
       {/* System Diagnostics Modal */}
       <SystemDiagnostics
         isOpen={showSystemDiagnostics}
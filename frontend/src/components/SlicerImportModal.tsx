@@ -1,4 +1,4 @@
-import React, { useState } from "react";
+import React, { useEffect, useState } from "react";
 import { AnimatePresence, motion } from "framer-motion";
 import { open } from "@tauri-apps/plugin-dialog";
 import { readTextFile } from "@tauri-apps/plugin-fs";
@@ -15,6 +15,8 @@ interface SlicerImportModalProps {
   theme: Theme;
   themeStyles: ReturnType<typeof import("../utils/themes").getThemeStyles>;
   onCreateOffer?: (offer: Offer) => void;
+  /** Megnyitáskor azonnal importálandó fájl (pl. az alkalmazásnak átadott fájl), fájlválasztó nélkül */
+  initialFilePath?: string;
 }
 
 export const SlicerImportModal: React.FC<SlicerImportModalProps> = ({
@@ -24,6 +26,7 @@ export const SlicerImportModal: React.FC<SlicerImportModalProps> = ({
   theme,
   themeStyles,
   onCreateOffer,
+  initialFilePath,
 }) => {
   const { showToast } = useToast();
   const t = useTranslation(settings.language);
@@ -33,9 +36,33 @@ export const SlicerImportModal: React.FC<SlicerImportModalProps> = ({
   const sumNumbers = (values?: number[]): number | undefined =>
     values && values.length ? values.reduce((acc: number, value: number) => acc + value, 0) : undefined;
 
-  const handleSlicerImport = async () => {
+  const importSlicerFile = async (filePath: string) => {
     try {
       setIsImporting(true);
+      if (filePath.toLowerCase().endsWith(".3mf")) {
+        showToast(t("slicerImport.unsupported3mf"), "error");
+        return;
+      }
+
+      const fileContent = await readTextFile(filePath);
+      const job = await parseSlicerFile(filePath, fileContent);
+      setLastImport(job);
+
+      showToast(t("slicerImport.importSuccess"), "success");
+    } catch (error) {
+      if (error instanceof SlicerParseError) {
+        showToast(t("slicerImport.importFailedPrefix") + error.message, "error");
+      } else {
+        console.error("[SlicerImportModal] unknown error", error);
+        showToast(t("slicerImport.unknownError"), "error");
+      }
+    } finally {
+      setIsImporting(false);
+    }
+  };
+
+  const handleSlicerImport = async () => {
+    try {
       const selected = await open({
         multiple: false,
         filters: [
@@ -56,28 +83,20 @@ export const SlicerImportModal: React.FC<SlicerImportModalProps> = ({
         return;
       }
 
-      if (filePath.toLowerCase().endsWith(".3mf")) {
-        showToast(t("slicerImport.unsupported3mf"), "error");
-        return;
-      }
-
-      const fileContent = await readTextFile(filePath);
-      const job = await parseSlicerFile(filePath, fileContent);
-      setLastImport(job);
-
-      showToast(t("slicerImport.importSuccess"), "success");
+      await importSlicerFile(filePath);
     } catch (error) {
-      if (error instanceof SlicerParseError) {
-        showToast(t("slicerImport.importFailedPrefix") + error.message, "error");
-      } else {
-        console.error("[SlicerImportModal] unknown error", error);
-        showToast(t("slicerImport.unknownError"), "error");
-      }
-    } finally {
-      setIsImporting(false);
+      console.error("[SlicerImportModal] unknown error", error);
+      showToast(t("slicerImport.unknownError"), "error");
     }
   };
 
+  useEffect(() => {
+    if (isOpen && initialFilePath) {
+      importSlicerFile(initialFilePath);
+    }
+    // eslint-disable-next-line react-hooks/exhaustive-deps
+  }, [isOpen, initialFilePath]);
+
   const handleCreateOfferDraft = () => {
     if (!lastImport) {
       showToast(t("slicerImport.noDataForDraft"), "error");
@@ -0,0 +1,434 @@
+import React, { useState } from "react";
+import { AnimatePresence, motion } from "framer-motion";
+import { useToast } from "./Toast";
+import type { Settings } from "../types";
+import type { Theme } from "../utils/themes";
+import {
+  chooseImportFile,
+  previewTableImport,
+  applyTableImport,
+  type TableImportEntity,
+  type TableImportOptions,
+  type TableImportPreview,
+} from "../utils/tableImport";
+
+interface TableImportModalProps {
+  isOpen: boolean;
+  onClose: () => void;
+  settings: Settings;
+  theme: Theme;
+  themeStyles: ReturnType<typeof import("../utils/themes").getThemeStyles>;
+}
+
+const ENCODINGS = ["utf-8", "windows-1250", "iso-8859-2", "windows-1252", "utf-16le"];
+const DELIMITERS = [";", ",", "\\t", "|"];
+
+// Mezőnevek a hozzárendeléshez: [magyar, német, angol]
+const FIELD_LABELS: Record<string, [string, string, string]> = {
+  brand: ["Márka", "Marke", "Brand"],
+  type: ["Típus", "Typ", "Type"],
+  weight: ["Súly (g)", "Gewicht (g)", "Weight (g)"],
+  pricePerKg: ["Ár / kg", "Preis / kg", "Price / kg"],
+  density: ["Sűrűség", "Dichte", "Density"],
+  color: ["Szín", "Farbe", "Color"],
+  colorHex: ["Színkód", "Farbcode", "Color hex"],
+  name: ["Név", "Name", "Name"],
+  power: ["Teljesítmény (W)", "Leistung (W)", "Power (W)"],
+  usageCost: ["Üzemeltetési költség / óra", "Betriebskosten / Stunde", "Usage cost / hour"],
+  amsCount: ["AMS darabszám", "AMS Anzahl", "AMS count"],
+  contact: ["Kapcsolat", "Kontakt", "Contact"],
+  company: ["Cég", "Firma", "Company"],
+  address: ["Cím", "Adresse", "Address"],
+  notes: ["Megjegyzés", "Notizen", "Notes"],
+};
+
+export const TableImportModal: React.FC<TableImportModalProps> = ({ isOpen, onClose, settings, theme, themeStyles }) => {
+  const { showToast } = useToast();
+  const [filePath, setFilePath] = useState<string | null>(null);
+  const [entityType, setEntityType] = useState<TableImportEntity>("filaments");
+  const [options, setOptions] = useState<TableImportOptions>({ hasHeader: true });
+  const [preview, setPreview] = useState<TableImportPreview | null>(null);
+  const [skipDuplicates, setSkipDuplicates] = useState(true);
+  const [isBusy, setIsBusy] = useState(false);
+
+  const label = (hu: string, de: string, en: string) =>
+    settings.language === "hu" ? hu : settings.language === "de" ? de : en;
+  const fieldLabel = (key: string) => {
+    const labels = FIELD_LABELS[key];
+    return labels ? label(...labels) : key;
+  };
+
+  const loadPreview = async (path: string, entity: TableImportEntity, nextOptions: TableImportOptions) => {
+    setIsBusy(true);
+    try {
+      const result = await previewTableImport(path, entity, nextOptions);
+      setPreview(result);
+      // A javasolt hozzárendelés rögzítése, hogy a további módosítások erre épüljenek
+      setOptions({ ...nextOptions, mapping: result.mapping });
+    } catch (error) {
+      console.error("❌ Import előnézet hiba:", error);
+      showToast(String(error), "error");
+      setPreview(null);
+    } finally {
+      setIsBusy(false);
+    }
+  };
+
+  const handleChooseFile = async () => {
+    const path = await chooseImportFile();
+    if (!path) {
+      return;
+    }
+    setFilePath(path);
+    // Új fájlnál újra felismerjük a kódolást, elválasztót és a hozzárendelést
+    await loadPreview(path, entityType, { hasHeader: options.hasHeader });
+  };
+
+  const updateOptions = async (changes: TableImportOptions, resetMapping = false) => {
+    const nextOptions = { ...options, ...changes };
+    if (resetMapping) {
+      delete nextOptions.mapping;
+    }
+    setOptions(nextOptions);
+    if (filePath) {
+      await loadPreview(filePath, entityType, nextOptions);
+    }
+  };
+
+  const handleEntityChange = async (entity: TableImportEntity) => {
+    setEntityType(entity);
+    const nextOptions = { ...options };
+    delete nextOptions.mapping;
+    setOptions(nextOptions);
+    if (filePath) {
+      await loadPreview(filePath, entity, nextOptions);
+    }
+  };
+
+  const handleMappingChange = async (field: string, column: string) => {
+    const mapping = { ...(options.mapping ?? {}) };
+    if (column === "") {
+      delete mapping[field];
+    } else {
+      mapping[field] = Number(column);
+    }
+    await updateOptions({ mapping });
+  };
+
+  const handleApply = async () => {
+    if (!filePath || !preview) {
+      return;
+    }
+    setIsBusy(true);
+    try {
+      const result = await applyTableImport(filePath, entityType, options, skipDuplicates);
+      showToast(
+        label(
+          `${result.imported} rekord importálva (${result.skippedInvalid} hibás, ${result.skippedDuplicates} duplikátum kihagyva)`,
+          `${result.imported} Datensätze importiert (${result.skippedInvalid} fehlerhaft, ${result.skippedDuplicates} Duplikate übersprungen)`,
+          `${result.imported} records imported (${result.skippedInvalid} invalid, ${result.skippedDuplicates} duplicates skipped)`
+        ),
+        "success"
+      );
+      setPreview(null);
+      setFilePath(null);
+      onClose();
+    } catch (error) {
+      console.error("❌ Import hiba:", error);
+      showToast(String(error), "error");
+    } finally {
+      setIsBusy(false);
+    }
+  };
+
+  const importableCount = preview ? preview.validRows - (skipDuplicates ? preview.duplicateRows : 0) : 0;
+  const cellStyle: React.CSSProperties = {
+    padding: "6px 8px",
+    borderBottom: `1px solid ${theme.colors.border}`,
+    fontSize: "12px",
+    textAlign: "left",
+    whiteSpace: "nowrap",
+  };
+  const selectStyle: React.CSSProperties = { ...themeStyles.select, padding: "6px 10px", fontSize: "12px" };
+
+  return (
+    <AnimatePresence>
+      {isOpen && (
+        <motion.div
+          initial={{ opacity: 0 }}
+          animate={{ opacity: 1 }}
+          exit={{ opacity: 0 }}
+          transition={{ duration: 0.2 }}
+          onClick={onClose}
+          style={{
+            position: "fixed",
+            top: 0,
+            left: 0,
+            right: 0,
+            bottom: 0,
+            backgroundColor: "rgba(15, 23, 42, 0.45)",
+            zIndex: 1100,
+            display: "flex",
+            alignItems: "center",
+            justifyContent: "center",
+            padding: "24px",
+            backdropFilter: "blur(6px)",
+          }}
+        >
+          <motion.div
+            initial={{ opacity: 0, scale: 0.95, y: 20 }}
+            animate={{ opacity: 1, scale: 1, y: 0 }}
+            exit={{ opacity: 0, scale: 0.95, y: 20 }}
+            transition={{ duration: 0.25, ease: "easeOut" }}
+            onClick={event => event.stopPropagation()}
+            style={{
+              ...themeStyles.card,
+              width: "min(1100px, 95vw)",
+              maxHeight: "90vh",
+              overflowY: "auto",
+              position: "relative",
+              padding: "32px",
+              display: "flex",
+              flexDirection: "column",
+              gap: "16px",
+            }}
+          >
+            <button
+              onClick={onClose}
+              style={{
+                position: "absolute",
+                top: "12px",
+                right: "12px",
+                border: "none",
+                background: "transparent",
+                color: theme.colors.text,
+                fontSize: "20px",
+                cursor: "pointer",
+              }}
+              aria-label={label("Bezárás", "Schließen", "Close")}
+            >
+              ✕
+            </button>
+
+            <div>
+              <h3 style={{ margin: "0 0 8px 0", fontSize: "20px", fontWeight: 600, color: theme.colors.text }}>
+                📥 {label("Import CSV / Excel fájlból", "Import aus CSV / Excel", "Import from CSV / Excel")}
+              </h3>
+              <p style={{ margin: 0, fontSize: "13px", color: theme.colors.textMuted }}>
+                {label(
+                  "Filamentek, nyomtatók vagy ügyfelek tömeges felvétele. A kódolást (pl. Windows-1250) és az elválasztót automatikusan felismerjük.",
+                  "Filamente, Drucker oder Kunden in großen Mengen anlegen. Kodierung (z. B. Windows-1250) und Trennzeichen werden automatisch erkannt.",
+                  "Bulk-create filaments, printers or customers. Encoding (e.g. Windows-1250) and delimiter are detected automatically."
+                )}
+              </p>
+            </div>
+
+            <div style={{ display: "flex", gap: "12px", flexWrap: "wrap", alignItems: "center" }}>
+              <select
+                value={entityType}
+                onChange={e => handleEntityChange(e.target.value as TableImportEntity)}
+                disabled={isBusy}
+                style={selectStyle}
+              >
+                <option value="filaments">{label("Filamentek", "Filamente", "Filaments")}</option>
+                <option value="printers">{label("Nyomtatók", "Drucker", "Printers")}</option>
+                <option value="customers">{label("Ügyfelek", "Kunden", "Customers")}</option>
+              </select>
+              <button
+                onClick={handleChooseFile}
+                disabled={isBusy}
+                style={{ ...themeStyles.button, ...themeStyles.buttonSecondary, padding: "8px 16px", fontSize: "13px" }}
+              >
+                📂 {label("Fájl kiválasztása", "Datei wählen", "Choose file")}
+              </button>
+              {filePath && (
+                <span style={{ fontSize: "12px", color: theme.colors.textMuted, wordBreak: "break-all" }}>{filePath}</span>
+              )}
+            </div>
+
+            {preview && (
+              <div style={{ display: "flex", gap: "12px", flexWrap: "wrap", alignItems: "center", fontSize: "12px", color: theme.colors.text }}>
+                {preview.format === "csv" ? (
+                  <>
+                    <label>
+                      {label("Kódolás", "Kodierung", "Encoding")}:{" "}
+                      <select
+                        value={options.encoding ?? ""}
+                        onChange={e => updateOptions({ encoding: e.target.value || undefined })}
+                        disabled={isBusy}
+                        style={selectStyle}
+                      >
+                        <option value="">
+                          {label("automatikus", "automatisch", "automatic")} ({preview.encoding})
+                        </option>
+                        {ENCODINGS.map(encoding => (
+                          <option key={encoding} value={encoding}>
+                            {encoding}
+                          </option>
+                        ))}
+                      </select>
+                    </label>
+                    <label>
+                      {label("Elválasztó", "Trennzeichen", "Delimiter")}:{" "}
+                      <select
+                        value={options.delimiter ?? ""}
+                        onChange={e => updateOptions({ delimiter: e.target.value || undefined }, true)}
+                        disabled={isBusy}
+                        style={selectStyle}
+                      >
+                        <option value="">
+                          {label("automatikus", "automatisch", "automatic")} ({preview.delimiter})
+                        </option>
+                        {DELIMITERS.map(delimiter => (
+                          <option key={delimiter} value={delimiter}>
+                            {delimiter}
+                          </option>
+                        ))}
+                      </select>
+                    </label>
+                  </>
+                ) : (
+                  <label>
+                    {label("Munkalap", "Arbeitsblatt", "Sheet")}:{" "}
+                    <select
+                      value={preview.sheet ?? ""}
+                      onChange={e => updateOptions({ sheet: e.target.value }, true)}
+                      disabled={isBusy}
+                      style={selectStyle}
+                    >
+                      {preview.sheets.map(sheet => (
+                        <option key={sheet} value={sheet}>
+                          {sheet}
+                        </option>
+                      ))}
+                    </select>
+                  </label>
+                )}
+                <label style={{ display: "flex", alignItems: "center", gap: "6px" }}>
+                  <input
+                    type="checkbox"
+                    checked={options.hasHeader ?? true}
+                    onChange={e => updateOptions({ hasHeader: e.target.checked }, true)}
+                    disabled={isBusy}
+                  />
+                  {label("Az első sor fejléc", "Erste Zeile ist Kopfzeile", "First row is a header")}
+                </label>
+              </div>
+            )}
+
+            {preview && (
+              <div>
+                <h4 style={{ margin: "0 0 8px 0", fontSize: "14px", color: theme.colors.text }}>
+                  🔗 {label("Oszlopok hozzárendelése", "Spaltenzuordnung", "Column mapping")}
+                </h4>
+                <div style={{ display: "grid", gridTemplateColumns: "repeat(auto-fill, minmax(220px, 1fr))", gap: "8px" }}>
+                  {preview.fields.map(field => (
+                    <label key={field.key} style={{ display: "flex", flexDirection: "column", gap: "4px", fontSize: "12px", color: theme.colors.text }}>
+                      <span>
+                        {fieldLabel(field.key)}
+                        {field.required && <span style={{ color: theme.colors.danger }}> *</span>}
+                      </span>
+                      <select
+                        value={preview.mapping[field.key] ?? ""}
+                        onChange={e => handleMappingChange(field.key, e.target.value)}
+                        disabled={isBusy}
+                        style={selectStyle}
+                      >
+                        <option value="">— {label("nincs", "keine", "none")} —</option>
+                        {preview.headers.map((header, index) => (
+                          <option key={index} value={index}>
+                            {header || `#${index + 1}`}
+                          </option>
+                        ))}
+                      </select>
+                    </label>
+                  ))}
+                </div>
+              </div>
+            )}
+
+            {preview && (
+              <div>
+                <div style={{ fontSize: "12px", color: theme.colors.textMuted, marginBottom: "8px" }}>
+                  {label(
+                    `${preview.totalRows} sor: ${preview.validRows} érvényes, ${preview.invalidRows} hibás, ${preview.duplicateRows} duplikátum`,
+                    `${preview.totalRows} Zeilen: ${preview.validRows} gültig, ${preview.invalidRows} fehlerhaft, ${preview.duplicateRows} Duplikate`,
+                    `${preview.totalRows} rows: ${preview.validRows} valid, ${preview.invalidRows} invalid, ${preview.duplicateRows} duplicates`
+                  )}
+                  {preview.rows.length < preview.totalRows &&
+                    ` (${label("az első", "die ersten", "showing first")} ${preview.rows.length})`}
+                </div>
+                <div style={{ overflowX: "auto", maxHeight: "40vh", border: `1px solid ${theme.colors.border}`, borderRadius: "8px" }}>
+                  <table style={{ borderCollapse: "collapse", width: "100%", color: theme.colors.text }}>
+                    <thead>
+                      <tr>
+                        <th style={cellStyle}>#</th>
+                        {preview.fields.map(field => (
+                          <th key={field.key} style={cellStyle}>
+                            {fieldLabel(field.key)}
+                          </th>
+                        ))}
+                        <th style={cellStyle}>{label("Állapot", "Status", "Status")}</th>
+                      </tr>
+                    </thead>
+                    <tbody>
+                      {preview.rows.map(row => (
+                        <tr key={row.rowNumber} style={{ opacity: row.duplicate && skipDuplicates ? 0.5 : 1 }}>
+                          <td style={cellStyle}>{row.rowNumber}</td>
+                          {preview.fields.map(field => {
+                            const column = preview.mapping[field.key];
+                            const hasError = row.errors.some(error => error.field === field.key);
+                            return (
+                              <td key={field.key} style={{ ...cellStyle, color: hasError ? theme.colors.danger : undefined }}>
+                                {column !== undefined ? row.cells[column] ?? "" : ""}
+                              </td>
+                            );
+                          })}
+                          <td style={{ ...cellStyle, whiteSpace: "normal", color: row.errors.length > 0 ? theme.colors.danger : theme.colors.textMuted }}>
+                            {row.errors.length > 0
+                              ? row.errors.map(error => `${fieldLabel(error.field)}: ${error.message}`).join("; ")
+                              : row.duplicate
+                              ? label("duplikátum", "Duplikat", "duplicate")
+                              : "✓"}
+                          </td>
+                        </tr>
+                      ))}
+                    </tbody>
+                  </table>
+                </div>
+              </div>
+            )}
+
+            {preview && (
+              <div style={{ display: "flex", gap: "12px", flexWrap: "wrap", alignItems: "center" }}>
+                <label style={{ display: "flex", alignItems: "center", gap: "6px", fontSize: "12px", color: theme.colors.text }}>
+                  <input type="checkbox" checked={skipDuplicates} onChange={e => setSkipDuplicates(e.target.checked)} />
+                  {label("Duplikátumok kihagyása", "Duplikate überspringen", "Skip duplicates")}
+                </label>
+                <button
+                  onClick={handleApply}
+                  disabled={isBusy || importableCount <= 0}
+                  style={{
+                    ...themeStyles.button,
+                    ...themeStyles.buttonPrimary,
+                    padding: "10px 20px",
+                    opacity: isBusy || importableCount <= 0 ? 0.65 : 1,
+                  }}
+                >
+                  📥 {label(`${importableCount} rekord importálása`, `${importableCount} Datensätze importieren`, `Import ${importableCount} records`)}
+                </button>
+                <span style={{ fontSize: "12px", color: theme.colors.textMuted }}>
+                  {label(
+                    "A hibás sorok kimaradnak, importálás előtt biztonsági mentés készül.",
+                    "Fehlerhafte Zeilen werden übersprungen, vor dem Import wird eine Sicherung erstellt.",
+                    "Invalid rows are skipped, a safety backup is made before importing."
+                  )}
+                </span>
+              </div>
+            )}
+          </motion.div>
+        </motion.div>
+      )}
+    </AnimatePresence>
+  );
+};
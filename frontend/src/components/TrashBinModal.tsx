@@ -0,0 +1,273 @@
+import React, { useCallback, useEffect, useState } from "react";
+import { AnimatePresence, motion } from "framer-motion";
+import { useToast } from "./Toast";
+import type { Settings } from "../types";
+import type { Theme } from "../utils/themes";
+import {
+  listTrash,
+  restoreFromTrash,
+  purgeTrash,
+  DEFAULT_TRASH_RETENTION_DAYS,
+  type TrashEntityType,
+  type TrashItem,
+} from "../utils/trash";
+
+interface TrashBinModalProps {
+  isOpen: boolean;
+  onClose: () => void;
+  settings: Settings;
+  theme: Theme;
+  themeStyles: ReturnType<typeof import("../utils/themes").getThemeStyles>;
+}
+
+export const TrashBinModal: React.FC<TrashBinModalProps> = ({ isOpen, onClose, settings, theme, themeStyles }) => {
+  const { showToast } = useToast();
+  const [items, setItems] = useState<TrashItem[]>([]);
+  const [entityFilter, setEntityFilter] = useState<TrashEntityType | "all">("all");
+  const [selectedIds, setSelectedIds] = useState<Set<string>>(new Set());
+  const [isBusy, setIsBusy] = useState(false);
+
+  const label = (hu: string, de: string, en: string) =>
+    settings.language === "hu" ? hu : settings.language === "de" ? de : en;
+  const entityLabel = (entityType: TrashEntityType) =>
+    entityType === "offers"
+      ? label("Árajánlat", "Angebot", "Offer")
+      : entityType === "customers"
+      ? label("Ügyfél", "Kunde", "Customer")
+      : label("Filament", "Filament", "Filament");
+
+  const loadItems = useCallback(async () => {
+    try {
+      setItems(await listTrash());
+      setSelectedIds(new Set());
+    } catch (error) {
+      console.error("❌ Hiba a lomtár betöltésekor:", error);
+      showToast(String(error), "error");
+    }
+  }, [showToast]);
+
+  useEffect(() => {
+    if (isOpen) {
+      loadItems();
+    }
+  }, [isOpen, loadItems]);
+
+  const visibleItems = entityFilter === "all" ? items : items.filter(item => item.entityType === entityFilter);
+  const retentionDays = settings.trashRetentionDays ?? DEFAULT_TRASH_RETENTION_DAYS;
+
+  const toggleSelected = (id: string) => {
+    setSelectedIds(prev => {
+      const next = new Set(prev);
+      if (next.has(id)) {
+        next.delete(id);
+      } else {
+        next.add(id);
+      }
+      return next;
+    });
+  };
+
+  const handleRestore = async (ids: string[]) => {
+    setIsBusy(true);
+    try {
+      // A backend írja a Store-t, az App a "data-store-changed" eseményre újratölti az adatokat
+      const result = await restoreFromTrash(ids);
+      if (result.restored.length > 0) {
+        showToast(label(`${result.restored.length} elem visszaállítva`, `${result.restored.length} Einträge wiederhergestellt`, `${result.restored.length} items restored`), "success");
+      }
+      if (result.conflicts.length > 0) {
+        showToast(
+          label(
+            `${result.conflicts.length} elem nem állítható vissza, mert már létezik azonos azonosítóval`,
+            `${result.conflicts.length} Einträge existieren bereits und wurden nicht wiederhergestellt`,
+            `${result.conflicts.length} items already exist and were not restored`
+          ),
+          "error"
+        );
+      }
+      await loadItems();
+    } catch (error) {
+      console.error("❌ Hiba a lomtárból visszaállításkor:", error);
+      showToast(String(error), "error");
+    } finally {
+      setIsBusy(false);
+    }
+  };
+
+  const handlePurge = async (ids?: string[]) => {
+    setIsBusy(true);
+    try {
+      const purged = await purgeTrash(ids);
+      showToast(label(`${purged} elem véglegesen törölve`, `${purged} Einträge endgültig gelöscht`, `${purged} items permanently deleted`), "success");
+      await loadItems();
+    } catch (error) {
+      console.error("❌ Hiba a lomtár ürítésekor:", error);
+      showToast(String(error), "error");
+    } finally {
+      setIsBusy(false);
+    }
+  };
+
+  const selected = Array.from(selectedIds);
+  const smallButton: React.CSSProperties = { ...themeStyles.button, padding: "6px 12px", fontSize: "12px" };
+
+  return (
+    <AnimatePresence>
+      {isOpen && (
+        <motion.div
+          initial={{ opacity: 0 }}
+          animate={{ opacity: 1 }}
+          exit={{ opacity: 0 }}
+          transition={{ duration: 0.2 }}
+          onClick={onClose}
+          style={{
+            position: "fixed",
+            top: 0,
+            left: 0,
+            right: 0,
+            bottom: 0,
+            backgroundColor: "rgba(15, 23, 42, 0.45)",
+            zIndex: 1100,
+            display: "flex",
+            alignItems: "center",
+            justifyContent: "center",
+            padding: "24px",
+            backdropFilter: "blur(6px)",
+          }}
+        >
+          <motion.div
+            initial={{ opacity: 0, scale: 0.95, y: 20 }}
+            animate={{ opacity: 1, scale: 1, y: 0 }}
+            exit={{ opacity: 0, scale: 0.95, y: 20 }}
+            transition={{ duration: 0.25, ease: "easeOut" }}
+            onClick={event => event.stopPropagation()}
+            style={{
+              ...themeStyles.card,
+              width: "min(900px, 95vw)",
+              maxHeight: "90vh",
+              overflowY: "auto",
+              position: "relative",
+              padding: "32px",
+              display: "flex",
+              flexDirection: "column",
+              gap: "16px",
+            }}
+          >
+            <button
+              onClick={onClose}
+              style={{
+                position: "absolute",
+                top: "12px",
+                right: "12px",
+                border: "none",
+                background: "transparent",
+                color: theme.colors.text,
+                fontSize: "20px",
+                cursor: "pointer",
+              }}
+              aria-label={label("Bezárás", "Schließen", "Close")}
+            >
+              ✕
+            </button>
+
+            <div>
+              <h3 style={{ margin: "0 0 8px 0", fontSize: "20px", fontWeight: 600, color: theme.colors.text }}>
+                🗑️ {label("Lomtár", "Papierkorb", "Trash")}
+              </h3>
+              <p style={{ margin: 0, fontSize: "13px", color: theme.colors.textMuted }}>
+                {retentionDays > 0
+                  ? label(
+                      `A törölt elemek ${retentionDays} napig állíthatók vissza, utána véglegesen törlődnek.`,
+                      `Gelöschte Einträge können ${retentionDays} Tage lang wiederhergestellt werden, danach werden sie endgültig gelöscht.`,
+                      `Deleted items can be restored for ${retentionDays} days, after which they are permanently deleted.`
+                    )
+                  : label(
+                      "A törölt elemek a lomtár kézi ürítéséig megmaradnak.",
+                      "Gelöschte Einträge bleiben bis zum manuellen Leeren des Papierkorbs erhalten.",
+                      "Deleted items are kept until the trash is emptied manually."
+                    )}
+              </p>
+            </div>
+
+            <div style={{ display: "flex", gap: "12px", flexWrap: "wrap", alignItems: "center" }}>
+              <select
+                value={entityFilter}
+                onChange={e => setEntityFilter(e.target.value as TrashEntityType | "all")}
+                style={{ ...themeStyles.select, padding: "6px 10px", fontSize: "12px" }}
+              >
+                <option value="all">{label("Összes", "Alle", "All")}</option>
+                <option value="offers">{label("Árajánlatok", "Angebote", "Offers")}</option>
+                <option value="customers">{label("Ügyfelek", "Kunden", "Customers")}</option>
+                <option value="filaments">{label("Filamentek", "Filamente", "Filaments")}</option>
+              </select>
+              <button
+                disabled={isBusy || selected.length === 0}
+                onClick={() => handleRestore(selected)}
+                style={{ ...smallButton, ...themeStyles.buttonPrimary, opacity: selected.length === 0 ? 0.5 : 1 }}
+              >
+                ↩️ {label("Kijelöltek visszaállítása", "Ausgewählte wiederherstellen", "Restore selected")}
+              </button>
+              <button
+                disabled={isBusy || selected.length === 0}
+                onClick={() => handlePurge(selected)}
+                style={{ ...smallButton, ...themeStyles.buttonSecondary, opacity: selected.length === 0 ? 0.5 : 1 }}
+              >
+                ❌ {label("Kijelöltek végleges törlése", "Ausgewählte endgültig löschen", "Delete selected permanently")}
+              </button>
+              <button
+                disabled={isBusy || items.length === 0}
+                onClick={() => handlePurge()}
+                style={{ ...smallButton, ...themeStyles.buttonSecondary, color: theme.colors.danger, opacity: items.length === 0 ? 0.5 : 1 }}
+              >
+                🗑️ {label("Lomtár ürítése", "Papierkorb leeren", "Empty trash")}
+              </button>
+            </div>
+
+            {visibleItems.length === 0 ? (
+              <div style={{ fontSize: "13px", color: theme.colors.textMuted, padding: "16px 0" }}>
+                {label("A lomtár üres.", "Der Papierkorb ist leer.", "The trash is empty.")}
+              </div>
+            ) : (
+              <table style={{ width: "100%", borderCollapse: "collapse", fontSize: "13px", color: theme.colors.text }}>
+                <thead>
+                  <tr style={{ textAlign: "left", borderBottom: `1px solid ${theme.colors.border}` }}>
+                    <th style={{ padding: "6px" }} />
+                    <th style={{ padding: "6px" }}>{label("Típus", "Typ", "Type")}</th>
+                    <th style={{ padding: "6px" }}>{label("Megnevezés", "Bezeichnung", "Name")}</th>
+                    <th style={{ padding: "6px" }}>{label("Törölve", "Gelöscht", "Deleted")}</th>
+                    <th style={{ padding: "6px" }}>{label("Törölte", "Gelöscht von", "Deleted by")}</th>
+                    <th style={{ padding: "6px" }} />
+                  </tr>
+                </thead>
+                <tbody>
+                  {visibleItems.map(item => (
+                    <tr key={item.id} style={{ borderBottom: `1px solid ${theme.colors.border}` }}>
+                      <td style={{ padding: "6px" }}>
+                        <input type="checkbox" checked={selectedIds.has(item.id)} onChange={() => toggleSelected(item.id)} />
+                      </td>
+                      <td style={{ padding: "6px" }}>{entityLabel(item.entityType)}</td>
+                      <td style={{ padding: "6px" }}>{item.label || item.recordKey}</td>
+                      <td style={{ padding: "6px", whiteSpace: "nowrap" }}>
+                        {new Date(item.deletedAt).toLocaleString(settings.language)}
+                      </td>
+                      <td style={{ padding: "6px" }}>{item.deletedBy}</td>
+                      <td style={{ padding: "6px", textAlign: "right" }}>
+                        <button
+                          disabled={isBusy}
+                          onClick={() => handleRestore([item.id])}
+                          style={{ ...smallButton, ...themeStyles.buttonSecondary }}
+                        >
+                          ↩️ {label("Visszaállítás", "Wiederherstellen", "Restore")}
+                        </button>
+                      </td>
+                    </tr>
+                  ))}
+                </tbody>
+              </table>
+            )}
+          </motion.div>
+        </motion.div>
+      )}
+    </AnimatePresence>
+  );
+};
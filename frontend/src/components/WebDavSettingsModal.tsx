@@ -0,0 +1,247 @@
+import React, { useEffect, useState } from "react";
+import { AnimatePresence, motion } from "framer-motion";
+import { useToast } from "./Toast";
+import type { Settings } from "../types";
+import type { Theme } from "../utils/themes";
+import {
+  EMPTY_WEBDAV_SETTINGS,
+  testWebDavConnection,
+  type WebDavAuth,
+  type WebDavSettings,
+  type WebDavTestReport,
+} from "../utils/webdav";
+
+interface WebDavSettingsModalProps {
+  isOpen: boolean;
+  onClose: () => void;
+  title: string;
+  initial?: WebDavSettings | null;
+  /** Backup célnál a cél neve is megadható */
+  withName?: boolean;
+  onSave: (webdav: WebDavSettings, name: string) => Promise<void>;
+  settings: Settings;
+  theme: Theme;
+  themeStyles: ReturnType<typeof import("../utils/themes").getThemeStyles>;
+}
+
+const formatBytes = (bytes: number) =>
+  bytes >= 1024 ** 3 ? `${(bytes / 1024 ** 3).toFixed(1)} GB` : `${(bytes / 1024 ** 2).toFixed(0)} MB`;
+
+export const WebDavSettingsModal: React.FC<WebDavSettingsModalProps> = ({
+  isOpen,
+  onClose,
+  title,
+  initial,
+  withName,
+  onSave,
+  settings,
+  theme,
+  themeStyles,
+}) => {
+  const { showToast } = useToast();
+  const [form, setForm] = useState<WebDavSettings>(EMPTY_WEBDAV_SETTINGS);
+  const [name, setName] = useState("Nextcloud");
+  const [report, setReport] = useState<WebDavTestReport | null>(null);
+  const [isBusy, setIsBusy] = useState(false);
+
+  const label = (hu: string, de: string, en: string) =>
+    settings.language === "hu" ? hu : settings.language === "de" ? de : en;
+
+  useEffect(() => {
+    if (isOpen) {
+      setForm(initial ?? EMPTY_WEBDAV_SETTINGS);
+      setReport(null);
+    }
+  }, [isOpen, initial]);
+
+  const update = (patch: Partial<WebDavSettings>) => {
+    setForm(current => ({ ...current, ...patch }));
+    setReport(null);
+  };
+
+  const runTest = async () => {
+    setIsBusy(true);
+    try {
+      setReport(await testWebDavConnection(form));
+    } catch (error) {
+      console.error("❌ WebDAV kapcsolatteszt hiba:", error);
+      showToast(String(error), "error");
+    } finally {
+      setIsBusy(false);
+    }
+  };
+
+  const save = async () => {
+    setIsBusy(true);
+    try {
+      await onSave({ ...form, url: form.url.trim(), username: form.username.trim() }, name.trim() || "WebDAV");
+      onClose();
+    } catch (error) {
+      console.error("❌ WebDAV beállítás mentési hiba:", error);
+      showToast(String(error), "error");
+    } finally {
+      setIsBusy(false);
+    }
+  };
+
+  const fieldLabel: React.CSSProperties = { display: "block", fontSize: "12px", fontWeight: 600, color: theme.colors.text, marginBottom: "4px" };
+  const smallButton: React.CSSProperties = { ...themeStyles.button, padding: "6px 12px", fontSize: "12px" };
+
+  return (
+    <AnimatePresence>
+      {isOpen && (
+        <motion.div
+          initial={{ opacity: 0 }}
+          animate={{ opacity: 1 }}
+          exit={{ opacity: 0 }}
+          transition={{ duration: 0.2 }}
+          onClick={onClose}
+          style={{
+            position: "fixed",
+            top: 0,
+            left: 0,
+            right: 0,
+            bottom: 0,
+            backgroundColor: "rgba(15, 23, 42, 0.45)",
+            zIndex: 1100,
+            display: "flex",
+            alignItems: "center",
+            justifyContent: "center",
+            padding: "24px",
+            backdropFilter: "blur(6px)",
+          }}
+        >
+          <motion.div
+            initial={{ opacity: 0, scale: 0.95, y: 20 }}
+            animate={{ opacity: 1, scale: 1, y: 0 }}
+            exit={{ opacity: 0, scale: 0.95, y: 20 }}
+            transition={{ duration: 0.25, ease: "easeOut" }}
+            onClick={event => event.stopPropagation()}
+            style={{
+              ...themeStyles.card,
+              width: "min(560px, 95vw)",
+              maxHeight: "90vh",
+              overflowY: "auto",
+              position: "relative",
+              padding: "32px",
+              display: "flex",
+              flexDirection: "column",
+              gap: "12px",
+            }}
+          >
+            <button
+              onClick={onClose}
+              style={{
+                position: "absolute",
+                top: "12px",
+                right: "12px",
+                border: "none",
+                background: "transparent",
+                color: theme.colors.text,
+                fontSize: "20px",
+                cursor: "pointer",
+              }}
+              aria-label={label("Bezárás", "Schließen", "Close")}
+            >
+              ✕
+            </button>
+
+            <div>
+              <h3 style={{ margin: "0 0 8px 0", fontSize: "20px", fontWeight: 600, color: theme.colors.text }}>☁️ {title}</h3>
+              <p style={{ margin: 0, fontSize: "12px", color: theme.colors.textMuted }}>
+                {label(
+                  "Nextcloud esetén: https://<szerver>/remote.php/dav/files/<felhasználó>/<mappa>/ és egy alkalmazásjelszó (Beállítások → Biztonság).",
+                  "Für Nextcloud: https://<server>/remote.php/dav/files/<benutzer>/<ordner>/ und ein App-Passwort (Einstellungen → Sicherheit).",
+                  "For Nextcloud: https://<server>/remote.php/dav/files/<user>/<folder>/ and an app password (Settings → Security)."
+                )}
+              </p>
+            </div>
+
+            {withName && (
+              <div>
+                <label style={fieldLabel}>{label("Név", "Name", "Name")}</label>
+                <input value={name} onChange={e => setName(e.target.value)} style={{ ...themeStyles.input, width: "100%" }} />
+              </div>
+            )}
+            <div>
+              <label style={fieldLabel}>{label("WebDAV cím", "WebDAV-Adresse", "WebDAV URL")}</label>
+              <input
+                value={form.url}
+                placeholder="https://cloud.example.com/remote.php/dav/files/anna/3DPrinterCalc/"
+                onChange={e => update({ url: e.target.value })}
+                style={{ ...themeStyles.input, width: "100%" }}
+              />
+            </div>
+            <div style={{ display: "flex", gap: "12px" }}>
+              <div style={{ flex: 1 }}>
+                <label style={fieldLabel}>{label("Felhasználónév", "Benutzername", "Username")}</label>
+                <input value={form.username} onChange={e => update({ username: e.target.value })} style={{ ...themeStyles.input, width: "100%" }} />
+              </div>
+              <div style={{ flex: 1 }}>
+                <label style={fieldLabel}>{label("Jelszó", "Passwort", "Password")}</label>
+                <input
+                  type="password"
+                  value={form.password}
+                  placeholder={initial?.url ? label("Változatlan", "Unverändert", "Unchanged") : undefined}
+                  autoComplete="new-password"
+                  onChange={e => update({ password: e.target.value })}
+                  style={{ ...themeStyles.input, width: "100%" }}
+                />
+              </div>
+              <div>
+                <label style={fieldLabel}>{label("Hitelesítés", "Authentifizierung", "Authentication")}</label>
+                <select value={form.auth} onChange={e => update({ auth: e.target.value as WebDavAuth })} style={themeStyles.select}>
+                  <option value="auto">{label("Automatikus", "Automatisch", "Automatic")}</option>
+                  <option value="basic">Basic</option>
+                  <option value="digest">Digest</option>
+                </select>
+              </div>
+            </div>
+
+            {report && (
+              <div
+                style={{
+                  fontSize: "12px",
+                  padding: "10px 12px",
+                  borderRadius: "8px",
+                  border: `1px solid ${report.success ? theme.colors.border : theme.colors.danger}`,
+                  color: report.success ? theme.colors.text : theme.colors.danger,
+                }}
+              >
+                <div style={{ fontWeight: 600 }}>
+                  {report.success ? "✅" : "⚠️"} {report.message}
+                </div>
+                {report.success && (
+                  <div style={{ color: theme.colors.textMuted, marginTop: "4px" }}>
+                    {[
+                      report.authScheme && `${label("Hitelesítés", "Authentifizierung", "Authentication")}: ${report.authScheme}`,
+                      report.server && `${label("Szerver", "Server", "Server")}: ${report.server}`,
+                      report.collectionExists && `${label("Elemek", "Einträge", "Entries")}: ${report.entryCount}`,
+                      report.quotaAvailableBytes !== null && `${label("Szabad hely", "Freier Speicher", "Free space")}: ${formatBytes(report.quotaAvailableBytes)}`,
+                      report.chunkedUpload && label("Darabolt feltöltés", "Chunked Upload", "Chunked upload"),
+                    ]
+                      .filter(Boolean)
+                      .join(" · ")}
+                  </div>
+                )}
+              </div>
+            )}
+
+            <div style={{ display: "flex", gap: "12px", justifyContent: "flex-end" }}>
+              <button disabled={isBusy || !form.url.trim()} onClick={runTest} style={{ ...smallButton, ...themeStyles.buttonSecondary }}>
+                🔌 {label("Kapcsolat tesztelése", "Verbindung testen", "Test connection")}
+              </button>
+              <button
+                disabled={isBusy || !form.url.trim()}
+                onClick={save}
+                style={{ ...smallButton, ...themeStyles.buttonPrimary, opacity: form.url.trim() ? 1 : 0.5 }}
+              >
+                💾 {label("Mentés", "Speichern", "Save")}
+              </button>
+            </div>
+          </motion.div>
+        </motion.div>
+      )}
+    </AnimatePresence>
+  );
+};
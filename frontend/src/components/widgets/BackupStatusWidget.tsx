@@ -3,7 +3,7 @@ import type { WidgetConfig } from "../../types/widgets";
 import type { Theme } from "../../utils/themes";
 import type { Settings } from "../../types";
 import { useTranslation } from "../../utils/translations";
-import { getAutomaticBackupHistory, type BackupHistoryItem } from "../../utils/backup";
+import { getAutomaticBackupHistory, getBackupDestinations, type BackupHistoryItem, type BackupDestination } from "../../utils/backup";
 
 interface BackupStatusWidgetProps {
   widget: WidgetConfig;
@@ -35,6 +35,7 @@ export const BackupStatusWidget: React.FC<BackupStatusWidgetProps> = ({
 }) => {
   const t = useTranslation(settings.language);
   const [backupHistory, setBackupHistory] = useState<BackupHistoryItem[]>([]);
+  const [destinations, setDestinations] = useState<BackupDestination[]>([]);
   const [loading, setLoading] = useState(true);
   const [error, setError] = useState<string | null>(null);
   const isLoadingRef = useRef(false); // Flag, hogy ne töltse be egyszerre többször
@@ -73,6 +74,14 @@ export const BackupStatusWidget: React.FC<BackupStatusWidgetProps> = ({
       ]);
       
       setBackupHistory(history);
+
+      // További backup célok állapota (hiba esetén csak a célok listája marad üres)
+      try {
+        setDestinations((await getBackupDestinations()).filter(d => d.enabled));
+      } catch (destinationError) {
+        console.warn("Backup célok betöltése sikertelen:", destinationError);
+        setDestinations([]);
+      }
     } catch (err) {
       console.error("❌ Hiba a backup history betöltésekor:", err);
       const errorMessage = err instanceof Error && err.message.includes("Timeout")
@@ -263,6 +272,48 @@ export const BackupStatusWidget: React.FC<BackupStatusWidgetProps> = ({
               </div>
             )}
 
+            {/* További backup célok állapota (utolsó sikeres másolás / utolsó hiba) */}
+            {destinations.length > 0 && (
+              <div style={{
+                display: "flex",
+                flexDirection: "column",
+                gap: "4px",
+                fontSize: isSmall ? "10px" : fontSize,
+              }}>
+                {destinations.map(destination => {
+                  const status = destination.status;
+                  const failing = !!status?.lastError && (status.lastErrorAt ?? "") > (status.lastSuccessAt ?? "");
+                  const color = failing ? (theme.colors.danger || "#dc3545") : status?.lastSuccessAt ? "#22c55e" : theme.colors.textMuted;
+                  return (
+                    <div
+                      key={destination.id}
+                      title={failing ? status?.lastError ?? "" : destination.path}
+                      style={{
+                        display: "flex",
+                        justifyContent: "space-between",
+                        gap: "8px",
+                        padding: "6px 10px",
+                        borderRadius: "6px",
+                        backgroundColor: `${color}15`,
+                        border: `1px solid ${color}30`,
+                      }}
+                    >
+                      <span style={{ color: theme.colors.text, fontWeight: "600", overflow: "hidden", textOverflow: "ellipsis", whiteSpace: "nowrap" }}>
+                        💽 {destination.name}
+                      </span>
+                      <span style={{ color, fontWeight: "600", whiteSpace: "nowrap" }}>
+                        {failing
+                          ? "⚠️"
+                          : status?.lastSuccessAt
+                          ? `✓ ${formatDateLocal(new Date(status.lastSuccessAt))} ${formatTimeLocal(new Date(status.lastSuccessAt))}`
+                          : "–"}
+                      </span>
+                    </div>
+                  );
+                })}
+              </div>
+            )}
+
             {/* Backup történet lista */}
             <div style={{
               flex: 1,
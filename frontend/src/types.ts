@@ -208,6 +208,7 @@ export interface Settings {
   showHelpInMenu?: boolean; // Help menüpont megjelenítése a Sidebar-ban
   logRetentionDays?: number; // Log fájlok megtartása napokban (0 = soha ne törölje)
   auditLogRetentionDays?: number; // Audit log fájlok megtartása napokban (0 = soha ne törölje)
+  trashRetentionDays?: number; // Lomtár elemek megtartása napokban (0 = soha ne törölje, alapból 30)
   logFormat?: "text" | "json"; // Log fájl formátum (szöveges vagy JSON) - v1.8.0
   logLevel?: "DEBUG" | "INFO" | "WARN" | "ERROR"; // Minimum log szint (csak e feletti szintű logok kerülnek fájlba) - v1.8.0
   hideMacOSNotificationWarning?: boolean; // macOS értesítési figyelmeztetés elrejtése
@@ -219,6 +220,9 @@ export interface Settings {
   automaticBackupEnabled?: boolean; // Automatikus backup bekapcsolása
   automaticBackupIntervalHours?: number; // Automatikus backup intervallum (órákban)
   maxAutomaticBackups?: number; // Maximum automatikus backup-ok száma (régi törlése)
+  backupRetentionPolicy?: { hourly: number; daily: number; weekly: number; monthly: number }; // GFS megőrzés (ha be van állítva, a maxAutomaticBackups helyett)
+  incrementalBackupsEnabled?: boolean; // Inkrementális, deduplikált automatikus backupok (snapshotok)
+  backupEncryptionEnabled?: boolean; // Backup fájlok titkosítása jelmondattal (a jelmondat csak memóriában él)
   lastBackupDate?: string; // Utolsó backup dátuma (ISO string)
 }
 
@@ -352,8 +356,9 @@ export interface CalculationTemplate {
 // Szűrő típusok
 export interface OfferFilter {
   searchTerm?: string;
-  minPrice?: number;
+  minPrice?: number; // összköltség
   maxPrice?: number;
+  currency?: Currency; // a minPrice / maxPrice pénzneme (hiányában az árajánlat saját pénzneme)
   startDate?: string; // ISO date string
   endDate?: string; // ISO date string
   minProfit?: number;
@@ -1,13 +1,208 @@
 import { save, open } from "@tauri-apps/plugin-dialog";
-import { writeTextFile, readTextFile, readDir, exists } from "@tauri-apps/plugin-fs";
-import { join } from "@tauri-apps/api/path";
+import { writeTextFile } from "@tauri-apps/plugin-fs";
 import { invoke } from "@tauri-apps/api/core";
 import type { Printer, Filament, Offer, Settings } from "../types";
 import { auditBackup } from "./auditLog";
+import type { WebDavSettings } from "./webdav";
 
 // Lock mechanizmus a párhuzamos backupok megelőzésére
 let isCreatingBackup = false;
 
+/**
+ * Backup integritás állapota (a backend manifest ellenőrzése alapján)
+ * - ok: ellenőrzőösszeg és darabszámok egyeznek
+ * - corrupted: csonka / sérült fájl
+ * - unverified: régi backup manifest nélkül
+ */
+export type BackupIntegrityStatus = "ok" | "corrupted" | "unverified";
+
+// list_backup_files elem: [fájlnév, útvonal, timestamp, méret, integritás]
+export type BackupFileEntry = [string, string, string, number, BackupIntegrityStatus];
+
+export interface BackupVerification {
+  fileName: string;
+  filePath: string;
+  size: number;
+  status: BackupIntegrityStatus;
+  message: string | null;
+  manifest: {
+    manifestVersion: number;
+    appVersion: string;
+    schemaVersion: number;
+    createdAt: string;
+    entityCounts: Record<string, number>;
+    checksumAlgorithm: string;
+    checksum: string;
+    encrypted: boolean;
+  } | null;
+}
+
+export interface BackupVerificationReport {
+  checked: number;
+  ok: number;
+  corrupted: number;
+  unverified: number;
+  results: BackupVerification[];
+}
+
+export async function verifyBackup(filePath: string): Promise<BackupVerification> {
+  return invoke<BackupVerification>("verify_backup", { filePath });
+}
+
+export async function verifyAllBackups(): Promise<BackupVerificationReport> {
+  return invoke<BackupVerificationReport>("verify_all_backups");
+}
+
+export interface BackupSnapshotInfo {
+  id: string;
+  timestamp: string;
+  path: string;
+  schemaVersion: number;
+  entityCounts: Record<string, number>;
+  newObjects: number;
+  newBytes: number;
+  created: boolean; // false, ha nem volt változás az előző snapshot óta
+}
+
+export async function createBackupSnapshot(): Promise<BackupSnapshotInfo> {
+  return invoke<BackupSnapshotInfo>("create_backup_snapshot");
+}
+
+export async function listBackupSnapshots(): Promise<BackupSnapshotInfo[]> {
+  return invoke<BackupSnapshotInfo[]>("list_backup_snapshots");
+}
+
+/**
+ * Snapshot tartalma backup formátumban (bármely időpont visszaállításához)
+ */
+export async function getBackupSnapshotData(snapshotId: string): Promise<BackupData> {
+  return invoke<BackupData>("get_backup_snapshot_data", { snapshotId });
+}
+
+export async function deleteBackupSnapshot(snapshotId: string): Promise<void> {
+  await invoke("delete_backup_snapshot", { snapshotId });
+}
+
+export interface BackupDestinationStatus {
+  lastSuccessAt: string | null;
+  lastSuccessFile: string | null;
+  lastErrorAt: string | null;
+  lastError: string | null;
+  backupCount: number;
+}
+
+/**
+ * További backup cél (második mappa, USB meghajtó, csatolt NAS megosztás vagy WebDAV / Nextcloud)
+ * A backupok a cél "3DPrinterCalcApp_backups" alkönyvtárába kerülnek, saját megőrzési szabállyal
+ */
+export interface BackupDestination {
+  id: string; // Üres = új cél
+  name: string;
+  /** Mappa cél útvonala; WebDAV célnál a backend a gyűjtemény címével tölti ki */
+  path: string;
+  /** Ha meg van adva, a backupok ide töltődnek fel (folytatható feltöltéssel) */
+  webdav?: WebDavSettings | null;
+  enabled: boolean;
+  retentionCount: number | null;
+  retentionDays: number | null;
+  status?: BackupDestinationStatus;
+}
+
+export interface BackupReplicationResult {
+  destinationId: string;
+  name: string;
+  success: boolean;
+  filePath: string | null;
+  error: string | null;
+  removedByRetention: number;
+}
+
+export async function getBackupDestinations(): Promise<BackupDestination[]> {
+  return invoke<BackupDestination[]>("get_backup_destinations");
+}
+
+export async function saveBackupDestination(destination: BackupDestination): Promise<BackupDestination> {
+  return invoke<BackupDestination>("save_backup_destination", { destination });
+}
+
+export async function removeBackupDestination(destinationId: string): Promise<void> {
+  await invoke("remove_backup_destination", { destinationId });
+}
+
+/**
+ * A legutóbbi automatikus backup másolása az összes engedélyezett célra
+ */
+export async function replicateLatestBackup(): Promise<BackupReplicationResult[]> {
+  return invoke<BackupReplicationResult[]>("replicate_latest_backup");
+}
+
+/**
+ * Új backup cél hozzáadása mappaválasztóval
+ * @returns null, ha a felhasználó megszakította
+ */
+export async function addBackupDestination(
+  retention: { retentionCount?: number; retentionDays?: number } = {}
+): Promise<BackupDestination | null> {
+  const path = await open({ directory: true });
+  if (!path || typeof path !== "string") {
+    return null;
+  }
+  return saveBackupDestination({
+    id: "",
+    name: path.split(/[/\\]/).filter(Boolean).pop() || path,
+    path,
+    enabled: true,
+    retentionCount: retention.retentionCount ?? null,
+    retentionDays: retention.retentionDays ?? null,
+  });
+}
+
+/**
+ * Új WebDAV (Nextcloud) backup cél hozzáadása
+ */
+export async function addWebDavBackupDestination(
+  name: string,
+  webdav: WebDavSettings,
+  retention: { retentionCount?: number; retentionDays?: number } = {}
+): Promise<BackupDestination> {
+  return saveBackupDestination({
+    id: "",
+    name,
+    path: webdav.url,
+    webdav,
+    enabled: true,
+    retentionCount: retention.retentionCount ?? null,
+    retentionDays: retention.retentionDays ?? null,
+  });
+}
+
+/**
+ * A backend backup ütemező állapota
+ * Az ütemező az automaticBackupEnabled / automaticBackupIntervalHours beállítások szerint dolgozik,
+ * és "backup-progress", "backup-completed", "backup-failed" eseményeket küld
+ */
+export interface BackupSchedulerStatus {
+  running: boolean;
+  enabled: boolean;
+  intervalHours: number;
+  lastBackupAt: string | null;
+  nextBackupAt: string | null;
+  lastAttemptAt: string | null;
+  lastError: string | null;
+  inProgress: boolean;
+}
+
+export async function getBackupSchedulerStatus(): Promise<BackupSchedulerStatus> {
+  return invoke<BackupSchedulerStatus>("get_backup_scheduler_status");
+}
+
+/**
+ * Azonnali backup kérése az ütemezőtől (az eredmény eseményként érkezik)
+ */
+export async function runScheduledBackupNow(): Promise<void> {
+  await invoke("run_scheduled_backup_now");
+}
+
 export interface BackupData {
   version: string;
   timestamp: string;
@@ -17,6 +212,129 @@ export interface BackupData {
   settings?: Settings;
 }
 
+// Backend hibakód: titkosított backup / bekapcsolt titkosítás, de nincs jelmondat
+const BACKUP_PASSPHRASE_REQUIRED = "BACKUP_PASSPHRASE_REQUIRED";
+
+function isPassphraseRequired(error: unknown): boolean {
+  return String(error).includes(BACKUP_PASSPHRASE_REQUIRED);
+}
+
+/**
+ * Backup jelmondat bekérése és beállítása a munkamenetre
+ * @returns false, ha a felhasználó megszakította
+ */
+async function promptBackupPassphrase(message: string): Promise<boolean> {
+  for (;;) {
+    const passphrase = window.prompt(message);
+    if (passphrase === null) {
+      return false;
+    }
+    try {
+      await invoke("set_backup_passphrase", { passphrase });
+      return true;
+    } catch (error) {
+      message = `${error}\nPróbálja újra:`;
+    }
+  }
+}
+
+/**
+ * Backup jelmondat beállítása / törlése a munkamenetre (null = törlés)
+ */
+export async function setBackupPassphrase(passphrase: string | null): Promise<void> {
+  await invoke("set_backup_passphrase", { passphrase });
+}
+
+/**
+ * Backend hívás, ami jelmondatot igényelhet: ha hiányzik, bekéri és újrapróbálja
+ */
+async function withBackupPassphrase<T>(action: () => Promise<T>): Promise<T> {
+  try {
+    return await action();
+  } catch (error) {
+    if (!isPassphraseRequired(error)) {
+      throw error;
+    }
+    if (!(await promptBackupPassphrase("A backup titkosítás be van kapcsolva. Adja meg a backup jelmondatot:"))) {
+      throw new Error("Backup jelmondat megadása megszakítva");
+    }
+    return action();
+  }
+}
+
+/**
+ * Backup fájlt megnyitó backend hívás: titkosított backup esetén bekéri a jelmondatot és újrapróbálja
+ * @returns null, ha a felhasználó megszakította; egyébként az eredmény és a használt jelmondat
+ */
+export async function withBackupFilePassphrase<T>(
+  action: (passphrase?: string) => Promise<T>
+): Promise<{ result: T; passphrase?: string } | null> {
+  try {
+    return { result: await action() };
+  } catch (error) {
+    if (!isPassphraseRequired(error)) {
+      throw error;
+    }
+  }
+
+  let message = "A backup titkosított. Adja meg a backup jelmondatot:";
+  for (;;) {
+    const passphrase = window.prompt(message);
+    if (passphrase === null) {
+      return null;
+    }
+    try {
+      return { result: await action(passphrase), passphrase };
+    } catch (error) {
+      console.error("❌ Backup visszafejtése sikertelen:", error);
+      message = `${error}\nPróbálja újra:`;
+    }
+  }
+}
+
+export interface EntityDiff {
+  entityType: string;
+  added: string[];
+  changed: string[];
+  removed: string[];
+  unchanged: number;
+}
+
+export interface RestoreReport {
+  filePath: string;
+  dryRun: boolean;
+  applied: boolean;
+  backupTimestamp: string | null;
+  backupSchemaVersion: number;
+  integrity: BackupIntegrityStatus;
+  migrationSteps: string[];
+  warnings: string[];
+  entities: EntityDiff[];
+  settingsChanged: boolean;
+  safetyBackupPath: string | null;
+}
+
+/**
+ * Visszaállítás előnézet szövegesen (megerősítéshez)
+ */
+export function formatRestoreSummary(report: RestoreReport): string {
+  const lines = [
+    `Backup: ${report.backupTimestamp ?? "ismeretlen időpont"} (séma v${report.backupSchemaVersion})`,
+    ...report.entities.map(
+      (diff) =>
+        `${diff.entityType}: +${diff.added.length} új, ~${diff.changed.length} módosított, -${diff.removed.length} törölt, ${diff.unchanged} változatlan`
+    ),
+  ];
+  if (report.settingsChanged) {
+    lines.push("Beállítások: módosulnak");
+  }
+  if (report.migrationSteps.length > 0) {
+    lines.push(`Migráció: ${report.migrationSteps.length} lépés`);
+  }
+  lines.push(...report.warnings.map((warning) => `⚠️ ${warning}`));
+  return lines.join("\n");
+}
+
 export async function createBackup(
   printers: Printer[],
   filaments: Filament[],
@@ -56,7 +374,11 @@ export async function createBackup(
       return null;
     }
 
-    await writeTextFile(filePath, JSON.stringify(backupData, null, 2));
+    // Titkosítás, ha be van kapcsolva (a backend változatlanul adja vissza, ha nincs)
+    const fileContent = await withBackupPassphrase(() =>
+      invoke<unknown>("encrypt_backup_content", { backupData })
+    );
+    await writeTextFile(filePath, JSON.stringify(fileContent, null, 2));
     console.log("✅ Backup sikeresen létrehozva", { filePath, timestamp });
 
     // Audit log
@@ -80,15 +402,20 @@ export async function createBackup(
   }
 }
 
-export async function restoreBackup(): Promise<BackupData | null> {
+/**
+ * Teljes visszaállítás backupból a backend-en keresztül
+ * Először dry-run előnézetet kér (mi kerül hozzáadásra / módosításra / törlésre), és csak megerősítés után alkalmazza
+ * Alkalmazás után a backend "data-store-changed" eseményt küld, az App újratölti az adatokat
+ */
+export async function restoreBackup(): Promise<RestoreReport | null> {
   try {
     console.log("📥 Backup visszaállítása...");
 
     const filePath = await open({
       filters: [
         {
-          name: "JSON",
-          extensions: ["json"],
+          name: "Backup",
+          extensions: ["json", "zip"],
         },
       ],
     });
@@ -98,44 +425,156 @@ export async function restoreBackup(): Promise<BackupData | null> {
       return null;
     }
 
-    const content = await readTextFile(filePath);
-    const backupData: BackupData = JSON.parse(content);
+    const preview = await withBackupFilePassphrase((passphrase) =>
+      invoke<RestoreReport>("restore_backup", { filePath, passphrase, dryRun: true })
+    );
+    if (!preview) {
+      console.log("ℹ️ Backup visszaállítás megszakítva (nincs jelmondat)");
+      return null;
+    }
 
-    // Validáció
-    if (!backupData.version || !backupData.timestamp) {
-      throw new Error("Érvénytelen backup fájl formátum");
+    if (!window.confirm(`${formatRestoreSummary(preview.result)}\n\nFolytatja a visszaállítást?`)) {
+      console.log("ℹ️ Backup visszaállítás megszakítva az előnézet után");
+      return null;
     }
 
-    console.log("✅ Backup sikeresen betöltve", {
-      version: backupData.version,
-      timestamp: backupData.timestamp,
-      printers: backupData.printers?.length || 0,
-      filaments: backupData.filaments?.length || 0,
-      offers: backupData.offers?.length || 0,
+    const report = await invoke<RestoreReport>("restore_backup", {
+      filePath,
+      passphrase: preview.passphrase,
+      dryRun: false,
+    });
+
+    console.log("✅ Backup sikeresen visszaállítva", {
+      timestamp: report.backupTimestamp,
+      safetyBackupPath: report.safetyBackupPath,
+      entities: report.entities,
     });
 
     // Audit log
     try {
-      const fileName = typeof filePath === "string" ? filePath.split(/[/\\]/).pop() || "backup.json" : "backup.json";
+      const fileName = filePath.split(/[/\\]/).pop() || "backup.json";
       await auditBackup("backup_restore", fileName, {
-        filePath: typeof filePath === "string" ? filePath : undefined,
-        version: backupData.version,
-        timestamp: backupData.timestamp,
-        printersCount: backupData.printers?.length || 0,
-        filamentsCount: backupData.filaments?.length || 0,
-        offersCount: backupData.offers?.length || 0,
+        filePath,
+        timestamp: report.backupTimestamp,
+        backupSchemaVersion: report.backupSchemaVersion,
+        safetyBackupPath: report.safetyBackupPath,
+        changes: Object.fromEntries(
+          report.entities.map((diff) => [
+            diff.entityType,
+            { added: diff.added.length, changed: diff.changed.length, removed: diff.removed.length },
+          ])
+        ),
       });
     } catch (error) {
       console.warn("Audit log hiba:", error);
     }
 
-    return backupData;
+    return report;
   } catch (error) {
     console.error("❌ Hiba a backup visszaállításakor:", error);
     throw error;
   }
 }
 
+export type SelectableEntityType = "printers" | "filaments" | "offers" | "customers" | "projects" | "tasks";
+export type BackupRecordState = "new" | "changed" | "unchanged";
+export type RestoreConflictMode = "skip" | "overwrite" | "renumber";
+
+export interface BackupRecordSummary {
+  key: string;
+  label: string;
+  state: BackupRecordState;
+}
+
+export interface BackupContents {
+  filePath: string;
+  backupTimestamp: string | null;
+  backupSchemaVersion: number;
+  integrity: BackupIntegrityStatus;
+  warnings: string[];
+  entities: { entityType: SelectableEntityType; records: BackupRecordSummary[] }[];
+}
+
+export interface RestoreSelection {
+  entityType: SelectableEntityType;
+  keys?: string[]; // Hiányzó kulcsok = a teljes entitás típus
+}
+
+export interface SelectiveRestoreResult {
+  entityType: SelectableEntityType;
+  added: string[];
+  overwritten: string[];
+  skipped: string[];
+  renumbered: { from: number; to: number }[];
+}
+
+export interface SelectiveRestoreReport {
+  filePath: string;
+  dryRun: boolean;
+  applied: boolean;
+  conflictMode: RestoreConflictMode;
+  results: SelectiveRestoreResult[];
+  warnings: string[];
+  safetyBackupPath: string | null;
+}
+
+/**
+ * Backup megnyitása részleges visszaállításhoz: entitásonként a rekordok listája
+ * @returns null, ha a felhasználó megszakította a jelmondat bekérését; a jelmondat a visszaállításhoz kell
+ */
+export async function listBackupContents(
+  filePath: string
+): Promise<{ result: BackupContents; passphrase?: string } | null> {
+  return withBackupFilePassphrase((passphrase) =>
+    invoke<BackupContents>("list_backup_contents", { filePath, passphrase })
+  );
+}
+
+/**
+ * Kiválasztott entitás típusok / rekordok visszaállítása a jelenlegi adatokba fésülve
+ * Alkalmazás után a backend "data-store-changed" eseményt küld, az App újratölti az adatokat
+ */
+export async function restoreBackupSelection(
+  filePath: string,
+  selection: RestoreSelection[],
+  conflictMode: RestoreConflictMode,
+  options: { passphrase?: string; dryRun?: boolean } = {}
+): Promise<SelectiveRestoreReport> {
+  const report = await invoke<SelectiveRestoreReport>("restore_backup_selection", {
+    filePath,
+    passphrase: options.passphrase,
+    selection,
+    conflictMode,
+    dryRun: options.dryRun ?? false,
+  });
+
+  if (report.applied) {
+    try {
+      const fileName = filePath.split(/[/\\]/).pop() || "backup.json";
+      await auditBackup("backup_restore", fileName, {
+        filePath,
+        selective: true,
+        conflictMode,
+        safetyBackupPath: report.safetyBackupPath,
+        changes: Object.fromEntries(
+          report.results.map((result) => [
+            result.entityType,
+            {
+              added: result.added.length,
+              overwritten: result.overwritten.length,
+              renumbered: result.renumbered.length,
+            },
+          ])
+        ),
+      });
+    } catch (error) {
+      console.warn("Audit log hiba:", error);
+    }
+  }
+
+  return report;
+}
+
 /**
  * Segédfüggvény: formázza a dátumot YYYY-MM-DD formátumban helyi időzóna szerint
  */
@@ -167,7 +606,8 @@ async function hasTodayBackup(): Promise<boolean> {
     
     // Használjuk a backend command-ot, ami már visszaadja a timestamp-eket is
     // Ez gyorsabb, mint minden fájlt beolvasni
-    const backupFiles = await invoke<[string, string, string, number][]>("list_backup_files");
+    const backupFiles = await invoke<BackupFileEntry[]>("list_backup_files");
+    const pinned = new Set(await getPinnedBackups().catch(() => [] as string[]));
     
     if (import.meta.env.DEV) {
       console.log("🔍 Backup fájlok száma:", backupFiles.length);
@@ -251,6 +691,15 @@ export async function createAutomaticBackup(
   isCreatingBackup = true;
 
   try {
+    // Inkrementális mód: content-addressed snapshot (csak a változott rekordok és képek íródnak ki)
+    // Változatlan adat esetén nem készül új snapshot, ezért minden mentéskor futtatható
+    if (settings.incrementalBackupsEnabled === true && settings.backupEncryptionEnabled !== true) {
+      const snapshot = await createBackupSnapshot();
+      await invoke("cleanup_old_backup_snapshots", { days: 5 });
+      isCreatingBackup = false; // Lock feloldása
+      return { filePath: snapshot.path, timestamp: snapshot.timestamp, isNew: snapshot.created };
+    }
+
     // Ellenőrizzük, hogy van-e már mai napra backup
     const hasToday = await hasTodayBackup();
     if (hasToday) {
@@ -260,7 +709,7 @@ export async function createAutomaticBackup(
       // Ha már van mai backup, NEM frissítjük - csak a dátumot visszaadjuk
       // Az auto_backup csak naponta egyszer kell, hogy létrejöjjön
       // Használjuk a list_backup_files backend command-ot, hogy ne kelljen minden fájlt beolvasni
-      const backupFiles = await invoke<[string, string, string, number][]>("list_backup_files");
+      const backupFiles = await invoke<BackupFileEntry[]>("list_backup_files");
       
       // Helyi időzóna szerint formázzuk a mai dátumot
       const today = new Date();
@@ -325,9 +774,8 @@ export async function createAutomaticBackup(
 
     // Backend command használata a backup fájl létrehozásához
     // Ez elkerüli a Tauri permissions problémákat
-    const [filePath, backupTimestamp] = await invoke<[string, string]>(
-      "create_automatic_backup_file",
-      { backupData }
+    const [filePath, backupTimestamp] = await withBackupPassphrase(() =>
+      invoke<[string, string]>("create_automatic_backup_file", { backupData })
     );
     
     console.log("✅ Automatikus backup sikeresen létrehozva", {
@@ -394,6 +842,71 @@ export async function cleanupOldBackups(maxBackups: number = 10): Promise<void>
   }
 }
 
+/**
+ * Grandfather-father-son megőrzési szabály: ennyi órás / napi / heti / havi backup marad meg
+ */
+export interface BackupRetentionPolicy {
+  hourly: number;
+  daily: number;
+  weekly: number;
+  monthly: number;
+}
+
+export const DEFAULT_BACKUP_RETENTION_POLICY: BackupRetentionPolicy = {
+  hourly: 24,
+  daily: 7,
+  weekly: 4,
+  monthly: 12,
+};
+
+export interface BackupRetentionEntry {
+  fileName: string;
+  filePath: string;
+  timestamp: string | null;
+  size: number;
+  pinned: boolean;
+  reasons: ("latest" | "pinned" | "undated" | "hourly" | "daily" | "weekly" | "monthly")[]; // Üres = törlődik
+}
+
+export interface BackupRetentionPlan {
+  policy: BackupRetentionPolicy;
+  keep: BackupRetentionEntry[];
+  delete: BackupRetentionEntry[];
+  freedBytes: number;
+}
+
+/**
+ * A következő GFS takarítás előnézete (mely fájlok törlődnének)
+ */
+export async function previewBackupRetention(policy: BackupRetentionPolicy): Promise<BackupRetentionPlan> {
+  return invoke<BackupRetentionPlan>("preview_backup_retention", { policy });
+}
+
+/**
+ * Régi automatikus backupok takarítása a GFS megőrzési szabály szerint
+ */
+export async function applyBackupRetention(policy: BackupRetentionPolicy): Promise<void> {
+  try {
+    const result = await invoke<{ kept: number; deleted: string[]; failed: string[] }>("apply_backup_retention", { policy });
+    if (import.meta.env.DEV) {
+      console.log(`✅ GFS takarítás: ${result.deleted.length} törölve, ${result.kept} megmaradt`, result.failed);
+    }
+  } catch (error) {
+    console.error("❌ Hiba a GFS backup takarításkor:", error);
+  }
+}
+
+/**
+ * Backup rögzítése ("soha ne töröld") vagy a rögzítés feloldása
+ */
+export async function setBackupPinned(fileName: string, pinned: boolean): Promise<void> {
+  await invoke("set_backup_pinned", { fileName, pinned });
+}
+
+export async function getPinnedBackups(): Promise<string[]> {
+  return invoke<string[]>("get_pinned_backups");
+}
+
 /**
  * Törli az 5 napnál régebbi automatikus backup fájlokat
  * Backend command-ot használ, hogy elkerüljük a Tauri permissions problémákat
@@ -440,19 +953,21 @@ export interface BackupHistoryItem {
   date: Date;
   daysOld: number;
   willBeDeletedIn: number; // napok száma a törlésig
+  integrity: BackupIntegrityStatus; // Manifest alapú integritás állapot
+  pinned: boolean; // Rögzített ("soha ne töröld") backup
 }
 
 export async function getAutomaticBackupHistory(): Promise<BackupHistoryItem[]> {
   try {
     // Használjuk a backend command-ot, ami közvetlenül a fájlrendszerből listázza a backup fájlokat
     // Ez elkerüli a Tauri permissions problémát
-    const backupFiles = await invoke<[string, string, string, number][]>("list_backup_files");
+    const backupFiles = await invoke<BackupFileEntry[]>("list_backup_files");
     
     // Debug logok eltávolítva a teljesítmény javítása érdekében
     const now = new Date();
     const history: BackupHistoryItem[] = [];
 
-    for (const [fileName, filePath, timestamp, _fileSize] of backupFiles) {
+    for (const [fileName, filePath, timestamp, _fileSize, integrity] of backupFiles) {
       try {
         if (timestamp) {
           const backupDate = new Date(timestamp);
@@ -470,6 +985,8 @@ export async function getAutomaticBackupHistory(): Promise<BackupHistoryItem[]>
             date: backupDate,
             daysOld,
             willBeDeletedIn,
+            integrity,
+            pinned: pinned.has(fileName),
           });
         }
       } catch (error) {
@@ -538,40 +1055,23 @@ export function shouldShowBackupReminder(
  */
 export async function getLastBackupDate(): Promise<string | null> {
   try {
-    // Cross-platform backup könyvtár útvonal
-    const backupDir = await invoke<string>("get_backup_directory_path");
-    
-    if (!(await exists(backupDir))) {
-      if (import.meta.env.DEV) {
-        console.log("ℹ️ Backup könyvtár nem létezik:", backupDir);
-      }
-      return null;
-    }
-
-    const entries = await readDir(backupDir);
-    const backupFiles = entries
-      .filter(entry => entry.name?.endsWith(".json") && entry.name?.startsWith("auto_backup_"))
-      .map(entry => entry.name!)
-      .sort()
-      .reverse(); // Legújabb először
+    // A backend listázza a .json és a tömörített .zip backupokat is, dátum szerint rendezve (legújabb először)
+    const backupFiles = await invoke<BackupFileEntry[]>("list_backup_files");
+    const latest = backupFiles.find(([, , timestamp]) => !!timestamp);
 
-    if (backupFiles.length === 0) {
+    if (!latest) {
       if (import.meta.env.DEV) {
         console.log("ℹ️ Nincs automatikus backup fájl");
       }
       return null;
     }
 
-    // Kiolvassuk a legújabb backup fájlt, hogy megkapjuk a dátumát
-    const latestBackupPath = await join(backupDir, backupFiles[0]);
-    const content = await readTextFile(latestBackupPath);
-    const backupData: BackupData = JSON.parse(content);
-    
+    const [fileName, , timestamp] = latest;
     if (import.meta.env.DEV) {
-      console.log("✅ Utolsó backup dátum:", backupData.timestamp, "fájl:", backupFiles[0]);
+      console.log("✅ Utolsó backup dátum:", timestamp, "fájl:", fileName);
     }
     
-    return backupData.timestamp || null;
+    return timestamp || null;
   } catch (error) {
     console.error("❌ Hiba az utolsó backup dátumának lekérdezésénél:", error);
     return null;
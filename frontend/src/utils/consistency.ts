@@ -0,0 +1,64 @@
+// Adatkonzisztencia ellenőrzés és javítás (backend: consistency.rs)
+// Nem létező rekordokra mutató hivatkozások, duplikált azonosítók, negatív értékek, lehetetlen dátumok
+
+import { invoke } from "@tauri-apps/api/core";
+
+export type ConsistencyIssueKind =
+  | "danglingReference"
+  | "missingId"
+  | "duplicateId"
+  | "duplicateKey"
+  | "unlinkedCustomer"
+  | "negativeValue"
+  | "outOfRange"
+  | "invalidDate"
+  | "impossibleDate";
+
+export type ConsistencyRepair =
+  | { action: "clearField"; field: string }
+  | { action: "removeFromList"; field: string; value: unknown }
+  | { action: "setField"; field: string; value: unknown };
+
+export interface ConsistencyIssue {
+  /** Stabil azonosító, a javítási kérés erre hivatkozik */
+  id: string;
+  severity: "error" | "warning";
+  kind: ConsistencyIssueKind;
+  entityType: string;
+  recordKey: string;
+  field: string | null;
+  /** Magyar nyelvű leírás a backendből */
+  message: string;
+  /** null, ha nincs biztonságos automatikus javítás */
+  repair: ConsistencyRepair | null;
+}
+
+export interface ConsistencyReport {
+  checkedAt: string;
+  recordsChecked: number;
+  errors: number;
+  warnings: number;
+  repairable: number;
+  issues: ConsistencyIssue[];
+}
+
+export interface ConsistencyRepairReport {
+  dryRun: boolean;
+  repaired: ConsistencyIssue[];
+  skipped: string[];
+  safetyBackup: string | null;
+  /** Ellenőrzés a javítások után */
+  report: ConsistencyReport;
+}
+
+export async function checkDataConsistency(): Promise<ConsistencyReport> {
+  return invoke<ConsistencyReport>("check_data_consistency");
+}
+
+/**
+ * Javítható problémák javítása (azonosítók nélkül az összes), előtte biztonsági mentés készül
+ * Alkalmazás után a backend "data-store-changed" eseményt küld, az App újratölti az adatokat
+ */
+export async function repairDataConsistency(issueIds?: string[], dryRun = false): Promise<ConsistencyRepairReport> {
+  return invoke<ConsistencyRepairReport>("repair_data_consistency", { issueIds: issueIds ?? null, dryRun });
+}
@@ -0,0 +1,55 @@
+// Adatkönyvtár (Store, logok, backupok, audit logok helye) - alapértelmezett, hordozható vagy választott
+
+import { invoke } from "@tauri-apps/api/core";
+import { open } from "@tauri-apps/plugin-dialog";
+
+/**
+ * Honnan származik az adatkönyvtár:
+ * - default: az operációs rendszer helyi adatkönyvtára
+ * - portable: a futtatható állomány melletti "data" könyvtár (portableMarker fájl jelenlétében)
+ * - custom: a beállításokban választott könyvtár
+ * - environment: az envVariable környezeti változó
+ */
+export type DataLocationMode = "default" | "portable" | "custom" | "environment";
+
+export interface DataLayout {
+  mode: DataLocationMode;
+  root: string;
+  storeFile: string;
+  logsDir: string;
+  backupsDir: string;
+  automaticBackupsDir: string;
+  auditLogsDir: string;
+  legacyBackupsDir: string | null;
+  customRoot: string | null;
+  restartRequired: boolean;
+  envVariable: string;
+  portableMarker: string;
+  /** Az aktív munkaterület azonosítója és könyvtára (alapértelmezett munkaterületnél a root) */
+  workspaceId: string;
+  workspaceRoot: string;
+}
+
+export async function getDataLayout(): Promise<DataLayout> {
+  return invoke<DataLayout>("get_data_layout");
+}
+
+/**
+ * Adatkönyvtár beállítása (null = vissza az alapértelmezetthez), újraindítás után lép életbe
+ * @param copyExisting a jelenlegi adatok átmásolása az új helyre (meglévő fájlokat nem ír felül)
+ */
+export async function setDataRoot(root: string | null, copyExisting: boolean): Promise<DataLayout> {
+  return invoke<DataLayout>("set_data_root", { root, copyExisting });
+}
+
+/**
+ * Könyvtár kiválasztása párbeszédablakban, majd beállítása adatkönyvtárként
+ * @returns null, ha a felhasználó megszakította
+ */
+export async function chooseDataRoot(copyExisting: boolean): Promise<DataLayout | null> {
+  const path = await open({ directory: true });
+  if (!path || typeof path !== "string") {
+    return null;
+  }
+  return setDataRoot(path, copyExisting);
+}
@@ -1,6 +1,7 @@
 // Mappa elérések és fájlok információinak logolása
 
 import { invoke } from "@tauri-apps/api/core";
+import type { BackupFileEntry } from "./backup";
 import { writeFrontendLog } from "./fileLogger";
 
 // Flag, hogy csak egyszer logoljuk a mappa információkat
@@ -79,7 +80,7 @@ export async function getLogDirectoryInfo(): Promise<DirectoryInfo | null> {
 export async function getBackupDirectoryInfo(): Promise<DirectoryInfo | null> {
   try {
     const backupDirPath = await invoke<string>("get_backup_directory_path");
-    const backupFiles = await invoke<[string, string, string, number][]>("list_backup_files");
+    const backupFiles = await invoke<BackupFileEntry[]>("list_backup_files");
     
     const files: FileInfo[] = [];
     let totalSize = 0;
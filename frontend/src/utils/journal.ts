@@ -0,0 +1,105 @@
+// Változásnapló: minden létrehozás / módosítás / törlés a korábbi és az új értékkel
+// Rekord előzmények böngészése és az adatok visszaállítása egy korábbi időpontra (backend: journal.rs)
+
+import { invoke } from "@tauri-apps/api/core";
+
+export type JournalEntityType = "printers" | "filaments" | "offers" | "customers" | "projects" | "tasks" | "templates";
+export type JournalOperation = "create" | "update" | "delete";
+
+export interface JournalEntry {
+  seq: number;
+  /** UTC, RFC 3339 */
+  timestamp: string;
+  entityType: JournalEntityType;
+  /** Azonosító, filamentnél "márka|típus|szín" kisbetűvel */
+  recordKey: string;
+  operation: JournalOperation;
+  before: Record<string, unknown> | null;
+  after: Record<string, unknown> | null;
+  /** frontend, restore, import, journal-restore... */
+  source: string;
+}
+
+export interface JournalStatus {
+  /** A legkorábbi időpont, amelyre az adatok visszaállíthatók */
+  checkpointAt: string;
+  entryCount: number;
+  segments: string[];
+  totalSize: number;
+}
+
+export interface JournalRestoreResult {
+  timestamp: string;
+  records: Partial<Record<JournalEntityType, number>>;
+  safetyBackup: string | null;
+}
+
+/** Filament rekord kulcsa a naplóban (a backend data_store::record_key alapján) */
+export function filamentRecordKey(filament: { brand?: string; type?: string; color?: string }): string {
+  const part = (value?: string) => (value ?? "").trim().toLowerCase();
+  return `${part(filament.brand)}|${part(filament.type)}|${part(filament.color)}`;
+}
+
+export async function getJournalStatus(): Promise<JournalStatus> {
+  return invoke<JournalStatus>("get_journal_status");
+}
+
+/** Egy rekord előzményei, legújabb elöl */
+export async function getRecordHistory(
+  entityType: JournalEntityType,
+  recordKey: string | number,
+  limit?: number
+): Promise<JournalEntry[]> {
+  return invoke<JournalEntry[]>("get_record_history", {
+    entityType,
+    recordKey: String(recordKey),
+    limit: limit ?? null,
+  });
+}
+
+/** Naplóbejegyzések időszak szerint (dátum "YYYY-MM-DD" vagy RFC 3339 időpont), legújabb elöl */
+export async function getJournalEntries(filter: {
+  entityType?: JournalEntityType;
+  since?: string;
+  until?: string;
+  limit?: number;
+}): Promise<JournalEntry[]> {
+  return invoke<JournalEntry[]>("get_journal_entries", {
+    entityType: filter.entityType ?? null,
+    since: filter.since ?? null,
+    until: filter.until ?? null,
+    limit: filter.limit ?? null,
+  });
+}
+
+/** Egy rekord állapota egy adott időpontban (null, ha akkor nem létezett) */
+export async function getRecordAsOf(
+  entityType: JournalEntityType,
+  recordKey: string | number,
+  timestamp: string
+): Promise<Record<string, unknown> | null> {
+  return invoke<Record<string, unknown> | null>("get_record_as_of", {
+    entityType,
+    recordKey: String(recordKey),
+    timestamp,
+  });
+}
+
+/** Az adatok egy adott időpontban, a Store formátumában (csak dátum esetén a nap végén) */
+export async function getDataAsOf(
+  timestamp: string,
+  entityType?: JournalEntityType
+): Promise<Partial<Record<JournalEntityType, unknown[]>>> {
+  return invoke<Partial<Record<JournalEntityType, unknown[]>>>("get_data_as_of", {
+    timestamp,
+    entityType: entityType ?? null,
+  });
+}
+
+/**
+ * Point-in-time visszaállítás (előtte biztonsági mentés készül)
+ * Alkalmazás után a backend "data-store-changed" eseményt küld, az App újratölti az adatokat
+ */
+export async function restoreDataAsOf(timestamp: string): Promise<JournalRestoreResult> {
+  return invoke<JournalRestoreResult>("restore_data_as_of", { timestamp });
+}
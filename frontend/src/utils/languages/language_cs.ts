@@ -823,6 +823,8 @@ export const languageCs: TranslationMap = {
   "offers.toast.reordered": "Nabídky přeuspořádány",
   "offers.toast.exportHtmlSuccess": "HTML soubor úspěšně uložen:",
   "offers.toast.exportHtmlError": "Došlo k chybě:",
+  "offers.toast.exportPdfSuccess": "PDF uloženo:",
+  "offers.toast.exportPdfMissingGlyphs": "Některé znaky nemají nainstalované písmo a v PDF se zobrazí jako prázdné čtverečky:",
   "offers.versionPrefix": "v",
   "offers.subtitle": "Spravovat a exportovat uložené nabídky",
   "offers.search.label": "Vyhledávání",
@@ -853,6 +855,7 @@ export const languageCs: TranslationMap = {
   "offers.tooltip.duplicate": "Duplikovat nabídku",
   "offers.tooltip.exportPdf": "Export PDF nebo tisk",
   "offers.tooltip.downloadHtml": "Stáhnout PDF jako HTML soubor",
+  "offers.tooltip.downloadPdf": "Uložit jako PDF – stejné rozvržení na všech platformách",
   "offers.details.created": "Vytvořeno:",
   "offers.details.statusUpdated": "Status aktualizován:",
   "offers.details.totalCost": "Celkové náklady",
@@ -917,6 +920,8 @@ export const languageCs: TranslationMap = {
   "offers.pdf.history.profit": "Zisk",
   "offers.pdf.history.totalCost": "Celkové náklady",
   "offers.pdf.statusHistory.none": "Žádná historie statusu k dispozici.",
+  "offers.pdf.signature": "Podpis",
+  "offers.pdf.page": "Strana {page} z {pages}",
   "offers.filters.minAmount": "Min. částka",
   "offers.filters.maxAmount": "Max. částka",
   "offers.filters.fromDate": "Datum od",
@@ -943,6 +948,7 @@ export const languageCs: TranslationMap = {
   "common.printerDeleted": "Tiskárna úspěšně smazána!",
   "common.offerSaved": "Nabídka úspěšně uložena!",
   "common.offerDeleted": "Nabídka úspěšně smazána!",
+  "common.trashMoveFailed": "Přesun do koše se nezdařil, mazání zrušeno.",
   "common.loading": "Načítání...",
   "common.duplicate": "Duplikovat",
   "common.undo": "Zpět",
@@ -873,6 +873,8 @@ export const languageDe: TranslationMap = {
   "offers.toast.reordered": "Angebote neu angeordnet",
   "offers.toast.exportHtmlSuccess": "HTML-Datei erfolgreich gespeichert:",
   "offers.toast.exportHtmlError": "Fehler aufgetreten:",
+  "offers.toast.exportPdfSuccess": "PDF gespeichert:",
+  "offers.toast.exportPdfMissingGlyphs": "Für einige Zeichen ist keine Schriftart installiert, sie erscheinen im PDF als leere Kästchen:",
   "offers.versionPrefix": "v",
   "offers.subtitle": "Gespeicherte Angebote verwalten und exportieren",
   "offers.search.label": "Suchen",
@@ -903,6 +905,7 @@ export const languageDe: TranslationMap = {
   "offers.tooltip.duplicate": "Angebot duplizieren",
   "offers.tooltip.exportPdf": "PDF-Export oder Drucken",
   "offers.tooltip.downloadHtml": "PDF als HTML-Datei herunterladen",
+  "offers.tooltip.downloadPdf": "Als PDF speichern – identisches Layout auf allen Plattformen",
   "offers.details.created": "Erstellt:",
   "offers.details.statusUpdated": "Status aktualisiert:",
   "offers.details.totalCost": "Gesamtkosten",
@@ -971,6 +974,8 @@ export const languageDe: TranslationMap = {
   "offers.pdf.history.profit": "Gewinn",
   "offers.pdf.history.totalCost": "Gesamtkosten",
   "offers.pdf.statusHistory.none": "Keine Statusänderungen vorhanden.",
+  "offers.pdf.signature": "Unterschrift",
+  "offers.pdf.page": "Seite {page} von {pages}",
   // Common
   "common.delete": "Löschen",
   "common.add": "Hinzufügen",
@@ -993,6 +998,7 @@ export const languageDe: TranslationMap = {
   "common.printerDeleted": "Drucker erfolgreich gelöscht!",
   "common.offerSaved": "Angebot erfolgreich gespeichert!",
   "common.offerDeleted": "Angebot erfolgreich gelöscht!",
+  "common.trashMoveFailed": "Verschieben in den Papierkorb fehlgeschlagen, Löschen abgebrochen.",
   "common.loading": "Laden...",
   "common.duplicate": "Duplizieren",
   "common.undo": "Rückgängig",
@@ -902,6 +902,8 @@ export const languageEn: TranslationMap = {
   "offers.toast.reordered": "Offers reordered",
   "offers.toast.exportHtmlSuccess": "HTML file saved successfully:",
   "offers.toast.exportHtmlError": "Error occurred:",
+  "offers.toast.exportPdfSuccess": "PDF saved:",
+  "offers.toast.exportPdfMissingGlyphs": "Some characters have no installed font and appear as empty boxes in the PDF:",
   "offers.versionPrefix": "v",
   "offers.subtitle": "Manage and export saved offers",
   "offers.search.label": "Search",
@@ -932,6 +934,7 @@ export const languageEn: TranslationMap = {
   "offers.tooltip.duplicate": "Duplicate offer",
   "offers.tooltip.exportPdf": "PDF export or print",
   "offers.tooltip.downloadHtml": "Download PDF as HTML file",
+  "offers.tooltip.downloadPdf": "Save as PDF – identical layout on every platform",
   "offers.details.created": "Created:",
   "offers.details.statusUpdated": "Status updated:",
   "offers.details.totalCost": "Total cost",
@@ -1000,6 +1003,8 @@ export const languageEn: TranslationMap = {
   "offers.pdf.history.profit": "Profit",
   "offers.pdf.history.totalCost": "Total cost",
   "offers.pdf.statusHistory.none": "No status history available.",
+  "offers.pdf.signature": "Signature",
+  "offers.pdf.page": "Page {page} of {pages}",
   // Common
   "common.delete": "Delete",
   "common.add": "Add",
@@ -1022,6 +1027,7 @@ export const languageEn: TranslationMap = {
   "common.printerDeleted": "Printer deleted successfully!",
   "common.offerSaved": "Offer saved successfully!",
   "common.offerDeleted": "Offer deleted successfully!",
+  "common.trashMoveFailed": "Could not move to the trash, deletion cancelled.",
   "common.loading": "Loading...",
   "common.duplicate": "Duplicate",
   "common.undo": "Undo",
@@ -823,6 +823,8 @@ export const languageEs: TranslationMap = {
   "offers.toast.reordered": "Ofertas reordenadas",
   "offers.toast.exportHtmlSuccess": "Archivo HTML guardado exitosamente:",
   "offers.toast.exportHtmlError": "Ocurrió un error:",
+  "offers.toast.exportPdfSuccess": "PDF guardado:",
+  "offers.toast.exportPdfMissingGlyphs": "Algunos caracteres no tienen fuente instalada y aparecen como cuadros vacíos en el PDF:",
   "offers.versionPrefix": "v",
   "offers.subtitle": "Gestionar y exportar ofertas guardadas",
   "offers.search.label": "Buscar",
@@ -853,6 +855,7 @@ export const languageEs: TranslationMap = {
   "offers.tooltip.duplicate": "Duplicar oferta",
   "offers.tooltip.exportPdf": "Exportar PDF o imprimir",
   "offers.tooltip.downloadHtml": "Descargar PDF como archivo HTML",
+  "offers.tooltip.downloadPdf": "Guardar como PDF: el mismo diseño en todas las plataformas",
   "offers.details.created": "Creado:",
   "offers.details.statusUpdated": "Estado actualizado:",
   "offers.details.totalCost": "Costo total",
@@ -921,6 +924,8 @@ export const languageEs: TranslationMap = {
   "offers.pdf.history.profit": "Beneficio",
   "offers.pdf.history.totalCost": "Costo total",
   "offers.pdf.statusHistory.none": "No hay historial de estado disponible.",
+  "offers.pdf.signature": "Firma",
+  "offers.pdf.page": "Página {page} de {pages}",
   // Common
   "common.delete": "Eliminar",
   "common.add": "Agregar",
@@ -943,6 +948,7 @@ export const languageEs: TranslationMap = {
   "common.printerDeleted": "¡Impresora eliminada exitosamente!",
   "common.offerSaved": "¡Oferta guardada exitosamente!",
   "common.offerDeleted": "¡Oferta eliminada exitosamente!",
+  "common.trashMoveFailed": "No se pudo mover a la papelera, eliminación cancelada.",
   "common.loading": "Cargando...",
   "common.duplicate": "Duplicar",
   "common.undo": "Deshacer",
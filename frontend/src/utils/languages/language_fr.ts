@@ -848,6 +848,8 @@ export const languageFr: TranslationMap = {
   "offers.toast.reordered": "Offres réorganisées.",
   "offers.toast.exportHtmlSuccess": "Fichier HTML enregistré :",
   "offers.toast.exportHtmlError": "Une erreur est survenue :",
+  "offers.toast.exportPdfSuccess": "PDF enregistré :",
+  "offers.toast.exportPdfMissingGlyphs": "Certains caractères n'ont pas de police installée et apparaissent comme des cases vides dans le PDF :",
   "offers.versionPrefix": "v ",
   "offers.subtitle": "Gérez et exportez vos offres",
   "offers.search.label": "Rechercher",
@@ -879,6 +881,7 @@ export const languageFr: TranslationMap = {
   "offers.tooltip.duplicate": "Dupliquer l'offre",
   "offers.tooltip.exportPdf": "Exporter ou imprimer en PDF",
   "offers.tooltip.downloadHtml": "Télécharger le PDF au format HTML",
+  "offers.tooltip.downloadPdf": "Enregistrer en PDF – mise en page identique sur toutes les plateformes",
   "offers.details.created": "Créé",
   "offers.details.statusUpdated": "Statut mis à jour :",
   "offers.details.totalCost": "Coût total",
@@ -947,6 +950,8 @@ export const languageFr: TranslationMap = {
   "offers.pdf.history.profit": "Bénéfice",
   "offers.pdf.history.totalCost": "Coût total",
   "offers.pdf.statusHistory.none": "Aucun historique de statut disponible.",
+  "offers.pdf.signature": "Signature",
+  "offers.pdf.page": "Page {page} sur {pages}",
 
   // Common
   "common.delete": "Supprimer",
@@ -970,6 +975,7 @@ export const languageFr: TranslationMap = {
   "common.printerDeleted": "Imprimante supprimée avec succès.",
   "common.offerSaved": "Offre enregistrée avec succès.",
   "common.offerDeleted": "Offre supprimée avec succès.",
+  "common.trashMoveFailed": "Impossible de déplacer vers la corbeille, suppression annulée.",
   "common.loading": "Chargement...",
   "common.duplicate": "Dupliquer",
   "common.undo": "Annuler",
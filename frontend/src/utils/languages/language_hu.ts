@@ -809,6 +809,8 @@ export const languageHu: TranslationMap = {
   "offers.toast.reordered": "Árajánlatok átrendezve",
   "offers.toast.exportHtmlSuccess": "HTML fájl sikeresen mentve:",
   "offers.toast.exportHtmlError": "Hiba történt:",
+  "offers.toast.exportPdfSuccess": "PDF mentve:",
+  "offers.toast.exportPdfMissingGlyphs": "Néhány karakterhez nincs telepített betűkészlet, a PDF-ben üres négyzetként jelennek meg:",
   "offers.versionPrefix": "v",
   "offers.subtitle": "Mentett árajánlatok kezelése és exportálása",
   "offers.search.label": "Keresés",
@@ -839,6 +841,7 @@ export const languageHu: TranslationMap = {
   "offers.tooltip.duplicate": "Árajánlat duplikálása",
   "offers.tooltip.exportPdf": "PDF export vagy nyomtatás",
   "offers.tooltip.downloadHtml": "PDF letöltése HTML fájlként",
+  "offers.tooltip.downloadPdf": "Mentés PDF-ként – minden platformon azonos megjelenés",
   "offers.details.created": "Létrehozva:",
   "offers.details.statusUpdated": "Státusz frissítve:",
   "offers.details.totalCost": "Összköltség",
@@ -907,6 +910,8 @@ export const languageHu: TranslationMap = {
   "offers.pdf.history.profit": "Profit",
   "offers.pdf.history.totalCost": "Összköltség",
   "offers.pdf.statusHistory.none": "Még nem történt státuszváltás.",
+  "offers.pdf.signature": "Aláírás",
+  "offers.pdf.page": "{page}. oldal / {pages}",
   // Common
   "common.delete": "Törlés",
   "common.add": "Hozzáadás",
@@ -929,6 +934,7 @@ export const languageHu: TranslationMap = {
   "common.printerDeleted": "Nyomtató sikeresen törölve!",
   "common.offerSaved": "Árajánlat sikeresen mentve!",
   "common.offerDeleted": "Árajánlat sikeresen törölve!",
+  "common.trashMoveFailed": "A lomtárba helyezés sikertelen, a törlés megszakítva.",
   "common.loading": "Betöltés...",
   "common.duplicate": "Duplikálás",
   "common.undo": "Visszavonás",
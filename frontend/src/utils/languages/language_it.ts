@@ -823,6 +823,8 @@ export const languageIt: TranslationMap = {
   "offers.toast.reordered": "Offerte riordinate",
   "offers.toast.exportHtmlSuccess": "File HTML salvato con successo:",
   "offers.toast.exportHtmlError": "Si è verificato un errore:",
+  "offers.toast.exportPdfSuccess": "PDF salvato:",
+  "offers.toast.exportPdfMissingGlyphs": "Alcuni caratteri non hanno un font installato e compaiono come riquadri vuoti nel PDF:",
   "offers.versionPrefix": "v",
   "offers.subtitle": "Gestisci ed esporta offerte salvate",
   "offers.search.label": "Cerca",
@@ -853,6 +855,7 @@ export const languageIt: TranslationMap = {
   "offers.tooltip.duplicate": "Duplica offerta",
   "offers.tooltip.exportPdf": "Esporta PDF o stampa",
   "offers.tooltip.downloadHtml": "Scarica PDF come file HTML",
+  "offers.tooltip.downloadPdf": "Salva come PDF – layout identico su tutte le piattaforme",
   "offers.details.created": "Creato:",
   "offers.details.statusUpdated": "Stato aggiornato:",
   "offers.details.totalCost": "Costo totale",
@@ -921,6 +924,8 @@ export const languageIt: TranslationMap = {
   "offers.pdf.history.profit": "Profitto",
   "offers.pdf.history.totalCost": "Costo totale",
   "offers.pdf.statusHistory.none": "Nessuna cronologia stato disponibile.",
+  "offers.pdf.signature": "Firma",
+  "offers.pdf.page": "Pagina {page} di {pages}",
   // Common
   "common.delete": "Elimina",
   "common.add": "Aggiungi",
@@ -943,6 +948,7 @@ export const languageIt: TranslationMap = {
   "common.printerDeleted": "Stampante eliminata con successo!",
   "common.offerSaved": "Offerta salvata con successo!",
   "common.offerDeleted": "Offerta eliminata con successo!",
+  "common.trashMoveFailed": "Impossibile spostare nel cestino, eliminazione annullata.",
   "common.loading": "Caricamento...",
   "common.duplicate": "Duplica",
   "common.undo": "Annulla",
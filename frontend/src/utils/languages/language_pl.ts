@@ -823,6 +823,8 @@ export const languagePl: TranslationMap = {
   "offers.toast.reordered": "Oferty uporządkowane",
   "offers.toast.exportHtmlSuccess": "Plik HTML zapisany pomyślnie:",
   "offers.toast.exportHtmlError": "Wystąpił błąd:",
+  "offers.toast.exportPdfSuccess": "Zapisano PDF:",
+  "offers.toast.exportPdfMissingGlyphs": "Niektóre znaki nie mają zainstalowanej czcionki i w PDF pojawią się jako puste kwadraty:",
   "offers.versionPrefix": "v",
   "offers.subtitle": "Zarządzaj i eksportuj zapisane oferty",
   "offers.search.label": "Szukaj",
@@ -853,6 +855,7 @@ export const languagePl: TranslationMap = {
   "offers.tooltip.duplicate": "Duplikuj ofertę",
   "offers.tooltip.exportPdf": "Eksport PDF lub drukuj",
   "offers.tooltip.downloadHtml": "Pobierz PDF jako plik HTML",
+  "offers.tooltip.downloadPdf": "Zapisz jako PDF – identyczny układ na każdej platformie",
   "offers.details.created": "Utworzono:",
   "offers.details.statusUpdated": "Status zaktualizowany:",
   "offers.details.totalCost": "Całkowity koszt",
@@ -917,6 +920,8 @@ export const languagePl: TranslationMap = {
   "offers.pdf.history.profit": "Zysk",
   "offers.pdf.history.totalCost": "Całkowity koszt",
   "offers.pdf.statusHistory.none": "Brak dostępnej historii statusu.",
+  "offers.pdf.signature": "Podpis",
+  "offers.pdf.page": "Strona {page} z {pages}",
   "offers.filters.minAmount": "Kwota min.",
   "offers.filters.maxAmount": "Kwota maks.",
   "offers.filters.fromDate": "Data od",
@@ -943,6 +948,7 @@ export const languagePl: TranslationMap = {
   "common.printerDeleted": "Drukarka usunięta pomyślnie!",
   "common.offerSaved": "Oferta zapisana pomyślnie!",
   "common.offerDeleted": "Oferta usunięta pomyślnie!",
+  "common.trashMoveFailed": "Nie udało się przenieść do kosza, usuwanie anulowane.",
   "common.loading": "Ładowanie...",
   "common.duplicate": "Duplikuj",
   "common.undo": "Cofnij",
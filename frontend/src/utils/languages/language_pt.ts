@@ -823,6 +823,8 @@ export const languagePt: TranslationMap = {
   "offers.toast.reordered": "Ofertas reordenadas",
   "offers.toast.exportHtmlSuccess": "Arquivo HTML salvo com sucesso:",
   "offers.toast.exportHtmlError": "Ocorreu um erro:",
+  "offers.toast.exportPdfSuccess": "PDF guardado:",
+  "offers.toast.exportPdfMissingGlyphs": "Alguns caracteres não têm fonte instalada e aparecem como caixas vazias no PDF:",
   "offers.versionPrefix": "v",
   "offers.subtitle": "Gerenciar e exportar ofertas salvas",
   "offers.search.label": "Pesquisar",
@@ -853,6 +855,7 @@ export const languagePt: TranslationMap = {
   "offers.tooltip.duplicate": "Duplicar oferta",
   "offers.tooltip.exportPdf": "Exportação PDF ou impressão",
   "offers.tooltip.downloadHtml": "Baixar PDF como arquivo HTML",
+  "offers.tooltip.downloadPdf": "Guardar como PDF – mesmo layout em todas as plataformas",
   "offers.details.created": "Criado:",
   "offers.details.statusUpdated": "Status atualizado:",
   "offers.details.totalCost": "Custo total",
@@ -917,6 +920,8 @@ export const languagePt: TranslationMap = {
   "offers.pdf.history.profit": "Lucro",
   "offers.pdf.history.totalCost": "Custo total",
   "offers.pdf.statusHistory.none": "Nenhum histórico de status disponível.",
+  "offers.pdf.signature": "Assinatura",
+  "offers.pdf.page": "Página {page} de {pages}",
   "offers.filters.minAmount": "Valor mínimo",
   "offers.filters.maxAmount": "Valor máximo",
   "offers.filters.fromDate": "Data inicial",
@@ -943,6 +948,7 @@ export const languagePt: TranslationMap = {
   "common.printerDeleted": "Impressora excluída com sucesso!",
   "common.offerSaved": "Oferta salva com sucesso!",
   "common.offerDeleted": "Oferta excluída com sucesso!",
+  "common.trashMoveFailed": "Não foi possível mover para a lixeira, exclusão cancelada.",
   "common.loading": "Carregando...",
   "common.duplicate": "Duplicar",
   "common.undo": "Desfazer",
@@ -823,6 +823,8 @@ export const languageRu: TranslationMap = {
   "offers.toast.reordered": "Предложения переупорядочены",
   "offers.toast.exportHtmlSuccess": "HTML-файл успешно сохранен:",
   "offers.toast.exportHtmlError": "Произошла ошибка:",
+  "offers.toast.exportPdfSuccess": "PDF сохранён:",
+  "offers.toast.exportPdfMissingGlyphs": "Для некоторых символов не установлен шрифт, в PDF они отображаются пустыми квадратами:",
   "offers.versionPrefix": "в",
   "offers.subtitle": "Управление и экспорт сохраненных предложений",
   "offers.search.label": "Поиск",
@@ -853,6 +855,7 @@ export const languageRu: TranslationMap = {
   "offers.tooltip.duplicate": "Дублировать предложение",
   "offers.tooltip.exportPdf": "Экспорт PDF или печать",
   "offers.tooltip.downloadHtml": "Загрузить PDF как HTML-файл",
+  "offers.tooltip.downloadPdf": "Сохранить как PDF – одинаковый вид на всех платформах",
   "offers.details.created": "Создано:",
   "offers.details.statusUpdated": "Статус обновлен:",
   "offers.details.totalCost": "Общая стоимость",
@@ -916,6 +919,8 @@ export const languageRu: TranslationMap = {
   "offers.pdf.history.profit": "Прибыль",
   "offers.pdf.history.totalCost": "Общая стоимость",
   "offers.pdf.statusHistory.none": "История статуса недоступна.",
+  "offers.pdf.signature": "Подпись",
+  "offers.pdf.page": "Страница {page} из {pages}",
   "offers.filters.maxAmount": "Макс. сумма",
   "offers.filters.fromDate": "Дата с",
   "offers.filters.toDate": "Дата по",
@@ -943,6 +948,7 @@ export const languageRu: TranslationMap = {
   "common.printerDeleted": "Принтер успешно удален!",
   "common.offerSaved": "Предложение успешно сохранено!",
   "common.offerDeleted": "Предложение успешно удалено!",
+  "common.trashMoveFailed": "Не удалось переместить в корзину, удаление отменено.",
   "common.loading": "Загрузка...",
   "common.duplicate": "Дублировать",
   "common.undo": "Отменить",
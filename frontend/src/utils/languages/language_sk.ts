@@ -823,6 +823,8 @@ export const languageSk: TranslationMap = {
   "offers.toast.reordered": "Ponuky usporiadané",
   "offers.toast.exportHtmlSuccess": "HTML súbor úspešne uložený:",
   "offers.toast.exportHtmlError": "Vyskytla sa chyba:",
+  "offers.toast.exportPdfSuccess": "PDF uložené:",
+  "offers.toast.exportPdfMissingGlyphs": "Niektoré znaky nemajú nainštalované písmo a v PDF sa zobrazia ako prázdne štvorčeky:",
   "offers.versionPrefix": "v",
   "offers.subtitle": "Spravovať a exportovať uložené ponuky",
   "offers.search.label": "Hľadať",
@@ -853,6 +855,7 @@ export const languageSk: TranslationMap = {
   "offers.tooltip.duplicate": "Duplikovať ponuku",
   "offers.tooltip.exportPdf": "Export PDF alebo tlač",
   "offers.tooltip.downloadHtml": "Stiahnuť PDF ako HTML súbor",
+  "offers.tooltip.downloadPdf": "Uložiť ako PDF – rovnaké rozloženie na všetkých platformách",
   "offers.details.created": "Vytvorené:",
   "offers.details.statusUpdated": "Stav aktualizovaný:",
   "offers.details.totalCost": "Celkové náklady",
@@ -917,6 +920,8 @@ export const languageSk: TranslationMap = {
   "offers.pdf.history.profit": "Zisk",
   "offers.pdf.history.totalCost": "Celkové náklady",
   "offers.pdf.statusHistory.none": "Žiadna história stavu nie je k dispozícii.",
+  "offers.pdf.signature": "Podpis",
+  "offers.pdf.page": "Strana {page} z {pages}",
   "offers.filters.minAmount": "Min. suma",
   "offers.filters.maxAmount": "Max. suma",
   "offers.filters.fromDate": "Dátum od",
@@ -943,6 +948,7 @@ export const languageSk: TranslationMap = {
   "common.printerDeleted": "Tlačiareň úspešne odstránená!",
   "common.offerSaved": "Ponuka úspešne uložená!",
   "common.offerDeleted": "Ponuka úspešne odstránená!",
+  "common.trashMoveFailed": "Presun do koša zlyhal, mazanie zrušené.",
   "common.loading": "Načítavanie...",
   "common.duplicate": "Duplikovať",
   "common.undo": "Späť",
@@ -823,6 +823,8 @@ export const languageUk: TranslationMap = {
   "offers.toast.reordered": "Пропозиції переупорядковано",
   "offers.toast.exportHtmlSuccess": "HTML-файл успішно збережено:",
   "offers.toast.exportHtmlError": "Сталася помилка:",
+  "offers.toast.exportPdfSuccess": "PDF збережено:",
+  "offers.toast.exportPdfMissingGlyphs": "Для деяких символів не встановлено шрифт, у PDF вони відображаються порожніми квадратами:",
   "offers.versionPrefix": "в",
   "offers.subtitle": "Управління та експорт збережених пропозицій",
   "offers.search.label": "Пошук",
@@ -853,6 +855,7 @@ export const languageUk: TranslationMap = {
   "offers.tooltip.duplicate": "Дублювати пропозицію",
   "offers.tooltip.exportPdf": "Експорт PDF або друк",
   "offers.tooltip.downloadHtml": "Завантажити PDF як HTML-файл",
+  "offers.tooltip.downloadPdf": "Зберегти як PDF – однаковий вигляд на всіх платформах",
   "offers.details.created": "Створено:",
   "offers.details.statusUpdated": "Статус оновлено:",
   "offers.details.totalCost": "Загальна вартість",
@@ -916,6 +919,8 @@ export const languageUk: TranslationMap = {
   "offers.pdf.history.profit": "Прибуток",
   "offers.pdf.history.totalCost": "Загальна вартість",
   "offers.pdf.statusHistory.none": "Історія статусу недоступна.",
+  "offers.pdf.signature": "Підпис",
+  "offers.pdf.page": "Сторінка {page} з {pages}",
   "offers.filters.maxAmount": "Макс. сума",
   "offers.filters.fromDate": "Дата з",
   "offers.filters.toDate": "Дата до",
@@ -943,6 +948,7 @@ export const languageUk: TranslationMap = {
   "common.printerDeleted": "Принтер успішно видалено!",
   "common.offerSaved": "Пропозицію успішно збережено!",
   "common.offerDeleted": "Пропозицію успішно видалено!",
+  "common.trashMoveFailed": "Не вдалося перемістити до кошика, видалення скасовано.",
   "common.loading": "Завантаження...",
   "common.duplicate": "Дублювати",
   "common.undo": "Скасувати",
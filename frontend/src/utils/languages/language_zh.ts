@@ -775,6 +775,8 @@ export const languageZh: TranslationMap = {
   "offers.toast.reordered": "重新排序的优惠",
   "offers.toast.exportHtmlSuccess": "HTML 文件保存成功:",
   "offers.toast.exportHtmlError": "发生错误:",
+  "offers.toast.exportPdfSuccess": "PDF 已保存:",
+  "offers.toast.exportPdfMissingGlyphs": "部分字符没有已安装的字体，在 PDF 中显示为空白方框:",
   "offers.versionPrefix": "v",
   "offers.subtitle": "管理和导出已保存的优惠",
   "offers.search.label": "搜索",
@@ -805,6 +807,7 @@ export const languageZh: TranslationMap = {
   "offers.tooltip.duplicate": "重复报价",
   "offers.tooltip.exportPdf": "PDF 导出或打印",
   "offers.tooltip.downloadHtml": "将 PDF 下载为 HTML 文件",
+  "offers.tooltip.downloadPdf": "另存为 PDF – 在所有平台上布局一致",
   "offers.details.created": "已创建:",
   "offers.details.statusUpdated": "状态已更新:",
   "offers.details.totalCost": "总成本",
@@ -872,6 +875,8 @@ export const languageZh: TranslationMap = {
   "offers.pdf.history.profit": "利润",
   "offers.pdf.history.totalCost": "总成本",
   "offers.pdf.statusHistory.none": "没有可用的状态历史记录。",
+  "offers.pdf.signature": "签名",
+  "offers.pdf.page": "第 {page} 页，共 {pages} 页",
   "offers.statusModal.deductOnComplete": "Do you also want to deduct the filament consumption of the final print from stock? (The accepted test print has already deducted the one-time consumption.)",
 // Common
   "common.delete": "删除",
@@ -895,6 +900,7 @@ export const languageZh: TranslationMap = {
   "common.printerDeleted": "打印机删除成功！",
   "common.offerSaved": "优惠已成功保存！",
   "common.offerDeleted": "优惠已成功删除！",
+  "common.trashMoveFailed": "无法移至回收站，已取消删除。",
   "common.loading": "正在加载...",
   "common.duplicate": "重复",
   "common.undo": "撤销",
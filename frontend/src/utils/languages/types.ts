@@ -956,6 +956,8 @@ export type TranslationKey =
   | "offers.toast.reordered"
   | "offers.toast.exportHtmlSuccess"
   | "offers.toast.exportHtmlError"
+  | "offers.toast.exportPdfSuccess"
+  | "offers.toast.exportPdfMissingGlyphs"
   | "offers.versionPrefix"
   | "offers.subtitle"
   | "offers.search.label"
@@ -978,6 +980,7 @@ export type TranslationKey =
   | "offers.tooltip.duplicate"
   | "offers.tooltip.exportPdf"
   | "offers.tooltip.downloadHtml"
+  | "offers.tooltip.downloadPdf"
   | "offers.details.created"
   | "offers.details.statusUpdated"
   | "offers.details.totalCost"
@@ -1050,6 +1053,8 @@ export type TranslationKey =
   | "offers.pdf.history.profit"
   | "offers.pdf.history.totalCost"
   | "offers.pdf.statusHistory.none"
+  | "offers.pdf.signature"
+  | "offers.pdf.page"
   | "calculator.saveAsOffer"
   // Common
   | "common.delete"
@@ -1075,6 +1080,7 @@ export type TranslationKey =
   | "common.printerDeleted"
   | "common.offerSaved"
   | "common.offerDeleted"
+  | "common.trashMoveFailed"
   | "common.loading"
   | "common.duplicate"
   | "common.undo"
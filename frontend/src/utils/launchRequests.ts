@@ -0,0 +1,44 @@
+// Indításkor / második indításból átadott fájlok feldolgozása (backend: instance_lock.rs)
+
+import { invoke } from "@tauri-apps/api/core";
+
+/** A window esemény, amellyel a már megnyitott Kalkulátor a sorban álló slicer fájlt átveszi */
+export const OPEN_SLICER_FILE_EVENT = "open-slicer-file";
+
+/** A slicer import által kezelt kiterjesztések (ugyanaz, mint a fájlválasztó szűrője) */
+const SLICER_FILE_EXTENSIONS = ["gcode", "json"];
+
+export interface LaunchRequest {
+  args: string[];
+  cwd: string;
+  files: string[];
+  /** Második indításból érkezett-e (false = az alkalmazás saját indítási argumentumai) */
+  forwarded: boolean;
+  receivedAt: string;
+}
+
+const pendingSlicerFiles: string[] = [];
+
+function isSlicerFile(filePath: string): boolean {
+  const extension = filePath.split(".").pop()?.toLowerCase() ?? "";
+  return SLICER_FILE_EXTENSIONS.includes(extension);
+}
+
+/**
+ * A backend sorában álló megnyitási kérések átvétele (a sor kiürül)
+ * A slicer fájlok a Kalkulátor importjához kerülnek; a többi fájlt visszaadja (nem kezelhető)
+ */
+export async function consumeLaunchRequests(): Promise<{ slicerFiles: string[]; unsupported: string[] }> {
+  const requests = await invoke<LaunchRequest[]>("take_pending_launch_requests");
+  const files = requests.flatMap((request) => request.files);
+  const slicerFiles = files.filter(isSlicerFile);
+  pendingSlicerFiles.push(...slicerFiles);
+  return { slicerFiles, unsupported: files.filter((file) => !isSlicerFile(file)) };
+}
+
+/**
+ * A következő importálandó slicer fájl (a Kalkulátor hívja betöltéskor és az OPEN_SLICER_FILE_EVENT eseményre)
+ */
+export function takePendingSlicerFile(): string | undefined {
+  return pendingSlicerFiles.shift();
+}
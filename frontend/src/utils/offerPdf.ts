@@ -0,0 +1,142 @@
+// Natív PDF árajánlat (backend: pdf.rs) - beágyazott betűkészletekkel, a webview nyomtatási útvonalától függetlenül
+// A PDF sablon (modern / minimal / professional) a Beállítások → PDF sablon értékét követi
+
+import { invoke } from "@tauri-apps/api/core";
+import { save } from "@tauri-apps/plugin-dialog";
+import type { CompanyInfo, Offer, PdfTemplate, Settings } from "../types";
+import type { TranslationKey } from "./translations";
+import { DEFAULT_COLOR_HEX, normalizeHex, resolveColorHexFromName } from "./filamentColors";
+
+/** A PDF feliratai a felhasználó nyelvén (offers.pdf.* kulcsok) */
+export interface OfferPdfLabels {
+  title: string;
+  date: string;
+  customerName: string;
+  customerContact: string;
+  description: string;
+  headquarters: string;
+  tax: string;
+  bank: string;
+  email: string;
+  phone: string;
+  website: string;
+  printer: string;
+  printTime: string;
+  filaments: string;
+  costs: string;
+  image: string;
+  brand: string;
+  type: string;
+  color: string;
+  used: string;
+  /** "{currency}" helyőrzővel */
+  pricePerKg: string;
+  drying: string;
+  costFilament: string;
+  costElectricity: string;
+  costDrying: string;
+  costUsage: string;
+  costTotal: string;
+  /** "{profit}" helyőrzővel */
+  revenue: string;
+  profit: string;
+  signature: string;
+  /** "{page}" és "{pages}" helyőrzővel */
+  page: string;
+}
+
+export interface OfferPdfRequest {
+  filePath: string;
+  template: PdfTemplate;
+  offer: Offer;
+  companyInfo: CompanyInfo;
+  /** Megjelenítési pénznem (a beállításokból) */
+  currency: string;
+  formattedDate: string;
+  labels: OfferPdfLabels;
+}
+
+export interface OfferPdfResult {
+  filePath: string;
+  pageCount: number;
+  /** Karakterek, amelyekhez nincs betűkészlet (pl. hiányzó CJK betűkészlet) - üres, ha minden rendben */
+  missingCharacters: string;
+}
+
+export function buildOfferPdfLabels(t: (key: TranslationKey) => string): OfferPdfLabels {
+  return {
+    title: t("offers.pdf.title"),
+    date: t("offers.date"),
+    customerName: t("offers.customerName"),
+    customerContact: t("offers.customerContact"),
+    description: t("offers.description"),
+    headquarters: t("offers.pdf.company.headquarters"),
+    tax: t("offers.pdf.company.tax"),
+    bank: t("offers.pdf.company.bank"),
+    email: t("offers.pdf.company.email"),
+    phone: t("offers.pdf.company.phone"),
+    website: t("offers.pdf.company.website"),
+    printer: t("offers.pdf.section.printer"),
+    printTime: t("offers.printTime"),
+    filaments: t("offers.pdf.section.filaments"),
+    costs: t("offers.pdf.section.costs"),
+    image: t("offers.pdf.table.image"),
+    brand: t("offers.pdf.table.brand"),
+    type: t("offers.pdf.table.type"),
+    color: t("offers.pdf.table.color"),
+    used: t("offers.pdf.table.used"),
+    pricePerKg: t("offers.pdf.table.pricePerKg"),
+    drying: t("offers.pdf.table.drying"),
+    costFilament: t("offers.pdf.cost.filament"),
+    costElectricity: t("offers.pdf.cost.electricity"),
+    costDrying: t("offers.pdf.cost.drying"),
+    costUsage: t("offers.pdf.cost.usage"),
+    costTotal: t("offers.pdf.cost.total"),
+    revenue: t("offers.pdf.summary.revenue"),
+    profit: t("offers.pdf.summary.profit"),
+    signature: t("offers.pdf.signature"),
+    page: t("offers.pdf.page"),
+  };
+}
+
+/**
+ * PDF export a megadott helyre
+ * A filament színeket itt oldjuk fel (színnév → hex), hogy a színminta egyezzen a HTML előnézettel
+ */
+export async function exportOfferPdf(
+  offer: Offer,
+  settings: Settings,
+  t: (key: TranslationKey) => string,
+  locale: string,
+  filePath: string
+): Promise<OfferPdfResult> {
+  const request: OfferPdfRequest = {
+    filePath,
+    template: settings.pdfTemplate ?? "modern",
+    offer: {
+      ...offer,
+      filaments: offer.filaments.map(filament => ({
+        ...filament,
+        colorHex: normalizeHex(filament.colorHex || resolveColorHexFromName(filament.color) || DEFAULT_COLOR_HEX) || DEFAULT_COLOR_HEX,
+      })),
+    },
+    companyInfo: settings.companyInfo ?? {},
+    currency: settings.currency,
+    formattedDate: new Date(offer.date).toLocaleDateString(locale),
+    labels: buildOfferPdfLabels(t),
+  };
+  return invoke<OfferPdfResult>("export_offer_pdf", { request });
+}
+
+/**
+ * Mentési hely kiválasztása
+ * @returns null, ha a felhasználó megszakította
+ */
+export async function chooseOfferPdfPath(offer: Offer): Promise<string | null> {
+  const dateStr = new Date().toISOString().split("T")[0];
+  const filePath = await save({
+    defaultPath: `ajánlat_${offer.id}_${dateStr}.pdf`,
+    filters: [{ name: "PDF", extensions: ["pdf"] }],
+  });
+  return filePath || null;
+}
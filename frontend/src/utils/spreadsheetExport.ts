@@ -0,0 +1,59 @@
+// Táblázat export (XLSX / ODS / CSV) - árajánlatok, ügyfelek, projektek, feladatok és havi pénzügyi összesítő
+// A fájlt a backend írja: a számok valódi szám cellák, a pénzösszegek pénznem formátummal
+
+import { invoke } from "@tauri-apps/api/core";
+import { save } from "@tauri-apps/plugin-dialog";
+import type { OfferFilter } from "../types";
+
+export type SpreadsheetFormat = "xlsx" | "ods" | "csv";
+export type SpreadsheetDataset = "offers" | "customers" | "projects" | "tasks" | "financialSummary";
+/** perOffer: egy sor / árajánlat, perFilament: egy sor / filament tétel */
+export type OfferExportLayout = "perOffer" | "perFilament";
+
+export interface SpreadsheetExportRequest {
+  filePath: string;
+  format: SpreadsheetFormat;
+  datasets: SpreadsheetDataset[];
+  offerLayout?: OfferExportLayout;
+  filter?: OfferFilter;
+  /** Fejlécek és státuszok nyelve (hu, de, egyébként angol) */
+  language?: string;
+  /** CSV elválasztó (alapból tizedesvesszőnél ";", egyébként ",") */
+  csvDelimiter?: string;
+  /** CSV-ben tizedesvessző */
+  decimalComma?: boolean;
+}
+
+export interface SpreadsheetExportResult {
+  /** CSV-nél több adatkör esetén adatkörönként külön fájl */
+  files: string[];
+  sheets: { dataset: SpreadsheetDataset; name: string; rows: number; filePath: string }[];
+  offerCount: number;
+}
+
+export async function exportSpreadsheet(request: SpreadsheetExportRequest): Promise<SpreadsheetExportResult> {
+  return invoke<SpreadsheetExportResult>("export_spreadsheet", { request });
+}
+
+/**
+ * Mentési hely kiválasztása; a formátumot a választott kiterjesztés adja
+ * @returns null, ha a felhasználó megszakította
+ */
+export async function chooseSpreadsheetPath(
+  defaultName: string
+): Promise<{ filePath: string; format: SpreadsheetFormat } | null> {
+  const filePath = await save({
+    defaultPath: `${defaultName}.xlsx`,
+    filters: [
+      { name: "Excel", extensions: ["xlsx"] },
+      { name: "OpenDocument", extensions: ["ods"] },
+      { name: "CSV", extensions: ["csv"] },
+    ],
+  });
+  if (!filePath) {
+    return null;
+  }
+  const extension = filePath.split(".").pop()?.toLowerCase();
+  const format: SpreadsheetFormat = extension === "ods" ? "ods" : extension === "csv" ? "csv" : "xlsx";
+  return { filePath, format };
+}
@@ -3,15 +3,118 @@ import type { Printer, Filament, Settings, Offer, CalculationTemplate, Customer,
 // deleteAllAutomaticBackups import eltávolítva - a FactoryResetProgress modal kezeli a backup fájlok törlését
 import { remove, exists } from "@tauri-apps/plugin-fs";
 import { BaseDirectory } from "@tauri-apps/plugin-fs";
+import { invoke } from "@tauri-apps/api/core";
+
+// A Store műveletek, amelyeket az alkalmazás használ (a tauri-plugin-store Store és a VaultStore is ezt valósítja meg)
+export interface DataStore {
+  get<T>(key: string): Promise<T | undefined>;
+  set(key: string, value: unknown): Promise<void>;
+  delete(key: string): Promise<boolean>;
+  save(): Promise<void>;
+  reload(): Promise<void>;
+}
+
+// Titkosított adattároló esetén a Store tartalma csak memóriában van visszafejtve:
+// a backend olvassa és titkosítva menti (read_vault_store / write_vault_store), titkosítatlan data.json nem keletkezik
+class VaultStore implements DataStore {
+  private data: Record<string, unknown> = {};
+
+  static async load(): Promise<VaultStore> {
+    const store = new VaultStore();
+    await store.reload();
+    return store;
+  }
+
+  async get<T>(key: string): Promise<T | undefined> {
+    return this.data[key] as T | undefined;
+  }
+
+  async set(key: string, value: unknown): Promise<void> {
+    this.data[key] = value;
+  }
+
+  async delete(key: string): Promise<boolean> {
+    const existed = key in this.data;
+    delete this.data[key];
+    return existed;
+  }
+
+  async save(): Promise<void> {
+    await invoke("write_vault_store", { data: this.data });
+  }
+
+  async reload(): Promise<void> {
+    this.data = (await invoke<Record<string, unknown> | null>("read_vault_store")) ?? {};
+  }
+}
+
+async function isVaultEnabled(): Promise<boolean> {
+  try {
+    const status = await invoke<{ enabled: boolean }>("get_vault_status");
+    return status.enabled;
+  } catch (error) {
+    console.warn("⚠️ Az adattároló állapotának lekérése sikertelen:", error);
+    return false;
+  }
+}
+
+// A Store megnyitása: titkosított adattárolónál a backend, különben a tauri-plugin-store kezeli
+export async function openStore(): Promise<DataStore> {
+  if (await isVaultEnabled()) {
+    return VaultStore.load();
+  }
+  return Store.load(await getStorePath());
+}
 
 // Lazy-initialized store
-let storeInstance: Store | null = null;
+let storeInstance: DataStore | null = null;
+
+// A data.json helye: a backend által feloldott abszolút útvonal (alapértelmezett módban macOS / Linux alatt
+// a régi bundle ID helyről az adatkönyvtárba költözik, lásd legacy_migration.rs); hiba esetén a plugin-store relatív útvonala
+let storePath: string | null = null;
 
-async function getStore(): Promise<Store> {
+export async function getStorePath(): Promise<string> {
+  if (storePath === null) {
+    try {
+      const layout = await invoke<{ storeFile: string }>("get_data_layout");
+      storePath = layout.storeFile;
+    } catch (error) {
+      console.warn("⚠️ Az adatkönyvtár lekérése sikertelen, az alapértelmezett helyet használjuk:", error);
+      storePath = "data.json";
+    }
+  }
+  return storePath;
+}
+
+// Létezik-e a data.json (a Store betöltése nélkül, mert az létrehozná a fájlt)
+export async function storeFileExists(): Promise<boolean> {
+  if (await isVaultEnabled()) {
+    // A backend a titkosított data.json.vault-ot ellenőrzi
+    return await invoke<boolean>("check_file_exists", { filePath: "data.json" });
+  }
+  const path = await getStorePath();
+  if (path === "data.json") {
+    return await exists("data.json", { baseDir: BaseDirectory.AppConfig });
+  }
+  return await invoke<boolean>("check_file_exists", { filePath: path });
+}
+
+// Munkaterület váltás közben nem írunk: a késleltetett (debounce) mentések különben
+// a régi munkaterület adatait az újba írnák, mielőtt az oldal újratöltődik
+let storeWritesSuspended = false;
+
+export function setStoreWritesSuspended(suspended: boolean): void {
+  storeWritesSuspended = suspended;
+}
+
+async function getStore(): Promise<DataStore> {
+  if (storeWritesSuspended) {
+    throw new Error("Munkaterület váltás folyamatban, a Store átmenetileg nem érhető el");
+  }
   if (!storeInstance) {
     // Ellenőrizzük, hogy létezik-e a data.json fájl, mielőtt betöltjük a Store-t
     // Ha nem létezik, akkor nem hozzuk létre automatikusan (Factory Reset után)
-    const dataJsonExists = await exists("data.json", { baseDir: BaseDirectory.AppConfig });
+    const dataJsonExists = await storeFileExists();
     if (!dataJsonExists) {
       // Ha nincs data.json, akkor még nem hozzuk létre a Store-t
       // Ez biztosítja, hogy a Factory Reset után ne generálódjon automatikusan a fájl
@@ -19,7 +122,7 @@ async function getStore(): Promise<Store> {
       // ellenőrizzük, és csak akkor hozzuk létre a Store-t, ha a fájl már létezik
       throw new Error("data.json fájl nem létezik. Kérjük, válasszon nyelvet először.");
     }
-    storeInstance = await Store.load("data.json");
+    storeInstance = await openStore();
   }
   return storeInstance;
 }
@@ -29,6 +132,36 @@ export function resetStoreInstance(): void {
   storeInstance = null;
 }
 
+// A Store bezárása (a tauri-plugin-store kilépéskor minden nyitott Store-t kiment, ezért a titkosítás
+// bekapcsolása előtt be kell zárni, különben titkosítatlan data.json keletkezne újra)
+export async function closeStoreInstance(): Promise<void> {
+  const store = storeInstance;
+  storeInstance = null;
+  if (store instanceof Store) {
+    await store.close();
+  }
+}
+
+// A Store újraolvasása a lemezről (ha a backend írta át a data.json-t, pl. visszaállításkor)
+export async function reloadStoreFromDisk(): Promise<void> {
+  if (storeInstance) {
+    await storeInstance.reload();
+  }
+}
+
+// Backend értesítése mentés után (keresési index frissítése, változásnapló)
+// Nem blokkolja a mentést, a hibát csak logoljuk
+function notifyBackendSaved(entityType: string, records: unknown[]): void {
+  invoke("update_search_index", { entityType, records }).catch((error) => {
+    if (import.meta.env.DEV) {
+      console.warn("⚠️ Keresési index frissítése sikertelen:", entityType, error);
+    }
+  });
+  invoke("record_entity_changes", { entityType, records }).catch((error) => {
+    console.warn("⚠️ Változásnapló frissítése sikertelen:", entityType, error);
+  });
+}
+
 // Printers
 export async function savePrinters(printers: Printer[]): Promise<void> {
   try {
@@ -38,6 +171,7 @@ export async function savePrinters(printers: Printer[]): Promise<void> {
     const store = await getStore();
     await store.set("printers", printers);
     await store.save();
+    notifyBackendSaved("printers", printers);
     if (import.meta.env.DEV) {
       console.log("✅ Nyomtatók sikeresen mentve", { count: printers.length });
     }
@@ -74,6 +208,7 @@ export async function saveFilaments(filaments: Filament[]): Promise<void> {
     const store = await getStore();
     await store.set("filaments", filaments);
     await store.save();
+    notifyBackendSaved("filaments", filaments);
     if (import.meta.env.DEV) {
       console.log("✅ Filamentek sikeresen mentve", { count: filaments.length });
     }
@@ -118,7 +253,7 @@ export async function saveSettings(settings: Settings): Promise<void> {
     }
     
     // Ha a getStore() hibát dob (mert nincs data.json), akkor először létrehozzuk a Store-t
-    let store: Store;
+    let store: DataStore;
     try {
       store = await getStore();
     } catch (error) {
@@ -126,7 +261,7 @@ export async function saveSettings(settings: Settings): Promise<void> {
       if (import.meta.env.DEV) {
         console.log("ℹ️ data.json nem létezik, létrehozás...");
       }
-      store = await Store.load("data.json");
+      store = await openStore();
       storeInstance = store; // Frissítjük a storeInstance-t
     }
     
@@ -144,7 +279,7 @@ export async function saveSettings(settings: Settings): Promise<void> {
 export async function loadSettings(): Promise<Settings | null> {
   try {
     // Ha nincs data.json, akkor null-t adunk vissza (nem hibaként kezeljük)
-    let store: Store;
+    let store: DataStore;
     try {
       store = await getStore();
     } catch (error) {
@@ -184,6 +319,7 @@ export async function saveOffers(offers: Offer[]): Promise<void> {
     const store = await getStore();
     await store.set("offers", offers);
     await store.save();
+    notifyBackendSaved("offers", offers);
     if (import.meta.env.DEV) {
       console.log("✅ Árajánlatok sikeresen mentve", { count: offers.length });
     }
@@ -241,6 +377,7 @@ export async function saveTemplates(templates: CalculationTemplate[]): Promise<v
     const store = await getStore();
     await store.set("templates", templates);
     await store.save();
+    notifyBackendSaved("templates", templates);
     console.log("✅ Template-ek sikeresen mentve", { count: templates.length });
   } catch (error) {
     console.error("❌ Hiba a template-ek mentésekor:", error);
@@ -271,6 +408,7 @@ export async function saveCustomers(customers: Customer[]): Promise<void> {
     const store = await getStore();
     await store.set("customers", customers);
     await store.save();
+    notifyBackendSaved("customers", customers);
     if (import.meta.env.DEV) {
       console.log("✅ Ügyfelek sikeresen mentve", { count: customers.length });
     }
@@ -335,6 +473,7 @@ export async function saveProjects(projects: Project[]): Promise<void> {
     const store = await getStore();
     await store.set("projects", projects);
     await store.save();
+    notifyBackendSaved("projects", projects);
     if (import.meta.env.DEV) {
       console.log("✅ Projektek sikeresen mentve", { count: projects.length });
     }
@@ -371,6 +510,7 @@ export async function saveTasks(tasks: Task[]): Promise<void> {
     const store = await getStore();
     await store.set("tasks", tasks);
     await store.save();
+    notifyBackendSaved("tasks", tasks);
     if (import.meta.env.DEV) {
       console.log("✅ Feladatok sikeresen mentve", { count: tasks.length });
     }
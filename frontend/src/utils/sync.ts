@@ -0,0 +1,94 @@
+// Mappa alapú szinkronizálás több eszköz között (Syncthing, Dropbox, hálózati megosztás vagy WebDAV / Nextcloud)
+// Rekord / mező szintű változáscsomagok, mezőnként "utolsó író nyer", ütközésnapló (backend: sync.rs)
+
+import { invoke } from "@tauri-apps/api/core";
+import { open } from "@tauri-apps/plugin-dialog";
+import type { WebDavSettings } from "./webdav";
+
+export interface SyncReport {
+  exported: number;
+  importedChangeSets: number;
+  applied: number;
+  conflicts: number;
+  storeChanged: boolean;
+  finishedAt: string;
+}
+
+export interface SyncPeer {
+  deviceId: string;
+  deviceName: string;
+  lastSeq: number;
+  importedSeq: number;
+  lastSyncAt: string | null;
+}
+
+export interface SyncStatus {
+  folder: string | null;
+  /** WebDAV gyűjtemény a mappa helyett */
+  webdav: WebDavSettings | null;
+  /** 0 = csak kézi szinkronizálás */
+  intervalMinutes: number;
+  deviceId: string;
+  deviceName: string;
+  lastSyncAt: string | null;
+  lastError: string | null;
+  lastReport: SyncReport | null;
+  inProgress: boolean;
+  peers: SyncPeer[];
+  conflictCount: number;
+  blockedByVault: boolean;
+}
+
+export interface SyncConflict {
+  timestamp: string;
+  entityType: string;
+  recordKey: string;
+  /** null: törlés és módosítás ütközése */
+  field: string | null;
+  localValue: unknown;
+  remoteValue: unknown;
+  winner: "local" | "remote";
+  remoteDevice: string;
+}
+
+export async function getSyncStatus(): Promise<SyncStatus> {
+  return invoke<SyncStatus>("get_sync_status");
+}
+
+/**
+ * Mappa és WebDAV nélkül (null) a szinkronizálás kikapcsol
+ * WebDAV megadásakor a mappa beállítás törlődik; üres jelszónál azonos címhez a korábban mentett marad
+ */
+export async function setSyncConfig(
+  folder: string | null,
+  intervalMinutes: number,
+  deviceName?: string,
+  webdav?: WebDavSettings | null
+): Promise<SyncStatus> {
+  return invoke<SyncStatus>("set_sync_config", { folder, webdav: webdav ?? null, intervalMinutes, deviceName: deviceName ?? null });
+}
+
+/**
+ * Szinkronizálási mappa kiválasztása párbeszédablakban
+ * @returns null, ha a felhasználó megszakította
+ */
+export async function chooseSyncFolder(intervalMinutes: number): Promise<SyncStatus | null> {
+  const path = await open({ directory: true });
+  if (!path || typeof path !== "string") {
+    return null;
+  }
+  return setSyncConfig(path, intervalMinutes);
+}
+
+/** Azonnali szinkronizálás - ha a Store változott, az App a "data-store-changed" eseményre újratölti az adatokat */
+export async function syncNow(): Promise<SyncReport> {
+  return invoke<SyncReport>("sync_now");
+}
+
+export async function getSyncConflicts(limit?: number): Promise<SyncConflict[]> {
+  return invoke<SyncConflict[]>("get_sync_conflicts", { limit: limit ?? null });
+}
+
+export async function clearSyncConflicts(): Promise<void> {
+  await invoke("clear_sync_conflicts");
+}
@@ -0,0 +1,96 @@
+// CSV / XLSX / ODS import filamentekhez, nyomtatókhoz és ügyfelekhez (a feldolgozás a backendben történik)
+
+import { invoke } from "@tauri-apps/api/core";
+import { open } from "@tauri-apps/plugin-dialog";
+
+export type TableImportEntity = "filaments" | "printers" | "customers";
+
+/** Mező kulcs -> oszlop index (0-tól) */
+export type ColumnMapping = Record<string, number>;
+
+export interface TableImportOptions {
+  /** Kényszerített karakterkódolás (pl. "windows-1250"), alapból automatikus felismerés */
+  encoding?: string;
+  /** Kényszerített CSV elválasztó (";", ",", "\\t", "|") */
+  delimiter?: string;
+  /** Munkalap neve (XLSX / ODS) */
+  sheet?: string;
+  hasHeader?: boolean;
+  mapping?: ColumnMapping;
+}
+
+export interface TableImportField {
+  key: string;
+  kind: "text" | "number" | "integer" | "color";
+  required: boolean;
+}
+
+export interface TableImportRow {
+  rowNumber: number;
+  cells: string[];
+  record: Record<string, unknown> | null;
+  errors: { field: string; message: string }[];
+  duplicate: boolean;
+}
+
+export interface TableImportPreview {
+  entityType: TableImportEntity;
+  format: "csv" | "xlsx" | "xlsm" | "xls" | "ods";
+  encoding: string | null;
+  delimiter: string | null;
+  sheets: string[];
+  sheet: string | null;
+  headers: string[];
+  fields: TableImportField[];
+  mapping: ColumnMapping;
+  /** Legfeljebb az első 200 sor, a számlálók a teljes fájlra vonatkoznak */
+  rows: TableImportRow[];
+  totalRows: number;
+  validRows: number;
+  invalidRows: number;
+  duplicateRows: number;
+}
+
+export interface TableImportResult {
+  entityType: TableImportEntity;
+  imported: number;
+  skippedInvalid: number;
+  skippedDuplicates: number;
+  safetyBackup: string | null;
+}
+
+export const TABLE_IMPORT_EXTENSIONS = ["csv", "txt", "tsv", "xlsx", "xlsm", "xls", "ods"];
+
+export async function chooseImportFile(): Promise<string | null> {
+  const selected = await open({
+    multiple: false,
+    filters: [{ name: "CSV / Excel / ODS", extensions: TABLE_IMPORT_EXTENSIONS }],
+  });
+  return typeof selected === "string" ? selected : null;
+}
+
+export async function previewTableImport(
+  filePath: string,
+  entityType: TableImportEntity,
+  options?: TableImportOptions
+): Promise<TableImportPreview> {
+  return invoke<TableImportPreview>("preview_import", { filePath, entityType, options: options ?? null });
+}
+
+/**
+ * Az érvényes sorok felvétele a Store-ba (előtte biztonsági mentés készül)
+ * Alkalmazás után a backend "data-store-changed" eseményt küld, az App újratölti az adatokat
+ */
+export async function applyTableImport(
+  filePath: string,
+  entityType: TableImportEntity,
+  options: TableImportOptions | undefined,
+  skipDuplicates: boolean
+): Promise<TableImportResult> {
+  return invoke<TableImportResult>("apply_import", {
+    filePath,
+    entityType,
+    options: options ?? null,
+    skipDuplicates,
+  });
+}
@@ -0,0 +1,54 @@
+// Lomtár: a törölt árajánlatok, ügyfelek és filamentek a megőrzési idő lejártáig visszaállíthatók (backend: trash.rs)
+
+import { invoke } from "@tauri-apps/api/core";
+
+export type TrashEntityType = "offers" | "customers" | "filaments";
+
+export interface TrashItem {
+  id: string;
+  entityType: TrashEntityType;
+  recordKey: string;
+  label: string;
+  record: Record<string, unknown>;
+  /** UTC, RFC 3339 */
+  deletedAt: string;
+  deletedBy: string;
+}
+
+export interface TrashRestoreResult {
+  restored: string[];
+  /** Már létező azonosítójú / kulcsú elemek, ezek a lomtárban maradnak */
+  conflicts: string[];
+  safetyBackup: string | null;
+}
+
+export const DEFAULT_TRASH_RETENTION_DAYS = 30;
+
+/**
+ * Törölt rekordok lomtárba helyezése a törlés előtt
+ * Hiba esetén kivételt dob: a hívó ilyenkor nem törölhet, különben a rekord visszaállíthatatlanul elveszne
+ */
+export async function moveToTrash(entityType: TrashEntityType, records: unknown[]): Promise<void> {
+  try {
+    await invoke("move_to_trash", { entityType, records, deletedBy: null });
+  } catch (error) {
+    console.error("❌ Lomtárba helyezés sikertelen, a törlés megszakítva:", entityType, error);
+    throw error;
+  }
+}
+
+export async function listTrash(entityType?: TrashEntityType): Promise<TrashItem[]> {
+  return invoke<TrashItem[]>("list_trash", { entityType: entityType ?? null });
+}
+
+/**
+ * Elemek visszaállítása (a backend írja a Store-t, az App a "data-store-changed" eseményre újratölti az adatokat)
+ */
+export async function restoreFromTrash(ids: string[]): Promise<TrashRestoreResult> {
+  return invoke<TrashRestoreResult>("restore_from_trash", { ids });
+}
+
+/** Végleges törlés, azonosítók nélkül a teljes lomtár ürítése */
+export async function purgeTrash(ids?: string[]): Promise<number> {
+  return invoke<number>("purge_trash", { ids: ids ?? null });
+}
@@ -1,7 +1,6 @@
 import type { Printer, Filament, Offer, Customer, Settings } from "../types";
-import { savePrinters, saveFilaments, saveOffers, saveCustomers, saveSettings, loadSettings } from "./store";
+import { savePrinters, saveFilaments, saveOffers, saveCustomers, saveSettings, loadSettings, openStore } from "./store";
 import { calculateOfferCosts } from "./offerCalc";
-import { Store } from "@tauri-apps/plugin-store";
 
 /**
  * Demo adatok generálása a tutorial számára
@@ -588,7 +587,7 @@ export async function clearTutorialDemoData(): Promise<void> {
     console.log("🗑️ Tutorial demo adatok törlése...");
     
     // Lazy-initialized store (ugyanaz, mint a store.ts-ben)
-    const store = await Store.load("data.json");
+    const store = await openStore();
     
     // Csak a demo adatokat töröljük, a settings-et megtartjuk
     await store.delete("printers");
@@ -0,0 +1,70 @@
+// Titkosított adattároló (Store és audit log titkosítás jelmondattal)
+
+import { invoke } from "@tauri-apps/api/core";
+import { closeStoreInstance, resetStoreInstance } from "./store";
+
+export interface VaultStatus {
+  enabled: boolean;
+  unlocked: boolean;
+  storeEncrypted: boolean;
+  plaintextStorePresent: boolean;
+}
+
+export async function getVaultStatus(): Promise<VaultStatus> {
+  return invoke<VaultStatus>("get_vault_status");
+}
+
+/**
+ * Titkosítás bekapcsolása: a titkosítatlan data.json törlődik, ezután a Store-t a backend kezeli
+ * (a nyitott tauri-plugin-store Store-t előtte bezárjuk, hogy ne írja vissza a fájlt)
+ */
+export async function enableVault(passphrase: string): Promise<VaultStatus> {
+  await closeStoreInstance();
+  return invoke<VaultStatus>("enable_vault", { passphrase });
+}
+
+export async function unlockVault(passphrase: string): Promise<VaultStatus> {
+  return invoke<VaultStatus>("unlock_vault", { passphrase });
+}
+
+export async function lockVault(): Promise<VaultStatus> {
+  const status = await invoke<VaultStatus>("lock_vault");
+  resetStoreInstance();
+  return status;
+}
+
+export async function changeVaultPassphrase(currentPassphrase: string, newPassphrase: string): Promise<VaultStatus> {
+  return invoke<VaultStatus>("change_vault_passphrase", { currentPassphrase, newPassphrase });
+}
+
+export async function disableVault(passphrase: string): Promise<VaultStatus> {
+  const status = await invoke<VaultStatus>("disable_vault", { passphrase });
+  resetStoreInstance();
+  return status;
+}
+
+/**
+ * Indításkor: ha az adattároló zárolva van, bekéri a jelmondatot, amíg a feloldás sikerül
+ * @returns false, ha a felhasználó megszakította (ilyenkor az adatok nem tölthetők be)
+ */
+export async function ensureVaultUnlocked(): Promise<boolean> {
+  const status = await getVaultStatus();
+  if (!status.enabled || status.unlocked) {
+    return true;
+  }
+
+  let message = "Az adatok titkosítva vannak. Adja meg a jelmondatot:";
+  for (;;) {
+    const passphrase = window.prompt(message);
+    if (passphrase === null) {
+      return false;
+    }
+    try {
+      await unlockVault(passphrase);
+      return true;
+    } catch (error) {
+      console.error("❌ Adattároló feloldása sikertelen:", error);
+      message = `${error}\nPróbálja újra:`;
+    }
+  }
+}
@@ -0,0 +1,42 @@
+// WebDAV (Nextcloud, ownCloud) elérés backup célhoz és szinkronizáláshoz (backend: webdav.rs)
+// Basic / digest hitelesítés, ETag alapú változásfigyelés, folytatható feltöltés
+
+import { invoke } from "@tauri-apps/api/core";
+
+/** auto: a szerver által kért módszer (digest előnyben) */
+export type WebDavAuth = "auto" | "basic" | "digest";
+
+export interface WebDavSettings {
+  /** A gyűjtemény címe, pl. https://felho.example.com/remote.php/dav/files/<felhasználó>/3DPrinterCalc/ */
+  url: string;
+  username: string;
+  /** Nextcloud esetén alkalmazásjelszó ajánlott */
+  password: string;
+  auth: WebDavAuth;
+}
+
+export interface WebDavTestReport {
+  /** Elérhető, a bejelentkezés elfogadott és a gyűjtemény listázható (vagy még nem létezik) */
+  success: boolean;
+  url: string;
+  reachable: boolean;
+  authenticated: boolean;
+  authScheme: string | null;
+  davClasses: string | null;
+  server: string | null;
+  collectionExists: boolean;
+  collectionEtag: string | null;
+  entryCount: number;
+  quotaAvailableBytes: number | null;
+  /** Nextcloud darabolt (folytatható) feltöltés elérhető */
+  chunkedUpload: boolean;
+  message: string;
+}
+
+export const EMPTY_WEBDAV_SETTINGS: WebDavSettings = { url: "", username: "", password: "", auth: "auto" };
+
+/** Kapcsolatteszt mentés előtt - a szerveren semmit nem ír és nem töröl */
+export async function testWebDavConnection(settings: WebDavSettings): Promise<WebDavTestReport> {
+  return invoke<WebDavTestReport>("test_webdav_connection", { settings });
+}
+
@@ -0,0 +1,88 @@
+// Munkaterületek: külön Store, backupok, audit log, cégadatok és beállítások névvel ellátott profilonként (backend: workspaces.rs)
+
+import { invoke } from "@tauri-apps/api/core";
+import { resetStoreInstance, setStoreWritesSuspended } from "./store";
+
+export const DEFAULT_WORKSPACE_ID = "default";
+
+export interface WorkspaceInfo {
+  id: string;
+  /** Az alapértelmezett munkaterületnél üres, a felület a nyelvnek megfelelő feliratot mutatja */
+  name: string;
+  createdAt: string;
+  isDefault: boolean;
+  isActive: boolean;
+  root: string;
+  storeExists: boolean;
+  vaultEnabled: boolean;
+}
+
+export interface WorkspaceList {
+  activeId: string;
+  workspaces: WorkspaceInfo[];
+}
+
+export async function listWorkspaces(): Promise<WorkspaceList> {
+  return invoke<WorkspaceList>("list_workspaces");
+}
+
+/**
+ * Új munkaterület létrehozása
+ * @param copySettings az aktív munkaterület beállításainak átvétele (a cégadatok nélkül)
+ */
+export async function createWorkspace(name: string, copySettings: boolean): Promise<WorkspaceList> {
+  return invoke<WorkspaceList>("create_workspace", { name, copySettings });
+}
+
+/** Munkaterület másolása új néven (adatok és beállítások, backupok és előzmények nélkül) */
+export async function duplicateWorkspace(sourceId: string, name: string): Promise<WorkspaceList> {
+  return invoke<WorkspaceList>("duplicate_workspace", { sourceId, name });
+}
+
+export async function renameWorkspace(id: string, name: string): Promise<WorkspaceList> {
+  return invoke<WorkspaceList>("rename_workspace", { id, name });
+}
+
+export async function deleteWorkspace(id: string): Promise<WorkspaceList> {
+  return invoke<WorkspaceList>("delete_workspace", { id });
+}
+
+type BeforeSwitchHandler = () => Promise<void>;
+
+const beforeSwitchHandlers = new Set<BeforeSwitchHandler>();
+
+/**
+ * Váltás előtt lefutó mentés regisztrálása (pl. a még debounce-ra váró autosave azonnali kiírása)
+ * A visszaadott függvény leiratkoztat
+ */
+export function onBeforeWorkspaceSwitch(handler: BeforeSwitchHandler): () => void {
+  beforeSwitchHandlers.add(handler);
+  return () => {
+    beforeSwitchHandlers.delete(handler);
+  };
+}
+
+/**
+ * Váltás másik munkaterületre, majd az alkalmazás újratöltése,
+ * hogy minden nézet az új munkaterület Store-ját és beállításait olvassa
+ * Előbb a függő mentések kiíródnak a jelenlegi munkaterületre, utána a Store írása szünetel a reloadig
+ */
+export async function switchWorkspace(id: string): Promise<void> {
+  await Promise.all([...beforeSwitchHandlers].map((handler) => handler()));
+  setStoreWritesSuspended(true);
+  try {
+    await invoke<WorkspaceList>("switch_workspace", { id });
+  } catch (error) {
+    setStoreWritesSuspended(false);
+    throw error;
+  }
+  resetStoreInstance();
+  window.location.reload();
+}
+
+export function workspaceDisplayName(workspace: WorkspaceInfo, language: string): string {
+  if (workspace.name) {
+    return workspace.name;
+  }
+  return language === "hu" ? "Alapértelmezett" : language === "de" ? "Standard" : "Default";
+}
@@ -1,3 +1,51 @@
+use std::path::Path;
+
+/// A natív PDF export kínai (CJK) betűkészleteinek helye (ugyanaz, mint a pdf.rs CJK_FONT_DIR-je)
+const CJK_FONT_DIR: &str = "fonts/cjk";
+
+fn is_font_file(name: &str) -> bool {
+    Path::new(name)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| matches!(ext.to_ascii_lowercase().as_str(), "ttf" | "otf" | "ttc" | "otc"))
+}
+
+/// A CJK mappából csomagolandó fájlok: a betűkészletek és a licencük (a README nem kerül az alkalmazásba)
+fn cjk_resources() -> Vec<String> {
+    let Ok(entries) = std::fs::read_dir(CJK_FONT_DIR) else {
+        return Vec::new();
+    };
+    let mut files: Vec<String> = entries
+        .flatten()
+        .filter_map(|entry| entry.file_name().to_str().map(|name| name.to_string()))
+        .filter(|name| is_font_file(name) || name.starts_with("LICENSE"))
+        .map(|name| format!("{}/{}", CJK_FONT_DIR, name))
+        .collect();
+    files.sort();
+    files
+}
+
+/// A CJK betűkészleteket a CI tölti le a kiadási build előtt (lásd fonts/cjk/README.md)
+/// Helyi buildnél hiányozhatnak: ilyenkor figyelmeztetünk, és a csomagolandó erőforrások listája
+/// a ténylegesen meglévő fájlokra szűkül (a tauri.conf.json mintája fájl nélkül hibát adna)
+fn configure_cjk_fonts() {
+    println!("cargo:rerun-if-changed={}", CJK_FONT_DIR);
+    let resources = cjk_resources();
+    if !resources.iter().any(|path| is_font_file(path)) {
+        println!(
+            "cargo:warning=Nincs CJK betűkészlet a(z) {} mappában (pl. NotoSansSC-Regular.otf, lásd {}/README.md)",
+            CJK_FONT_DIR, CJK_FONT_DIR
+        );
+    }
+    // Egy kívülről megadott konfiguráció felülírást nem írunk felül
+    if std::env::var_os("TAURI_CONFIG").is_some() {
+        return;
+    }
+    let list: Vec<String> = resources.iter().map(|path| format!("{:?}", path)).collect();
+    std::env::set_var("TAURI_CONFIG", format!("{{\"bundle\":{{\"resources\":[{}]}}}}", list.join(",")));
+}
+
 fn main() {
-  tauri_build::build()
+    configure_cjk_fonts();
+    tauri_build::build()
 }
@@ -0,0 +1,242 @@
+use crate::logger;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Az atomikus íráshoz használt ideiglenes fájlok utótagja
+/// Név: `.{célfájl}.{pid}-{sorszám}.atomic-tmp`, ugyanabban a könyvtárban, mint a célfájl
+pub const TEMP_SUFFIX: &str = ".atomic-tmp";
+
+static TEMP_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+fn temp_path_for(path: &Path) -> Result<PathBuf, String> {
+    let file_name = path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .ok_or_else(|| format!("Érvénytelen fájlnév: {}", path.display()))?;
+    let unique = format!(
+        "{}-{}",
+        std::process::id(),
+        TEMP_COUNTER.fetch_add(1, Ordering::Relaxed)
+    );
+    Ok(path.with_file_name(format!(".{}.{}{}", file_name, unique, TEMP_SUFFIX)))
+}
+
+/// A könyvtár fsync-elése, hogy az átnevezés (a könyvtárbejegyzés) is lemezre kerüljön
+/// Windows-on könyvtár nem nyitható meg fájlként; ott az NTFS naplózás biztosítja ugyanezt
+#[cfg(unix)]
+fn sync_dir(dir: &Path) -> io::Result<()> {
+    File::open(dir)?.sync_all()
+}
+
+#[cfg(not(unix))]
+fn sync_dir(_dir: &Path) -> io::Result<()> {
+    Ok(())
+}
+
+/// Atomikus fájlírás: ideiglenes fájl ugyanabban a könyvtárban, fsync, átnevezés, könyvtár fsync
+/// Összeomlás esetén a célfájl vagy a régi, vagy a teljes új tartalmat tartalmazza, csonkát soha
+/// A `write` a megnyitott (írható és olvasható) ideiglenes fájlt kapja; hibája esetén a célfájl érintetlen marad
+pub fn write_atomic_with<F>(path: &Path, write: F) -> Result<(), String>
+where
+    F: FnOnce(&mut File) -> io::Result<()>,
+{
+    let dir = path
+        .parent()
+        .filter(|p| !p.as_os_str().is_empty())
+        .unwrap_or_else(|| Path::new("."));
+    fs::create_dir_all(dir).map_err(|e| format!("Nem sikerült létrehozni a könyvtárat: {} - {}", dir.display(), e))?;
+
+    let temp_path = temp_path_for(path)?;
+    let result = (|| {
+        let mut file = fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create_new(true)
+            .open(&temp_path)?;
+        write(&mut file)?;
+        file.flush()?;
+        file.sync_all()?;
+        drop(file);
+        fs::rename(&temp_path, path)?;
+        sync_dir(dir)
+    })();
+
+    result.map_err(|e| {
+        let _ = fs::remove_file(&temp_path);
+        format!("Nem sikerült írni a fájlt: {} - {}", path.display(), e)
+    })
+}
+
+/// Teljes tartalom atomikus kiírása
+pub fn write_atomic(path: &Path, contents: impl AsRef<[u8]>) -> Result<(), String> {
+    write_atomic_with(path, |file| file.write_all(contents.as_ref()))
+}
+
+/// Fájl atomikus másolása (pl. biztonsági mentés a data.json-ról)
+pub fn copy_atomic(source: &Path, target: &Path) -> Result<(), String> {
+    let contents = fs::read(source).map_err(|e| format!("Nem sikerült beolvasni a fájlt: {} - {}", source.display(), e))?;
+    write_atomic(target, contents)
+}
+
+/// Ideiglenes fájl → célfájl (None, ha nem ideiglenes fájl)
+fn target_of_temp(path: &Path) -> Option<PathBuf> {
+    let name = path.file_name()?.to_str()?;
+    let inner = name.strip_prefix('.')?.strip_suffix(TEMP_SUFFIX)?;
+    // `.{célfájl}.{pid}-{sorszám}` → a célfájl neve az utolsó pont előtti rész
+    let (target_name, unique) = inner.rsplit_once('.')?;
+    let is_number = |part: &str| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit());
+    let is_unique = unique.split_once('-').is_some_and(|(pid, seq)| is_number(pid) && is_number(seq));
+    if target_name.is_empty() || !is_unique {
+        return None;
+    }
+    Some(path.with_file_name(target_name))
+}
+
+/// Egy ideiglenes fájl feloldása: ha a célfájl megvan, az ideiglenes fájl félbemaradt írás → törlés
+/// Ha a célfájl hiányzik és az ideiglenes fájl érvényes JSON (az átnevezés előtt szakadt meg), helyreállítjuk
+fn recover_temp_file(temp_path: &Path, target: &Path) -> bool {
+    if !target.exists() {
+        let is_json_target = target.extension().and_then(|e| e.to_str()) == Some("json");
+        let is_complete = is_json_target
+            && fs::read(temp_path)
+                .ok()
+                .is_some_and(|bytes| serde_json::from_slice::<serde_json::Value>(&bytes).is_ok());
+        if is_complete && fs::rename(temp_path, target).is_ok() {
+            logger::log_warn(&format!("Félbemaradt írás helyreállítva: {}", target.display()));
+            return true;
+        }
+    }
+
+    match fs::remove_file(temp_path) {
+        Ok(_) => {
+            logger::log_info(&format!("Félbemaradt ideiglenes fájl törölve: {}", temp_path.display()));
+            true
+        }
+        Err(e) => {
+            logger::log_warn(&format!(
+                "Nem sikerült törölni az ideiglenes fájlt: {} - {}",
+                temp_path.display(),
+                e
+            ));
+            false
+        }
+    }
+}
+
+fn recover_dir(dir: &Path, recursive: bool, recovered: &mut usize) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for path in entries.flatten().map(|entry| entry.path()) {
+        if path.is_dir() {
+            if recursive {
+                recover_dir(&path, recursive, recovered);
+            }
+            continue;
+        }
+        // Csak a saját (`.{célfájl}.{pid}-{sorszám}.atomic-tmp` nevű) ideiglenes fájljainkat kezeljük:
+        // a könyvtárban a felhasználó vagy más programok fájljai is lehetnek
+        let Some(target) = target_of_temp(&path) else {
+            continue;
+        };
+        if recover_temp_file(&path, &target) {
+            *recovered += 1;
+        }
+    }
+}
+
+/// Indításkor: a korábbi összeomlásból maradt ideiglenes fájlok feltakarítása / helyreállítása
+/// Csak az alkalmazás saját könyvtárait vizsgálja: a választott / hordozható adatkönyvtár gyökere
+/// a felhasználó bármelyik mappája lehet, ezért azt (és a munkaterületek gyökerét) nem járjuk be rekurzívan
+pub fn recover_temp_files() -> usize {
+    let mut recovered = 0;
+    if let Ok(app_dir) = crate::paths::installation_root() {
+        recover_dir(&app_dir, false, &mut recovered);
+        recover_dir(&app_dir.join("logs"), false, &mut recovered);
+    }
+    for workspace_id in crate::workspaces::workspace_ids() {
+        if workspace_id != crate::workspaces::DEFAULT_WORKSPACE_ID {
+            if let Ok(root) = crate::paths::workspace_root(&workspace_id) {
+                recover_dir(&root, false, &mut recovered);
+            }
+        }
+        for dir in crate::paths::workspace_owned_dirs(&workspace_id).unwrap_or_default() {
+            recover_dir(&dir, true, &mut recovered);
+        }
+        // Az alapértelmezett munkaterület Store-ja lehet az adatkönyvtáron kívül (bundle ID könyvtár)
+        let store_file = crate::paths::workspace_store_file(&workspace_id);
+        if let Some(store_dir) = store_file.ok().and_then(|p| p.parent().map(Path::to_path_buf)) {
+            recover_dir(&store_dir, false, &mut recovered);
+        }
+    }
+    recovered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_dir(name: &str) -> PathBuf {
+        let dir = crate::paths::test_support::data_root().join("atomic-write").join(name);
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn leftover_temp_file_is_promoted_only_when_the_target_is_missing() {
+        let dir = test_dir("promote");
+        let missing = dir.join("data.json");
+        let temp = dir.join(".data.json.4242-0.atomic-tmp");
+        fs::write(&temp, r#"{"offers":[]}"#).unwrap();
+
+        let existing = dir.join("settings.json");
+        fs::write(&existing, r#"{"regi":true}"#).unwrap();
+        let stale = dir.join(".settings.json.4242-1.atomic-tmp");
+        fs::write(&stale, r#"{"uj":true}"#).unwrap();
+
+        let mut recovered = 0;
+        recover_dir(&dir, false, &mut recovered);
+        assert_eq!(recovered, 2);
+        assert_eq!(fs::read_to_string(&missing).unwrap(), r#"{"offers":[]}"#);
+        assert_eq!(fs::read_to_string(&existing).unwrap(), r#"{"regi":true}"#, "a meglévő célfájl nem íródhat felül");
+        assert!(!temp.exists() && !stale.exists());
+    }
+
+    #[test]
+    fn foreign_temp_files_and_other_directories_are_left_alone() {
+        let dir = test_dir("foreign");
+        let foreign = [
+            ".data.json.atomic-tmp",
+            ".data.json.tmp-file.atomic-tmp",
+            "data.json.4242-0.atomic-tmp",
+            ".data.json.4242-0.tmp",
+        ];
+        for name in foreign {
+            fs::write(dir.join(name), "{}").unwrap();
+        }
+        let nested = dir.join("alkonyvtar");
+        fs::create_dir_all(&nested).unwrap();
+        let nested_temp = nested.join(".data.json.4242-0.atomic-tmp");
+        fs::write(&nested_temp, "{}").unwrap();
+
+        let mut recovered = 0;
+        recover_dir(&dir, false, &mut recovered);
+        assert_eq!(recovered, 0);
+        for name in foreign {
+            assert!(dir.join(name).exists(), "nem saját ideiglenes fájl: {}", name);
+        }
+        assert!(nested_temp.exists(), "nem rekurzív bejárásnál az alkönyvtár érintetlen");
+        assert!(!dir.join("data.json").exists());
+
+        write_atomic(&dir.join("data.json"), "{}").unwrap();
+        let leftovers = fs::read_dir(&dir)
+            .unwrap()
+            .flatten()
+            .filter(|e| target_of_temp(&e.path()).is_some())
+            .count();
+        assert_eq!(leftovers, 0, "sikeres írás után nem marad ideiglenes fájl");
+    }
+}
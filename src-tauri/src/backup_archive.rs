@@ -0,0 +1,265 @@
+use crate::backup_manifest::MANIFEST_KEY;
+use crate::crypto;
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{self, Cursor, Read, Seek, Write};
+use std::path::Path;
+use tauri::AppHandle;
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+/// Automatikus backup fájlnév előtag
+pub const AUTO_BACKUP_PREFIX: &str = "auto_backup_";
+
+/// Régi (tömörítetlen) backup kiterjesztés
+pub const JSON_BACKUP_EXTENSION: &str = "json";
+
+/// Tömörített backup archívum kiterjesztés
+pub const ARCHIVE_BACKUP_EXTENSION: &str = "zip";
+
+/// Az archívum formátum azonosítója (a manifest.json-ban)
+pub const ARCHIVE_FORMAT: &str = "3DPrinterCalcApp-backup-archive";
+pub const ARCHIVE_FORMAT_VERSION: u32 = 1;
+
+const MANIFEST_ENTRY: &str = "manifest.json";
+const DATA_ENTRY: &str = "backup.json";
+const IMAGES_DIR: &str = "images/";
+
+/// Kiemelt kép hivatkozás kulcsa a backup.json-ban
+const IMAGE_REF_KEY: &str = "$image";
+
+/// Automatikus backup fájl-e (régi .json vagy új .zip formátum)
+pub fn is_automatic_backup_file_name(file_name: &str) -> bool {
+    file_name.starts_with(AUTO_BACKUP_PREFIX)
+        && (file_name.ends_with(&format!(".{}", JSON_BACKUP_EXTENSION))
+            || file_name.ends_with(&format!(".{}", ARCHIVE_BACKUP_EXTENSION)))
+}
+
+fn is_archive(path: &Path) -> bool {
+    path.extension().and_then(|e| e.to_str()) == Some(ARCHIVE_BACKUP_EXTENSION)
+}
+
+/// `data:image/png;base64,...` felbontása (mime, nyers bytes)
+fn decode_data_url(value: &str) -> Option<(String, Vec<u8>)> {
+    let rest = value.strip_prefix("data:")?;
+    let (mime, encoded) = rest.split_once(";base64,")?;
+    if !mime.starts_with("image/") {
+        return None;
+    }
+    let bytes = crypto::from_base64(encoded).ok()?;
+    // Csak akkor emeljük ki, ha visszakódolva bájtra pontosan ugyanazt kapjuk (különben sérülne az ellenőrzőösszeg)
+    if crypto::to_base64(&bytes) != encoded {
+        return None;
+    }
+    Some((mime.to_string(), bytes))
+}
+
+fn image_extension(mime: &str) -> &str {
+    match mime {
+        "image/jpeg" => "jpg",
+        "image/svg+xml" => "svg",
+        other => other.strip_prefix("image/").unwrap_or("bin"),
+    }
+}
+
+/// Képek (data URL-ek) kiemelése: a helyükön `{"$image": "images/<sha256>.<ext>", "mime": ...}` marad
+fn extract_images(value: &Value, images: &mut BTreeMap<String, Vec<u8>>) -> Value {
+    match value {
+        Value::String(text) => match decode_data_url(text) {
+            Some((mime, bytes)) => {
+                let entry = format!("{}{}.{}", IMAGES_DIR, crypto::sha256_hex(&bytes), image_extension(&mime));
+                images.insert(entry.clone(), bytes);
+                serde_json::json!({ IMAGE_REF_KEY: entry, "mime": mime })
+            }
+            None => value.clone(),
+        },
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(key, field)| (key.clone(), extract_images(field, images)))
+                .collect(),
+        ),
+        Value::Array(items) => Value::Array(items.iter().map(|item| extract_images(item, images)).collect()),
+        other => other.clone(),
+    }
+}
+
+/// Kép hivatkozások visszaállítása data URL-ekké
+fn resolve_images(value: Value, images: &BTreeMap<String, Vec<u8>>) -> Result<Value, String> {
+    match value {
+        Value::Object(map) => {
+            if let (Some(entry), Some(mime)) = (
+                map.get(IMAGE_REF_KEY).and_then(|e| e.as_str()),
+                map.get("mime").and_then(|m| m.as_str()),
+            ) {
+                let bytes = images
+                    .get(entry)
+                    .ok_or_else(|| format!("Hiányzó kép az archívumban: {}", entry))?;
+                return Ok(Value::String(format!("data:{};base64,{}", mime, crypto::to_base64(bytes))));
+            }
+            let mut result = Map::new();
+            for (key, field) in map {
+                result.insert(key, resolve_images(field, images)?);
+            }
+            Ok(Value::Object(result))
+        }
+        Value::Array(items) => Ok(Value::Array(
+            items
+                .into_iter()
+                .map(|item| resolve_images(item, images))
+                .collect::<Result<_, _>>()?,
+        )),
+        other => Ok(other),
+    }
+}
+
+/// Backup dokumentum (manifesttel) zip archívummá alakítása
+/// Bejegyzések: manifest.json (timestamp + integritás manifest), backup.json (tömörítve), images/ (képek, tömörítés nélkül)
+fn write_archive_to<W: Write + Seek>(writer: W, document: &Value) -> Result<(), String> {
+    let mut data = document.clone();
+    let map = data
+        .as_object_mut()
+        .ok_or_else(|| "A backup data nem JSON objektum".to_string())?;
+    let manifest = map.remove(MANIFEST_KEY);
+
+    let mut images = BTreeMap::new();
+    let stripped = extract_images(&data, &mut images);
+
+    let archive_manifest = serde_json::json!({
+        "format": ARCHIVE_FORMAT,
+        "formatVersion": ARCHIVE_FORMAT_VERSION,
+        "timestamp": document.get("timestamp").cloned().unwrap_or(Value::Null),
+        "version": document.get("version").cloned().unwrap_or(Value::Null),
+        "images": images.len(),
+        MANIFEST_KEY: manifest,
+    });
+
+    let compressed = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+    // A képek (PNG/JPEG) már tömörítettek, felesleges újra tömöríteni őket
+    let stored = SimpleFileOptions::default().compression_method(CompressionMethod::Stored);
+    let manifest_bytes = serde_json::to_vec_pretty(&archive_manifest)
+        .map_err(|e| format!("Nem sikerült írni a backup archívumot: {}", e))?;
+    let data_bytes = serde_json::to_vec(&stripped)
+        .map_err(|e| format!("Nem sikerült írni a backup archívumot: {}", e))?;
+
+    (|| -> io::Result<()> {
+        let mut zip = ZipWriter::new(writer);
+        zip.start_file(MANIFEST_ENTRY, compressed).map_err(io::Error::other)?;
+        zip.write_all(&manifest_bytes)?;
+        zip.start_file(DATA_ENTRY, compressed).map_err(io::Error::other)?;
+        zip.write_all(&data_bytes)?;
+        for (entry, bytes) in &images {
+            zip.start_file(entry.as_str(), stored).map_err(io::Error::other)?;
+            zip.write_all(bytes)?;
+        }
+        zip.finish().map_err(io::Error::other)?;
+        Ok(())
+    })()
+    .map_err(|e| format!("Nem sikerült írni a backup archívumot: {}", e))
+}
+
+/// Backup dokumentum írása zip archívumba
+pub fn write_archive(path: &Path, document: &Value) -> Result<(), String> {
+    // Atomikus írás: félbeszakadt mentés nem hagy csonka (megnyithatatlan) archívumot
+    crate::atomic_write::write_atomic_with(path, |file| {
+        write_archive_to(&mut *file, document).map_err(io::Error::other)
+    })
+}
+
+/// Backup dokumentum zip archívuma memóriában (titkosított backupnál ez kerül titkosításra)
+pub fn archive_bytes(document: &Value) -> Result<Vec<u8>, String> {
+    let mut cursor = Cursor::new(Vec::new());
+    write_archive_to(&mut cursor, document)?;
+    Ok(cursor.into_inner())
+}
+
+fn open_archive(path: &Path) -> Result<ZipArchive<fs::File>, String> {
+    let file = fs::File::open(path).map_err(|e| format!("Nem sikerült megnyitni a backup archívumot: {}", e))?;
+    ZipArchive::new(file).map_err(|e| format!("Sérült backup archívum: {}", e))
+}
+
+fn read_entry<R: Read + Seek>(archive: &mut ZipArchive<R>, name: &str) -> Result<Vec<u8>, String> {
+    let mut entry = archive
+        .by_name(name)
+        .map_err(|e| format!("Hiányzó bejegyzés a backup archívumban: {} - {}", name, e))?;
+    let mut bytes = Vec::new();
+    entry
+        .read_to_end(&mut bytes)
+        .map_err(|e| format!("Nem sikerült olvasni a backup archívumot: {} - {}", name, e))?;
+    Ok(bytes)
+}
+
+fn read_archive_manifest<R: Read + Seek>(archive: &mut ZipArchive<R>) -> Result<Value, String> {
+    serde_json::from_slice(&read_entry(archive, MANIFEST_ENTRY)?)
+        .map_err(|e| format!("Érvénytelen archívum manifest: {}", e))
+}
+
+/// Zip archívum visszaalakítása a teljes backup dokumentummá (képekkel és integritás manifesttel),
+/// így az ellenőrzés és a visszaállítás ugyanúgy működik, mint a .json backupoknál
+fn read_archive_from<R: Read + Seek>(mut archive: ZipArchive<R>) -> Result<Value, String> {
+    let archive_manifest = read_archive_manifest(&mut archive)?;
+    if archive_manifest.get("formatVersion").and_then(|v| v.as_u64()).unwrap_or(0) > ARCHIVE_FORMAT_VERSION as u64 {
+        return Err("A backup archívumot egy újabb alkalmazás verzió készítette, kérjük frissítse az alkalmazást".to_string());
+    }
+
+    let data: Value = serde_json::from_slice(&read_entry(&mut archive, DATA_ENTRY)?)
+        .map_err(|e| format!("Érvénytelen backup adat az archívumban: {}", e))?;
+
+    let image_entries: Vec<String> = archive
+        .file_names()
+        .filter(|name| name.starts_with(IMAGES_DIR))
+        .map(|name| name.to_string())
+        .collect();
+    let mut images = BTreeMap::new();
+    for name in image_entries {
+        let bytes = read_entry(&mut archive, &name)?;
+        images.insert(name, bytes);
+    }
+
+    let mut document = resolve_images(data, &images)?;
+    if let (Some(map), Some(manifest)) = (
+        document.as_object_mut(),
+        archive_manifest.get(MANIFEST_KEY).filter(|m| !m.is_null()),
+    ) {
+        map.insert(MANIFEST_KEY.to_string(), manifest.clone());
+    }
+    Ok(document)
+}
+
+fn read_archive(path: &Path) -> Result<Value, String> {
+    read_archive_from(open_archive(path)?)
+}
+
+/// Memóriában lévő zip archívum (visszafejtett backup tartalom) visszaalakítása backup dokumentummá
+pub fn document_from_archive_bytes(bytes: &[u8]) -> Result<Value, String> {
+    let archive = ZipArchive::new(Cursor::new(bytes)).map_err(|e| format!("Sérült backup archívum: {}", e))?;
+    read_archive_from(archive)
+}
+
+/// Backup fájl beolvasása formátumtól függetlenül (.json: változatlan, .zip: kicsomagolva)
+/// Titkosított .json backupnál a titkosított dokumentumot adja vissza (lásd backup_format::open_backup)
+pub fn read_backup_document(path: &Path) -> Result<Value, String> {
+    if is_archive(path) {
+        return read_archive(path);
+    }
+    let content = fs::read_to_string(path).map_err(|e| format!("Nem sikerült beolvasni a backup fájlt: {}", e))?;
+    serde_json::from_str(&content).map_err(|e| format!("A backup fájl nem érvényes JSON (csonka vagy sérült): {}", e))
+}
+
+/// Backup timestamp kiolvasása (archívumnál csak a manifest.json-t olvassuk)
+pub fn read_backup_timestamp(path: &Path) -> Option<String> {
+    let document = if is_archive(path) {
+        read_archive_manifest(&mut open_archive(path).ok()?).ok()?
+    } else {
+        serde_json::from_str::<Value>(&fs::read_to_string(path).ok()?).ok()?
+    };
+    document.get("timestamp").and_then(|v| v.as_str()).map(|v| v.to_string())
+}
+
+/// Backup fájl megnyitása visszaállításhoz (.json vagy .zip, titkosított esetben jelmondattal)
+#[tauri::command]
+pub fn read_backup_file(app: AppHandle, file_path: String, passphrase: Option<String>) -> Result<Value, String> {
+    let path = crate::paths::resolve_backup_file(&app, &file_path)?;
+    let document = read_backup_document(&path)?;
+    crate::backup_format::open_backup(document, passphrase.as_deref())
+}
@@ -0,0 +1,498 @@
+use crate::backup_archive;
+use crate::crypto;
+use crate::logger;
+use crate::webdav::{WebDavClient, WebDavSettings};
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter};
+
+lazy_static! {
+    /// A cél konfiguráció olvasás-módosítás-írás műveleteinek zárja
+    static ref DESTINATIONS_LOCK: Mutex<()> = Mutex::new(());
+}
+
+/// A háttérben futó másolás eredménye a frontend felé
+pub const BACKUP_REPLICATED_EVENT: &str = "backup-replicated";
+
+/// A backupok alkönyvtára a felhasználó által választott célmappán belül
+/// (a megőrzési szabály csak ezen belül töröl, a mappa többi tartalmához nem nyúlunk)
+pub const DESTINATION_SUBDIR: &str = "3DPrinterCalcApp_backups";
+
+/// Egy cél utolsó másolásának állapota
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestinationStatus {
+    pub last_success_at: Option<String>,
+    pub last_success_file: Option<String>,
+    pub last_error_at: Option<String>,
+    pub last_error: Option<String>,
+    #[serde(default)]
+    pub backup_count: usize,
+}
+
+/// További backup cél (második helyi mappa, USB meghajtó, csatolt hálózati megosztás vagy WebDAV / Nextcloud)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupDestination {
+    pub id: String,
+    pub name: String,
+    /// Mappa cél útvonala (WebDAV célnál a megjelenítéshez a gyűjtemény címe)
+    pub path: String,
+    /// WebDAV cél elérése; ha meg van adva, a backup ide kerül feltöltésre a `path` helyett
+    #[serde(default)]
+    pub webdav: Option<WebDavSettings>,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    /// Megőrzendő backupok maximális száma (None = nincs korlát)
+    pub retention_count: Option<u32>,
+    /// Ennél régebbi backupok törlése napokban (None = nincs korlát)
+    pub retention_days: Option<u32>,
+    #[serde(default)]
+    pub status: DestinationStatus,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+/// A backup_destinations.json tartalma
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DestinationConfig {
+    #[serde(default)]
+    destinations: Vec<BackupDestination>,
+}
+
+/// Egy backup másolásának eredménye egy célra
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplicationResult {
+    pub destination_id: String,
+    pub name: String,
+    pub success: bool,
+    pub file_path: Option<String>,
+    pub error: Option<String>,
+    pub removed_by_retention: usize,
+}
+
+fn config_path() -> Result<PathBuf, String> {
+    Ok(crate::paths::data_root()?.join("backup_destinations.json"))
+}
+
+fn read_config() -> Result<DestinationConfig, String> {
+    let path = config_path()?;
+    if !path.exists() {
+        return Ok(DestinationConfig::default());
+    }
+    let content = fs::read_to_string(&path)
+        .map_err(|e| format!("Nem sikerült beolvasni a backup cél konfigurációt: {}", e))?;
+    serde_json::from_str(&content).map_err(|e| format!("Érvénytelen backup cél konfiguráció: {}", e))
+}
+
+/// A WebDAV jelszavak bekapcsolt adattárolónál titkosítva kerülnek a fájlba
+fn write_config(config: &DestinationConfig) -> Result<(), String> {
+    let mut config = config.clone();
+    for destination in &mut config.destinations {
+        destination.webdav = destination.webdav.as_ref().map(|settings| settings.sealed()).transpose()?;
+    }
+    write_config_raw(&config)
+}
+
+fn write_config_raw(config: &DestinationConfig) -> Result<(), String> {
+    let content = serde_json::to_string_pretty(config)
+        .map_err(|e| format!("Backup cél konfiguráció szerializálási hiba: {}", e))?;
+    crate::atomic_write::write_atomic(&config_path()?, content)
+        .map_err(|e| format!("Nem sikerült menteni a backup cél konfigurációt: {}", e))
+}
+
+/// A mentett WebDAV célok beállításai (a jelszó üres jelszavas kapcsolatteszthez)
+pub fn stored_webdav_settings() -> Vec<WebDavSettings> {
+    read_config()
+        .map(|config| config.destinations.into_iter().filter_map(|d| d.webdav).collect())
+        .unwrap_or_default()
+}
+
+/// A mentett WebDAV jelszavak átírása a vault állapotváltásakor (bekapcsolás / jelmondat csere / kikapcsolás)
+pub fn rewrite_webdav_secrets(f: &mut dyn FnMut(&str) -> Result<String, String>) -> Result<(), String> {
+    let _guard = DESTINATIONS_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let mut config = read_config()?;
+    if !config.destinations.iter().any(|d| d.webdav.is_some()) {
+        return Ok(());
+    }
+    for settings in config.destinations.iter_mut().filter_map(|d| d.webdav.as_mut()) {
+        settings.rewrite_password(f)?;
+    }
+    write_config_raw(&config)
+}
+
+impl BackupDestination {
+    /// A frontend felé a WebDAV jelszó nélkül
+    fn redacted(mut self) -> Self {
+        self.webdav = self.webdav.as_ref().map(WebDavSettings::redacted);
+        self
+    }
+}
+
+/// A mappa backup célok backup könyvtárai (ezekből a backupok visszaállíthatók / ellenőrizhetők)
+pub fn local_destination_dirs() -> Vec<PathBuf> {
+    read_config()
+        .map(|config| {
+            config
+                .destinations
+                .iter()
+                .filter(|destination| destination.webdav.is_none())
+                .map(destination_dir)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn destination_dir(destination: &BackupDestination) -> PathBuf {
+    Path::new(&destination.path).join(DESTINATION_SUBDIR)
+}
+
+fn now() -> String {
+    chrono::Utc::now().to_rfc3339()
+}
+
+/// Backup fájl másolása a célkönyvtárba ellenőrzéssel
+/// Atomikusan ír (fsync után nevez át), majd a célfájlt újra megnyitva visszaolvassa és összeveti a SHA-256
+/// ellenőrzőösszeget; eltérés esetén a hibás másolatot törli
+fn copy_verified(source: &Path, target_dir: &Path) -> Result<PathBuf, String> {
+    let file_name = source
+        .file_name()
+        .ok_or_else(|| "Érvénytelen backup fájlnév".to_string())?;
+    let target = target_dir.join(file_name);
+
+    let bytes = fs::read(source).map_err(|e| format!("Nem sikerült beolvasni a backup fájlt: {}", e))?;
+    let expected = crypto::sha256_hex(&bytes);
+
+    crate::atomic_write::write_atomic_with(&target, |file| file.write_all(&bytes))
+        .map_err(|e| format!("Nem sikerült a backup másolása: {}", e))?;
+
+    // Újranyitás: a megnyitott leíró olvasása a lapgyorsítótárból jönne, nem azt ellenőrizné, ami a célra került
+    let copied = fs::read(&target).map_err(|e| format!("Nem sikerült visszaolvasni a másolatot: {}", e))?;
+    if crypto::sha256_hex(&copied) != expected {
+        if let Err(e) = fs::remove_file(&target) {
+            logger::log_warn(&format!("Nem sikerült törölni a hibás másolatot: {} - {}", target.display(), e));
+        }
+        return Err("Nem sikerült a backup másolása: a másolat ellenőrzőösszege nem egyezik az eredetivel".to_string());
+    }
+    Ok(target)
+}
+
+/// Automatikus backupok a célkönyvtárban (legújabb elöl)
+fn destination_backups(dir: &Path) -> Vec<(PathBuf, chrono::DateTime<chrono::Utc>)> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut backups: Vec<(PathBuf, chrono::DateTime<chrono::Utc>)> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(backup_archive::is_automatic_backup_file_name)
+        })
+        .filter_map(|path| {
+            let timestamp = backup_archive::read_backup_timestamp(&path)
+                .and_then(|ts| chrono::DateTime::parse_from_rfc3339(&ts).ok())
+                .map(|ts| ts.with_timezone(&chrono::Utc))
+                .or_else(|| fs::metadata(&path).and_then(|m| m.modified()).ok().map(Into::into))?;
+            Some((path, timestamp))
+        })
+        .collect();
+    backups.sort_by_key(|backup| std::cmp::Reverse(backup.1));
+    backups
+}
+
+/// A cél saját megőrzési szabálya (darabszám és/vagy kor) szerint törlendő backupok indexei
+/// A lista legújabb elöl rendezett; a legújabb backup mindig megmarad
+fn retention_victims(destination: &BackupDestination, timestamps: &[chrono::DateTime<chrono::Utc>]) -> Vec<usize> {
+    let cutoff = destination
+        .retention_days
+        .map(|days| chrono::Utc::now() - chrono::Duration::days(days as i64));
+    let max_count = destination.retention_count.map(|count| count.max(1) as usize);
+
+    timestamps
+        .iter()
+        .enumerate()
+        .skip(1)
+        .filter(|(index, timestamp)| {
+            max_count.is_some_and(|max| *index >= max) || cutoff.is_some_and(|cutoff| **timestamp < cutoff)
+        })
+        .map(|(index, _)| index)
+        .collect()
+}
+
+fn apply_retention(destination: &BackupDestination, dir: &Path) -> (usize, usize) {
+    let backups = destination_backups(dir);
+    let timestamps: Vec<_> = backups.iter().map(|backup| backup.1).collect();
+
+    let mut removed = 0;
+    for index in retention_victims(destination, &timestamps) {
+        let path = &backups[index].0;
+        match fs::remove_file(path) {
+            Ok(_) => removed += 1,
+            Err(e) => logger::log_warn(&format!(
+                "Nem sikerült törölni a régi backupot ({}): {} - {}",
+                destination.name,
+                path.display(),
+                e
+            )),
+        }
+    }
+    (removed, backups.len() - removed)
+}
+
+/// Automatikus backupok a WebDAV célon (legújabb elöl, a szerver szerinti módosítási idővel)
+fn webdav_backups(client: &WebDavClient) -> Result<Vec<(String, chrono::DateTime<chrono::Utc>)>, String> {
+    let mut backups: Vec<(String, chrono::DateTime<chrono::Utc>)> = client
+        .list(DESTINATION_SUBDIR)?
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|entry| !entry.is_collection && backup_archive::is_automatic_backup_file_name(&entry.name))
+        .filter_map(|entry| Some((entry.name, entry.last_modified?)))
+        .collect();
+    backups.sort_by_key(|backup| std::cmp::Reverse(backup.1));
+    Ok(backups)
+}
+
+fn apply_webdav_retention(destination: &BackupDestination, client: &WebDavClient) -> Result<(usize, usize), String> {
+    let backups = webdav_backups(client)?;
+    let timestamps: Vec<_> = backups.iter().map(|backup| backup.1).collect();
+
+    let mut removed = 0;
+    for index in retention_victims(destination, &timestamps) {
+        let name = &backups[index].0;
+        match client.delete(&format!("{}/{}", DESTINATION_SUBDIR, name)) {
+            Ok(_) => removed += 1,
+            Err(e) => logger::log_warn(&format!(
+                "Nem sikerült törölni a régi backupot ({}): {} - {}",
+                destination.name, name, e
+            )),
+        }
+    }
+    Ok((removed, backups.len() - removed))
+}
+
+/// Backup feltöltése WebDAV célra (folytatható feltöltéssel), majd a megőrzési szabály alkalmazása
+fn upload_to_webdav(destination: &BackupDestination, settings: &WebDavSettings, source: &Path) -> Result<(String, (usize, usize)), String> {
+    let file_name = source
+        .file_name()
+        .and_then(|n| n.to_str())
+        .ok_or_else(|| "Érvénytelen backup fájlnév".to_string())?;
+    let client = WebDavClient::new(settings)?;
+    let remote_path = format!("{}/{}", DESTINATION_SUBDIR, file_name);
+    client.ensure_collection(DESTINATION_SUBDIR)?;
+    let outcome = client.upload_file(source, &remote_path)?;
+    logger::log_info(&format!(
+        "WebDAV feltöltés kész: {} ({} bájt, ebből {} egy korábbi próbálkozásból folytatva, ETag: {})",
+        remote_path,
+        outcome.size,
+        outcome.resumed_bytes,
+        outcome.etag.as_deref().unwrap_or("-")
+    ));
+    let retention = apply_webdav_retention(destination, &client)?;
+    Ok((client.url(&remote_path), retention))
+}
+
+fn replicate_to(destination: &mut BackupDestination, source: &Path) -> ReplicationResult {
+    let mut result = ReplicationResult {
+        destination_id: destination.id.clone(),
+        name: destination.name.clone(),
+        success: false,
+        file_path: None,
+        error: None,
+        removed_by_retention: 0,
+    };
+
+    // A célmappát nem hozzuk létre: egy le nem csatolt meghajtó csatolási pontja ne teljen meg helyi másolatokkal
+    let outcome = if let Some(settings) = &destination.webdav {
+        upload_to_webdav(destination, settings, source)
+    } else if !Path::new(&destination.path).is_dir() {
+        Err(format!("A cél nem elérhető (nincs csatlakoztatva?): {}", destination.path))
+    } else {
+        let dir = destination_dir(destination);
+        fs::create_dir_all(&dir)
+            .map_err(|e| format!("Nem sikerült létrehozni a backup könyvtárat a célon: {}", e))
+            .and_then(|_| copy_verified(source, &dir))
+            .map(|target| (target.to_string_lossy().to_string(), apply_retention(destination, &dir)))
+    };
+
+    let status = &mut destination.status;
+    match outcome {
+        Ok((target, (removed, remaining))) => {
+            status.last_success_at = Some(now());
+            status.last_success_file = Some(target.clone());
+            status.backup_count = remaining;
+            result.success = true;
+            result.removed_by_retention = removed;
+            logger::log_info(&format!("Backup másolva ide: {} ({})", destination.name, target));
+            result.file_path = Some(target);
+        }
+        Err(e) => {
+            status.last_error_at = Some(now());
+            status.last_error = Some(e.clone());
+            result.error = Some(e.clone());
+            logger::log_warn(&format!("Backup másolása sikertelen ide: {} - {}", destination.name, e));
+        }
+    }
+    result
+}
+
+/// Egy elkészült backup fájl másolása az összes engedélyezett további célra
+/// A hibák nem akadályozzák az elsődleges backupot, a cél állapotában és a logban jelennek meg
+pub fn replicate_backup(source: &Path) -> Vec<ReplicationResult> {
+    let _guard = DESTINATIONS_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let mut config = match read_config() {
+        Ok(config) => config,
+        Err(e) => {
+            logger::log_warn(&format!("Backup célok betöltése sikertelen: {}", e));
+            return Vec::new();
+        }
+    };
+    if !config.destinations.iter().any(|d| d.enabled) {
+        return Vec::new();
+    }
+
+    let results = config
+        .destinations
+        .iter_mut()
+        .filter(|destination| destination.enabled)
+        .map(|destination| replicate_to(destination, source))
+        .collect();
+
+    if let Err(e) = write_config(&config) {
+        logger::log_warn(&format!("Backup cél állapot mentése sikertelen: {}", e));
+    }
+    results
+}
+
+/// Háttérben futó másolás eredménye (BACKUP_REPLICATED_EVENT)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ReplicationReport {
+    file_path: String,
+    results: Vec<ReplicationResult>,
+}
+
+/// Egy elkészült backup másolása a további célokra külön szálon (lassú USB vagy hálózati cél ne tartsa fel a backupot)
+/// Az eredmény BACKUP_REPLICATED_EVENT eseményként érkezik a frontend-re; nincs engedélyezett cél esetén nincs esemény
+pub fn replicate_backup_in_background(app: AppHandle, source: PathBuf) {
+    let spawned = std::thread::Builder::new()
+        .name("backup-replication".to_string())
+        .spawn(move || {
+            let results = replicate_backup(&source);
+            if results.is_empty() {
+                return;
+            }
+            let report = ReplicationReport {
+                file_path: source.to_string_lossy().to_string(),
+                results,
+            };
+            if let Err(e) = app.emit(BACKUP_REPLICATED_EVENT, &report) {
+                logger::log_warn(&format!("Nem sikerült elküldeni a backup másolás eseményt: {}", e));
+            }
+        });
+    if let Err(e) = spawned {
+        logger::log_error(&format!("Nem sikerült elindítani a backup másolást: {}", e));
+    }
+}
+
+/// A cél útvonal ellenőrzése: létező könyvtár, és nem az elsődleges backup könyvtáron belül
+fn validate_destination_path(path: &str) -> Result<(), String> {
+    let target = Path::new(path);
+    if path.trim().is_empty() || !target.is_absolute() {
+        return Err("A backup cél útvonalának abszolútnak kell lennie".to_string());
+    }
+    if !target.is_dir() {
+        return Err(format!("A backup cél könyvtár nem létezik: {}", path));
+    }
+
+    let primary = crate::paths::backups_dir()?;
+    let canonical = |p: &Path| fs::canonicalize(p).unwrap_or_else(|_| p.to_path_buf());
+    if canonical(target).starts_with(canonical(&primary)) {
+        return Err("A backup cél nem lehet az elsődleges backup könyvtáron belül".to_string());
+    }
+    Ok(())
+}
+
+/// A beállított backup célok lekérése (állapottal együtt)
+#[tauri::command]
+pub fn get_backup_destinations() -> Result<Vec<BackupDestination>, String> {
+    Ok(read_config()?.destinations.into_iter().map(BackupDestination::redacted).collect())
+}
+
+/// Backup cél hozzáadása vagy módosítása (üres id = új cél)
+/// Az állapotot a backend vezeti, a frontend-ről érkező állapot figyelmen kívül marad
+#[tauri::command]
+pub fn save_backup_destination(destination: BackupDestination) -> Result<BackupDestination, String> {
+    let mut destination = destination;
+    match &destination.webdav {
+        // A cím formátumát ellenőrizzük, a kapcsolatot a test_webdav_connection parancs teszteli
+        Some(settings) => destination.path = WebDavClient::new(settings)?.url(""),
+        None => validate_destination_path(&destination.path)?,
+    }
+    if destination.name.trim().is_empty() {
+        return Err("A backup cél neve nem lehet üres".to_string());
+    }
+
+    let _guard = DESTINATIONS_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let mut config = read_config()?;
+
+    let saved = match config.destinations.iter_mut().find(|d| !destination.id.is_empty() && d.id == destination.id) {
+        Some(existing) => {
+            destination.status = existing.status.clone();
+            // Üres jelszónál (a frontend nem kapja meg a mentettet) a korábbi marad
+            if let (Some(settings), Some(previous)) = (destination.webdav.as_mut(), existing.webdav.as_ref()) {
+                settings.keep_password_of(previous);
+            }
+            *existing = destination;
+            existing.clone()
+        }
+        None => {
+            destination.id = format!("dest_{}", chrono::Utc::now().timestamp_millis());
+            destination.status = DestinationStatus::default();
+            config.destinations.push(destination.clone());
+            destination
+        }
+    };
+
+    write_config(&config)?;
+    logger::log_info(&format!("Backup cél mentve: {} ({})", saved.name, saved.path));
+    Ok(saved.redacted())
+}
+
+/// Backup cél eltávolítása (a célon lévő backup fájlok megmaradnak)
+#[tauri::command]
+pub fn remove_backup_destination(destination_id: String) -> Result<(), String> {
+    let _guard = DESTINATIONS_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let mut config = read_config()?;
+    let before = config.destinations.len();
+    config.destinations.retain(|d| d.id != destination_id);
+    if config.destinations.len() == before {
+        return Err(format!("A backup cél nem található: {}", destination_id));
+    }
+    write_config(&config)?;
+    logger::log_info(&format!("Backup cél eltávolítva: {}", destination_id));
+    Ok(())
+}
+
+/// A legutóbbi automatikus backup másolása az összes engedélyezett célra (pl. egy meghajtó csatlakoztatása után)
+/// A WebDAV feltöltés percekig tarthat, ezért háttérszálon fut
+#[tauri::command]
+pub async fn replicate_latest_backup() -> Result<Vec<ReplicationResult>, String> {
+    crate::vault::run_blocking(|| {
+        let latest = crate::commands::list_backup_files()?
+            .into_iter()
+            .next()
+            .ok_or_else(|| "Nincs automatikus backup, amit másolni lehetne".to_string())?;
+        Ok(replicate_backup(Path::new(&latest.1)))
+    })
+    .await
+}
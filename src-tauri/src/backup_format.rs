@@ -0,0 +1,435 @@
+use crate::backup_manifest::{self, BackupManifest};
+use crate::crypto;
+use crate::data_store;
+use crate::logger;
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::sync::Mutex;
+
+lazy_static! {
+    /// A backup jelmondat az aktuális munkamenetre (csak memóriában)
+    static ref BACKUP_PASSPHRASE: Mutex<Option<String>> = Mutex::new(None);
+}
+
+/// A titkosított backup fájlok formátum azonosítója
+pub const ENCRYPTED_BACKUP_FORMAT: &str = "3DPrinterCalcApp-encrypted-backup";
+
+/// A titkosított backup fejléc verziója
+/// - v1: a fejléc nincs a titkosításhoz kötve
+/// - v2: a fejléc (a payload és a manifest kivételével) AES-GCM kapcsolt adatként (AAD) védett
+/// - v3: a titkosított tartalom a backup zip archívuma (lásd backup_archive), nem a nyers JSON
+pub const ENCRYPTED_BACKUP_FORMAT_VERSION: u32 = 3;
+
+/// Az első fejléc verzió, amelynél a fejléc kapcsolt adat
+const HEADER_AAD_FORMAT_VERSION: u32 = 2;
+
+/// Az első fejléc verzió, amelynél a titkosított tartalom zip archívum
+const ARCHIVE_PAYLOAD_FORMAT_VERSION: u32 = 3;
+
+/// A fejlécben elfogadott PBKDF2 iterációszám: kevesebbel gyenge a kulcs, többel egy módosított fejléc
+/// percekig tartó kulcslevezetést (lefagyást) okozhatna a jelmondat ellenőrzése előtt
+const KDF_ITERATIONS_RANGE: std::ops::RangeInclusive<u32> = 100_000..=10_000_000;
+
+/// Hibakód: titkosított backup, de nincs megadva jelmondat (a frontend ez alapján kéri be)
+pub const PASSPHRASE_REQUIRED_ERROR: &str = "BACKUP_PASSPHRASE_REQUIRED";
+
+/// Kulcs levezetés paraméterei a fejlécben
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KdfParams {
+    pub algorithm: String,
+    pub iterations: u32,
+    pub salt: String,
+}
+
+/// Titkosított backup fájl: a fejléc mezők titkosítatlanok (listázáshoz), a tartalom a `payload`-ban
+/// A `timestamp` a legfelső szinten marad, így a list_backup_files jelmondat nélkül is rendezni tud
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EncryptedBackup {
+    pub format: String,
+    pub format_version: u32,
+    pub encrypted: bool,
+    pub timestamp: String,
+    pub version: Option<String>,
+    pub app_version: String,
+    pub kdf: KdfParams,
+    pub cipher: String,
+    pub payload: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub manifest: Option<BackupManifest>,
+}
+
+/// A fejléc titkosításhoz kötött része (mezősorrend rögzített, hogy a szerializálás determinisztikus legyen)
+/// A payload és a manifest nincs benne: a manifest a titkosított payload ellenőrzőösszegét tartalmazza
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct HeaderAad<'a> {
+    format: &'a str,
+    format_version: u32,
+    encrypted: bool,
+    timestamp: &'a str,
+    version: Option<&'a str>,
+    app_version: &'a str,
+    kdf: &'a KdfParams,
+    cipher: &'a str,
+}
+
+impl EncryptedBackup {
+    fn associated_data(&self) -> Result<Vec<u8>, String> {
+        serde_json::to_vec(&HeaderAad {
+            format: &self.format,
+            format_version: self.format_version,
+            encrypted: self.encrypted,
+            timestamp: &self.timestamp,
+            version: self.version.as_deref(),
+            app_version: &self.app_version,
+            kdf: &self.kdf,
+            cipher: &self.cipher,
+        })
+        .map_err(|e| format!("Nem sikerült a backup fejlécet szerializálni: {}", e))
+    }
+}
+
+/// Titkosított backup-e a dokumentum (a fejléc alapján)
+pub fn is_encrypted_backup(document: &Value) -> bool {
+    document.get("format").and_then(|f| f.as_str()) == Some(ENCRYPTED_BACKUP_FORMAT)
+        && document.get("encrypted").and_then(|e| e.as_bool()) == Some(true)
+}
+
+/// Backup adat titkosítása jelmondattal (minden backuphoz friss só és nonce)
+pub fn encrypt_backup(backup_data: &Value, passphrase: &str) -> Result<Value, String> {
+    let salt = crypto::generate_salt();
+    let key = crypto::derive_key(passphrase, &salt, crypto::KDF_ITERATIONS);
+    encrypt_backup_with_key(backup_data, &key, &salt, crypto::KDF_ITERATIONS)
+}
+
+/// Backup adat titkosítása egy már levezetett kulccsal (a fejlécbe a levezetés sója és iterációszáma kerül)
+/// Előbb tömörítünk (zip archívum), utána titkosítunk: a titkosított adat már nem tömöríthető
+fn encrypt_backup_with_key(backup_data: &Value, key: &crypto::EncryptionKey, salt: &[u8], iterations: u32) -> Result<Value, String> {
+    let timestamp = backup_data
+        .get("timestamp")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| "Nincs timestamp a backup data-ban".to_string())?;
+
+    let plaintext = crate::backup_archive::archive_bytes(backup_data)?;
+
+    let mut encrypted = EncryptedBackup {
+        format: ENCRYPTED_BACKUP_FORMAT.to_string(),
+        format_version: ENCRYPTED_BACKUP_FORMAT_VERSION,
+        encrypted: true,
+        timestamp: timestamp.to_string(),
+        version: backup_data.get("version").and_then(|v| v.as_str()).map(|v| v.to_string()),
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        kdf: KdfParams {
+            algorithm: crypto::KDF_ALGORITHM.to_string(),
+            iterations,
+            salt: crypto::to_base64(salt),
+        },
+        cipher: crypto::CIPHER_ALGORITHM.to_string(),
+        payload: String::new(),
+        manifest: None,
+    };
+    let payload = crypto::encrypt_with_aad(key, &plaintext, &encrypted.associated_data()?)?;
+    encrypted.payload = crypto::to_base64(&payload);
+    encrypted.manifest = Some(backup_manifest::encrypted_manifest(backup_data, &payload));
+
+    serde_json::to_value(encrypted).map_err(|e| format!("Nem sikerült a titkosított backup fejlécet létrehozni: {}", e))
+}
+
+/// Titkosított backup visszafejtése
+pub fn decrypt_backup(document: &Value, passphrase: &str) -> Result<Value, String> {
+    let encrypted: EncryptedBackup = serde_json::from_value(document.clone())
+        .map_err(|e| format!("Érvénytelen titkosított backup fejléc: {}", e))?;
+
+    if !KDF_ITERATIONS_RANGE.contains(&encrypted.kdf.iterations) {
+        return Err(format!(
+            "Érvénytelen kulcslevezetési iterációszám a backup fejlécében: {}",
+            encrypted.kdf.iterations
+        ));
+    }
+    let salt = crypto::from_base64(&encrypted.kdf.salt)?;
+    let key = crypto::derive_key(passphrase, &salt, encrypted.kdf.iterations);
+    decrypt_backup_with_key(&encrypted, &key)
+}
+
+fn validate_header(encrypted: &EncryptedBackup) -> Result<(), String> {
+    if encrypted.format_version > ENCRYPTED_BACKUP_FORMAT_VERSION {
+        return Err(format!(
+            "A backupot egy újabb alkalmazás verzió készítette (formátum: v{}), kérjük frissítse az alkalmazást",
+            encrypted.format_version
+        ));
+    }
+    if encrypted.kdf.algorithm != crypto::KDF_ALGORITHM || encrypted.cipher != crypto::CIPHER_ALGORITHM {
+        return Err(format!(
+            "Nem támogatott titkosítás: {} / {}",
+            encrypted.kdf.algorithm, encrypted.cipher
+        ));
+    }
+    Ok(())
+}
+
+fn decrypt_backup_with_key(encrypted: &EncryptedBackup, key: &crypto::EncryptionKey) -> Result<Value, String> {
+    validate_header(encrypted)?;
+    let payload = crypto::from_base64(&encrypted.payload)?;
+    // A v1 backupok fejléce még nem volt a titkosításhoz kötve
+    let plaintext = if encrypted.format_version >= HEADER_AAD_FORMAT_VERSION {
+        crypto::decrypt_with_aad(key, &payload, &encrypted.associated_data()?)
+    } else {
+        crypto::decrypt(key, &payload)
+    }
+    .map_err(|_| "Visszafejtés sikertelen: hibás jelmondat, sérült adat vagy módosított fejléc".to_string())?;
+
+    // A v3 előtti backupok tartalma tömörítetlen JSON
+    if encrypted.format_version >= ARCHIVE_PAYLOAD_FORMAT_VERSION {
+        crate::backup_archive::document_from_archive_bytes(&plaintext)
+    } else {
+        serde_json::from_slice(&plaintext).map_err(|e| format!("A visszafejtett backup nem érvényes JSON: {}", e))
+    }
+}
+
+/// A munkamenet backup jelmondata (ha be van állítva)
+pub fn session_passphrase() -> Option<String> {
+    BACKUP_PASSPHRASE.lock().ok().and_then(|guard| guard.clone())
+}
+
+/// Be van-e kapcsolva a backup titkosítás a beállításokban (settings.backupEncryptionEnabled)
+pub fn encryption_enabled_in_settings() -> bool {
+    data_store::read_store()
+        .ok()
+        .flatten()
+        .and_then(|data| data.get("settings")?.get("backupEncryptionEnabled")?.as_bool())
+        .unwrap_or(false)
+}
+
+/// Backup adat előkészítése fájlba íráshoz: manifest hozzáadása, majd titkosítás, ha be van kapcsolva
+/// Bekapcsolt titkosítás esetén jelmondat nélkül nem készül titkosítatlan backup
+/// Bekapcsolt titkosított adattárolónál a backup mindig titkosított: külön backup jelmondat nélkül
+/// a vault kulcsával (a vault jelmondattal nyitható meg)
+pub fn prepare_for_write(backup_data: &Value) -> Result<Value, String> {
+    let backup_data = &backup_manifest::attach_manifest(backup_data)?;
+    if let Some(passphrase) = session_passphrase() {
+        return encrypt_backup(backup_data, &passphrase);
+    }
+    if crate::vault::is_enabled() {
+        let (key, salt, iterations) = crate::vault::backup_key()?
+            .ok_or_else(|| "Az adattároló zárolva van, titkosítatlan backup nem készülhet".to_string())?;
+        return encrypt_backup_with_key(backup_data, &key, &salt, iterations);
+    }
+    if encryption_enabled_in_settings() {
+        return Err(PASSPHRASE_REQUIRED_ERROR.to_string());
+    }
+    Ok(backup_data.clone())
+}
+
+/// Titkosítás kötelező-e az új backupokhoz (beállítás vagy titkosított adattároló)
+pub fn encryption_required() -> bool {
+    crate::vault::is_enabled() || encryption_enabled_in_settings()
+}
+
+/// A vault kulcsával készült backup megnyitása jelmondat nélkül (a fejlécben lévő só egyezik a vaultéval)
+fn open_with_vault_key(document: &Value) -> Option<Result<Value, String>> {
+    let encrypted: EncryptedBackup = serde_json::from_value(document.clone()).ok()?;
+    let (key, salt, iterations) = crate::vault::backup_key().ok()??;
+    let same_kdf = crypto::from_base64(&encrypted.kdf.salt).ok()? == salt && encrypted.kdf.iterations == iterations;
+    same_kdf.then(|| decrypt_backup_with_key(&encrypted, &key))
+}
+
+/// Backup dokumentum megnyitása: titkosított esetben a megadott vagy a munkamenet jelmondattal
+pub fn open_backup(document: Value, passphrase: Option<&str>) -> Result<Value, String> {
+    if !is_encrypted_backup(&document) {
+        return Ok(document);
+    }
+
+    if let Some(passphrase) = passphrase {
+        return decrypt_backup(&document, passphrase);
+    }
+    if let Some(result) = open_with_vault_key(&document) {
+        return result;
+    }
+    match session_passphrase() {
+        Some(passphrase) => decrypt_backup(&document, &passphrase),
+        None => Err(PASSPHRASE_REQUIRED_ERROR.to_string()),
+    }
+}
+
+/// Backup jelmondat beállítása az aktuális munkamenetre (None = törlés)
+/// A jelmondat nem kerül lemezre; minden indítás után újra meg kell adni
+#[tauri::command]
+pub fn set_backup_passphrase(passphrase: Option<String>) -> Result<(), String> {
+    if let Some(passphrase) = &passphrase {
+        crypto::validate_passphrase(passphrase)?;
+    }
+
+    let mut guard = BACKUP_PASSPHRASE
+        .lock()
+        .map_err(|e| format!("Backup jelmondat zárolási hiba: {}", e))?;
+    let is_set = passphrase.is_some();
+    *guard = passphrase;
+
+    logger::log_info(if is_set {
+        "Backup jelmondat beállítva"
+    } else {
+        "Backup jelmondat törölve"
+    });
+    Ok(())
+}
+
+/// Backup titkosítás állapota
+#[tauri::command]
+pub fn get_backup_encryption_status() -> Result<serde_json::Value, String> {
+    Ok(serde_json::json!({
+        "enabled": encryption_required(),
+        "vaultKey": crate::vault::is_enabled(),
+        "passphraseSet": session_passphrase().is_some(),
+    }))
+}
+
+/// Backup tartalom előkészítése (manuális backup mentéséhez a frontend-ből)
+/// Manifestet ad hozzá, és titkosít, ha van munkamenet jelmondat (bekapcsolt titkosítás nélküle hibát ad)
+#[tauri::command]
+pub fn encrypt_backup_content(backup_data: Value) -> Result<Value, String> {
+    prepare_for_write(&backup_data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn key() -> (crypto::EncryptionKey, Vec<u8>) {
+        let salt = crypto::generate_salt().to_vec();
+        // Kevés iteráció, hogy a teszt gyors legyen (a fejlécbe ez kerül, a visszafejtés is ezt használja)
+        (crypto::derive_key("teszt jelmondat", &salt, 1_000), salt)
+    }
+
+    fn sample_backup() -> Value {
+        json!({
+            "version": "2.0.0",
+            "timestamp": "2026-01-02T03:04:05Z",
+            "data": { "offers": [{ "id": 1, "customerName": "Kovács Éva" }] }
+        })
+    }
+
+    fn encrypted_sample() -> (Value, crypto::EncryptionKey) {
+        let (key, salt) = key();
+        (encrypt_backup_with_key(&sample_backup(), &key, &salt, 1_000).unwrap(), key)
+    }
+
+    fn decrypt(document: &Value, key: &crypto::EncryptionKey) -> Result<Value, String> {
+        let encrypted: EncryptedBackup = serde_json::from_value(document.clone()).unwrap();
+        decrypt_backup_with_key(&encrypted, key)
+    }
+
+    #[test]
+    fn encrypted_backup_round_trips() {
+        let (document, key) = encrypted_sample();
+        assert!(is_encrypted_backup(&document));
+        assert_eq!(document["formatVersion"], json!(ENCRYPTED_BACKUP_FORMAT_VERSION));
+
+        let decrypted = decrypt(&document, &key).unwrap();
+        assert_eq!(decrypted["data"], sample_backup()["data"]);
+        assert_eq!(decrypted["timestamp"], sample_backup()["timestamp"]);
+    }
+
+    #[test]
+    fn passphrase_decryption_checks_the_iteration_count() {
+        // A gyors tesztkulcs iterációszáma az elfogadott tartomány alatt van: a levezetés el sem indul
+        let (document, _) = encrypted_sample();
+        let error = decrypt_backup(&document, "teszt jelmondat").unwrap_err();
+        assert!(error.contains("iterációszám"), "{}", error);
+        let mut huge = document.clone();
+        huge["kdf"]["iterations"] = json!(u32::MAX);
+        assert!(decrypt_backup(&huge, "teszt jelmondat").unwrap_err().contains("iterációszám"));
+
+        let salt = crypto::generate_salt();
+        let iterations = *KDF_ITERATIONS_RANGE.start();
+        let key = crypto::derive_key("teszt jelmondat", &salt, iterations);
+        let document = encrypt_backup_with_key(&sample_backup(), &key, &salt, iterations).unwrap();
+        assert_eq!(decrypt_backup(&document, "teszt jelmondat").unwrap()["data"], sample_backup()["data"]);
+        assert!(decrypt_backup(&document, "rossz jelmondat").is_err());
+    }
+
+    #[test]
+    fn header_tampering_is_detected() {
+        let (document, key) = encrypted_sample();
+        let tampered_fields = [
+            ("timestamp", json!("2020-01-01T00:00:00Z")),
+            ("appVersion", json!("0.0.1")),
+            ("version", json!("9.9.9")),
+            ("formatVersion", json!(1)),
+            ("formatVersion", json!(2)),
+        ];
+        for (field, value) in tampered_fields {
+            let mut tampered = document.clone();
+            tampered[field] = value;
+            assert!(decrypt(&tampered, &key).is_err(), "módosított mező: {}", field);
+        }
+
+        let mut tampered = document.clone();
+        tampered["kdf"]["iterations"] = json!(999);
+        assert!(decrypt(&tampered, &key).is_err());
+    }
+
+    #[test]
+    fn version_1_backups_without_aad_still_open() {
+        let (key, salt) = key();
+        let plaintext = serde_json::to_vec(&sample_backup()).unwrap();
+        let payload = crypto::encrypt(&key, &plaintext).unwrap();
+        let document = json!({
+            "format": ENCRYPTED_BACKUP_FORMAT,
+            "formatVersion": 1,
+            "encrypted": true,
+            "timestamp": "2026-01-02T03:04:05Z",
+            "version": "2.0.0",
+            "appVersion": "1.9.0",
+            "kdf": { "algorithm": crypto::KDF_ALGORITHM, "iterations": 1_000, "salt": crypto::to_base64(&salt) },
+            "cipher": crypto::CIPHER_ALGORITHM,
+            "payload": crypto::to_base64(&payload),
+        });
+
+        assert_eq!(decrypt(&document, &key).unwrap()["data"], sample_backup()["data"]);
+    }
+
+    #[test]
+    fn version_2_json_payloads_still_open() {
+        let (key, salt) = key();
+        let mut encrypted = EncryptedBackup {
+            format: ENCRYPTED_BACKUP_FORMAT.to_string(),
+            format_version: 2,
+            encrypted: true,
+            timestamp: "2026-01-02T03:04:05Z".to_string(),
+            version: Some("2.0.0".to_string()),
+            app_version: "2.0.0".to_string(),
+            kdf: KdfParams {
+                algorithm: crypto::KDF_ALGORITHM.to_string(),
+                iterations: 1_000,
+                salt: crypto::to_base64(&salt),
+            },
+            cipher: crypto::CIPHER_ALGORITHM.to_string(),
+            payload: String::new(),
+            manifest: None,
+        };
+        let plaintext = serde_json::to_vec(&sample_backup()).unwrap();
+        let payload = crypto::encrypt_with_aad(&key, &plaintext, &encrypted.associated_data().unwrap()).unwrap();
+        encrypted.payload = crypto::to_base64(&payload);
+
+        assert_eq!(decrypt_backup_with_key(&encrypted, &key).unwrap()["data"], sample_backup()["data"]);
+    }
+
+    #[test]
+    fn payload_is_compressed_before_encryption() {
+        let repetitive = json!({
+            "version": "2.0.0",
+            "timestamp": "2026-01-02T03:04:05Z",
+            "data": { "offers": vec![json!({ "customerName": "Kovács Éva", "notes": "ugyanaz" }); 500] }
+        });
+        let (key, salt) = key();
+        let document = encrypt_backup_with_key(&repetitive, &key, &salt, 1_000).unwrap();
+        let payload = crypto::from_base64(document["payload"].as_str().unwrap()).unwrap();
+
+        assert!(payload.len() < serde_json::to_vec(&repetitive).unwrap().len() / 4, "a tartalom nincs tömörítve");
+        assert_eq!(decrypt(&document, &key).unwrap()["data"], repetitive["data"]);
+    }
+}
@@ -0,0 +1,451 @@
+use crate::crypto;
+use crate::migrations;
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::SystemTime;
+use tauri::AppHandle;
+
+lazy_static! {
+    /// Backup fájlonként az utolsó teljes ellenőrzés eredménye; csak változatlan módosítási idő és méret mellett érvényes
+    static ref INTEGRITY_CACHE: Mutex<HashMap<PathBuf, CachedIntegrity>> = Mutex::new(HashMap::new());
+}
+
+/// A manifest kulcsa a backup fájlban (titkosított backupnál a fejlécben)
+pub const MANIFEST_KEY: &str = "manifest";
+
+/// A manifest formátum verziója
+pub const MANIFEST_VERSION: u32 = 1;
+
+pub const CHECKSUM_ALGORITHM: &str = "SHA-256";
+
+/// Backup manifest: ellenőrzőösszeg, entitás darabszámok és verzió információk
+/// Titkosítatlan backupnál az ellenőrzőösszeg a manifest nélküli adat kanonikus JSON-jára vonatkozik,
+/// titkosított backupnál a (base64-ből dekódolt) titkosított payload-ra, így jelmondat nélkül is ellenőrizhető
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupManifest {
+    pub manifest_version: u32,
+    pub app_version: String,
+    pub schema_version: u32,
+    pub created_at: String,
+    pub entity_counts: BTreeMap<String, usize>,
+    pub checksum_algorithm: String,
+    pub checksum: String,
+    #[serde(default)]
+    pub encrypted: bool,
+}
+
+/// Backup integritás állapota
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IntegrityStatus {
+    /// Az ellenőrzőösszeg és a darabszámok egyeznek
+    Ok,
+    /// Csonka / sérült fájl vagy eltérő ellenőrzőösszeg
+    Corrupted,
+    /// Régi backup manifest nélkül (nem ellenőrizhető)
+    Unverified,
+}
+
+impl IntegrityStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IntegrityStatus::Ok => "ok",
+            IntegrityStatus::Corrupted => "corrupted",
+            IntegrityStatus::Unverified => "unverified",
+        }
+    }
+}
+
+#[derive(Clone)]
+struct CachedIntegrity {
+    modified: Option<SystemTime>,
+    size: u64,
+    timestamp: String,
+    status: IntegrityStatus,
+}
+
+/// Egy backup fájl ellenőrzésének eredménye
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupVerification {
+    pub file_name: String,
+    pub file_path: String,
+    pub size: u64,
+    pub status: IntegrityStatus,
+    pub message: Option<String>,
+    pub manifest: Option<BackupManifest>,
+}
+
+/// Összesítő jelentés az összes automatikus backup ellenőrzéséről
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupVerificationReport {
+    pub checked: usize,
+    pub ok: usize,
+    pub corrupted: usize,
+    pub unverified: usize,
+    pub results: Vec<BackupVerification>,
+}
+
+/// Kulcsok szerint rendezett (kanonikus) JSON, hogy az ellenőrzőösszeg ne függjön a kulcs sorrendtől
+pub fn canonical_bytes(value: &Value) -> Vec<u8> {
+    fn sorted(value: &Value) -> Value {
+        match value {
+            Value::Object(map) => {
+                let mut keys: Vec<&String> = map.keys().collect();
+                keys.sort();
+                let mut result = Map::new();
+                for key in keys {
+                    result.insert(key.clone(), sorted(&map[key]));
+                }
+                Value::Object(result)
+            }
+            Value::Array(items) => Value::Array(items.iter().map(sorted).collect()),
+            other => other.clone(),
+        }
+    }
+
+    serde_json::to_vec(&sorted(value)).unwrap_or_default()
+}
+
+/// Entitás darabszámok (a backup legfelső szintű tömbjei: printers, filaments, offers, ...)
+pub fn entity_counts(data: &Value) -> BTreeMap<String, usize> {
+    data.as_object()
+        .map(|map| {
+            map.iter()
+                .filter_map(|(key, value)| value.as_array().map(|items| (key.clone(), items.len())))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn without_manifest(document: &Value) -> Value {
+    let mut data = document.clone();
+    if let Some(map) = data.as_object_mut() {
+        map.remove(MANIFEST_KEY);
+    }
+    data
+}
+
+fn new_manifest(entity_counts: BTreeMap<String, usize>, checksum: String, encrypted: bool) -> BackupManifest {
+    BackupManifest {
+        manifest_version: MANIFEST_VERSION,
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        schema_version: migrations::CURRENT_SCHEMA_VERSION,
+        created_at: chrono::Utc::now().to_rfc3339(),
+        entity_counts,
+        checksum_algorithm: CHECKSUM_ALGORITHM.to_string(),
+        checksum,
+        encrypted,
+    }
+}
+
+/// Manifest hozzáadása egy titkosítatlan backuphoz (egy esetleges régi manifest felülíródik)
+pub fn attach_manifest(backup_data: &Value) -> Result<Value, String> {
+    let data = without_manifest(backup_data);
+    let manifest = new_manifest(
+        entity_counts(&data),
+        crypto::sha256_hex(&canonical_bytes(&data)),
+        false,
+    );
+
+    let mut document = data;
+    let map = document
+        .as_object_mut()
+        .ok_or_else(|| "A backup data nem JSON objektum".to_string())?;
+    map.insert(
+        MANIFEST_KEY.to_string(),
+        serde_json::to_value(manifest).map_err(|e| format!("Manifest szerializálási hiba: {}", e))?,
+    );
+    Ok(document)
+}
+
+/// Manifest egy titkosított backup fejlécéhez (a darabszámok a titkosítatlan adatból jönnek)
+pub fn encrypted_manifest(backup_data: &Value, payload: &[u8]) -> BackupManifest {
+    new_manifest(
+        entity_counts(&without_manifest(backup_data)),
+        crypto::sha256_hex(payload),
+        true,
+    )
+}
+
+/// Egy beolvasott backup dokumentum integritásának ellenőrzése
+pub fn check_document(document: &Value) -> (IntegrityStatus, Option<String>, Option<BackupManifest>) {
+    let Some(manifest_value) = document.get(MANIFEST_KEY) else {
+        return (
+            IntegrityStatus::Unverified,
+            Some("Régi formátumú backup, nincs manifest".to_string()),
+            None,
+        );
+    };
+
+    let manifest: BackupManifest = match serde_json::from_value(manifest_value.clone()) {
+        Ok(manifest) => manifest,
+        Err(e) => {
+            return (
+                IntegrityStatus::Corrupted,
+                Some(format!("Érvénytelen manifest: {}", e)),
+                None,
+            )
+        }
+    };
+
+    if manifest.checksum_algorithm != CHECKSUM_ALGORITHM {
+        return (
+            IntegrityStatus::Unverified,
+            Some(format!("Nem támogatott ellenőrzőösszeg: {}", manifest.checksum_algorithm)),
+            Some(manifest),
+        );
+    }
+
+    if manifest.encrypted {
+        let payload = document
+            .get("payload")
+            .and_then(|p| p.as_str())
+            .ok_or_else(|| "Hiányzó titkosított payload".to_string())
+            .and_then(crypto::from_base64);
+        return match payload {
+            Ok(bytes) if crypto::sha256_hex(&bytes) == manifest.checksum => (IntegrityStatus::Ok, None, Some(manifest)),
+            Ok(_) => (
+                IntegrityStatus::Corrupted,
+                Some("Az ellenőrzőösszeg nem egyezik".to_string()),
+                Some(manifest),
+            ),
+            Err(e) => (IntegrityStatus::Corrupted, Some(e), Some(manifest)),
+        };
+    }
+
+    let data = without_manifest(document);
+    if crypto::sha256_hex(&canonical_bytes(&data)) != manifest.checksum {
+        return (
+            IntegrityStatus::Corrupted,
+            Some("Az ellenőrzőösszeg nem egyezik".to_string()),
+            Some(manifest),
+        );
+    }
+
+    let actual_counts = entity_counts(&data);
+    if actual_counts != manifest.entity_counts {
+        return (
+            IntegrityStatus::Corrupted,
+            Some(format!(
+                "Eltérő entitás darabszámok (manifest: {:?}, fájl: {:?})",
+                manifest.entity_counts, actual_counts
+            )),
+            Some(manifest),
+        );
+    }
+
+    (IntegrityStatus::Ok, None, Some(manifest))
+}
+
+fn file_stamp(path: &Path) -> (Option<SystemTime>, u64) {
+    let metadata = std::fs::metadata(path).ok();
+    (
+        metadata.as_ref().and_then(|m| m.modified().ok()),
+        metadata.map(|m| m.len()).unwrap_or(0),
+    )
+}
+
+/// Teljes ellenőrzés (beolvasás, kicsomagolás, ellenőrzőösszeg), az eredmény a gyorsítótárba kerül
+fn verify_file(path: &Path) -> BackupVerification {
+    let file_name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let (modified, size) = file_stamp(path);
+
+    let mut timestamp = String::new();
+    let (status, message, manifest) = match crate::backup_archive::read_backup_document(path) {
+        Ok(document) => {
+            timestamp = document.get("timestamp").and_then(|v| v.as_str()).unwrap_or_default().to_string();
+            check_document(&document)
+        }
+        Err(e) => (IntegrityStatus::Corrupted, Some(e), None),
+    };
+
+    if let Ok(mut cache) = INTEGRITY_CACHE.lock() {
+        cache.insert(
+            path.to_path_buf(),
+            CachedIntegrity {
+                modified,
+                size,
+                timestamp,
+                status,
+            },
+        );
+    }
+
+    BackupVerification {
+        file_name,
+        file_path: path.to_string_lossy().to_string(),
+        size,
+        status,
+        message,
+        manifest,
+    }
+}
+
+/// Backup fájl timestamp-je és integritás állapota listázáshoz
+/// A teljes ellenőrzés fájlonként egyszer fut le; amíg a fájl módosítási ideje és mérete nem változik,
+/// a tárolt eredményt adjuk vissza (a listázás így nem olvassa és ellenőrzi újra az összes backupot)
+pub fn cached_integrity(path: &Path) -> (String, IntegrityStatus) {
+    let (modified, size) = file_stamp(path);
+    let cached = INTEGRITY_CACHE
+        .lock()
+        .ok()
+        .and_then(|cache| cache.get(path).cloned())
+        .filter(|cached| cached.modified == modified && cached.size == size);
+    if let Some(cached) = cached {
+        return (cached.timestamp, cached.status);
+    }
+
+    verify_file(path);
+    INTEGRITY_CACHE
+        .lock()
+        .ok()
+        .and_then(|cache| cache.get(path).map(|cached| (cached.timestamp.clone(), cached.status)))
+        .unwrap_or((String::new(), IntegrityStatus::Corrupted))
+}
+
+/// Egy backup fájl integritásának ellenőrzése
+#[tauri::command]
+pub fn verify_backup(app: AppHandle, file_path: String) -> Result<BackupVerification, String> {
+    let path = crate::paths::resolve_backup_file(&app, &file_path)?;
+    Ok(verify_file(&path))
+}
+
+/// Az összes automatikus backup ellenőrzése, összesítő jelentéssel
+#[tauri::command]
+pub fn verify_all_backups() -> Result<BackupVerificationReport, String> {
+    let backup_files = crate::commands::list_backup_files()?;
+
+    let results: Vec<BackupVerification> = backup_files
+        .iter()
+        .map(|(_, path, _, _, _)| verify_file(Path::new(path)))
+        .collect();
+    let count = |status: IntegrityStatus| results.iter().filter(|r| r.status == status).count();
+
+    let report = BackupVerificationReport {
+        checked: results.len(),
+        ok: count(IntegrityStatus::Ok),
+        corrupted: count(IntegrityStatus::Corrupted),
+        unverified: count(IntegrityStatus::Unverified),
+        results,
+    };
+
+    crate::logger::log_info(&format!(
+        "Backup ellenőrzés: {} fájl, {} rendben, {} sérült, {} nem ellenőrizhető",
+        report.checked, report.ok, report.corrupted, report.unverified
+    ));
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn sample_backup() -> Value {
+        json!({
+            "version": "2.0.0",
+            "timestamp": "2026-01-02T03:04:05Z",
+            "offers": [{ "id": 1, "customerName": "Kovács Éva", "notes": "ugyanaz ".repeat(200) }],
+            "filaments": [{ "brand": "Prusa", "type": "PLA", "color": "fekete" }]
+        })
+    }
+
+    fn test_dir(name: &str) -> PathBuf {
+        let dir = crate::paths::test_support::data_root().join("backup-manifest").join(name);
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn write_sample_archive(path: &Path) {
+        let document = attach_manifest(&sample_backup()).unwrap();
+        crate::backup_archive::write_archive(path, &document).unwrap();
+    }
+
+    #[test]
+    fn attached_manifest_verifies() {
+        let document = attach_manifest(&sample_backup()).unwrap();
+        let (status, message, manifest) = check_document(&document);
+        assert_eq!(status, IntegrityStatus::Ok, "{:?}", message);
+        assert_eq!(manifest.unwrap().entity_counts.get("offers"), Some(&1));
+
+        let mut tampered = document.clone();
+        tampered["offers"][0]["customerName"] = json!("Nagy Péter");
+        assert_eq!(check_document(&tampered).0, IntegrityStatus::Corrupted);
+
+        assert_eq!(check_document(&sample_backup()).0, IntegrityStatus::Unverified);
+
+        let dir = test_dir("ok");
+        let path = dir.join("backup.zip");
+        write_sample_archive(&path);
+        assert_eq!(verify_file(&path).status, IntegrityStatus::Ok);
+    }
+
+    #[test]
+    fn truncated_or_bit_flipped_archive_is_corrupted() {
+        let dir = test_dir("corrupted");
+        let path = dir.join("backup.zip");
+        write_sample_archive(&path);
+        let bytes = std::fs::read(&path).unwrap();
+
+        let truncated = dir.join("truncated.zip");
+        std::fs::write(&truncated, &bytes[..bytes.len() / 2]).unwrap();
+        assert_eq!(verify_file(&truncated).status, IntegrityStatus::Corrupted);
+
+        // Bitcsere a tömörített adatban (a fájl elején lévő bejegyzésekben)
+        let flipped = dir.join("flipped.zip");
+        let mut flipped_bytes = bytes.clone();
+        let middle = bytes.len() / 3;
+        flipped_bytes[middle] ^= 0x01;
+        std::fs::write(&flipped, &flipped_bytes).unwrap();
+        assert_eq!(verify_file(&flipped).status, IntegrityStatus::Corrupted);
+    }
+
+    #[test]
+    fn tampered_encrypted_payload_is_corrupted() {
+        let payload = vec![7u8; 64];
+        let manifest = encrypted_manifest(&sample_backup(), &payload);
+        let mut document = json!({
+            "format": "encrypted-backup",
+            "encrypted": true,
+            "payload": crypto::to_base64(&payload),
+            "manifest": serde_json::to_value(&manifest).unwrap(),
+        });
+        assert_eq!(check_document(&document).0, IntegrityStatus::Ok);
+
+        let mut tampered = payload.clone();
+        tampered[10] ^= 0x80;
+        document["payload"] = json!(crypto::to_base64(&tampered));
+        assert_eq!(check_document(&document).0, IntegrityStatus::Corrupted);
+
+        document["payload"] = json!("nem base64!");
+        assert_eq!(check_document(&document).0, IntegrityStatus::Corrupted);
+    }
+
+    #[test]
+    fn rewritten_file_invalidates_the_cached_result() {
+        let dir = test_dir("cache");
+        let path = dir.join("backup.zip");
+        write_sample_archive(&path);
+        let (timestamp, status) = cached_integrity(&path);
+        assert_eq!(status, IntegrityStatus::Ok);
+        assert_eq!(timestamp, "2026-01-02T03:04:05Z");
+
+        // Más méretű tartalom: a tárolt eredmény nem használható
+        std::fs::write(&path, b"nem zip").unwrap();
+        assert_eq!(cached_integrity(&path).1, IntegrityStatus::Corrupted);
+
+        write_sample_archive(&path);
+        assert_eq!(cached_integrity(&path).1, IntegrityStatus::Ok);
+    }
+}
@@ -0,0 +1,390 @@
+use crate::logger;
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashSet};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+lazy_static! {
+    /// A rögzített backupok listájának és a takarításnak a zárja
+    static ref RETENTION_LOCK: Mutex<()> = Mutex::new(());
+}
+
+/// Grandfather-father-son megőrzési szabály: hány órás / napi / heti / havi backup maradjon meg
+/// Minden időszakból (óra, nap, ISO hét, hónap) a legújabb backup marad meg, a legutóbbi N időszakra
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RetentionPolicy {
+    pub hourly: u32,
+    pub daily: u32,
+    pub weekly: u32,
+    pub monthly: u32,
+}
+
+impl Default for RetentionPolicy {
+    fn default() -> Self {
+        RetentionPolicy {
+            hourly: 24,
+            daily: 7,
+            weekly: 4,
+            monthly: 12,
+        }
+    }
+}
+
+/// Egy backup besorolása a megőrzési szabály szerint
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RetentionEntry {
+    pub file_name: String,
+    pub file_path: String,
+    pub timestamp: Option<String>,
+    pub size: u64,
+    pub pinned: bool,
+    /// Miért marad meg: latest, pinned, undated, hourly, daily, weekly, monthly (üres = törlődik)
+    pub reasons: Vec<String>,
+}
+
+/// A következő takarítás előnézete
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RetentionPlan {
+    pub policy: RetentionPolicy,
+    pub keep: Vec<RetentionEntry>,
+    pub delete: Vec<RetentionEntry>,
+    pub freed_bytes: u64,
+}
+
+/// A takarítás eredménye
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RetentionResult {
+    pub kept: usize,
+    pub deleted: Vec<String>,
+    pub failed: Vec<String>,
+}
+
+/// A rögzített ("soha ne töröld") backupok fájlja
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PinnedBackups {
+    #[serde(default)]
+    pinned: BTreeSet<String>,
+}
+
+fn pins_path() -> Result<PathBuf, String> {
+    Ok(crate::paths::backups_dir()?.join("pinned_backups.json"))
+}
+
+fn read_pins() -> Result<PinnedBackups, String> {
+    let path = pins_path()?;
+    if !path.exists() {
+        return Ok(PinnedBackups::default());
+    }
+    let content = fs::read_to_string(&path)
+        .map_err(|e| format!("Nem sikerült beolvasni a rögzített backupok listáját: {}", e))?;
+    serde_json::from_str(&content).map_err(|e| format!("Érvénytelen rögzített backup lista: {}", e))
+}
+
+fn write_pins(pins: &PinnedBackups) -> Result<(), String> {
+    let content = serde_json::to_string_pretty(pins)
+        .map_err(|e| format!("Rögzített backup lista szerializálási hiba: {}", e))?;
+    crate::atomic_write::write_atomic(&pins_path()?, content)
+        .map_err(|e| format!("Nem sikerült menteni a rögzített backupok listáját: {}", e))
+}
+
+/// Rögzített backup fájlnevek (a régi, darabszám / kor alapú takarítás is kihagyja őket)
+pub fn pinned_backups() -> HashSet<String> {
+    match read_pins() {
+        Ok(pins) => pins.pinned.into_iter().collect(),
+        Err(e) => {
+            logger::log_warn(&format!("Rögzített backupok betöltése sikertelen: {}", e));
+            HashSet::new()
+        }
+    }
+}
+
+/// Megőrzési okok kiosztása; a bejegyzéseket legújabb elöl rendezi (a dátum nélküliek a végére kerülnek)
+/// Az időszakok a megadott időzóna szerint számítanak (éles használatban helyi idő)
+fn assign_reasons<Tz: chrono::TimeZone>(entries: &mut [(RetentionEntry, Option<chrono::DateTime<Tz>>)], policy: RetentionPolicy)
+where
+    Tz::Offset: std::fmt::Display,
+{
+    entries.sort_by(|a, b| b.1.cmp(&a.1));
+
+    for (entry, local) in entries.iter_mut() {
+        if entry.pinned {
+            entry.reasons.push("pinned".to_string());
+        }
+        if local.is_none() {
+            entry.reasons.push("undated".to_string());
+        }
+    }
+    if let Some((latest, _)) = entries.first_mut() {
+        latest.reasons.push("latest".to_string());
+    }
+
+    let tiers = [
+        ("hourly", policy.hourly, "%Y-%m-%d %H"),
+        ("daily", policy.daily, "%Y-%m-%d"),
+        ("weekly", policy.weekly, "%G-W%V"),
+        ("monthly", policy.monthly, "%Y-%m"),
+    ];
+    for (tier, count, bucket_format) in tiers {
+        let mut buckets = HashSet::new();
+        for (entry, local) in entries.iter_mut() {
+            if buckets.len() >= count as usize {
+                break;
+            }
+            let Some(local) = local else {
+                continue;
+            };
+            // Időszakonként az első (legújabb) backup marad meg
+            if buckets.insert(local.format(bucket_format).to_string()) {
+                entry.reasons.push(tier.to_string());
+            }
+        }
+    }
+}
+
+/// A megőrzési szabály kiértékelése az automatikus backupokra
+/// Rögzített, dátum nélküli és a legújabb backup mindig megmarad
+pub fn plan_retention(policy: RetentionPolicy) -> Result<RetentionPlan, String> {
+    let pinned = pinned_backups();
+    let backups = crate::commands::list_backup_files()?;
+
+    let mut entries: Vec<(RetentionEntry, Option<chrono::DateTime<chrono::Local>>)> = backups
+        .into_iter()
+        .map(|(file_name, file_path, timestamp, size, _)| {
+            let local = timestamp
+                .parse::<chrono::DateTime<chrono::Utc>>()
+                .ok()
+                .map(|ts| ts.with_timezone(&chrono::Local));
+            let entry = RetentionEntry {
+                pinned: pinned.contains(&file_name),
+                file_name,
+                file_path,
+                timestamp: (!timestamp.is_empty()).then_some(timestamp),
+                size,
+                reasons: Vec::new(),
+            };
+            (entry, local)
+        })
+        .collect();
+    assign_reasons(&mut entries, policy);
+
+    let (keep, delete): (Vec<RetentionEntry>, Vec<RetentionEntry>) = entries
+        .into_iter()
+        .map(|(entry, _)| entry)
+        .partition(|entry| !entry.reasons.is_empty());
+    let freed_bytes = delete.iter().map(|entry| entry.size).sum();
+
+    Ok(RetentionPlan {
+        policy,
+        keep,
+        delete,
+        freed_bytes,
+    })
+}
+
+/// A következő takarítás előnézete: mely backupok maradnak meg (és miért), és melyek törlődnének
+#[tauri::command]
+pub fn preview_backup_retention(policy: Option<RetentionPolicy>) -> Result<RetentionPlan, String> {
+    plan_retention(policy.unwrap_or_default())
+}
+
+/// Takarítás a GFS megőrzési szabály szerint
+#[tauri::command]
+pub fn apply_backup_retention(policy: Option<RetentionPolicy>) -> Result<RetentionResult, String> {
+    let _guard = RETENTION_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let plan = plan_retention(policy.unwrap_or_default())?;
+
+    let mut result = RetentionResult {
+        kept: plan.keep.len(),
+        deleted: Vec::new(),
+        failed: Vec::new(),
+    };
+    for entry in plan.delete {
+        match fs::remove_file(&entry.file_path) {
+            Ok(_) => {
+                logger::log_info(&format!("Backup törölve (GFS megőrzés): {}", entry.file_name));
+                result.deleted.push(entry.file_name);
+            }
+            Err(e) => {
+                logger::log_warn(&format!("Nem sikerült törölni a backup fájlt: {} - {}", entry.file_path, e));
+                result.failed.push(entry.file_name);
+            }
+        }
+    }
+
+    logger::log_info(&format!(
+        "GFS backup takarítás: {} megmaradt, {} törölve, {} sikertelen",
+        result.kept,
+        result.deleted.len(),
+        result.failed.len()
+    ));
+    Ok(result)
+}
+
+/// Backup rögzítése ("soha ne töröld") vagy a rögzítés feloldása
+#[tauri::command]
+pub fn set_backup_pinned(file_name: String, pinned: bool) -> Result<(), String> {
+    if !crate::backup_archive::is_automatic_backup_file_name(&file_name) {
+        return Err(format!("Nem automatikus backup fájl: {}", file_name));
+    }
+
+    let _guard = RETENTION_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let mut pins = read_pins()?;
+    let changed = if pinned {
+        pins.pinned.insert(file_name.clone())
+    } else {
+        pins.pinned.remove(&file_name)
+    };
+    if changed {
+        write_pins(&pins)?;
+        logger::log_info(&format!(
+            "Backup {}: {}",
+            if pinned { "rögzítve" } else { "rögzítés feloldva" },
+            file_name
+        ));
+    }
+    Ok(())
+}
+
+/// A rögzített backupok fájlnevei
+#[tauri::command]
+pub fn get_pinned_backups() -> Result<Vec<String>, String> {
+    Ok(read_pins()?.pinned.into_iter().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, Utc};
+    use serde_json::json;
+
+    const NONE: RetentionPolicy = RetentionPolicy {
+        hourly: 0,
+        daily: 0,
+        weekly: 0,
+        monthly: 0,
+    };
+
+    fn entry(name: &str, timestamp: &str) -> (RetentionEntry, Option<DateTime<Utc>>) {
+        (
+            RetentionEntry {
+                file_name: name.to_string(),
+                file_path: name.to_string(),
+                timestamp: Some(timestamp.to_string()),
+                size: 0,
+                pinned: false,
+                reasons: Vec::new(),
+            },
+            Some(timestamp.parse().unwrap()),
+        )
+    }
+
+    /// A megtartott bejegyzések neve és okai, legújabb elöl
+    fn kept(mut entries: Vec<(RetentionEntry, Option<DateTime<Utc>>)>, policy: RetentionPolicy) -> Vec<(String, Vec<String>)> {
+        assign_reasons(&mut entries, policy);
+        entries
+            .into_iter()
+            .filter(|(entry, _)| !entry.reasons.is_empty())
+            .map(|(entry, _)| (entry.file_name, entry.reasons))
+            .collect()
+    }
+
+    fn names(kept: &[(String, Vec<String>)]) -> Vec<&str> {
+        kept.iter().map(|(name, _)| name.as_str()).collect()
+    }
+
+    #[test]
+    fn weekly_buckets_follow_iso_weeks_across_year_boundary() {
+        // 2020-12-31 (csütörtök) és 2021-01-03 (vasárnap) is a 2020-W53 hétbe esik, 2021-01-04 már 2021-W01
+        let entries = vec![
+            entry("a", "2020-12-28T10:00:00Z"),
+            entry("b", "2020-12-31T10:00:00Z"),
+            entry("c", "2021-01-03T10:00:00Z"),
+            entry("d", "2021-01-04T10:00:00Z"),
+        ];
+        let result = kept(entries, RetentionPolicy { weekly: 3, ..NONE });
+        assert_eq!(names(&result), vec!["d", "c"], "ISO hetenként csak a legújabb maradhat meg");
+        assert!(result[1].1.contains(&"weekly".to_string()));
+
+        // 2024-12-30 (hétfő) már a 2025-W01 hétbe esik, a naptári év szerint mégis 2024
+        let entries = vec![
+            entry("old", "2024-12-23T10:00:00Z"),
+            entry("mon", "2024-12-30T10:00:00Z"),
+            entry("new", "2025-01-02T10:00:00Z"),
+        ];
+        let result = kept(entries, RetentionPolicy { weekly: 2, ..NONE });
+        assert_eq!(names(&result), vec!["new", "old"], "a %G-W%V az ISO évet használja, nem a naptári évet");
+    }
+
+    #[test]
+    fn hourly_daily_and_monthly_buckets() {
+        let entries = vec![
+            entry("h1", "2026-03-01T10:05:00Z"),
+            entry("h2", "2026-03-01T10:45:00Z"),
+            entry("h3", "2026-03-01T11:10:00Z"),
+            entry("d1", "2026-02-28T23:59:00Z"),
+            entry("m1", "2026-01-31T12:00:00Z"),
+            entry("m2", "2026-01-01T00:00:00Z"),
+            entry("y1", "2025-12-31T23:00:00Z"),
+        ];
+
+        let hourly = kept(entries.clone(), RetentionPolicy { hourly: 2, ..NONE });
+        assert_eq!(names(&hourly), vec!["h3", "h2"]);
+
+        let daily = kept(entries.clone(), RetentionPolicy { daily: 3, ..NONE });
+        assert_eq!(names(&daily), vec!["h3", "d1", "m1"]);
+
+        let monthly = kept(entries, RetentionPolicy { monthly: 4, ..NONE });
+        assert_eq!(names(&monthly), vec!["h3", "d1", "m1", "y1"], "havonta (és évváltáskor is) a legújabb marad meg");
+    }
+
+    #[test]
+    fn latest_pinned_and_undated_entries_are_always_kept() {
+        let mut entries = vec![
+            entry("latest", "2026-03-01T10:00:00Z"),
+            entry("pinned", "2020-01-01T10:00:00Z"),
+            entry("removed", "2021-01-01T10:00:00Z"),
+        ];
+        entries[1].0.pinned = true;
+        let mut undated = entry("undated", "2026-01-01T00:00:00Z");
+        undated.1 = None;
+        entries.push(undated);
+
+        let result = kept(entries, NONE);
+        assert_eq!(names(&result), vec!["latest", "pinned", "undated"]);
+        assert_eq!(result[1].1, vec!["pinned".to_string()]);
+    }
+
+    #[test]
+    fn apply_never_removes_pinned_backups() {
+        crate::paths::test_support::data_root();
+        let _guard = crate::paths::test_support::lock();
+        let dir = crate::paths::automatic_backups_dir().unwrap();
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        let files = [
+            ("auto_backup_2026-03-01T10-00-00-000Z.json", "2026-03-01T10:00:00.000Z"),
+            ("auto_backup_2026-02-01T10-00-00-000Z.json", "2026-02-01T10:00:00.000Z"),
+            ("auto_backup_2025-01-01T10-00-00-000Z.json", "2025-01-01T10:00:00.000Z"),
+        ];
+        for (name, timestamp) in files {
+            fs::write(dir.join(name), json!({ "version": "1.0", "timestamp": timestamp }).to_string()).unwrap();
+        }
+        set_backup_pinned(files[2].0.to_string(), true).unwrap();
+
+        let result = apply_backup_retention(Some(NONE)).unwrap();
+        assert_eq!(result.deleted, vec![files[1].0.to_string()]);
+        assert!(dir.join(files[0].0).exists(), "a legújabb backup nem törölhető");
+        assert!(dir.join(files[2].0).exists(), "a rögzített backup nem törölhető");
+
+        set_backup_pinned(files[2].0.to_string(), false).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,360 @@
+use crate::backup_retention::{self, RetentionPolicy};
+use crate::backup_snapshots;
+use crate::commands;
+use crate::data_store;
+use crate::logger;
+use lazy_static::lazy_static;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::fs;
+use std::sync::{Mutex, MutexGuard};
+use std::thread;
+use std::time::{Duration, SystemTime};
+use tauri::{AppHandle, Emitter};
+
+/// Backup folyamat események a frontend felé
+pub const BACKUP_PROGRESS_EVENT: &str = "backup-progress";
+pub const BACKUP_COMPLETED_EVENT: &str = "backup-completed";
+pub const BACKUP_FAILED_EVENT: &str = "backup-failed";
+
+/// Alapértelmezett backup intervallum, ha a beállításokban nincs megadva
+const DEFAULT_INTERVAL_HOURS: u32 = 24;
+
+/// Alapértelmezett maximum backup szám (ha nincs GFS megőrzés beállítva)
+const DEFAULT_MAX_BACKUPS: u32 = 10;
+
+/// A snapshotok megőrzése napokban (ugyanaz, mint a frontend inkrementális módjában)
+const SNAPSHOT_RETENTION_DAYS: u32 = 5;
+
+/// Milyen gyakran ellenőrzi az ütemező a beállításokat és az esedékességet
+const TICK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Késleltetés indításkor, hogy a frontend betöltése ne versenyezzen a backuppal
+const STARTUP_DELAY: Duration = Duration::from_secs(10);
+
+/// Sikertelen backup után ennyi perc múlva próbálkozunk újra (nem az intervallum végén)
+const RETRY_AFTER_FAILURE_MINUTES: i64 = 30;
+
+lazy_static! {
+    /// Egy ütemezési kör (beállítások olvasása + backup) alatt tartott zár - a munkaterület váltás erre vár
+    static ref RUN_LOCK: Mutex<()> = Mutex::new(());
+    static ref SCHEDULER_STATE: Mutex<SchedulerStatus> = Mutex::new(SchedulerStatus::default());
+    static ref SCHEDULER_TRIGGER: Mutex<Option<Sender<()>>> = Mutex::new(None);
+}
+
+/// Az ütemező állapota a frontend számára
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SchedulerStatus {
+    pub running: bool,
+    pub enabled: bool,
+    pub interval_hours: u32,
+    pub last_backup_at: Option<String>,
+    pub next_backup_at: Option<String>,
+    pub last_attempt_at: Option<String>,
+    pub last_error: Option<String>,
+    pub in_progress: bool,
+}
+
+/// Backup folyamat lépése (backup-progress esemény)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BackupProgress<'a> {
+    trigger: &'a str,
+    stage: &'a str,
+    percent: u8,
+}
+
+/// Elkészült backup (backup-completed esemény)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupCompleted {
+    pub trigger: String,
+    pub file_path: String,
+    pub timestamp: String,
+    pub incremental: bool,
+    pub removed_by_cleanup: u32,
+}
+
+/// Az automatikus backup beállításai a Store-ból
+struct ScheduleSettings {
+    enabled: bool,
+    interval_hours: u32,
+    incremental: bool,
+    encrypted: bool,
+    retention_policy: Option<RetentionPolicy>,
+    max_backups: u32,
+}
+
+fn read_schedule_settings(store: &Map<String, Value>) -> ScheduleSettings {
+    let settings = store.get("settings");
+    let flag = |key: &str| settings.and_then(|s| s.get(key)).and_then(|v| v.as_bool()) == Some(true);
+    let number = |key: &str| {
+        settings
+            .and_then(|s| s.get(key))
+            .and_then(|v| v.as_u64())
+            .filter(|v| *v > 0)
+            .map(|v| v.min(u32::MAX as u64) as u32)
+    };
+
+    ScheduleSettings {
+        enabled: flag("automaticBackupEnabled"),
+        interval_hours: number("automaticBackupIntervalHours").unwrap_or(DEFAULT_INTERVAL_HOURS),
+        incremental: flag("incrementalBackupsEnabled"),
+        // Titkosított adattárolónál a backup is mindig titkosított, ilyenkor nem készül (titkosítatlan) snapshot
+        encrypted: flag("backupEncryptionEnabled") || crate::vault::is_enabled(),
+        retention_policy: settings
+            .and_then(|s| s.get("backupRetentionPolicy"))
+            .filter(|p| p.is_object())
+            .and_then(|p| serde_json::from_value(p.clone()).ok()),
+        max_backups: number("maxAutomaticBackups").unwrap_or(DEFAULT_MAX_BACKUPS),
+    }
+}
+
+fn parse_timestamp(timestamp: &str) -> Option<chrono::DateTime<chrono::Utc>> {
+    timestamp.parse::<chrono::DateTime<chrono::Utc>>().ok()
+}
+
+/// A legutóbbi automatikus backup fájl módosítási ideje
+fn latest_backup_file_modified() -> Option<SystemTime> {
+    crate::paths::automatic_backup_dirs()
+        .ok()?
+        .iter()
+        .filter_map(|dir| fs::read_dir(dir).ok())
+        .flat_map(|entries| entries.flatten())
+        .filter(|entry| {
+            entry
+                .file_name()
+                .to_str()
+                .is_some_and(crate::backup_archive::is_automatic_backup_file_name)
+        })
+        .filter_map(|entry| entry.metadata().ok()?.modified().ok())
+        .max()
+}
+
+/// A legutóbbi automatikus backup (fájl vagy snapshot) időpontja
+/// Percenként fut, ezért csak a fájlok módosítási idejét nézi (a backupokat nem olvassa be és nem ellenőrzi)
+fn last_backup_time() -> Option<chrono::DateTime<chrono::Utc>> {
+    latest_backup_file_modified()
+        .max(backup_snapshots::latest_snapshot_modified())
+        .map(Into::into)
+}
+
+fn emit_progress(app: &AppHandle, trigger: &str, stage: &str, percent: u8) {
+    if let Err(e) = app.emit(BACKUP_PROGRESS_EVENT, BackupProgress { trigger, stage, percent }) {
+        logger::log_warn(&format!("Nem sikerült elküldeni a backup állapot eseményt: {}", e));
+    }
+}
+
+/// Régi backupok takarítása a beállítások szerint (GFS megőrzés vagy maximum darabszám)
+fn run_cleanup(settings: &ScheduleSettings) -> Result<u32, String> {
+    if settings.incremental && !settings.encrypted {
+        let result = backup_snapshots::cleanup_old_backup_snapshots(SNAPSHOT_RETENTION_DAYS)?;
+        return Ok(result.removed_objects as u32);
+    }
+    match settings.retention_policy {
+        Some(policy) => Ok(backup_retention::apply_backup_retention(Some(policy))?.deleted.len() as u32),
+        None => commands::cleanup_old_backups_by_count(settings.max_backups),
+    }
+}
+
+/// Egy automatikus backup elkészítése a Store aktuális tartalmából, majd takarítás
+fn run_backup(app: &AppHandle, trigger: &str) -> Result<BackupCompleted, String> {
+    if crate::vault::is_locked() {
+        return Err("Az adattároló zárolva van, a backup a feloldás után készül el".to_string());
+    }
+
+    emit_progress(app, trigger, "collecting", 10);
+    let store = data_store::read_store()?.ok_or_else(|| "Még nincsenek mentett adatok".to_string())?;
+    let settings = read_schedule_settings(&store);
+
+    emit_progress(app, trigger, "writing", 40);
+    let (file_path, timestamp, incremental) = if settings.incremental && !settings.encrypted {
+        let snapshot = backup_snapshots::create_snapshot()?;
+        (snapshot.path, snapshot.timestamp, true)
+    } else {
+        let timestamp = chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true);
+        let mut backup_data = store;
+        backup_data.insert("version".to_string(), Value::String("1.0".to_string()));
+        backup_data.insert("timestamp".to_string(), Value::String(timestamp));
+        let (file_path, timestamp) = commands::create_automatic_backup_file(app.clone(), Value::Object(backup_data))?;
+        (file_path, timestamp, false)
+    };
+
+    emit_progress(app, trigger, "cleanup", 80);
+    let removed_by_cleanup = run_cleanup(&settings).unwrap_or_else(|e| {
+        logger::log_warn(&format!("Backup takarítás sikertelen: {}", e));
+        0
+    });
+
+    emit_progress(app, trigger, "done", 100);
+    Ok(BackupCompleted {
+        trigger: trigger.to_string(),
+        file_path,
+        timestamp,
+        incremental,
+        removed_by_cleanup,
+    })
+}
+
+fn update_status(update: impl FnOnce(&mut SchedulerStatus)) {
+    let mut status = SCHEDULER_STATE.lock().unwrap_or_else(|e| e.into_inner());
+    update(&mut status);
+}
+
+/// Egy ütemezési kör: esedékesség ellenőrzése és szükség esetén backup
+/// `forced` esetén (kézi indítás) az intervallumtól függetlenül készül backup
+fn tick(app: &AppHandle, first_run: bool, forced: bool) {
+    let _run_guard = RUN_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let settings = match data_store::read_store() {
+        Ok(Some(store)) => read_schedule_settings(&store),
+        Ok(None) => return,
+        Err(e) => {
+            logger::log_warn(&format!("Backup ütemező: a beállítások nem olvashatók: {}", e));
+            return;
+        }
+    };
+
+    let now = chrono::Utc::now();
+    let last_backup = last_backup_time();
+    let interval = chrono::Duration::hours(settings.interval_hours as i64);
+    let (last_attempt, last_failed) = {
+        let status = SCHEDULER_STATE.lock().unwrap_or_else(|e| e.into_inner());
+        (
+            status.last_attempt_at.as_deref().and_then(parse_timestamp),
+            status.last_error.is_some(),
+        )
+    };
+
+    // Változatlan adatnál az inkrementális mód nem készít új snapshotot, ezért a sikeres futás ideje is számít
+    let last_run = if last_failed { last_backup } else { last_backup.max(last_attempt) };
+    let next_due = match (last_run, last_attempt) {
+        (_, Some(attempt)) if last_failed => attempt + chrono::Duration::minutes(RETRY_AFTER_FAILURE_MINUTES),
+        (Some(last), _) => last + interval,
+        (None, _) => now,
+    };
+    update_status(|status| {
+        status.enabled = settings.enabled;
+        status.interval_hours = settings.interval_hours;
+        status.last_backup_at = last_backup.map(|t| t.to_rfc3339());
+        status.next_backup_at = settings.enabled.then(|| next_due.max(now).to_rfc3339());
+    });
+
+    let due = settings.enabled && next_due <= now;
+    if !(forced || due) {
+        return;
+    }
+
+    // Indításkor esedékes backup = kimaradt backup pótlása
+    let trigger = if forced {
+        "manual"
+    } else if first_run {
+        "catch-up"
+    } else {
+        "scheduled"
+    };
+    logger::log_info(&format!("Automatikus backup indítása ({})", trigger));
+    update_status(|status| {
+        status.in_progress = true;
+        status.last_attempt_at = Some(now.to_rfc3339());
+    });
+
+    match run_backup(app, trigger) {
+        Ok(completed) => {
+            logger::log_info(&format!(
+                "Automatikus backup kész ({}): {}, {} elem takarítva",
+                trigger, completed.file_path, completed.removed_by_cleanup
+            ));
+            update_status(|status| {
+                status.in_progress = false;
+                status.last_error = None;
+                status.last_backup_at = Some(completed.timestamp.clone());
+                status.next_backup_at = Some((now + interval).to_rfc3339());
+            });
+            if let Err(e) = app.emit(BACKUP_COMPLETED_EVENT, &completed) {
+                logger::log_warn(&format!("Nem sikerült elküldeni a backup kész eseményt: {}", e));
+            }
+        }
+        Err(e) => {
+            logger::log_error(&format!("Automatikus backup sikertelen ({}): {}", trigger, e));
+            update_status(|status| {
+                status.in_progress = false;
+                status.last_error = Some(e.clone());
+            });
+            let payload = serde_json::json!({ "trigger": trigger, "error": e });
+            if let Err(e) = app.emit(BACKUP_FAILED_EVENT, payload) {
+                logger::log_warn(&format!("Nem sikerült elküldeni a backup hiba eseményt: {}", e));
+            }
+        }
+    }
+}
+
+/// A háttér ütemező indítása (main.rs setup-ból, egyszer)
+/// Percenként ellenőrzi az `automaticBackupEnabled` / `automaticBackupIntervalHours` beállításokat,
+/// indításkor pótolja a kimaradt backupot, utána takarít, és eseményekkel jelzi a folyamatot
+pub fn start(app: AppHandle) {
+    let (sender, receiver) = mpsc::channel::<()>();
+    *SCHEDULER_TRIGGER.lock().unwrap_or_else(|e| e.into_inner()) = Some(sender);
+    update_status(|status| status.running = true);
+
+    let spawn_result = thread::Builder::new()
+        .name("backup-scheduler".to_string())
+        .spawn(move || {
+            thread::sleep(STARTUP_DELAY);
+            let mut first_run = true;
+            let mut forced = false;
+            loop {
+                tick(&app, first_run, forced);
+                first_run = false;
+                forced = match receiver.recv_timeout(TICK_INTERVAL) {
+                    Ok(()) => true,
+                    Err(RecvTimeoutError::Timeout) => false,
+                    Err(RecvTimeoutError::Disconnected) => break,
+                };
+            }
+            update_status(|status| status.running = false);
+        });
+
+    match spawn_result {
+        Ok(_) => logger::log_info("Backup ütemező elindítva"),
+        Err(e) => {
+            update_status(|status| status.running = false);
+            logger::log_error(&format!("Backup ütemező indítása sikertelen: {}", e));
+        }
+    }
+}
+
+/// Az ütemező futásainak felfüggesztése a visszaadott zár eldobásáig
+/// A folyamatban lévő backup befejeződik, a következő kör a zár elengedése után indul
+pub fn pause_runs() -> MutexGuard<'static, ()> {
+    RUN_LOCK.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Munkaterület váltáskor: az előző munkaterület backup állapota nem vonatkozik az újra
+/// (a következő kör az új munkaterület beállításaiból és backupjaiból számol)
+pub fn reset_status() {
+    update_status(|status| {
+        status.last_backup_at = None;
+        status.next_backup_at = None;
+        status.last_attempt_at = None;
+        status.last_error = None;
+    });
+}
+
+/// Az ütemező állapota (utolsó / következő backup, utolsó hiba)
+#[tauri::command]
+pub fn get_backup_scheduler_status() -> Result<SchedulerStatus, String> {
+    Ok(SCHEDULER_STATE.lock().map_err(|e| format!("Ütemező zárolási hiba: {}", e))?.clone())
+}
+
+/// Azonnali backup kérése az ütemezőtől (az eredmény backup-completed / backup-failed eseményként érkezik)
+#[tauri::command]
+pub fn run_scheduled_backup_now() -> Result<(), String> {
+    let trigger = SCHEDULER_TRIGGER.lock().map_err(|e| format!("Ütemező zárolási hiba: {}", e))?;
+    trigger
+        .as_ref()
+        .ok_or_else(|| "A backup ütemező nem fut".to_string())?
+        .send(())
+        .map_err(|_| "A backup ütemező nem fut".to_string())
+}
@@ -0,0 +1,565 @@
+use crate::backup_format;
+use crate::crypto;
+use crate::data_store;
+use crate::logger;
+use crate::migrations;
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+lazy_static! {
+    /// Snapshot létrehozás / törlés / szemétgyűjtés egyszerre csak egy szálon fut
+    static ref SNAPSHOT_LOCK: Mutex<()> = Mutex::new(());
+}
+
+/// A snapshot manifest formátum verziója
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+/// Ennél hosszabb `...Base64` mezők (képek, logó) külön objektumba kerülnek
+const MIN_BLOB_LEN: usize = 1024;
+
+/// Kiemelt kép objektum hivatkozás kulcsa a rekordokban
+const BLOB_REF_KEY: &str = "$blob";
+
+/// Snapshot manifest: a Store tartalma objektum hash-ekként
+/// A tömbök (printers, filaments, offers, ...) rekordonként, minden más kulcs egyben kerül objektumba,
+/// így a változatlan rekordok és képek a snapshotok között csak egyszer tárolódnak
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotManifest {
+    pub snapshot_version: u32,
+    pub id: String,
+    pub timestamp: String,
+    pub app_version: String,
+    pub schema_version: u32,
+    pub collections: BTreeMap<String, Vec<String>>,
+    pub values: BTreeMap<String, String>,
+    pub new_objects: usize,
+    pub new_bytes: u64,
+}
+
+/// Snapshot összefoglaló a listázáshoz
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotInfo {
+    pub id: String,
+    pub timestamp: String,
+    pub path: String,
+    pub schema_version: u32,
+    pub entity_counts: BTreeMap<String, usize>,
+    pub new_objects: usize,
+    pub new_bytes: u64,
+    /// false, ha nem volt változás az előző snapshot óta (ilyenkor az előző snapshotot adjuk vissza)
+    pub created: bool,
+}
+
+/// Szemétgyűjtés eredménye
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotGcResult {
+    pub removed_objects: usize,
+    pub freed_bytes: u64,
+    pub remaining_objects: usize,
+}
+
+fn snapshots_dir() -> Result<PathBuf, String> {
+    Ok(crate::paths::backups_dir()?.join("snapshots"))
+}
+
+fn objects_dir() -> Result<PathBuf, String> {
+    Ok(snapshots_dir()?.join("objects"))
+}
+
+fn manifests_dir() -> Result<PathBuf, String> {
+    Ok(snapshots_dir()?.join("manifests"))
+}
+
+/// Objektum útvonala: objects/<első 2 hex>/<sha256>
+fn object_path(objects: &Path, hash: &str) -> PathBuf {
+    objects.join(&hash[..2]).join(hash)
+}
+
+fn manifest_path(id: &str) -> Result<PathBuf, String> {
+    if id.is_empty() || !id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+        return Err(format!("Érvénytelen snapshot azonosító: {}", id));
+    }
+    Ok(manifests_dir()?.join(format!("snapshot_{}.json", id)))
+}
+
+/// Objektum tárolása (ha még nincs meg), visszatérés: (hash, új objektum mérete, ha most íródott)
+fn put_object(objects: &Path, value: &Value) -> Result<(String, Option<u64>), String> {
+    let bytes = serde_json::to_vec(value).map_err(|e| format!("Objektum szerializálási hiba: {}", e))?;
+    let hash = crypto::sha256_hex(&bytes);
+    let path = object_path(objects, &hash);
+    if path.exists() {
+        return Ok((hash, None));
+    }
+
+    // Atomikus írás, hogy félbeszakadt írás ne hagyjon csonka (de létezőnek látszó) objektumot
+    crate::atomic_write::write_atomic(&path, &bytes)
+        .map_err(|e| format!("Nem sikerült írni az objektumot: {}", e))?;
+    Ok((hash, Some(bytes.len() as u64)))
+}
+
+/// Objektum beolvasása a hash ellenőrzésével
+fn get_object(objects: &Path, hash: &str) -> Result<Value, String> {
+    if hash.len() != 64 || !hash.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(format!("Érvénytelen objektum hash: {}", hash));
+    }
+    let bytes = fs::read(object_path(objects, hash))
+        .map_err(|e| format!("Hiányzó backup objektum: {} - {}", hash, e))?;
+    if crypto::sha256_hex(&bytes) != hash {
+        return Err(format!("Sérült backup objektum: {}", hash));
+    }
+    serde_json::from_slice(&bytes).map_err(|e| format!("Érvénytelen backup objektum: {} - {}", hash, e))
+}
+
+/// A nagy `...Base64` mezők (képek) kiemelése külön objektumba, a helyükön `{"$blob": hash}` marad
+fn extract_blobs(value: &Value, objects: &Path, stats: &mut (usize, u64)) -> Result<Value, String> {
+    match value {
+        Value::Object(map) => {
+            let mut result = Map::new();
+            for (key, field) in map {
+                let is_blob = key.ends_with("Base64") && field.as_str().is_some_and(|s| s.len() >= MIN_BLOB_LEN);
+                let stored = if is_blob {
+                    let (hash, written) = put_object(objects, field)?;
+                    count_new(stats, written);
+                    serde_json::json!({ BLOB_REF_KEY: hash })
+                } else {
+                    extract_blobs(field, objects, stats)?
+                };
+                result.insert(key.clone(), stored);
+            }
+            Ok(Value::Object(result))
+        }
+        Value::Array(items) => Ok(Value::Array(
+            items
+                .iter()
+                .map(|item| extract_blobs(item, objects, stats))
+                .collect::<Result<_, _>>()?,
+        )),
+        other => Ok(other.clone()),
+    }
+}
+
+/// A `{"$blob": hash}` hivatkozások visszaállítása az eredeti értékre
+fn resolve_blobs(value: Value, objects: &Path) -> Result<Value, String> {
+    match value {
+        Value::Object(map) => {
+            if map.len() == 1 {
+                if let Some(hash) = map.get(BLOB_REF_KEY).and_then(|h| h.as_str()) {
+                    return get_object(objects, hash);
+                }
+            }
+            let mut result = Map::new();
+            for (key, field) in map {
+                result.insert(key, resolve_blobs(field, objects)?);
+            }
+            Ok(Value::Object(result))
+        }
+        Value::Array(items) => Ok(Value::Array(
+            items
+                .into_iter()
+                .map(|item| resolve_blobs(item, objects))
+                .collect::<Result<_, _>>()?,
+        )),
+        other => Ok(other),
+    }
+}
+
+fn count_new(stats: &mut (usize, u64), written: Option<u64>) {
+    if let Some(size) = written {
+        stats.0 += 1;
+        stats.1 += size;
+    }
+}
+
+/// Egy manifest összes hivatkozott objektuma (a kiemelt képekkel együtt)
+fn referenced_objects(manifest: &SnapshotManifest, objects: &Path, into: &mut HashSet<String>) -> Result<(), String> {
+    fn collect_blob_refs(value: &Value, into: &mut HashSet<String>) {
+        match value {
+            Value::Object(map) => {
+                if let Some(hash) = map.get(BLOB_REF_KEY).and_then(|h| h.as_str()) {
+                    into.insert(hash.to_string());
+                }
+                map.values().for_each(|v| collect_blob_refs(v, into));
+            }
+            Value::Array(items) => items.iter().for_each(|v| collect_blob_refs(v, into)),
+            _ => {}
+        }
+    }
+
+    for hash in manifest.collections.values().flatten().chain(manifest.values.values()) {
+        if into.insert(hash.clone()) {
+            collect_blob_refs(&get_object(objects, hash)?, into);
+        }
+    }
+    Ok(())
+}
+
+fn read_manifest(path: &Path) -> Result<SnapshotManifest, String> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("Nem sikerült beolvasni a snapshot manifestet: {} - {}", path.display(), e))?;
+    serde_json::from_str(&content)
+        .map_err(|e| format!("Érvénytelen snapshot manifest: {} - {}", path.display(), e))
+}
+
+/// Az összes snapshot manifest fájl útvonala
+fn manifest_paths() -> Result<Vec<PathBuf>, String> {
+    let dir = manifests_dir()?;
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let entries = fs::read_dir(&dir).map_err(|e| format!("Nem sikerült olvasni a snapshot könyvtárat: {}", e))?;
+    Ok(entries
+        .flatten()
+        .map(|e| e.path())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|n| n.starts_with("snapshot_") && n.ends_with(".json"))
+        })
+        .collect())
+}
+
+/// A legutóbbi snapshot manifest módosítási ideje (a manifestek beolvasása nélkül)
+pub fn latest_snapshot_modified() -> Option<std::time::SystemTime> {
+    manifest_paths()
+        .ok()?
+        .iter()
+        .filter_map(|path| fs::metadata(path).ok()?.modified().ok())
+        .max()
+}
+
+/// Az összes beolvasható snapshot manifest, időrendben (legrégebbi először)
+/// A sérült manifesteket kihagyja (listázáshoz); a szemétgyűjtés ehelyett megszakad, lásd collect_garbage
+fn read_all_manifests() -> Result<Vec<(PathBuf, SnapshotManifest)>, String> {
+    let mut manifests = Vec::new();
+    for path in manifest_paths()? {
+        match read_manifest(&path) {
+            Ok(manifest) => manifests.push((path, manifest)),
+            Err(e) => logger::log_warn(&e),
+        }
+    }
+    manifests.sort_by(|a, b| a.1.timestamp.cmp(&b.1.timestamp));
+    Ok(manifests)
+}
+
+fn snapshot_info(path: &Path, manifest: &SnapshotManifest, created: bool) -> SnapshotInfo {
+    SnapshotInfo {
+        id: manifest.id.clone(),
+        timestamp: manifest.timestamp.clone(),
+        path: path.to_string_lossy().to_string(),
+        schema_version: manifest.schema_version,
+        entity_counts: manifest
+            .collections
+            .iter()
+            .map(|(key, hashes)| (key.clone(), hashes.len()))
+            .collect(),
+        new_objects: manifest.new_objects,
+        new_bytes: manifest.new_bytes,
+        created,
+    }
+}
+
+/// Snapshot készítése a Store aktuális tartalmáról
+/// Csak az új (még nem tárolt) rekordok és képek íródnak ki; változatlan Store esetén nem készül új manifest
+pub fn create_snapshot() -> Result<SnapshotInfo, String> {
+    if backup_format::encryption_required() {
+        return Err("Az inkrementális backup nem titkosított, ezért bekapcsolt backup titkosítás vagy adattároló titkosítás mellett nem használható".to_string());
+    }
+
+    let _guard = SNAPSHOT_LOCK.lock().map_err(|e| format!("Snapshot zárolási hiba: {}", e))?;
+    let data = data_store::read_store()?.ok_or_else(|| "Nincs menthető adat (a Store fájl nem létezik)".to_string())?;
+
+    let objects = objects_dir()?;
+    fs::create_dir_all(&objects).map_err(|e| format!("Nem sikerült létrehozni a snapshot könyvtárat: {}", e))?;
+
+    let mut stats = (0usize, 0u64);
+    let mut collections = BTreeMap::new();
+    let mut values = BTreeMap::new();
+    for (key, value) in &data {
+        match value {
+            Value::Array(items) => {
+                let mut hashes = Vec::with_capacity(items.len());
+                for item in items {
+                    let (hash, written) = put_object(&objects, &extract_blobs(item, &objects, &mut stats)?)?;
+                    count_new(&mut stats, written);
+                    hashes.push(hash);
+                }
+                collections.insert(key.clone(), hashes);
+            }
+            other => {
+                let (hash, written) = put_object(&objects, &extract_blobs(other, &objects, &mut stats)?)?;
+                count_new(&mut stats, written);
+                values.insert(key.clone(), hash);
+            }
+        }
+    }
+
+    // Ha semmi nem változott a legutóbbi snapshot óta, nem készítünk újat
+    if let Some((path, latest)) = read_all_manifests()?.pop() {
+        if latest.collections == collections && latest.values == values {
+            return Ok(snapshot_info(&path, &latest, false));
+        }
+    }
+
+    let now = chrono::Utc::now();
+    let manifest = SnapshotManifest {
+        snapshot_version: SNAPSHOT_VERSION,
+        id: now.format("%Y%m%dT%H%M%S%3fZ").to_string(),
+        timestamp: now.to_rfc3339(),
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        schema_version: migrations::schema_version_of(&data),
+        collections,
+        values,
+        new_objects: stats.0,
+        new_bytes: stats.1,
+    };
+
+    let path = manifest_path(&manifest.id)?;
+    let content = serde_json::to_string_pretty(&manifest)
+        .map_err(|e| format!("Manifest szerializálási hiba: {}", e))?;
+    crate::atomic_write::write_atomic(&path, content)
+        .map_err(|e| format!("Nem sikerült írni a snapshot manifestet: {}", e))?;
+
+    logger::log_info(&format!(
+        "Backup snapshot létrehozva: {} ({} új objektum, {} bytes)",
+        manifest.id, manifest.new_objects, manifest.new_bytes
+    ));
+    Ok(snapshot_info(&path, &manifest, true))
+}
+
+/// Egy snapshot teljes Store tartalmának visszaállítása az objektumokból
+pub fn reconstruct_snapshot(id: &str) -> Result<Map<String, Value>, String> {
+    let manifest = read_manifest(&manifest_path(id)?)?;
+    let objects = objects_dir()?;
+
+    let mut data = Map::new();
+    for (key, hashes) in &manifest.collections {
+        let items = hashes
+            .iter()
+            .map(|hash| resolve_blobs(get_object(&objects, hash)?, &objects))
+            .collect::<Result<Vec<_>, _>>()?;
+        data.insert(key.clone(), Value::Array(items));
+    }
+    for (key, hash) in &manifest.values {
+        data.insert(key.clone(), resolve_blobs(get_object(&objects, hash)?, &objects)?);
+    }
+    Ok(data)
+}
+
+/// Már nem hivatkozott objektumok törlése
+/// Ha bármelyik manifest nem olvasható, a szemétgyűjtés megszakad: a hivatkozásai nem ismertek,
+/// így az objektumai tévesen törlődnének
+fn collect_garbage() -> Result<SnapshotGcResult, String> {
+    let objects = objects_dir()?;
+    let mut referenced = HashSet::new();
+    for path in manifest_paths()? {
+        let manifest = read_manifest(&path).map_err(|e| format!("Snapshot szemétgyűjtés megszakítva: {}", e))?;
+        referenced_objects(&manifest, &objects, &mut referenced)?;
+    }
+
+    let mut result = SnapshotGcResult {
+        removed_objects: 0,
+        freed_bytes: 0,
+        remaining_objects: 0,
+    };
+    if !objects.exists() {
+        return Ok(result);
+    }
+
+    let prefixes = fs::read_dir(&objects).map_err(|e| format!("Nem sikerült olvasni az objektum könyvtárat: {}", e))?;
+    for prefix in prefixes.flatten().map(|e| e.path()).filter(|p| p.is_dir()) {
+        let entries = fs::read_dir(&prefix).map_err(|e| format!("Nem sikerült olvasni az objektum könyvtárat: {}", e))?;
+        for path in entries.flatten().map(|e| e.path()) {
+            let name = path.file_name().and_then(|n| n.to_str()).unwrap_or_default().to_string();
+            if referenced.contains(&name) {
+                result.remaining_objects += 1;
+                continue;
+            }
+            let size = fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+            if fs::remove_file(&path).is_ok() {
+                result.removed_objects += 1;
+                result.freed_bytes += size;
+            }
+        }
+        // Üres prefix könyvtár törlése (nem üres esetén a hiba várt, figyelmen kívül hagyjuk)
+        let _ = fs::remove_dir(&prefix);
+    }
+
+    Ok(result)
+}
+
+/// Inkrementális backup snapshot készítése
+#[tauri::command]
+pub fn create_backup_snapshot() -> Result<SnapshotInfo, String> {
+    create_snapshot()
+}
+
+/// Snapshotok listázása (legújabb először)
+#[tauri::command]
+pub fn list_backup_snapshots() -> Result<Vec<SnapshotInfo>, String> {
+    Ok(read_all_manifests()?
+        .iter()
+        .rev()
+        .map(|(path, manifest)| snapshot_info(path, manifest, true))
+        .collect())
+}
+
+/// Snapshot tartalmának visszaállítása backup formátumban (a frontend a sima backuphoz hasonlóan tölti be)
+#[tauri::command]
+pub fn get_backup_snapshot_data(snapshot_id: String) -> Result<Value, String> {
+    let manifest = read_manifest(&manifest_path(&snapshot_id)?)?;
+    let mut data = reconstruct_snapshot(&snapshot_id)?;
+    data.insert("version".to_string(), Value::String("1.0".to_string()));
+    data.insert("timestamp".to_string(), Value::String(manifest.timestamp));
+    Ok(Value::Object(data))
+}
+
+/// Snapshot törlése és a már nem hivatkozott objektumok felszabadítása
+#[tauri::command]
+pub fn delete_backup_snapshot(snapshot_id: String) -> Result<SnapshotGcResult, String> {
+    let _guard = SNAPSHOT_LOCK.lock().map_err(|e| format!("Snapshot zárolási hiba: {}", e))?;
+    fs::remove_file(manifest_path(&snapshot_id)?)
+        .map_err(|e| format!("Nem sikerült törölni a snapshotot: {} - {}", snapshot_id, e))?;
+    collect_garbage()
+}
+
+/// A megadott napnál régebbi snapshotok törlése (a legutolsó mindig megmarad) és szemétgyűjtés
+#[tauri::command]
+pub fn cleanup_old_backup_snapshots(days: u32) -> Result<SnapshotGcResult, String> {
+    let _guard = SNAPSHOT_LOCK.lock().map_err(|e| format!("Snapshot zárolási hiba: {}", e))?;
+    let cutoff = chrono::Utc::now() - chrono::Duration::days(days as i64);
+
+    let mut manifests = read_all_manifests()?;
+    manifests.pop();
+    for (path, manifest) in manifests {
+        let is_old = chrono::DateTime::parse_from_rfc3339(&manifest.timestamp)
+            .is_ok_and(|ts| ts.with_timezone(&chrono::Utc) < cutoff);
+        if is_old {
+            if let Err(e) = fs::remove_file(&path) {
+                logger::log_warn(&format!("Nem sikerült törölni a snapshotot: {} - {}", path.display(), e));
+            }
+        }
+    }
+    collect_garbage()
+}
+
+/// Az összes snapshot törlése (Factory Reset-hez), a törölt snapshotok számával
+pub fn delete_all_snapshots() -> Result<u32, String> {
+    let _guard = SNAPSHOT_LOCK.lock().map_err(|e| format!("Snapshot zárolási hiba: {}", e))?;
+    let dir = snapshots_dir()?;
+    if !dir.exists() {
+        return Ok(0);
+    }
+    let count = manifest_paths()?.len() as u32;
+    fs::remove_dir_all(&dir).map_err(|e| format!("Nem sikerült törölni a snapshot könyvtárat: {} - {}", dir.display(), e))?;
+    logger::log_info(&format!("Snapshotok törölve: {} ({} db)", dir.display(), count));
+    Ok(count)
+}
+
+/// Nem hivatkozott snapshot objektumok törlése
+#[tauri::command]
+pub fn gc_backup_snapshots() -> Result<SnapshotGcResult, String> {
+    let _guard = SNAPSHOT_LOCK.lock().map_err(|e| format!("Snapshot zárolási hiba: {}", e))?;
+    let result = collect_garbage()?;
+    logger::log_info(&format!(
+        "Snapshot szemétgyűjtés: {} objektum törölve ({} bytes), {} megmaradt",
+        result.removed_objects, result.freed_bytes, result.remaining_objects
+    ));
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn write_store(data: &Value) -> PathBuf {
+        let store = data_store::store_file_path().unwrap();
+        fs::write(&store, serde_json::to_string_pretty(data).unwrap()).unwrap();
+        store
+    }
+
+    /// Új snapshot (a manifest azonosítója ezredmásodperc pontosságú, ezért várunk a következő előtt)
+    fn snapshot() -> SnapshotInfo {
+        let info = create_snapshot().unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        info
+    }
+
+    fn sample_store(price: u32) -> Value {
+        json!({
+            "printers": [{ "id": 1, "name": "Prusa MK4" }],
+            "offers": [{
+                "id": 1,
+                "customerName": "Kovács Éva",
+                "price": price,
+                "imageBase64": "A".repeat(MIN_BLOB_LEN * 2),
+                "thumbnailBase64": "rövid"
+            }],
+            "settings": { "language": "hu", "companyLogoBase64": "L".repeat(MIN_BLOB_LEN) }
+        })
+    }
+
+    #[test]
+    fn snapshot_round_trips_with_extracted_blobs() {
+        crate::paths::test_support::data_root();
+        let _guard = crate::paths::test_support::lock();
+        delete_all_snapshots().unwrap();
+        let data = sample_store(1000);
+        let store = write_store(&data);
+
+        let info = snapshot();
+        assert!(info.created);
+        assert_eq!(info.entity_counts.get("offers"), Some(&1));
+        assert!(!create_snapshot().unwrap().created, "változatlan Store esetén nem készül új snapshot");
+
+        // A nagy képmező külön objektumba kerül, a rövid marad a rekordban
+        let manifest = read_manifest(&manifest_path(&info.id).unwrap()).unwrap();
+        let objects = objects_dir().unwrap();
+        let offer = get_object(&objects, &manifest.collections["offers"][0]).unwrap();
+        assert!(offer["imageBase64"].get(BLOB_REF_KEY).is_some());
+        assert_eq!(offer["thumbnailBase64"], json!("rövid"));
+        let settings = get_object(&objects, &manifest.values["settings"]).unwrap();
+        assert!(settings["companyLogoBase64"].get(BLOB_REF_KEY).is_some());
+
+        assert_eq!(Value::Object(reconstruct_snapshot(&info.id).unwrap()), data);
+
+        delete_all_snapshots().unwrap();
+        fs::remove_file(&store).unwrap();
+    }
+
+    #[test]
+    fn garbage_collection_keeps_referenced_objects() {
+        crate::paths::test_support::data_root();
+        let _guard = crate::paths::test_support::lock();
+        delete_all_snapshots().unwrap();
+
+        write_store(&sample_store(1000));
+        let first = snapshot();
+        let data = sample_store(2000);
+        let store = write_store(&data);
+        let second = snapshot();
+        assert!(second.created);
+        // Csak a módosult ajánlat rekord új, a kép és a többi rekord közös
+        assert_eq!(second.new_objects, 1);
+
+        let result = delete_backup_snapshot(first.id).unwrap();
+        assert_eq!(result.removed_objects, 1, "csak a régi ajánlat rekord törölhető");
+        assert!(result.remaining_objects > 0);
+        assert_eq!(Value::Object(reconstruct_snapshot(&second.id).unwrap()), data);
+
+        let result = gc_backup_snapshots().unwrap();
+        assert_eq!(result.removed_objects, 0);
+
+        delete_all_snapshots().unwrap();
+        fs::remove_file(&store).unwrap();
+    }
+}
@@ -109,12 +109,8 @@ pub async fn open_file(path: String) -> Result<(), String> {
 /// - Linux: ~/.local/share/3DPrinterCalcApp/logs/
 #[tauri::command]
 pub fn init_frontend_log() -> Result<String, String> {
-    use dirs;
     
-    let log_dir = dirs::data_local_dir()
-        .ok_or_else(|| "Nem található data directory".to_string())?
-        .join("3DPrinterCalcApp")
-        .join("logs");
+    let log_dir = crate::paths::logs_dir()?;
     
     // Létrehozzuk a log könyvtárat, ha nem létezik
     std::fs::create_dir_all(&log_dir)
@@ -145,15 +141,11 @@ pub fn init_frontend_log() -> Result<String, String> {
 pub fn write_frontend_log(level: String, message: String, format: Option<String>) -> Result<(), String> {
     use std::fs::OpenOptions;
     use std::io::Write;
-    use dirs;
     use serde_json;
     
     let log_format = format.as_deref().unwrap_or("text"); // Alapértelmezett: text
     
-    let log_dir = dirs::data_local_dir()
-        .ok_or_else(|| "Nem található data directory".to_string())?
-        .join("3DPrinterCalcApp")
-        .join("logs");
+    let log_dir = crate::paths::logs_dir()?;
     
     // Log fájl neve: frontend-YYYY-MM-DD.log vagy frontend-YYYY-MM-DD.json
     let today = chrono::Local::now().format("%Y-%m-%d").to_string();
@@ -241,12 +233,8 @@ pub fn write_frontend_log(level: String, message: String, format: Option<String>
 /// Frontend log fájl útvonalának lekérése
 #[tauri::command]
 pub fn get_frontend_log_path() -> Result<Option<String>, String> {
-    use dirs;
     
-    let log_dir = dirs::data_local_dir()
-        .ok_or_else(|| "Nem található data directory".to_string())?
-        .join("3DPrinterCalcApp")
-        .join("logs");
+    let log_dir = crate::paths::logs_dir()?;
     
     let today = chrono::Local::now().format("%Y-%m-%d").to_string();
     let log_file_path = log_dir.join(format!("frontend-{}.log", today));
@@ -257,14 +245,10 @@ pub fn get_frontend_log_path() -> Result<Option<String>, String> {
 /// Log fájlok törlése a megadott napnál régebbiek
 #[tauri::command]
 pub fn delete_old_logs(days: u32) -> Result<u32, String> {
-    use dirs;
     use std::fs;
     use chrono::NaiveDate;
     
-    let log_dir = dirs::data_local_dir()
-        .ok_or_else(|| "Nem található data directory".to_string())?
-        .join("3DPrinterCalcApp")
-        .join("logs");
+    let log_dir = crate::paths::logs_dir()?;
     
     if !log_dir.exists() {
         return Ok(0);
@@ -316,13 +300,9 @@ pub fn delete_old_logs(days: u32) -> Result<u32, String> {
 /// Összes log fájl törlése (Factory Reset-hez)
 #[tauri::command]
 pub fn delete_all_logs() -> Result<u32, String> {
-    use dirs;
     use std::fs;
     
-    let log_dir = dirs::data_local_dir()
-        .ok_or_else(|| "Nem található data directory".to_string())?
-        .join("3DPrinterCalcApp")
-        .join("logs");
+    let log_dir = crate::paths::logs_dir()?;
     
     if !log_dir.exists() {
         return Ok(0);
@@ -366,12 +346,8 @@ pub fn delete_all_logs() -> Result<u32, String> {
 /// - Linux: ~/.local/share/3DPrinterCalcApp/logs/
 #[tauri::command]
 pub fn get_log_directory_path() -> Result<String, String> {
-    use dirs;
     
-    let log_dir = dirs::data_local_dir()
-        .ok_or_else(|| "Nem található data directory".to_string())?
-        .join("3DPrinterCalcApp")
-        .join("logs");
+    let log_dir = crate::paths::logs_dir()?;
     
     Ok(log_dir.to_string_lossy().to_string())
 }
@@ -384,14 +360,9 @@ pub fn get_log_directory_path() -> Result<String, String> {
 /// Létrehozza a könyvtárat, ha nem létezik
 #[tauri::command]
 pub fn get_backup_directory_path() -> Result<String, String> {
-    use dirs;
     use std::fs;
     
-    let backup_dir = dirs::data_local_dir()
-        .ok_or_else(|| "Nem található data directory".to_string())?
-        .join("3DPrinterCalcApp")
-        .join("backups")
-        .join("automatic");
+    let backup_dir = crate::paths::automatic_backups_dir()?;
     
     // Létrehozzuk a könyvtárat, ha nem létezik
     if !backup_dir.exists() {
@@ -407,13 +378,9 @@ pub fn get_backup_directory_path() -> Result<String, String> {
 /// Visszaadja: (fájlnév, teljes útvonal, méret bytes-ban)
 #[tauri::command]
 pub fn list_log_files() -> Result<Vec<(String, String, u64)>, String> {
-    use dirs;
     use std::fs;
     
-    let log_dir = dirs::data_local_dir()
-        .ok_or_else(|| "Nem található data directory".to_string())?
-        .join("3DPrinterCalcApp")
-        .join("logs");
+    let log_dir = crate::paths::logs_dir()?;
     
     // Ellenőrizzük, hogy létezik-e a könyvtár
     if !log_dir.exists() {
@@ -479,10 +446,7 @@ pub fn read_log_file(file_path: String) -> Result<String, String> {
     use std::path::Path;
     
     // Biztonsági ellenőrzés: csak a log könyvtárban lévő fájlokat olvashatjuk
-    let log_dir = dirs::data_local_dir()
-        .ok_or_else(|| "Nem található data directory".to_string())?
-        .join("3DPrinterCalcApp")
-        .join("logs");
+    let log_dir = crate::paths::logs_dir()?;
     
     let requested_path = Path::new(&file_path);
     
@@ -500,43 +464,26 @@ pub fn read_log_file(file_path: String) -> Result<String, String> {
     Ok(content)
 }
 
+/// Backup fájl lista elem: (fájlnév, útvonal, timestamp, méret, integritás: "ok" | "corrupted" | "unverified")
+pub type BackupFileEntry = (String, String, String, u64, String);
+
 /// Backup fájlok listázása a backup könyvtárból
 /// Visszaadja a backup fájlok listáját a fájlnévvel, teljes útvonallal, timestamp-tel és mérettel (bytes)
 /// Keres mindkét lehetséges helyen: az új cross-platform helyen és a régi bundle ID helyen (kompatibilitás)
+/// A lista elemei integritás állapotot is tartalmaznak (a manifest alapján, gyorsítótárazva, lásd backup_manifest::cached_integrity)
 #[tauri::command]
-pub fn list_backup_files() -> Result<Vec<(String, String, String, u64)>, String> {
-    use dirs;
+pub fn list_backup_files() -> Result<Vec<BackupFileEntry>, String> {
     use std::fs;
-    use serde_json::Value;
     use std::collections::HashSet;
     
-    // Új cross-platform könyvtár (3DPrinterCalcApp)
-    let backup_dir_new = dirs::data_local_dir()
-        .ok_or_else(|| "Nem található data directory".to_string())?
-        .join("3DPrinterCalcApp")
-        .join("backups")
-        .join("automatic");
-    
-    // Régi bundle ID könyvtár (kompatibilitás miatt - csak macOS/Linux-on létezik)
-    // Windows-on a bundle ID könyvtár másként néz ki, de itt csak az új helyet használjuk
-    #[cfg(any(target_os = "macos", target_os = "linux"))]
-    let backup_dir_old = dirs::data_local_dir()
-        .ok_or_else(|| "Nem található data directory".to_string())?
-        .join("com.lekszikov.3dprintercalcapp")
-        .join("backups")
-        .join("automatic");
+    // Új cross-platform könyvtár, és a régi bundle ID könyvtár (kompatibilitás miatt - csak macOS/Linux-on létezik)
+    let backup_dirs = crate::paths::automatic_backup_dirs()?;
     
     // Listázzuk a backup fájlokat (mindkét helyről, ha létezik)
-    let mut backup_files: Vec<(String, String, String, u64)> = Vec::new();
+    let mut backup_files: Vec<BackupFileEntry> = Vec::new();
     let mut seen_files = HashSet::new(); // Elkerüljük a duplikációt
     
-    // Mindkét könyvtárat ellenőrizzük (régi csak macOS/Linux-on)
-    #[cfg(any(target_os = "macos", target_os = "linux"))]
-    let backup_dirs = vec![&backup_dir_new, &backup_dir_old];
-    #[cfg(not(any(target_os = "macos", target_os = "linux")))]
-    let backup_dirs = vec![&backup_dir_new];
-    
-    for backup_dir in backup_dirs {
+    for backup_dir in &backup_dirs {
         if !backup_dir.exists() {
             continue;
         }
@@ -548,7 +495,7 @@ pub fn list_backup_files() -> Result<Vec<(String, String, String, u64)>, String>
                     if path.is_file() {
                         if let Some(file_name) = path.file_name() {
                             if let Some(file_name_str) = file_name.to_str() {
-                                if file_name_str.starts_with("auto_backup_") && file_name_str.ends_with(".json") {
+                                if crate::backup_archive::is_automatic_backup_file_name(file_name_str) {
                                     // Elkerüljük a duplikációt (ha mindkét könyvtárban van ugyanaz a fájl)
                                     if seen_files.contains(file_name_str) {
                                         continue;
@@ -562,17 +509,11 @@ pub fn list_backup_files() -> Result<Vec<(String, String, String, u64)>, String>
                                         .map(|m| m.len())
                                         .unwrap_or(0);
                                     
-                                    // Kiolvassuk a backup fájlt, hogy megkapjuk a timestamp-et
-                                    let mut timestamp = String::new();
-                                    if let Ok(content) = fs::read_to_string(&path) {
-                                        if let Ok(json) = serde_json::from_str::<Value>(&content) {
-                                            if let Some(ts) = json.get("timestamp").and_then(|v| v.as_str()) {
-                                                timestamp = ts.to_string();
-                                            }
-                                        }
-                                    }
+                                    // Timestamp és integritás: a teljes ellenőrzés fájlonként egyszer fut, utána a tárolt eredmény
+                                    // érvényes, amíg a fájl nem változik (ami nem olvasható be vagy nem érvényes, az sérült)
+                                    let (timestamp, integrity) = crate::backup_manifest::cached_integrity(&path);
                                     
-                                    backup_files.push((file_name_str.to_string(), full_path, timestamp, file_size));
+                                    backup_files.push((file_name_str.to_string(), full_path, timestamp, file_size, integrity.as_str().to_string()));
                                 }
                             }
                         }
@@ -654,17 +595,12 @@ pub fn get_backend_log_path() -> Result<Option<String>, String> {
 /// így elkerüljük a Tauri permissions problémákat
 #[tauri::command]
 pub fn create_automatic_backup_file(
+    app: AppHandle,
     backup_data: serde_json::Value,
 ) -> Result<(String, String), String> {
-    use dirs;
     use std::fs;
-    use std::io::Write;
     
-    let backup_dir = dirs::data_local_dir()
-        .ok_or_else(|| "Nem található data directory".to_string())?
-        .join("3DPrinterCalcApp")
-        .join("backups")
-        .join("automatic");
+    let backup_dir = crate::paths::automatic_backups_dir()?;
     
     // Létrehozzuk a könyvtárat, ha nem létezik
     if !backup_dir.exists() {
@@ -682,22 +618,35 @@ pub fn create_automatic_backup_file(
     // Létrehozzuk a fájlnevet (ugyanúgy formázva, mint a frontend-ben)
     // Csere: : -> -, . -> - (minden előfordulás)
     let formatted_timestamp = timestamp.replace(":", "-").replace(".", "-");
-    let file_name = format!("auto_backup_{}.json", formatted_timestamp);
-    let file_path = backup_dir.join(&file_name);
-    
-    // JSON stringgé alakítjuk a backup data-t
-    let json_content = serde_json::to_string_pretty(&backup_data)
-        .map_err(|e| format!("Nem sikerült JSON-né alakítani a backup data-t: {}", e))?;
     
-    // Létrehozzuk a fájlt
-    let mut file = fs::File::create(&file_path)
-        .map_err(|e| format!("Nem sikerült létrehozni a backup fájlt: {}", e))?;
+    // Titkosítás, ha be van állítva backup jelmondat (a fejléc timestamp-je titkosítatlan marad)
+    let backup_data = crate::backup_format::prepare_for_write(&backup_data)?;
     
-    file.write_all(json_content.as_bytes())
-        .map_err(|e| format!("Nem sikerült írni a backup fájlba: {}", e))?;
+    let file_path = if crate::backup_format::is_encrypted_backup(&backup_data) {
+        // Titkosított backup: .json fájl titkosítatlan fejléccel, a payload a titkosított zip archívum
+        let file_path = backup_dir.join(format!("auto_backup_{}.json", formatted_timestamp));
+        
+        // JSON stringgé alakítjuk a backup data-t
+        let json_content = serde_json::to_string_pretty(&backup_data)
+            .map_err(|e| format!("Nem sikerült JSON-né alakítani a backup data-t: {}", e))?;
+        
+        // Atomikus írás: félbeszakadt mentés nem hagy csonka backup fájlt
+        crate::atomic_write::write_atomic(&file_path, json_content)
+            .map_err(|e| format!("Nem sikerült írni a backup fájlba: {}", e))?;
+        file_path
+    } else {
+        // Titkosítatlan backup: tömörített zip archívum, a képek külön bejegyzésekben
+        let file_path = backup_dir.join(format!("auto_backup_{}.zip", formatted_timestamp));
+        crate::backup_archive::write_archive(&file_path, &backup_data)?;
+        file_path
+    };
     
     logger::log_info(&format!("Automatikus backup fájl létrehozva: {}", file_path.to_string_lossy()));
     
+    // Másolás a további backup célokra (USB, NAS, ...) a háttérben - ezek hibája nem akadályozza a backupot,
+    // az eredmény "backup-replicated" eseményként érkezik
+    crate::backup_destinations::replicate_backup_in_background(app, file_path.clone());
+    
     Ok((
         file_path.to_string_lossy().to_string(),
         timestamp.to_string(),
@@ -709,37 +658,20 @@ pub fn create_automatic_backup_file(
 /// Backend-ből történik, hogy elkerüljük a Tauri permissions problémákat
 #[tauri::command]
 pub fn cleanup_old_backups_by_days(days: u32) -> Result<u32, String> {
-    use dirs;
     use std::fs;
-    use serde_json::Value;
     use chrono::DateTime;
     
-    // Új cross-platform könyvtár (3DPrinterCalcApp)
-    let backup_dir_new = dirs::data_local_dir()
-        .ok_or_else(|| "Nem található data directory".to_string())?
-        .join("3DPrinterCalcApp")
-        .join("backups")
-        .join("automatic");
-    
-    // Régi bundle ID könyvtár (kompatibilitás miatt - csak macOS/Linux-on létezik)
-    #[cfg(any(target_os = "macos", target_os = "linux"))]
-    let backup_dir_old = dirs::data_local_dir()
-        .ok_or_else(|| "Nem található data directory".to_string())?
-        .join("com.lekszikov.3dprintercalcapp")
-        .join("backups")
-        .join("automatic");
+    // Új cross-platform könyvtár, és a régi bundle ID könyvtár (kompatibilitás miatt - csak macOS/Linux-on létezik)
+    let backup_dirs = crate::paths::automatic_backup_dirs()?;
     
     // Cutoff dátum: most - days nap
     let cutoff_date = chrono::Local::now() - chrono::Duration::days(days as i64);
     let mut deleted_count = 0;
     
-    // Mindkét könyvtárat ellenőrizzük (régi csak macOS/Linux-on)
-    #[cfg(any(target_os = "macos", target_os = "linux"))]
-    let backup_dirs = vec![&backup_dir_new, &backup_dir_old];
-    #[cfg(not(any(target_os = "macos", target_os = "linux")))]
-    let backup_dirs = vec![&backup_dir_new];
+    // A rögzített ("soha ne töröld") backupokat kihagyjuk
+    let pinned = crate::backup_retention::pinned_backups();
     
-    for backup_dir in backup_dirs {
+    for backup_dir in &backup_dirs {
         if !backup_dir.exists() {
             continue;
         }
@@ -751,25 +683,21 @@ pub fn cleanup_old_backups_by_days(days: u32) -> Result<u32, String> {
                     if path.is_file() {
                         if let Some(file_name) = path.file_name() {
                             if let Some(file_name_str) = file_name.to_str() {
-                                if file_name_str.starts_with("auto_backup_") && file_name_str.ends_with(".json") {
-                                    // Kiolvassuk a backup fájlt, hogy megkapjuk a timestamp-et
-                                    if let Ok(content) = fs::read_to_string(&path) {
-                                        if let Ok(json) = serde_json::from_str::<Value>(&content) {
-                                            if let Some(ts_str) = json.get("timestamp").and_then(|v| v.as_str()) {
-                                                // Parse-oljuk a timestamp-et
-                                                if let Ok(backup_date) = ts_str.parse::<DateTime<chrono::Utc>>() {
-                                                    // UTC-ből local time-ra konvertáljuk
-                                                    let backup_date_local = backup_date.with_timezone(&chrono::Local);
-                                                    
-                                                    // Ha a backup régebbi, mint a cutoff dátum, töröljük
-                                                    if backup_date_local < cutoff_date {
-                                                        if let Err(e) = fs::remove_file(&path) {
-                                                            logger::log_warn(&format!("Nem sikerült törölni a backup fájlt: {} - {}", path.display(), e));
-                                                        } else {
-                                                            deleted_count += 1;
-                                                            logger::log_info(&format!("Régi backup fájl törölve ({} napnál régebbi): {}", days, path.display()));
-                                                        }
-                                                    }
+                                if crate::backup_archive::is_automatic_backup_file_name(file_name_str) && !pinned.contains(file_name_str) {
+                                    // Kiolvassuk a backup fájlt (.json vagy .zip), hogy megkapjuk a timestamp-et
+                                    if let Some(ts_str) = crate::backup_archive::read_backup_timestamp(&path) {
+                                        // Parse-oljuk a timestamp-et
+                                        if let Ok(backup_date) = ts_str.parse::<DateTime<chrono::Utc>>() {
+                                            // UTC-ből local time-ra konvertáljuk
+                                            let backup_date_local = backup_date.with_timezone(&chrono::Local);
+                                            
+                                            // Ha a backup régebbi, mint a cutoff dátum, töröljük
+                                            if backup_date_local < cutoff_date {
+                                                if let Err(e) = fs::remove_file(&path) {
+                                                    logger::log_warn(&format!("Nem sikerült törölni a backup fájlt: {} - {}", path.display(), e));
+                                                } else {
+                                                    deleted_count += 1;
+                                                    logger::log_info(&format!("Régi backup fájl törölve ({} napnál régebbi): {}", days, path.display()));
                                                 }
                                             }
                                         }
@@ -792,38 +720,21 @@ pub fn cleanup_old_backups_by_days(days: u32) -> Result<u32, String> {
 /// Backend-ből történik, hogy elkerüljük a Tauri permissions problémákat
 #[tauri::command]
 pub fn cleanup_old_backups_by_count(max_backups: u32) -> Result<u32, String> {
-    use dirs;
     use std::fs;
-    use serde_json::Value;
     use chrono::DateTime;
     
-    // Új cross-platform könyvtár (3DPrinterCalcApp)
-    let backup_dir_new = dirs::data_local_dir()
-        .ok_or_else(|| "Nem található data directory".to_string())?
-        .join("3DPrinterCalcApp")
-        .join("backups")
-        .join("automatic");
-    
-    // Régi bundle ID könyvtár (kompatibilitás miatt - csak macOS/Linux-on létezik)
-    #[cfg(any(target_os = "macos", target_os = "linux"))]
-    let backup_dir_old = dirs::data_local_dir()
-        .ok_or_else(|| "Nem található data directory".to_string())?
-        .join("com.lekszikov.3dprintercalcapp")
-        .join("backups")
-        .join("automatic");
+    // Új cross-platform könyvtár, és a régi bundle ID könyvtár (kompatibilitás miatt - csak macOS/Linux-on létezik)
+    let backup_dirs = crate::paths::automatic_backup_dirs()?;
     
     let mut deleted_count = 0;
     
-    // Mindkét könyvtárat ellenőrizzük (régi csak macOS/Linux-on)
-    #[cfg(any(target_os = "macos", target_os = "linux"))]
-    let backup_dirs = vec![&backup_dir_new, &backup_dir_old];
-    #[cfg(not(any(target_os = "macos", target_os = "linux")))]
-    let backup_dirs = vec![&backup_dir_new];
+    // A rögzített ("soha ne töröld") backupok nem számítanak bele a maximumba és nem törlődnek
+    let pinned = crate::backup_retention::pinned_backups();
     
     // Összegyűjtjük az összes backup fájlt timestamp-tel
     let mut backup_files: Vec<(std::path::PathBuf, String, chrono::DateTime<chrono::Utc>)> = Vec::new();
     
-    for backup_dir in backup_dirs {
+    for backup_dir in &backup_dirs {
         if !backup_dir.exists() {
             continue;
         }
@@ -835,16 +746,12 @@ pub fn cleanup_old_backups_by_count(max_backups: u32) -> Result<u32, String> {
                     if path.is_file() {
                         if let Some(file_name) = path.file_name() {
                             if let Some(file_name_str) = file_name.to_str() {
-                                if file_name_str.starts_with("auto_backup_") && file_name_str.ends_with(".json") {
-                                    // Kiolvassuk a backup fájlt, hogy megkapjuk a timestamp-et
-                                    if let Ok(content) = fs::read_to_string(&path) {
-                                        if let Ok(json) = serde_json::from_str::<Value>(&content) {
-                                            if let Some(ts_str) = json.get("timestamp").and_then(|v| v.as_str()) {
-                                                // Parse-oljuk a timestamp-et
-                                                if let Ok(backup_date) = ts_str.parse::<DateTime<chrono::Utc>>() {
-                                                    backup_files.push((path.clone(), file_name_str.to_string(), backup_date));
-                                                }
-                                            }
+                                if crate::backup_archive::is_automatic_backup_file_name(file_name_str) && !pinned.contains(file_name_str) {
+                                    // Kiolvassuk a backup fájlt (.json vagy .zip), hogy megkapjuk a timestamp-et
+                                    if let Some(ts_str) = crate::backup_archive::read_backup_timestamp(&path) {
+                                        // Parse-oljuk a timestamp-et
+                                        if let Ok(backup_date) = ts_str.parse::<DateTime<chrono::Utc>>() {
+                                            backup_files.push((path.clone(), file_name_str.to_string(), backup_date));
                                         }
                                     }
                                 }
@@ -879,37 +786,27 @@ pub fn cleanup_old_backups_by_count(max_backups: u32) -> Result<u32, String> {
 /// Összes automatikus backup fájl törlése (Factory Reset-hez)
 #[tauri::command]
 pub fn delete_all_backups() -> Result<u32, String> {
-    use dirs;
     use std::fs;
     use std::collections::HashSet;
     
-    // Új cross-platform könyvtár (3DPrinterCalcApp)
-    let backup_dir_new = dirs::data_local_dir()
-        .ok_or_else(|| "Nem található data directory".to_string())?
-        .join("3DPrinterCalcApp")
-        .join("backups")
-        .join("automatic");
-    
-    // Régi bundle ID könyvtár (com.lekszikov.3dprintercalcapp) - csak macOS és Linux
-    #[cfg(any(target_os = "macos", target_os = "linux"))]
-    let backup_dir_old = dirs::data_local_dir()
-        .ok_or_else(|| "Nem található data directory".to_string())?
-        .join("com.lekszikov.3dprintercalcapp")
-        .join("backups")
-        .join("automatic");
+    // Új cross-platform könyvtár, és a régi bundle ID könyvtár (csak macOS és Linux)
+    let backup_dirs = crate::paths::automatic_backup_dirs()?;
     
     let mut deleted_count = 0;
     let mut seen_files = HashSet::new();
     
-    // Új könyvtár feldolgozása
-    if backup_dir_new.exists() {
-        if let Ok(entries) = fs::read_dir(&backup_dir_new) {
+    for backup_dir in &backup_dirs {
+        if !backup_dir.exists() {
+            continue;
+        }
+        if let Ok(entries) = fs::read_dir(backup_dir) {
             for entry in entries {
                 if let Ok(entry) = entry {
                     let path = entry.path();
                     if path.is_file() {
                         if let Some(file_name) = path.file_name().and_then(|n| n.to_str()) {
-                            if file_name.starts_with("auto_backup_") && file_name.ends_with(".json") {
+                            if crate::backup_archive::is_automatic_backup_file_name(file_name) {
+                                // Elkerüljük a duplikációt
                                 if !seen_files.contains(file_name) {
                                     seen_files.insert(file_name.to_string());
                                     if let Err(e) = fs::remove_file(&path) {
@@ -927,34 +824,10 @@ pub fn delete_all_backups() -> Result<u32, String> {
         }
     }
     
-    // Régi könyvtár feldolgozása (csak macOS és Linux)
-    #[cfg(any(target_os = "macos", target_os = "linux"))]
-    {
-        if backup_dir_old.exists() {
-            if let Ok(entries) = fs::read_dir(&backup_dir_old) {
-                for entry in entries {
-                    if let Ok(entry) = entry {
-                        let path = entry.path();
-                        if path.is_file() {
-                            if let Some(file_name) = path.file_name().and_then(|n| n.to_str()) {
-                                if file_name.starts_with("auto_backup_") && file_name.ends_with(".json") {
-                                    // Elkerüljük a duplikációt
-                                    if !seen_files.contains(file_name) {
-                                        seen_files.insert(file_name.to_string());
-                                        if let Err(e) = fs::remove_file(&path) {
-                                            logger::log_warn(&format!("Nem sikerült törölni a backup fájlt: {} - {}", path.display(), e));
-                                        } else {
-                                            deleted_count += 1;
-                                            logger::log_info(&format!("Backup fájl törölve: {}", path.display()));
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
-            }
-        }
+    // Inkrementális snapshotok (backups/snapshots)
+    match crate::backup_snapshots::delete_all_snapshots() {
+        Ok(count) => deleted_count += count,
+        Err(e) => logger::log_warn(&e),
     }
     
     Ok(deleted_count)
@@ -1153,53 +1026,34 @@ pub fn get_performance_metrics() -> Result<serde_json::Value, String> {
 }
 
 /// Ellenőrzi, hogy egy fájl létezik-e az alkalmazás adatkönyvtárában
+/// A data.json a Store tényleges helyén (lásd data_store::stored_file_path, titkosított adattárolónál a data.json.vault) kerül ellenőrzésre
 #[tauri::command]
 pub async fn check_file_exists(file_path: String) -> Result<bool, String> {
     use std::path::PathBuf;
-    use dirs::data_local_dir;
-    
-    let app_name = "3DPrinterCalcApp";
-    let data_dir = data_local_dir()
-        .ok_or("Nem sikerült meghatározni az adatkönyvtárat")?
-        .join(app_name);
     
     let file_path_buf = PathBuf::from(&file_path);
     let full_path = if file_path_buf.is_absolute() {
         file_path_buf
+    } else if file_path == crate::paths::STORE_FILE_NAME {
+        crate::data_store::stored_file_path()?
     } else {
-        data_dir.join(&file_path)
+        crate::paths::data_root()?.join(&file_path)
     };
     
-    // Ellenőrizzük mindkét lehetséges helyet (régi és új bundle ID)
-    let old_bundle_dir = data_local_dir()
-        .ok_or("Nem sikerült meghatározni az adatkönyvtárat")?
-        .join("com.lekszikov.3dprintercalcapp");
-    
-    let new_path = full_path.clone();
-    let old_path = old_bundle_dir.join(&file_path);
+    // Ha az új helyen nem található, próbáljuk meg a régi bundle ID helyen (csak macOS, a migráció előtt)
+    let old_path = crate::paths::legacy_root().map(|dir| dir.join(&file_path));
     
-    // Ha az új helyen nem található, próbáljuk meg a régi helyen
-    if new_path.exists() {
-        Ok(true)
-    } else if cfg!(target_os = "macos") && old_path.exists() {
-        Ok(true)
-    } else {
-        Ok(false)
-    }
+    Ok(full_path.exists() || (cfg!(target_os = "macos") && old_path.is_some_and(|p| p.exists())))
 }
 
 /// Audit log entry írása
 #[tauri::command]
 pub fn write_audit_log(entry: serde_json::Value) -> Result<(), String> {
-    use dirs;
     use std::fs::{self, OpenOptions};
     use std::io::Write;
     use chrono::Local;
     
-    let audit_dir = dirs::data_local_dir()
-        .ok_or_else(|| "Nem található data directory".to_string())?
-        .join("3DPrinterCalcApp")
-        .join("audit_logs");
+    let audit_dir = crate::paths::audit_logs_dir()?;
     
     // Létrehozzuk az audit könyvtárat, ha nem létezik
     if !audit_dir.exists() {
@@ -1222,6 +1076,9 @@ pub fn write_audit_log(entry: serde_json::Value) -> Result<(), String> {
     let json_line = serde_json::to_string(&entry)
         .map_err(|e| format!("Nem sikerült JSON formátumra alakítani: {}", e))?;
     
+    // Titkosított adattároló esetén a sort titkosítva írjuk
+    let json_line = crate::vault::seal_line(&json_line)?;
+    
     file.write_all(json_line.as_bytes())
         .map_err(|e| format!("Nem sikerült írni az audit log fájlba: {}", e))?;
     file.write_all(b"\n")
@@ -1236,14 +1093,10 @@ pub fn write_audit_log(entry: serde_json::Value) -> Result<(), String> {
 /// Audit log fájlok listázása
 #[tauri::command]
 pub fn list_audit_logs() -> Result<Vec<(String, String, u64)>, String> {
-    use dirs;
     use std::fs;
     use chrono::NaiveDate;
     
-    let audit_dir = dirs::data_local_dir()
-        .ok_or_else(|| "Nem található data directory".to_string())?
-        .join("3DPrinterCalcApp")
-        .join("audit_logs");
+    let audit_dir = crate::paths::audit_logs_dir()?;
     
     if !audit_dir.exists() {
         return Ok(Vec::new());
@@ -1294,10 +1147,7 @@ pub fn read_audit_log_file(file_path: String) -> Result<String, String> {
     use std::path::Path;
     
     // Biztonsági ellenőrzés: csak audit könyvtárban lévő fájlokat olvashatunk
-    let audit_dir = dirs::data_local_dir()
-        .ok_or_else(|| "Nem található data directory".to_string())?
-        .join("3DPrinterCalcApp")
-        .join("audit_logs");
+    let audit_dir = crate::paths::audit_logs_dir()?;
     
     let requested_path = Path::new(&file_path);
     
@@ -1318,6 +1168,9 @@ pub fn read_audit_log_file(file_path: String) -> Result<String, String> {
     let content = fs::read_to_string(requested_path)
         .map_err(|e| format!("Nem sikerült beolvasni az audit log fájlt: {}", e))?;
     
+    // Titkosított sorok visszafejtése (zárolt adattároló esetén hiba)
+    let content = crate::vault::open_lines(&content)?;
+    
     logger::log_info(&format!("Audit log fájl beolvasva: {}", file_path));
     
     Ok(content)
@@ -1326,14 +1179,10 @@ pub fn read_audit_log_file(file_path: String) -> Result<String, String> {
 /// Régi audit log fájlok törlése
 #[tauri::command]
 pub fn delete_old_audit_logs(days: u32) -> Result<u32, String> {
-    use dirs;
     use std::fs;
     use chrono::NaiveDate;
     
-    let audit_dir = dirs::data_local_dir()
-        .ok_or_else(|| "Nem található data directory".to_string())?
-        .join("3DPrinterCalcApp")
-        .join("audit_logs");
+    let audit_dir = crate::paths::audit_logs_dir()?;
     
     if !audit_dir.exists() {
         return Ok(0);
@@ -1380,13 +1229,9 @@ pub fn delete_old_audit_logs(days: u32) -> Result<u32, String> {
 /// Audit log könyvtár útvonalának lekérése
 #[tauri::command]
 pub fn get_audit_log_directory_path() -> Result<String, String> {
-    use dirs;
     use std::fs;
     
-    let audit_dir = dirs::data_local_dir()
-        .ok_or_else(|| "Nem található data directory".to_string())?
-        .join("3DPrinterCalcApp")
-        .join("audit_logs");
+    let audit_dir = crate::paths::audit_logs_dir()?;
     
     // Létrehozzuk a könyvtárat, ha nem létezik
     if !audit_dir.exists() {
@@ -0,0 +1,760 @@
+use crate::data_store;
+use crate::logger;
+use crate::restore::RECORD_REFERENCES;
+use chrono::Datelike;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::collections::{HashMap, HashSet};
+use tauri::AppHandle;
+
+/// Numerikus azonosítójú entitás típusok (ezeknél ellenőrizzük az azonosítók egyediségét)
+const ID_ENTITY_TYPES: [&str; 5] = ["printers", "offers", "customers", "projects", "tasks"];
+
+/// Nem lehet negatív: (entitás típus, mező útvonal); a "filaments[]." előtag az árajánlat filament tételeire vonatkozik
+const NON_NEGATIVE_FIELDS: [(&str, &str); 12] = [
+    ("filaments", "weight"),
+    ("filaments", "pricePerKg"),
+    ("filaments", "density"),
+    ("printers", "power"),
+    ("printers", "usageCost"),
+    ("offers", "totalPrintTimeHours"),
+    ("offers", "costs.totalCost"),
+    ("offers", "filaments[].usedGrams"),
+    ("offers", "filaments[].pricePerKg"),
+    ("projects", "budget"),
+    ("projects", "actualCost"),
+    ("projects", "progress"),
+];
+
+/// Dátum mezők: (entitás típus, mező, kötelező)
+const DATE_FIELDS: [(&str, &str, bool); 15] = [
+    ("offers", "date", true),
+    ("offers", "printDueDate", false),
+    ("offers", "statusUpdatedAt", false),
+    ("customers", "createdAt", true),
+    ("customers", "updatedAt", true),
+    ("projects", "createdAt", true),
+    ("projects", "updatedAt", true),
+    ("projects", "deadline", false),
+    ("projects", "startedAt", false),
+    ("projects", "completedAt", false),
+    ("tasks", "createdAt", true),
+    ("tasks", "updatedAt", true),
+    ("tasks", "dueDate", true),
+    ("tasks", "completedAt", false),
+    ("tasks", "nextRecurrenceDate", false),
+];
+
+/// Ennél korábbi dátum biztosan hibás (az alkalmazás nem létezett)
+const EARLIEST_PLAUSIBLE_YEAR: i32 = 2000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IssueSeverity {
+    Error,
+    Warning,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum IssueKind {
+    DanglingReference,
+    MissingId,
+    DuplicateId,
+    DuplicateKey,
+    UnlinkedCustomer,
+    NegativeValue,
+    OutOfRange,
+    InvalidDate,
+    ImpossibleDate,
+}
+
+/// Biztonságos, automatikusan alkalmazható javítás
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "action", rename_all = "camelCase")]
+pub enum Repair {
+    /// A mező törlése (pl. nem létező rekordra mutató hivatkozás, értelmezhetetlen opcionális dátum)
+    ClearField { field: String },
+    /// Egy azonosító eltávolítása egy azonosító listából (pl. Project.offerIds)
+    RemoveFromList { field: String, value: Value },
+    /// A mező új értéke (pl. név alapján újrakapcsolt hivatkozás, új egyedi azonosító)
+    SetField { field: String, value: Value },
+}
+
+/// Egy talált probléma
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConsistencyIssue {
+    /// Stabil azonosító: egy későbbi javítási kérés erre hivatkozik
+    pub id: String,
+    pub severity: IssueSeverity,
+    pub kind: IssueKind,
+    pub entity_type: String,
+    pub record_key: String,
+    pub field: Option<String>,
+    pub message: String,
+    pub repair: Option<Repair>,
+    /// A rekord helye a listában (csak a javításhoz, ugyanazon futáson belül érvényes)
+    #[serde(skip)]
+    index: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConsistencyReport {
+    pub checked_at: String,
+    pub records_checked: usize,
+    pub errors: usize,
+    pub warnings: usize,
+    pub repairable: usize,
+    pub issues: Vec<ConsistencyIssue>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepairReport {
+    pub dry_run: bool,
+    /// Az alkalmazott (dry-run esetén alkalmazandó) javítások
+    pub repaired: Vec<ConsistencyIssue>,
+    /// A kért, de már nem létező vagy nem javítható problémák azonosítói
+    pub skipped: Vec<String>,
+    pub safety_backup: Option<String>,
+    /// Ellenőrzés a javítások után
+    pub report: ConsistencyReport,
+}
+
+fn records<'a>(data: &'a Map<String, Value>, entity_type: &str) -> &'a [Value] {
+    data.get(entity_type)
+        .and_then(|v| v.as_array())
+        .map(|a| a.as_slice())
+        .unwrap_or_default()
+}
+
+fn record_key_of(entity_type: &str, record: &Value, index: usize) -> String {
+    data_store::record_key(entity_type, record).unwrap_or_else(|| format!("#{}", index))
+}
+
+/// Név összevetéshez: kisbetű, összevont szóközök
+fn normalize_name(name: &str) -> String {
+    name.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase()
+}
+
+/// ISO dátum / időpont vagy csak dátum (dátum választó mezők)
+fn parse_date(value: &str) -> Option<chrono::DateTime<chrono::Utc>> {
+    if let Ok(parsed) = chrono::DateTime::parse_from_rfc3339(value) {
+        return Some(parsed.with_timezone(&chrono::Utc));
+    }
+    if let Ok(parsed) = chrono::NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f") {
+        return Some(parsed.and_utc());
+    }
+    chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|date| date.and_utc())
+}
+
+/// Mező értéke pont-jelöléssel (pl. "costs.totalCost")
+fn field_value<'a>(record: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.').try_fold(record, |value, part| value.get(part))
+}
+
+struct Checker {
+    issues: Vec<ConsistencyIssue>,
+    occurrences: HashMap<String, usize>,
+}
+
+impl Checker {
+    #[allow(clippy::too_many_arguments)]
+    fn push(
+        &mut self,
+        severity: IssueSeverity,
+        kind: IssueKind,
+        entity_type: &str,
+        record_key: &str,
+        index: usize,
+        field: Option<&str>,
+        message: String,
+        repair: Option<Repair>,
+    ) {
+        let base = format!("{:?}:{}:{}:{}", kind, entity_type, record_key, field.unwrap_or_default());
+        // Azonos kulcsú rekordoknál (duplikált azonosító) az előfordulás sorszáma különbözteti meg
+        let occurrence = self.occurrences.entry(base.clone()).or_insert(0);
+        *occurrence += 1;
+        let id = if *occurrence == 1 { base } else { format!("{}#{}", base, occurrence) };
+        self.issues.push(ConsistencyIssue {
+            id,
+            severity,
+            kind,
+            entity_type: entity_type.to_string(),
+            record_key: record_key.to_string(),
+            field: field.map(|f| f.to_string()),
+            message,
+            repair,
+            index,
+        });
+    }
+}
+
+fn check_duplicates(data: &Map<String, Value>, checker: &mut Checker) {
+    for entity_type in ID_ENTITY_TYPES {
+        let list = records(data, entity_type);
+        let mut next_id = list
+            .iter()
+            .filter_map(|record| record.get("id").and_then(|id| id.as_i64()))
+            .max()
+            .unwrap_or(0);
+        let mut seen = HashSet::new();
+        for (index, record) in list.iter().enumerate() {
+            let Some(key) = data_store::record_key(entity_type, record) else {
+                next_id += 1;
+                checker.push(
+                    IssueSeverity::Error,
+                    IssueKind::MissingId,
+                    entity_type,
+                    &format!("#{}", index),
+                    index,
+                    Some("id"),
+                    "Hiányzó azonosító".to_string(),
+                    Some(Repair::SetField {
+                        field: "id".to_string(),
+                        value: Value::from(next_id),
+                    }),
+                );
+                continue;
+            };
+            if seen.insert(key.clone()) {
+                continue;
+            }
+            // A későbbi előfordulás új azonosítót kap, a meglévő hivatkozások az elsőre mutatnak tovább
+            let repair = record.get("id").and_then(|id| id.as_i64()).map(|_| {
+                next_id += 1;
+                Repair::SetField {
+                    field: "id".to_string(),
+                    value: Value::from(next_id),
+                }
+            });
+            checker.push(
+                IssueSeverity::Error,
+                IssueKind::DuplicateId,
+                entity_type,
+                &key,
+                index,
+                Some("id"),
+                format!("Duplikált azonosító: {}", key),
+                repair,
+            );
+        }
+    }
+
+    // Filamenteknél a márka / típus / szín a kulcs - összevonni nem biztonságos, csak jelezzük
+    let mut seen = HashSet::new();
+    for (index, record) in records(data, "filaments").iter().enumerate() {
+        if let Some(key) = data_store::record_key("filaments", record) {
+            if !seen.insert(key.clone()) {
+                checker.push(
+                    IssueSeverity::Warning,
+                    IssueKind::DuplicateKey,
+                    "filaments",
+                    &key,
+                    index,
+                    None,
+                    "Azonos márkájú, típusú és színű filament többször szerepel".to_string(),
+                    None,
+                );
+            }
+        }
+    }
+}
+
+fn check_references(data: &Map<String, Value>, checker: &mut Checker) {
+    let ids_of = |entity_type: &str| -> HashSet<i64> {
+        records(data, entity_type)
+            .iter()
+            .filter_map(|record| record.get("id").and_then(|id| id.as_i64()))
+            .collect()
+    };
+
+    // Nyomtató név -> azonosító (csak egyértelmű név alapján kapcsolunk újra)
+    let mut printers_by_name: HashMap<String, Vec<i64>> = HashMap::new();
+    for printer in records(data, "printers") {
+        if let (Some(name), Some(id)) = (
+            printer.get("name").and_then(|v| v.as_str()),
+            printer.get("id").and_then(|v| v.as_i64()),
+        ) {
+            printers_by_name.entry(normalize_name(name)).or_default().push(id);
+        }
+    }
+
+    for (source, field, target) in RECORD_REFERENCES {
+        let existing = ids_of(target);
+        for (index, record) in records(data, source).iter().enumerate() {
+            let key = record_key_of(source, record, index);
+            match record.get(field) {
+                None | Some(Value::Null) => {}
+                Some(Value::Array(ids)) => {
+                    for id in ids {
+                        if id.as_i64().is_some_and(|id| existing.contains(&id)) {
+                            continue;
+                        }
+                        checker.push(
+                            IssueSeverity::Error,
+                            IssueKind::DanglingReference,
+                            source,
+                            &key,
+                            index,
+                            Some(&format!("{}[{}]", field, id)),
+                            format!("A(z) {} mező nem létező rekordra hivatkozik: {}", field, id),
+                            Some(Repair::RemoveFromList {
+                                field: field.to_string(),
+                                value: id.clone(),
+                            }),
+                        );
+                    }
+                }
+                Some(id) => {
+                    if id.as_i64().is_some_and(|id| existing.contains(&id)) {
+                        continue;
+                    }
+                    // Árajánlatnál a nyomtató neve is el van mentve - egyértelmű egyezésnél újrakapcsolás
+                    let relinked = (source == "offers" && field == "printerId")
+                        .then(|| record.get("printerName").and_then(|v| v.as_str()))
+                        .flatten()
+                        .and_then(|name| printers_by_name.get(&normalize_name(name)))
+                        .filter(|ids| ids.len() == 1)
+                        .map(|ids| ids[0]);
+                    let repair = match relinked {
+                        Some(new_id) => Repair::SetField {
+                            field: field.to_string(),
+                            value: Value::from(new_id),
+                        },
+                        None => Repair::ClearField { field: field.to_string() },
+                    };
+                    checker.push(
+                        IssueSeverity::Error,
+                        IssueKind::DanglingReference,
+                        source,
+                        &key,
+                        index,
+                        Some(field),
+                        match relinked {
+                            Some(new_id) => format!(
+                                "A(z) {} mező nem létező rekordra hivatkozik: {} (név alapján: {})",
+                                field, id, new_id
+                            ),
+                            None => format!("A(z) {} mező nem létező rekordra hivatkozik: {}", field, id),
+                        },
+                        Some(repair),
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Az árajánlatok csak névvel kapcsolódnak az ügyfelekhez: eltérő írásmódnál a név egységesítése
+fn check_customer_links(data: &Map<String, Value>, checker: &mut Checker) {
+    let customers = records(data, "customers");
+    // Ügyfélkezelés nélkül használt alkalmazásnál nincs mit összevetni
+    if customers.is_empty() {
+        return;
+    }
+
+    let mut exact = HashSet::new();
+    let mut by_normalized: HashMap<String, Vec<&str>> = HashMap::new();
+    for customer in customers {
+        if let Some(name) = customer.get("name").and_then(|v| v.as_str()) {
+            exact.insert(name);
+            by_normalized.entry(normalize_name(name)).or_default().push(name);
+        }
+    }
+
+    for (index, offer) in records(data, "offers").iter().enumerate() {
+        let Some(name) = offer
+            .get("customerName")
+            .and_then(|v| v.as_str())
+            .filter(|name| !name.trim().is_empty())
+        else {
+            continue;
+        };
+        if exact.contains(name) {
+            continue;
+        }
+        let key = record_key_of("offers", offer, index);
+        match by_normalized.get(&normalize_name(name)).filter(|names| names.len() == 1) {
+            Some(names) => checker.push(
+                IssueSeverity::Warning,
+                IssueKind::UnlinkedCustomer,
+                "offers",
+                &key,
+                index,
+                Some("customerName"),
+                format!("Az ügyfél neve eltérő írásmóddal szerepel: \"{}\" → \"{}\"", name, names[0]),
+                Some(Repair::SetField {
+                    field: "customerName".to_string(),
+                    value: Value::from(names[0]),
+                }),
+            ),
+            None => checker.push(
+                IssueSeverity::Warning,
+                IssueKind::UnlinkedCustomer,
+                "offers",
+                &key,
+                index,
+                Some("customerName"),
+                format!("Nincs ilyen nevű ügyfél: \"{}\"", name),
+                None,
+            ),
+        }
+    }
+}
+
+fn check_numbers(data: &Map<String, Value>, checker: &mut Checker) {
+    for (entity_type, path) in NON_NEGATIVE_FIELDS {
+        for (index, record) in records(data, entity_type).iter().enumerate() {
+            let key = record_key_of(entity_type, record, index);
+            let values: Vec<f64> = match path.strip_prefix("filaments[].") {
+                Some(inner) => record
+                    .get("filaments")
+                    .and_then(|v| v.as_array())
+                    .map(|items| items.iter().filter_map(|item| item.get(inner).and_then(|v| v.as_f64())).collect())
+                    .unwrap_or_default(),
+                None => field_value(record, path).and_then(|v| v.as_f64()).into_iter().collect(),
+            };
+            if values.iter().any(|value| *value < 0.0) {
+                checker.push(
+                    IssueSeverity::Error,
+                    IssueKind::NegativeValue,
+                    entity_type,
+                    &key,
+                    index,
+                    Some(path),
+                    format!("Negatív érték: {}", path),
+                    None,
+                );
+            }
+        }
+    }
+
+    // A projekt készültsége százalék
+    for (index, record) in records(data, "projects").iter().enumerate() {
+        if let Some(progress) = record.get("progress").and_then(|v| v.as_f64()) {
+            if progress > 100.0 {
+                checker.push(
+                    IssueSeverity::Warning,
+                    IssueKind::OutOfRange,
+                    "projects",
+                    &record_key_of("projects", record, index),
+                    index,
+                    Some("progress"),
+                    format!("A készültség 100% feletti: {}", progress),
+                    Some(Repair::SetField {
+                        field: "progress".to_string(),
+                        value: Value::from(100),
+                    }),
+                );
+            }
+        }
+    }
+}
+
+fn check_dates(data: &Map<String, Value>, checker: &mut Checker) {
+    let latest_plausible = chrono::Utc::now() + chrono::Duration::days(1);
+    let mut parsed: HashMap<(&str, usize, &str), chrono::DateTime<chrono::Utc>> = HashMap::new();
+
+    for (entity_type, field, required) in DATE_FIELDS {
+        for (index, record) in records(data, entity_type).iter().enumerate() {
+            let key = record_key_of(entity_type, record, index);
+            let value = match record.get(field) {
+                None | Some(Value::Null) => {
+                    if required {
+                        checker.push(
+                            IssueSeverity::Warning,
+                            IssueKind::InvalidDate,
+                            entity_type,
+                            &key,
+                            index,
+                            Some(field),
+                            format!("Hiányzó dátum: {}", field),
+                            None,
+                        );
+                    }
+                    continue;
+                }
+                Some(Value::String(value)) if value.is_empty() && !required => continue,
+                Some(value) => value,
+            };
+            let Some(date) = value.as_str().and_then(parse_date) else {
+                checker.push(
+                    if required { IssueSeverity::Error } else { IssueSeverity::Warning },
+                    IssueKind::InvalidDate,
+                    entity_type,
+                    &key,
+                    index,
+                    Some(field),
+                    format!("Értelmezhetetlen dátum ({}): {}", field, value),
+                    (!required).then(|| Repair::ClearField { field: field.to_string() }),
+                );
+                continue;
+            };
+            if date.year() < EARLIEST_PLAUSIBLE_YEAR {
+                checker.push(
+                    IssueSeverity::Warning,
+                    IssueKind::ImpossibleDate,
+                    entity_type,
+                    &key,
+                    index,
+                    Some(field),
+                    format!("Valószínűtlenül korai dátum ({}): {}", field, value),
+                    None,
+                );
+            }
+            // Határidő és ismétlődés lehet a jövőben, a rögzítés időpontja nem
+            if matches!(field, "createdAt" | "updatedAt" | "completedAt" | "startedAt" | "date") && date > latest_plausible {
+                checker.push(
+                    IssueSeverity::Warning,
+                    IssueKind::ImpossibleDate,
+                    entity_type,
+                    &key,
+                    index,
+                    Some(field),
+                    format!("Jövőbeli dátum ({}): {}", field, value),
+                    None,
+                );
+            }
+            parsed.insert((entity_type, index, field), date);
+        }
+    }
+
+    // Időrend: módosítás / befejezés nem lehet a létrehozás előtt
+    for entity_type in ["customers", "projects", "tasks"] {
+        for (index, record) in records(data, entity_type).iter().enumerate() {
+            let Some(created) = parsed.get(&(entity_type, index, "createdAt")) else {
+                continue;
+            };
+            let key = record_key_of(entity_type, record, index);
+            if parsed.get(&(entity_type, index, "updatedAt")).is_some_and(|updated| updated < created) {
+                checker.push(
+                    IssueSeverity::Warning,
+                    IssueKind::ImpossibleDate,
+                    entity_type,
+                    &key,
+                    index,
+                    Some("updatedAt"),
+                    "A módosítás dátuma korábbi a létrehozásénál".to_string(),
+                    record.get("createdAt").map(|created| Repair::SetField {
+                        field: "updatedAt".to_string(),
+                        value: created.clone(),
+                    }),
+                );
+            }
+            if parsed.get(&(entity_type, index, "completedAt")).is_some_and(|completed| completed < created) {
+                checker.push(
+                    IssueSeverity::Warning,
+                    IssueKind::ImpossibleDate,
+                    entity_type,
+                    &key,
+                    index,
+                    Some("completedAt"),
+                    "A befejezés dátuma korábbi a létrehozásénál".to_string(),
+                    None,
+                );
+            }
+        }
+    }
+    for (index, record) in records(data, "projects").iter().enumerate() {
+        let started = parsed.get(&("projects", index, "startedAt"));
+        let completed = parsed.get(&("projects", index, "completedAt"));
+        if let (Some(started), Some(completed)) = (started, completed) {
+            if completed < started {
+                checker.push(
+                    IssueSeverity::Warning,
+                    IssueKind::ImpossibleDate,
+                    "projects",
+                    &record_key_of("projects", record, index),
+                    index,
+                    Some("completedAt"),
+                    "A projekt befejezése korábbi a kezdésénél".to_string(),
+                    None,
+                );
+            }
+        }
+    }
+}
+
+/// Az összes ellenőrzés futtatása a megadott adatokon
+pub fn check(data: &Map<String, Value>) -> ConsistencyReport {
+    let mut checker = Checker {
+        issues: Vec::new(),
+        occurrences: HashMap::new(),
+    };
+    check_duplicates(data, &mut checker);
+    check_references(data, &mut checker);
+    check_customer_links(data, &mut checker);
+    check_numbers(data, &mut checker);
+    check_dates(data, &mut checker);
+
+    let issues = checker.issues;
+    ConsistencyReport {
+        checked_at: chrono::Utc::now().to_rfc3339(),
+        records_checked: data_store::ENTITY_KEYS
+            .iter()
+            .map(|entity_type| records(data, entity_type).len())
+            .sum(),
+        errors: issues.iter().filter(|i| i.severity == IssueSeverity::Error).count(),
+        warnings: issues.iter().filter(|i| i.severity == IssueSeverity::Warning).count(),
+        repairable: issues.iter().filter(|i| i.repair.is_some()).count(),
+        issues,
+    }
+}
+
+fn apply_repair(data: &mut Map<String, Value>, issue: &ConsistencyIssue) -> bool {
+    let Some(repair) = &issue.repair else {
+        return false;
+    };
+    let Some(record) = data
+        .get_mut(&issue.entity_type)
+        .and_then(|v| v.as_array_mut())
+        .and_then(|list| list.get_mut(issue.index))
+        .and_then(|record| record.as_object_mut())
+    else {
+        return false;
+    };
+    match repair {
+        Repair::ClearField { field } => record.remove(field).is_some(),
+        Repair::RemoveFromList { field, value } => match record.get_mut(field).and_then(|v| v.as_array_mut()) {
+            Some(list) => {
+                let before = list.len();
+                list.retain(|item| item != value);
+                list.len() != before
+            }
+            None => false,
+        },
+        Repair::SetField { field, value } => {
+            record.insert(field.clone(), value.clone());
+            true
+        }
+    }
+}
+
+/// Adatkonzisztencia ellenőrzése: nem létező rekordokra mutató hivatkozások, duplikált azonosítók,
+/// ügyfél nélküli árajánlatok, negatív súlyok / árak, értelmezhetetlen vagy lehetetlen dátumok
+#[tauri::command]
+pub fn check_data_consistency() -> Result<ConsistencyReport, String> {
+    if crate::vault::is_locked() {
+        return Err("Az adattároló zárolva van, előbb oldja fel".to_string());
+    }
+    let data = data_store::read_store()?.unwrap_or_default();
+    Ok(check(&data))
+}
+
+/// A javítható problémák javítása (azonosítók nélkül az összes javítható)
+/// Az ellenőrzés a friss adatokon újra lefut, így csak a még fennálló problémák javulnak
+/// Alkalmazás előtt biztonsági mentés készül, a frontend a "data-store-changed" eseményre újratölti az adatokat
+#[tauri::command]
+pub fn repair_data_consistency(
+    app: AppHandle,
+    issue_ids: Option<Vec<String>>,
+    dry_run: bool,
+) -> Result<RepairReport, String> {
+    if crate::vault::is_locked() {
+        return Err("Az adattároló zárolva van, előbb oldja fel".to_string());
+    }
+    let mut data = data_store::read_store()?.unwrap_or_default();
+    let report = check(&data);
+
+    let selected: Option<HashSet<&String>> = issue_ids.as_ref().map(|ids| ids.iter().collect());
+    let mut repaired = Vec::new();
+    for issue in &report.issues {
+        if selected.as_ref().is_some_and(|ids| !ids.contains(&issue.id)) {
+            continue;
+        }
+        if apply_repair(&mut data, issue) {
+            repaired.push(issue.clone());
+        }
+    }
+    let repaired_ids: HashSet<&String> = repaired.iter().map(|issue| &issue.id).collect();
+    let skipped = issue_ids
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|id| !repaired_ids.contains(id))
+        .collect();
+
+    let mut safety_backup = None;
+    if !dry_run && !repaired.is_empty() {
+        safety_backup = crate::restore::apply_store_data(&app, &data, "consistency")?
+            .map(|path| path.to_string_lossy().to_string());
+        logger::log_info(&format!("Adatkonzisztencia: {} probléma javítva", repaired.len()));
+    }
+
+    Ok(RepairReport {
+        dry_run,
+        repaired,
+        skipped,
+        safety_backup,
+        report: check(&data),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn sample_data() -> Map<String, Value> {
+        json!({
+            "printers": [{ "id": 1, "name": "Prusa MK4" }],
+            "offers": [
+                { "id": 10, "printerId": 99, "printerName": "prusa  MK4" },
+                { "id": 11, "printerId": 98, "printerName": "Ismeretlen" },
+                { "id": 12, "printerId": 1 }
+            ],
+            "projects": [{ "id": 20, "offerIds": [10, 404] }],
+            "tasks": [{ "id": 30, "relatedOfferId": 405, "relatedProjectId": 20 }]
+        })
+        .as_object()
+        .cloned()
+        .unwrap()
+    }
+
+    fn dangling(report: &ConsistencyReport) -> Vec<&ConsistencyIssue> {
+        report
+            .issues
+            .iter()
+            .filter(|issue| issue.kind == IssueKind::DanglingReference)
+            .collect()
+    }
+
+    #[test]
+    fn dangling_references_are_detected_and_repaired() {
+        let mut data = sample_data();
+        let report = check(&data);
+        let issues = dangling(&report);
+        let found: Vec<(&str, &str, Option<&str>)> = issues
+            .iter()
+            .map(|issue| (issue.entity_type.as_str(), issue.record_key.as_str(), issue.field.as_deref()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("offers", "10", Some("printerId")),
+                ("offers", "11", Some("printerId")),
+                ("projects", "20", Some("offerIds[404]")),
+                ("tasks", "30", Some("relatedOfferId")),
+            ]
+        );
+        // Egyértelmű nyomtató név esetén újrakapcsolás, egyébként a hivatkozás törlése
+        assert!(matches!(&issues[0].repair, Some(Repair::SetField { value, .. }) if *value == json!(1)));
+        assert!(matches!(&issues[1].repair, Some(Repair::ClearField { .. })));
+        assert!(matches!(&issues[2].repair, Some(Repair::RemoveFromList { value, .. }) if *value == json!(404)));
+
+        for issue in issues {
+            assert!(apply_repair(&mut data, issue), "javítás: {}", issue.id);
+        }
+        assert!(dangling(&check(&data)).is_empty());
+        assert_eq!(data["offers"][0]["printerId"], json!(1));
+        assert!(data["offers"][1].get("printerId").is_none());
+        assert_eq!(data["projects"][0]["offerIds"], json!([10]));
+        assert!(data["tasks"][0].get("relatedOfferId").is_none());
+        assert_eq!(data["tasks"][0]["relatedProjectId"], json!(20));
+    }
+}
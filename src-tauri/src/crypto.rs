@@ -0,0 +1,124 @@
+use aes_gcm::aead::{Aead, KeyInit, Payload};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use rand::rngs::OsRng;
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+
+/// Kulcs levezetés algoritmusa (metaadatokba írjuk, hogy később váltani lehessen)
+pub const KDF_ALGORITHM: &str = "PBKDF2-HMAC-SHA256";
+
+/// Titkosítási algoritmus neve (metaadatokhoz)
+pub const CIPHER_ALGORITHM: &str = "AES-256-GCM";
+
+/// PBKDF2 iterációk száma (OWASP 2023 ajánlás PBKDF2-HMAC-SHA256-hoz)
+pub const KDF_ITERATIONS: u32 = 600_000;
+
+pub const SALT_LEN: usize = 16;
+pub const NONCE_LEN: usize = 12;
+pub const KEY_LEN: usize = 32;
+
+/// Titkosított fájlok fejléce, ebből ismerjük fel őket
+pub const ENCRYPTED_FILE_MAGIC: &[u8; 8] = b"3DPCENC1";
+
+/// Minimális jelmondat hossz
+pub const MIN_PASSPHRASE_LEN: usize = 8;
+
+pub type EncryptionKey = [u8; KEY_LEN];
+
+/// Véletlen só generálása a kulcs levezetéshez
+pub fn generate_salt() -> [u8; SALT_LEN] {
+    let mut salt = [0u8; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+    salt
+}
+
+/// Kulcs levezetése jelmondatból PBKDF2-HMAC-SHA256-tal
+pub fn derive_key(passphrase: &str, salt: &[u8], iterations: u32) -> EncryptionKey {
+    let mut key = [0u8; KEY_LEN];
+    pbkdf2::pbkdf2_hmac::<Sha256>(passphrase.as_bytes(), salt, iterations, &mut key);
+    key
+}
+
+/// Jelmondat ellenőrzése (hossz)
+pub fn validate_passphrase(passphrase: &str) -> Result<(), String> {
+    if passphrase.chars().count() < MIN_PASSPHRASE_LEN {
+        return Err(format!(
+            "A jelmondatnak legalább {} karakter hosszúnak kell lennie",
+            MIN_PASSPHRASE_LEN
+        ));
+    }
+    Ok(())
+}
+
+/// AES-256-GCM titkosítás, kimenet: nonce (12 byte) || ciphertext + tag
+pub fn encrypt(key: &EncryptionKey, plaintext: &[u8]) -> Result<Vec<u8>, String> {
+    encrypt_with_aad(key, plaintext, &[])
+}
+
+/// AES-256-GCM titkosítás kapcsolt adattal (AAD): az `aad` nem titkosított, de a tag védi,
+/// így a módosítása visszafejtési hibát okoz (pl. a titkosítatlan fájl fejléc)
+pub fn encrypt_with_aad(key: &EncryptionKey, plaintext: &[u8], aad: &[u8]) -> Result<Vec<u8>, String> {
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(key));
+    let mut nonce = [0u8; NONCE_LEN];
+    OsRng.fill_bytes(&mut nonce);
+
+    let ciphertext = cipher
+        .encrypt(Nonce::from_slice(&nonce), Payload { msg: plaintext, aad })
+        .map_err(|_| "Titkosítás sikertelen".to_string())?;
+
+    let mut output = Vec::with_capacity(NONCE_LEN + ciphertext.len());
+    output.extend_from_slice(&nonce);
+    output.extend_from_slice(&ciphertext);
+    Ok(output)
+}
+
+/// AES-256-GCM visszafejtés (bemenet: nonce || ciphertext + tag)
+/// Hibás kulcs vagy sérült adat esetén hibát ad (a GCM tag ellenőrzés miatt)
+pub fn decrypt(key: &EncryptionKey, data: &[u8]) -> Result<Vec<u8>, String> {
+    decrypt_with_aad(key, data, &[])
+}
+
+/// AES-256-GCM visszafejtés kapcsolt adattal (ugyanazzal az `aad`-dal, amivel titkosítottuk)
+pub fn decrypt_with_aad(key: &EncryptionKey, data: &[u8], aad: &[u8]) -> Result<Vec<u8>, String> {
+    if data.len() < NONCE_LEN {
+        return Err("A titkosított adat túl rövid".to_string());
+    }
+
+    let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(key));
+    cipher
+        .decrypt(Nonce::from_slice(nonce), Payload { msg: ciphertext, aad })
+        .map_err(|_| "Visszafejtés sikertelen: hibás jelmondat vagy sérült adat".to_string())
+}
+
+/// Titkosított fájl tartalom: fejléc || nonce || ciphertext + tag
+pub fn encrypt_file_bytes(key: &EncryptionKey, plaintext: &[u8]) -> Result<Vec<u8>, String> {
+    let mut output = ENCRYPTED_FILE_MAGIC.to_vec();
+    output.extend(encrypt(key, plaintext)?);
+    Ok(output)
+}
+
+/// Titkosított fájl tartalom visszafejtése (fejléc ellenőrzéssel)
+pub fn decrypt_file_bytes(key: &EncryptionKey, data: &[u8]) -> Result<Vec<u8>, String> {
+    let payload = data
+        .strip_prefix(ENCRYPTED_FILE_MAGIC.as_slice())
+        .ok_or_else(|| "A fájl nem titkosított vagy ismeretlen formátumú".to_string())?;
+    decrypt(key, payload)
+}
+
+pub fn to_base64(bytes: &[u8]) -> String {
+    BASE64.encode(bytes)
+}
+
+pub fn from_base64(text: &str) -> Result<Vec<u8>, String> {
+    BASE64
+        .decode(text.trim())
+        .map_err(|e| format!("Érvénytelen base64 adat: {}", e))
+}
+
+/// SHA-256 ellenőrzőösszeg hex formátumban
+pub fn sha256_hex(bytes: &[u8]) -> String {
+    hex::encode(Sha256::digest(bytes))
+}
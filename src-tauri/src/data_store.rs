@@ -0,0 +1,100 @@
+use serde_json::{Map, Value};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A Store-ban tárolt entitás listák kulcsai
+pub const ENTITY_KEYS: [&str; 8] = [
+    "printers",
+    "filaments",
+    "offers",
+    "customers",
+    "projects",
+    "tasks",
+    "templates",
+    "priceHistory",
+];
+
+/// A data.json útvonala (a feloldást lásd: paths::store_file_path)
+pub fn store_file_path() -> Result<PathBuf, String> {
+    crate::paths::store_file_path()
+}
+
+/// A Store lemezen lévő fájlja: bekapcsolt titkosított adattárolónál a data.json.vault, különben a data.json
+pub fn stored_file_path() -> Result<PathBuf, String> {
+    if crate::vault::is_enabled() {
+        return crate::vault::encrypted_store_path();
+    }
+    store_file_path()
+}
+
+/// Beolvassa a data.json tartalmát
+/// Bekapcsolt titkosított adattárolónál a data.json.vault-ot fejti vissza memóriába (zárolt állapotban hibát ad)
+/// Ha a fájl nem létezik (első indítás vagy Factory Reset után), `None`-t ad vissza
+pub fn read_store() -> Result<Option<Map<String, Value>>, String> {
+    let content = if crate::vault::is_enabled() {
+        match crate::vault::read_sealed_store()? {
+            Some(content) => content,
+            None => return Ok(None),
+        }
+    } else {
+        let path = store_file_path()?;
+        if !path.exists() {
+            return Ok(None);
+        }
+        fs::read(&path).map_err(|e| format!("Nem sikerült beolvasni a Store fájlt: {}", e))?
+    };
+
+    let value: Value = serde_json::from_slice(&content)
+        .map_err(|e| format!("A Store fájl nem érvényes JSON: {}", e))?;
+
+    match value {
+        Value::Object(map) => Ok(Some(map)),
+        _ => Err("A Store fájl gyökere nem JSON objektum".to_string()),
+    }
+}
+
+/// Kiírja a data.json tartalmát (ugyanabban a formátumban, mint a tauri-plugin-store)
+/// Bekapcsolt titkosított adattárolónál csak a titkosított data.json.vault íródik
+pub fn write_store(data: &Map<String, Value>) -> Result<(), String> {
+    let content = serde_json::to_string_pretty(data)
+        .map_err(|e| format!("Nem sikerült JSON-né alakítani a Store tartalmát: {}", e))?;
+    if crate::vault::is_enabled() {
+        return crate::vault::write_sealed_store(content.as_bytes());
+    }
+    // Atomikus írás: összeomláskor a data.json vagy a régi, vagy az új tartalmat tartalmazza
+    crate::atomic_write::write_atomic(&store_file_path()?, content)
+        .map_err(|e| format!("Nem sikerült írni a Store fájlt: {}", e))
+}
+
+/// Biztonsági másolat a Store lemezen lévő fájljáról (migráció, visszaállítás előtt)
+/// Titkosított adattárolónál a titkosított fájl másolódik (`{név}.json.vault`), így a másolat sem titkosítatlan
+/// Visszaadja a másolat útvonalát, vagy None-t, ha még nincs Store
+pub fn copy_stored_file(target_dir: &Path, name: &str) -> Result<Option<PathBuf>, String> {
+    let source = stored_file_path()?;
+    if !source.exists() {
+        return Ok(None);
+    }
+    fs::create_dir_all(target_dir)
+        .map_err(|e| format!("Nem sikerült létrehozni a könyvtárat: {} - {}", target_dir.display(), e))?;
+
+    let extension = if crate::vault::is_enabled() { "json.vault" } else { "json" };
+    let target = target_dir.join(format!("{}.{}", name, extension));
+    crate::atomic_write::copy_atomic(&source, &target)?;
+    Ok(Some(target))
+}
+
+/// Egy rekord azonosító kulcsa az összehasonlításhoz (visszaállítás, összefésülés)
+/// A filamenteknek nincs `id` mezője, náluk a márka|típus|szín hármas azonosít
+pub fn record_key(entity_type: &str, record: &Value) -> Option<String> {
+    if entity_type == "filaments" {
+        let field = |name: &str| record.get(name).and_then(|v| v.as_str()).unwrap_or_default().trim().to_lowercase();
+        let key = format!("{}|{}|{}", field("brand"), field("type"), field("color"));
+        return (key != "||").then_some(key);
+    }
+
+    match record.get("id")? {
+        Value::Number(id) => Some(id.to_string()),
+        Value::String(id) if !id.is_empty() => Some(id.clone()),
+        _ => None,
+    }
+}
@@ -0,0 +1,1197 @@
+use crate::data_store;
+use crate::logger;
+use chrono::{NaiveDate, NaiveDateTime};
+use rust_xlsxwriter::{Format, FormatBorder, Workbook};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::io::{self, Cursor, Write};
+use std::path::{Path, PathBuf};
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+/// Alapértelmezett profit százalék, ha az árajánlatban nincs megadva (a frontenddel egyezően)
+const DEFAULT_PROFIT_PERCENTAGE: f64 = 30.0;
+
+/// A filament árak (pricePerKg) mindig EUR-ban vannak tárolva
+const FILAMENT_PRICE_CURRENCY: &str = "EUR";
+
+/// Kimeneti formátum
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    Xlsx,
+    Csv,
+    Ods,
+}
+
+impl ExportFormat {
+    fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Xlsx => "xlsx",
+            ExportFormat::Csv => "csv",
+            ExportFormat::Ods => "ods",
+        }
+    }
+}
+
+/// Exportálható adatkör (XLSX / ODS esetén munkalaponként, CSV esetén fájlonként)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ExportDataset {
+    Offers,
+    Customers,
+    Projects,
+    Tasks,
+    FinancialSummary,
+}
+
+impl ExportDataset {
+    fn key(self) -> &'static str {
+        match self {
+            ExportDataset::Offers => "offers",
+            ExportDataset::Customers => "customers",
+            ExportDataset::Projects => "projects",
+            ExportDataset::Tasks => "tasks",
+            ExportDataset::FinancialSummary => "financialSummary",
+        }
+    }
+}
+
+/// Árajánlat munkalap elrendezése: egy sor / árajánlat, vagy egy sor / filament tétel
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum OfferLayout {
+    #[default]
+    PerOffer,
+    PerFilament,
+}
+
+/// Árajánlat szűrő (a frontend `OfferFilter` típusával egyező mezők)
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct OfferFilter {
+    pub search_term: Option<String>,
+    /// Összköltség határok (az Árajánlatok oldal összeg szűrőjével egyezően)
+    pub min_price: Option<f64>,
+    pub max_price: Option<f64>,
+    /// A min / max összeg pénzneme; hiányában az árajánlat saját pénznemében hasonlítunk
+    pub currency: Option<String>,
+    pub start_date: Option<String>,
+    pub end_date: Option<String>,
+    pub min_profit: Option<f64>,
+    pub max_profit: Option<f64>,
+    pub customer_name: Option<String>,
+    pub printer_names: Option<Vec<String>>,
+    pub filament_types: Option<Vec<String>>,
+    pub statuses: Option<Vec<String>>,
+}
+
+impl OfferFilter {
+    pub fn matches(&self, offer: &Value) -> bool {
+        let contains = |haystack: &str, needle: &str| haystack.to_lowercase().contains(&needle.trim().to_lowercase());
+
+        if let Some(term) = self.search_term.as_deref().filter(|t| !t.trim().is_empty()) {
+            let id = offer.get("id").map(|id| id.to_string()).unwrap_or_default();
+            let found = ["customerName", "customerContact", "description", "printerName", "printerType"]
+                .iter()
+                .any(|key| contains(text(offer, key), term))
+                || contains(&id, term);
+            if !found {
+                return false;
+            }
+        }
+        if let Some(name) = self.customer_name.as_deref().filter(|n| !n.trim().is_empty()) {
+            if !contains(text(offer, "customerName"), name) {
+                return false;
+            }
+        }
+
+        let total = cost(offer, "totalCost");
+        let price = match self.currency.as_deref() {
+            Some(target) => convert_currency(total, &currency(offer), target),
+            None => total,
+        };
+        if self.min_price.is_some_and(|min| price < min) || self.max_price.is_some_and(|max| price > max) {
+            return false;
+        }
+        let profit = profit_percentage(offer);
+        if self.min_profit.is_some_and(|min| profit < min) || self.max_profit.is_some_and(|max| profit > max) {
+            return false;
+        }
+
+        // Napra pontos összevetés (ISO dátumok első 10 karaktere), a végdátum napja is beleszámít
+        let day = text(offer, "date").get(..10).unwrap_or_default();
+        if let Some(start) = self.start_date.as_deref().and_then(|d| d.get(..10)) {
+            if day < start {
+                return false;
+            }
+        }
+        if let Some(end) = self.end_date.as_deref().and_then(|d| d.get(..10)) {
+            if day > end {
+                return false;
+            }
+        }
+
+        if let Some(printers) = self.printer_names.as_ref().filter(|p| !p.is_empty()) {
+            if !printers.iter().any(|p| p == text(offer, "printerName")) {
+                return false;
+            }
+        }
+        if let Some(types) = self.filament_types.as_ref().filter(|t| !t.is_empty()) {
+            let has_type = filaments(offer)
+                .iter()
+                .any(|filament| types.iter().any(|t| t == text(filament, "type")));
+            if !has_type {
+                return false;
+            }
+        }
+        if let Some(statuses) = self.statuses.as_ref().filter(|s| !s.is_empty()) {
+            if !statuses.iter().any(|s| s == offer_status(offer)) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// Táblázat export kérés
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpreadsheetExportRequest {
+    pub file_path: String,
+    pub format: ExportFormat,
+    pub datasets: Vec<ExportDataset>,
+    #[serde(default)]
+    pub offer_layout: OfferLayout,
+    #[serde(default)]
+    pub filter: Option<OfferFilter>,
+    /// Fejlécek és státuszok nyelve (hu, de, egyébként angol)
+    #[serde(default)]
+    pub language: Option<String>,
+    /// CSV elválasztó (alapból tizedesvesszőnél ";", egyébként ",")
+    #[serde(default)]
+    pub csv_delimiter: Option<String>,
+    /// CSV-ben tizedesvessző (a magyar Excel így olvassa be számként)
+    #[serde(default)]
+    pub decimal_comma: bool,
+}
+
+/// Egy elkészült munkalap / fájl
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportedSheet {
+    pub dataset: String,
+    pub name: String,
+    pub rows: usize,
+    pub file_path: String,
+}
+
+/// Az export eredménye
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpreadsheetExportResult {
+    pub files: Vec<String>,
+    pub sheets: Vec<ExportedSheet>,
+    /// A szűrő után exportált árajánlatok száma
+    pub offer_count: usize,
+}
+
+/// Egy cella típusos értéke: a számok valódi szám cellaként, a pénzösszegek pénznem formátummal kerülnek ki
+#[derive(Debug, Clone)]
+enum Cell {
+    Empty,
+    Text(String),
+    Number(f64),
+    Money(f64, String),
+    /// Százalék értékben (30 = 30%)
+    Percent(f64),
+    Date(NaiveDate),
+    DateTime(NaiveDateTime),
+}
+
+struct Sheet {
+    dataset: ExportDataset,
+    name: String,
+    headers: Vec<String>,
+    rows: Vec<Vec<Cell>>,
+}
+
+fn text<'a>(value: &'a Value, key: &str) -> &'a str {
+    value.get(key).and_then(|v| v.as_str()).unwrap_or_default()
+}
+
+fn number(value: &Value, key: &str) -> Option<f64> {
+    value.get(key).and_then(|v| v.as_f64())
+}
+
+fn filaments(offer: &Value) -> &[Value] {
+    offer
+        .get("filaments")
+        .and_then(|v| v.as_array())
+        .map(|a| a.as_slice())
+        .unwrap_or_default()
+}
+
+fn cost(offer: &Value, key: &str) -> f64 {
+    offer.get("costs").and_then(|costs| number(costs, key)).unwrap_or(0.0)
+}
+
+fn currency(offer: &Value) -> String {
+    Some(text(offer, "currency")).filter(|c| !c.is_empty()).unwrap_or("EUR").to_string()
+}
+
+fn offer_status(offer: &Value) -> &str {
+    Some(text(offer, "status")).filter(|s| !s.is_empty()).unwrap_or("draft")
+}
+
+fn profit_percentage(offer: &Value) -> f64 {
+    number(offer, "profitPercentage").unwrap_or(DEFAULT_PROFIT_PERCENTAGE)
+}
+
+/// Eladási ár az árajánlat pénznemében: összköltség + profit
+fn selling_price(offer: &Value) -> f64 {
+    cost(offer, "totalCost") * (1.0 + profit_percentage(offer) / 100.0)
+}
+
+/// Fix árfolyamok EUR-hoz (a frontend utils/currency.ts táblájával egyezően)
+fn eur_rate(currency: &str) -> f64 {
+    match currency {
+        "HUF" => 400.0,
+        "USD" => 1.10,
+        "GBP" => 0.85,
+        "PLN" => 4.30,
+        "CZK" => 25.0,
+        "CNY" => 7.80,
+        "UAH" => 40.0,
+        "RUB" => 100.0,
+        _ => 1.0,
+    }
+}
+
+pub fn convert_currency(amount: f64, from: &str, to: &str) -> f64 {
+    if from == to {
+        return amount;
+    }
+    amount / eur_rate(from) * eur_rate(to)
+}
+
+/// Fizetett-e (a frontenddel egyezően a hiányzó fizetési státusz fizetettnek számít)
+fn is_paid(offer: &Value) -> bool {
+    matches!(offer.get("paymentStatus").and_then(|v| v.as_str()), None | Some("paid"))
+}
+
+fn text_cell(value: &str) -> Cell {
+    if value.is_empty() {
+        Cell::Empty
+    } else {
+        Cell::Text(value.to_string())
+    }
+}
+
+fn number_cell(value: Option<f64>) -> Cell {
+    value.map(Cell::Number).unwrap_or(Cell::Empty)
+}
+
+fn id_cell(record: &Value, key: &str) -> Cell {
+    match record.get(key) {
+        Some(Value::Number(n)) => number_cell(n.as_f64()),
+        Some(Value::String(s)) => text_cell(s),
+        _ => Cell::Empty,
+    }
+}
+
+/// ISO dátum (helyi időre alakítva) vagy csak dátum ("2024-05-01") cellává alakítása
+fn date_cell(value: &str) -> Cell {
+    if value.is_empty() {
+        return Cell::Empty;
+    }
+    if let Ok(parsed) = chrono::DateTime::parse_from_rfc3339(value) {
+        return Cell::DateTime(parsed.with_timezone(&chrono::Local).naive_local());
+    }
+    if let Ok(parsed) = NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M") {
+        return Cell::DateTime(parsed);
+    }
+    match value.get(..10).and_then(|day| NaiveDate::parse_from_str(day, "%Y-%m-%d").ok()) {
+        Some(date) => Cell::Date(date),
+        None => Cell::Text(value.to_string()),
+    }
+}
+
+fn tags_cell(record: &Value) -> Cell {
+    let tags: Vec<&str> = record
+        .get("tags")
+        .and_then(|v| v.as_array())
+        .map(|tags| tags.iter().filter_map(|t| t.as_str()).collect())
+        .unwrap_or_default();
+    text_cell(&tags.join(", "))
+}
+
+/// Fordító a fejlécekhez és státuszokhoz (magyar / német / angol)
+struct Labels<'a> {
+    language: &'a str,
+}
+
+impl Labels<'_> {
+    fn pick(&self, hu: &str, de: &str, en: &str) -> String {
+        match self.language {
+            "hu" => hu,
+            "de" => de,
+            _ => en,
+        }
+        .to_string()
+    }
+
+    fn headers(&self, columns: &[(&str, &str, &str)]) -> Vec<String> {
+        columns.iter().map(|(hu, de, en)| self.pick(hu, de, en)).collect()
+    }
+
+    fn status(&self, status: &str) -> String {
+        match status {
+            "draft" => self.pick("Piszkozat", "Entwurf", "Draft"),
+            "sent" => self.pick("Elküldve", "Gesendet", "Sent"),
+            "accepted" => self.pick("Elfogadva", "Angenommen", "Accepted"),
+            "rejected" => self.pick("Elutasítva", "Abgelehnt", "Rejected"),
+            "completed" => self.pick("Befejezve", "Abgeschlossen", "Completed"),
+            "paid" => self.pick("Fizetve", "Bezahlt", "Paid"),
+            "unpaid" => self.pick("Nincs fizetve", "Unbezahlt", "Unpaid"),
+            "gift" => self.pick("Ajándék", "Geschenk", "Gift"),
+            "planning" => self.pick("Tervezés", "Planung", "Planning"),
+            "active" => self.pick("Aktív", "Aktiv", "Active"),
+            "on-hold" => self.pick("Felfüggesztve", "Pausiert", "On hold"),
+            "cancelled" => self.pick("Megszakítva", "Abgebrochen", "Cancelled"),
+            "pending" => self.pick("Függőben", "Ausstehend", "Pending"),
+            "in-progress" => self.pick("Folyamatban", "In Bearbeitung", "In progress"),
+            "high" => self.pick("Magas", "Hoch", "High"),
+            "medium" => self.pick("Közepes", "Mittel", "Medium"),
+            "low" => self.pick("Alacsony", "Niedrig", "Low"),
+            other => other.to_string(),
+        }
+    }
+}
+
+fn offer_summary_columns(offer: &Value, labels: &Labels) -> Vec<Cell> {
+    let currency = currency(offer);
+    vec![
+        id_cell(offer, "id"),
+        date_cell(text(offer, "date")),
+        Cell::Text(labels.status(offer_status(offer))),
+        text_cell(text(offer, "customerName")),
+        text_cell(text(offer, "printerName")),
+        Cell::Money(selling_price(offer), currency),
+    ]
+}
+
+const OFFER_SUMMARY_HEADERS: [(&str, &str, &str); 6] = [
+    ("Azonosító", "ID", "ID"),
+    ("Dátum", "Datum", "Date"),
+    ("Státusz", "Status", "Status"),
+    ("Ügyfél", "Kunde", "Customer"),
+    ("Nyomtató", "Drucker", "Printer"),
+    ("Eladási ár", "Verkaufspreis", "Selling price"),
+];
+
+fn offers_sheet(offers: &[&Value], layout: OfferLayout, labels: &Labels) -> Sheet {
+    let name = labels.pick("Árajánlatok", "Angebote", "Offers");
+    match layout {
+        OfferLayout::PerOffer => {
+            let headers = labels.headers(&[
+                ("Azonosító", "ID", "ID"),
+                ("Dátum", "Datum", "Date"),
+                ("Nyomtatási határidő", "Druckfrist", "Print due date"),
+                ("Státusz", "Status", "Status"),
+                ("Fizetés", "Zahlung", "Payment"),
+                ("Ügyfél", "Kunde", "Customer"),
+                ("Kapcsolat", "Kontakt", "Contact"),
+                ("Leírás", "Beschreibung", "Description"),
+                ("Nyomtató", "Drucker", "Printer"),
+                ("Nyomtatási idő (óra)", "Druckzeit (h)", "Print time (h)"),
+                ("Filamentek", "Filamente", "Filaments"),
+                ("Filament összesen (g)", "Filament gesamt (g)", "Filament total (g)"),
+                ("Filament költség", "Filamentkosten", "Filament cost"),
+                ("Áram költség", "Stromkosten", "Electricity cost"),
+                ("Szárítási költség", "Trocknungskosten", "Drying cost"),
+                ("Üzemeltetési költség", "Betriebskosten", "Usage cost"),
+                ("Összköltség", "Gesamtkosten", "Total cost"),
+                ("Profit %", "Gewinn %", "Profit %"),
+                ("Profit", "Gewinn", "Profit"),
+                ("Eladási ár", "Verkaufspreis", "Selling price"),
+                ("Pénznem", "Währung", "Currency"),
+            ]);
+            let rows = offers
+                .iter()
+                .map(|offer| {
+                    let currency = currency(offer);
+                    let money = |value: f64| Cell::Money(value, currency.clone());
+                    let lines = filaments(offer);
+                    let summary: Vec<String> = lines
+                        .iter()
+                        .map(|f| {
+                            let label = [text(f, "brand"), text(f, "type"), text(f, "color")]
+                                .iter()
+                                .filter(|part| !part.is_empty())
+                                .copied()
+                                .collect::<Vec<_>>()
+                                .join(" ");
+                            format!("{} ({} g)", label, number(f, "usedGrams").unwrap_or(0.0))
+                        })
+                        .collect();
+                    let grams: f64 = lines.iter().filter_map(|f| number(f, "usedGrams")).sum();
+                    let total = cost(offer, "totalCost");
+                    vec![
+                        id_cell(offer, "id"),
+                        date_cell(text(offer, "date")),
+                        date_cell(text(offer, "printDueDate")),
+                        Cell::Text(labels.status(offer_status(offer))),
+                        match offer.get("paymentStatus").and_then(|v| v.as_str()) {
+                            Some(status) => Cell::Text(labels.status(status)),
+                            None => Cell::Empty,
+                        },
+                        text_cell(text(offer, "customerName")),
+                        text_cell(text(offer, "customerContact")),
+                        text_cell(text(offer, "description")),
+                        text_cell(text(offer, "printerName")),
+                        number_cell(number(offer, "totalPrintTimeHours")),
+                        text_cell(&summary.join("; ")),
+                        Cell::Number(grams),
+                        money(cost(offer, "filamentCost")),
+                        money(cost(offer, "electricityCost")),
+                        money(cost(offer, "dryingCost")),
+                        money(cost(offer, "usageCost")),
+                        money(total),
+                        Cell::Percent(profit_percentage(offer)),
+                        money(selling_price(offer) - total),
+                        money(selling_price(offer)),
+                        Cell::Text(currency.clone()),
+                    ]
+                })
+                .collect();
+            Sheet {
+                dataset: ExportDataset::Offers,
+                name,
+                headers,
+                rows,
+            }
+        }
+        OfferLayout::PerFilament => {
+            let mut headers = labels.headers(&OFFER_SUMMARY_HEADERS);
+            headers.extend(labels.headers(&[
+                ("Tétel", "Position", "Line"),
+                ("Márka", "Marke", "Brand"),
+                ("Típus", "Typ", "Type"),
+                ("Szín", "Farbe", "Color"),
+                ("Felhasznált (g)", "Verbraucht (g)", "Used (g)"),
+                ("Ár / kg", "Preis / kg", "Price / kg"),
+                ("Anyagköltség", "Materialkosten", "Material cost"),
+                ("Szárítás (óra)", "Trocknung (h)", "Drying (h)"),
+            ]));
+            let mut rows = Vec::new();
+            for offer in offers {
+                for (index, filament) in filaments(offer).iter().enumerate() {
+                    let grams = number(filament, "usedGrams").unwrap_or(0.0);
+                    let price_per_kg = number(filament, "pricePerKg").unwrap_or(0.0);
+                    let mut row = offer_summary_columns(offer, labels);
+                    row.extend([
+                        Cell::Number((index + 1) as f64),
+                        text_cell(text(filament, "brand")),
+                        text_cell(text(filament, "type")),
+                        text_cell(text(filament, "color")),
+                        Cell::Number(grams),
+                        Cell::Money(price_per_kg, FILAMENT_PRICE_CURRENCY.to_string()),
+                        Cell::Money(grams / 1000.0 * price_per_kg, FILAMENT_PRICE_CURRENCY.to_string()),
+                        match filament.get("needsDrying").and_then(|v| v.as_bool()) {
+                            Some(true) => number_cell(number(filament, "dryingTime")),
+                            _ => Cell::Empty,
+                        },
+                    ]);
+                    rows.push(row);
+                }
+            }
+            Sheet {
+                dataset: ExportDataset::Offers,
+                name: labels.pick("Árajánlat tételek", "Angebotspositionen", "Offer lines"),
+                headers,
+                rows,
+            }
+        }
+    }
+}
+
+fn customers_sheet(customers: &[Value], offers: &[&Value], labels: &Labels) -> Sheet {
+    let headers = labels.headers(&[
+        ("Azonosító", "ID", "ID"),
+        ("Név", "Name", "Name"),
+        ("Cég", "Firma", "Company"),
+        ("Kapcsolat", "Kontakt", "Contact"),
+        ("Cím", "Adresse", "Address"),
+        ("Megjegyzés", "Notizen", "Notes"),
+        ("Árajánlatok", "Angebote", "Offers"),
+        ("Utolsó árajánlat", "Letztes Angebot", "Last offer"),
+        ("Létrehozva", "Erstellt", "Created"),
+    ]);
+    let rows = customers
+        .iter()
+        .map(|customer| {
+            let name = text(customer, "name").trim().to_lowercase();
+            let related: Vec<&&Value> = offers
+                .iter()
+                .filter(|offer| !name.is_empty() && text(offer, "customerName").trim().to_lowercase() == name)
+                .collect();
+            let last_offer = related.iter().map(|offer| text(offer, "date")).max().unwrap_or_default();
+            vec![
+                id_cell(customer, "id"),
+                text_cell(text(customer, "name")),
+                text_cell(text(customer, "company")),
+                text_cell(text(customer, "contact")),
+                text_cell(text(customer, "address")),
+                text_cell(text(customer, "notes")),
+                Cell::Number(related.len() as f64),
+                date_cell(last_offer),
+                date_cell(text(customer, "createdAt")),
+            ]
+        })
+        .collect();
+    Sheet {
+        dataset: ExportDataset::Customers,
+        name: labels.pick("Ügyfelek", "Kunden", "Customers"),
+        headers,
+        rows,
+    }
+}
+
+fn projects_sheet(projects: &[Value], labels: &Labels) -> Sheet {
+    let headers = labels.headers(&[
+        ("Azonosító", "ID", "ID"),
+        ("Név", "Name", "Name"),
+        ("Státusz", "Status", "Status"),
+        ("Készültség", "Fortschritt", "Progress"),
+        ("Határidő", "Frist", "Deadline"),
+        ("Költségvetés", "Budget", "Budget"),
+        ("Tényleges költség", "Tatsächliche Kosten", "Actual cost"),
+        ("Árajánlatok", "Angebote", "Offers"),
+        ("Címkék", "Tags", "Tags"),
+        ("Felelős", "Verantwortlich", "Assignee"),
+        ("Létrehozva", "Erstellt", "Created"),
+        ("Befejezve", "Abgeschlossen", "Completed"),
+    ]);
+    // A projekt költségvetés és tényleges költség EUR-ban tárolt
+    let money = |project: &Value, key: &str| match number(project, key) {
+        Some(value) => Cell::Money(value, "EUR".to_string()),
+        None => Cell::Empty,
+    };
+    let rows = projects
+        .iter()
+        .map(|project| {
+            let offer_count = project.get("offerIds").and_then(|v| v.as_array()).map(|ids| ids.len()).unwrap_or(0);
+            vec![
+                id_cell(project, "id"),
+                text_cell(text(project, "name")),
+                Cell::Text(labels.status(text(project, "status"))),
+                number(project, "progress").map(Cell::Percent).unwrap_or(Cell::Empty),
+                date_cell(text(project, "deadline")),
+                money(project, "budget"),
+                money(project, "actualCost"),
+                Cell::Number(offer_count as f64),
+                tags_cell(project),
+                text_cell(text(project, "assignee")),
+                date_cell(text(project, "createdAt")),
+                date_cell(text(project, "completedAt")),
+            ]
+        })
+        .collect();
+    Sheet {
+        dataset: ExportDataset::Projects,
+        name: labels.pick("Projektek", "Projekte", "Projects"),
+        headers,
+        rows,
+    }
+}
+
+fn tasks_sheet(tasks: &[Value], projects: &[Value], labels: &Labels) -> Sheet {
+    let headers = labels.headers(&[
+        ("Azonosító", "ID", "ID"),
+        ("Cím", "Titel", "Title"),
+        ("Státusz", "Status", "Status"),
+        ("Prioritás", "Priorität", "Priority"),
+        ("Határidő", "Fällig", "Due date"),
+        ("Árajánlat", "Angebot", "Offer"),
+        ("Projekt", "Projekt", "Project"),
+        ("Címkék", "Tags", "Tags"),
+        ("Felelős", "Verantwortlich", "Assignee"),
+        ("Leírás", "Beschreibung", "Description"),
+        ("Létrehozva", "Erstellt", "Created"),
+        ("Befejezve", "Abgeschlossen", "Completed"),
+    ]);
+    let project_names: HashMap<String, &str> = projects
+        .iter()
+        .filter_map(|p| p.get("id").map(|id| (id.to_string(), text(p, "name"))))
+        .collect();
+    let rows = tasks
+        .iter()
+        .map(|task| {
+            let project = task
+                .get("relatedProjectId")
+                .and_then(|id| project_names.get(&id.to_string()).copied())
+                .unwrap_or_default();
+            vec![
+                id_cell(task, "id"),
+                text_cell(text(task, "title")),
+                Cell::Text(labels.status(text(task, "status"))),
+                Cell::Text(labels.status(text(task, "priority"))),
+                date_cell(text(task, "dueDate")),
+                id_cell(task, "relatedOfferId"),
+                text_cell(project),
+                tags_cell(task),
+                text_cell(text(task, "assignee")),
+                text_cell(text(task, "description")),
+                date_cell(text(task, "createdAt")),
+                date_cell(text(task, "completedAt")),
+            ]
+        })
+        .collect();
+    Sheet {
+        dataset: ExportDataset::Tasks,
+        name: labels.pick("Feladatok", "Aufgaben", "Tasks"),
+        headers,
+        rows,
+    }
+}
+
+/// Havi pénzügyi összesítő pénznemenként (az árajánlatok saját pénznemében, átváltás nélkül)
+fn financial_summary_sheet(offers: &[&Value], labels: &Labels) -> Sheet {
+    #[derive(Default)]
+    struct MonthTotals {
+        offers: usize,
+        completed: usize,
+        quoted: f64,
+        completed_cost: f64,
+        revenue: f64,
+        unpaid: f64,
+    }
+
+    let mut months: BTreeMap<(String, String), MonthTotals> = BTreeMap::new();
+    for offer in offers {
+        let month = text(offer, "date").get(..7).unwrap_or("?").to_string();
+        let totals = months.entry((month, currency(offer))).or_default();
+        totals.offers += 1;
+        totals.quoted += selling_price(offer);
+        if offer_status(offer) == "completed" {
+            totals.completed += 1;
+            // Ajándéknál nincs bevétel, de a költség felmerült
+            totals.completed_cost += cost(offer, "totalCost");
+            match offer.get("paymentStatus").and_then(|v| v.as_str()) {
+                Some("gift") => {}
+                _ if is_paid(offer) => totals.revenue += selling_price(offer),
+                _ => totals.unpaid += selling_price(offer),
+            }
+        }
+    }
+
+    let headers = labels.headers(&[
+        ("Hónap", "Monat", "Month"),
+        ("Pénznem", "Währung", "Currency"),
+        ("Árajánlatok", "Angebote", "Offers"),
+        ("Befejezett", "Abgeschlossen", "Completed"),
+        ("Ajánlott érték", "Angebotswert", "Quoted value"),
+        ("Befejezett költség", "Kosten (abgeschlossen)", "Completed cost"),
+        ("Bevétel (fizetve)", "Umsatz (bezahlt)", "Revenue (paid)"),
+        ("Kintlévőség", "Offene Forderungen", "Outstanding"),
+        ("Profit", "Gewinn", "Profit"),
+    ]);
+    let rows = months
+        .into_iter()
+        .map(|((month, currency), totals)| {
+            let money = |value: f64| Cell::Money(value, currency.clone());
+            vec![
+                Cell::Text(month),
+                Cell::Text(currency.clone()),
+                Cell::Number(totals.offers as f64),
+                Cell::Number(totals.completed as f64),
+                money(totals.quoted),
+                money(totals.completed_cost),
+                money(totals.revenue),
+                money(totals.unpaid),
+                money(totals.revenue + totals.unpaid - totals.completed_cost),
+            ]
+        })
+        .collect();
+    Sheet {
+        dataset: ExportDataset::FinancialSummary,
+        name: labels.pick("Pénzügyi összesítő", "Finanzübersicht", "Financial summary"),
+        headers,
+        rows,
+    }
+}
+
+/// Excel számformátum pénznemenként (a szimbólum a frontend CURRENCY_SYMBOLS táblájával egyezik)
+fn excel_currency_format(currency: &str) -> String {
+    match currency {
+        "HUF" => "#,##0 \"Ft\"".to_string(),
+        "EUR" => "#,##0.00 \"€\"".to_string(),
+        "USD" => "\"$\"#,##0.00".to_string(),
+        "GBP" => "\"£\"#,##0.00".to_string(),
+        "CNY" => "\"¥\"#,##0.00".to_string(),
+        "PLN" => "#,##0.00 \"zł\"".to_string(),
+        "CZK" => "#,##0.00 \"Kč\"".to_string(),
+        "UAH" => "#,##0.00 \"₴\"".to_string(),
+        "RUB" => "#,##0.00 \"₽\"".to_string(),
+        other => format!("#,##0.00 \"{}\"", other.replace('"', "")),
+    }
+}
+
+fn currency_symbol(currency: &str) -> &str {
+    match currency {
+        "HUF" => "Ft",
+        "EUR" => "€",
+        "USD" => "$",
+        "GBP" => "£",
+        "CNY" => "¥",
+        "PLN" => "zł",
+        "CZK" => "Kč",
+        "UAH" => "₴",
+        "RUB" => "₽",
+        other => other,
+    }
+}
+
+fn currency_decimals(currency: &str) -> usize {
+    if currency == "HUF" {
+        0
+    } else {
+        2
+    }
+}
+
+fn write_xlsx(sheets: &[Sheet], path: &Path) -> Result<(), String> {
+    let xlsx_error = |e: rust_xlsxwriter::XlsxError| format!("XLSX írási hiba: {}", e);
+
+    let header_format = Format::new().set_bold().set_border_bottom(FormatBorder::Thin);
+    let date_format = Format::new().set_num_format("yyyy-mm-dd");
+    let datetime_format = Format::new().set_num_format("yyyy-mm-dd hh:mm");
+    let percent_format = Format::new().set_num_format("0.0%");
+    let number_format = Format::new().set_num_format("#,##0.##");
+    let mut money_formats: HashMap<String, Format> = HashMap::new();
+
+    let mut workbook = Workbook::new();
+    for sheet in sheets {
+        let worksheet = workbook.add_worksheet();
+        worksheet.set_name(&sheet.name).map_err(xlsx_error)?;
+        for (col, header) in sheet.headers.iter().enumerate() {
+            worksheet
+                .write_string_with_format(0, col as u16, header, &header_format)
+                .map_err(xlsx_error)?;
+        }
+        for (index, row) in sheet.rows.iter().enumerate() {
+            let row_number = (index + 1) as u32;
+            for (col, cell) in row.iter().enumerate() {
+                let col = col as u16;
+                match cell {
+                    Cell::Empty => {}
+                    Cell::Text(value) => {
+                        worksheet.write_string(row_number, col, value).map_err(xlsx_error)?;
+                    }
+                    Cell::Number(value) => {
+                        worksheet
+                            .write_number_with_format(row_number, col, *value, &number_format)
+                            .map_err(xlsx_error)?;
+                    }
+                    Cell::Money(value, currency) => {
+                        let format = money_formats
+                            .entry(currency.clone())
+                            .or_insert_with(|| Format::new().set_num_format(excel_currency_format(currency)));
+                        worksheet
+                            .write_number_with_format(row_number, col, *value, format)
+                            .map_err(xlsx_error)?;
+                    }
+                    Cell::Percent(value) => {
+                        worksheet
+                            .write_number_with_format(row_number, col, *value / 100.0, &percent_format)
+                            .map_err(xlsx_error)?;
+                    }
+                    Cell::Date(value) => {
+                        worksheet
+                            .write_datetime_with_format(row_number, col, value, &date_format)
+                            .map_err(xlsx_error)?;
+                    }
+                    Cell::DateTime(value) => {
+                        worksheet
+                            .write_datetime_with_format(row_number, col, value, &datetime_format)
+                            .map_err(xlsx_error)?;
+                    }
+                }
+            }
+        }
+        if !sheet.headers.is_empty() {
+            worksheet.set_freeze_panes(1, 0).map_err(xlsx_error)?;
+            worksheet
+                .autofilter(0, 0, sheet.rows.len() as u32, (sheet.headers.len() - 1) as u16)
+                .map_err(xlsx_error)?;
+        }
+        worksheet.autofit();
+    }
+
+    let bytes = workbook.save_to_buffer().map_err(xlsx_error)?;
+    crate::atomic_write::write_atomic(path, bytes).map_err(|e| format!("Nem sikerült menteni a táblázatot: {}", e))
+}
+
+fn format_csv_number(value: f64, decimals: Option<usize>, decimal_comma: bool) -> String {
+    let formatted = match decimals {
+        Some(decimals) => format!("{:.*}", decimals, value),
+        None => {
+            // Felesleges tizedesjegyek nélkül (pl. 1000, 12.5)
+            let rounded = format!("{:.4}", value);
+            rounded.trim_end_matches('0').trim_end_matches('.').to_string()
+        }
+    };
+    if decimal_comma {
+        formatted.replace('.', ",")
+    } else {
+        formatted
+    }
+}
+
+/// CSV: UTF-8 BOM-mal (az Excel így ismeri fel a kódolást), a számok formázás nélkül, ezres elválasztó nélkül
+fn write_csv(sheet: &Sheet, path: &Path, delimiter: u8, decimal_comma: bool) -> Result<(), String> {
+    let mut writer = csv::WriterBuilder::new().delimiter(delimiter).from_writer(Vec::new());
+    let csv_error = |e: csv::Error| format!("CSV írási hiba: {}", e);
+
+    writer.write_record(&sheet.headers).map_err(csv_error)?;
+    for row in &sheet.rows {
+        let record: Vec<String> = row
+            .iter()
+            .map(|cell| match cell {
+                Cell::Empty => String::new(),
+                Cell::Text(value) => value.clone(),
+                Cell::Number(value) => format_csv_number(*value, None, decimal_comma),
+                Cell::Money(value, currency) => format_csv_number(*value, Some(currency_decimals(currency)), decimal_comma),
+                Cell::Percent(value) => format_csv_number(*value, None, decimal_comma),
+                Cell::Date(value) => value.format("%Y-%m-%d").to_string(),
+                Cell::DateTime(value) => value.format("%Y-%m-%d %H:%M").to_string(),
+            })
+            .collect();
+        writer.write_record(&record).map_err(csv_error)?;
+    }
+
+    let mut bytes = vec![0xEF, 0xBB, 0xBF];
+    bytes.extend(writer.into_inner().map_err(|e| format!("CSV írási hiba: {}", e))?);
+    crate::atomic_write::write_atomic(path, bytes).map_err(|e| format!("Nem sikerült menteni a CSV fájlt: {}", e))
+}
+
+fn xml_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Pénznem azonosító stílusnévhez (csak betű és szám)
+fn style_suffix(currency: &str) -> String {
+    currency.chars().filter(|c| c.is_ascii_alphanumeric()).collect()
+}
+
+/// ODS tartalom (content.xml) típusos cellákkal: pénznem, százalék és dátum értékek
+fn ods_content(sheets: &[Sheet]) -> String {
+    let currencies: BTreeSet<&str> = sheets
+        .iter()
+        .flat_map(|sheet| sheet.rows.iter().flatten())
+        .filter_map(|cell| match cell {
+            Cell::Money(_, currency) => Some(currency.as_str()),
+            _ => None,
+        })
+        .collect();
+
+    let mut xml = String::from(concat!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+        r#"<office:document-content xmlns:office="urn:oasis:names:tc:opendocument:xmlns:office:1.0" "#,
+        r#"xmlns:style="urn:oasis:names:tc:opendocument:xmlns:style:1.0" "#,
+        r#"xmlns:text="urn:oasis:names:tc:opendocument:xmlns:text:1.0" "#,
+        r#"xmlns:table="urn:oasis:names:tc:opendocument:xmlns:table:1.0" "#,
+        r#"xmlns:number="urn:oasis:names:tc:opendocument:xmlns:datastyle:1.0" "#,
+        r#"xmlns:fo="urn:oasis:names:tc:opendocument:xmlns:xsl-fo-compatible:1.0" office:version="1.2">"#,
+        "<office:automatic-styles>",
+        r#"<number:date-style style:name="N_DATE"><number:year number:style="long"/><number:text>-</number:text><number:month number:style="long"/><number:text>-</number:text><number:day number:style="long"/></number:date-style>"#,
+        r#"<number:date-style style:name="N_DATETIME"><number:year number:style="long"/><number:text>-</number:text><number:month number:style="long"/><number:text>-</number:text><number:day number:style="long"/><number:text> </number:text><number:hours number:style="long"/><number:text>:</number:text><number:minutes number:style="long"/></number:date-style>"#,
+        r#"<number:percentage-style style:name="N_PCT"><number:number number:decimal-places="1" number:min-integer-digits="1"/><number:text>%</number:text></number:percentage-style>"#,
+        r#"<style:style style:name="ce_header" style:family="table-cell"><style:text-properties fo:font-weight="bold"/></style:style>"#,
+        r#"<style:style style:name="ce_date" style:family="table-cell" style:data-style-name="N_DATE"/>"#,
+        r#"<style:style style:name="ce_datetime" style:family="table-cell" style:data-style-name="N_DATETIME"/>"#,
+        r#"<style:style style:name="ce_pct" style:family="table-cell" style:data-style-name="N_PCT"/>"#,
+    ));
+    for currency in &currencies {
+        let suffix = style_suffix(currency);
+        xml.push_str(&format!(
+            concat!(
+                r#"<number:currency-style style:name="N_{suffix}"><number:number number:decimal-places="{decimals}" number:min-integer-digits="1" number:grouping="true"/>"#,
+                r#"<number:text> </number:text><number:currency-symbol>{symbol}</number:currency-symbol></number:currency-style>"#,
+                r#"<style:style style:name="ce_{suffix}" style:family="table-cell" style:data-style-name="N_{suffix}"/>"#,
+            ),
+            suffix = suffix,
+            decimals = currency_decimals(currency),
+            symbol = xml_escape(currency_symbol(currency)),
+        ));
+    }
+    xml.push_str("</office:automatic-styles><office:body><office:spreadsheet>");
+
+    for sheet in sheets {
+        xml.push_str(&format!(r#"<table:table table:name="{}"><table:table-row>"#, xml_escape(&sheet.name)));
+        for header in &sheet.headers {
+            xml.push_str(&format!(
+                r#"<table:table-cell table:style-name="ce_header" office:value-type="string"><text:p>{}</text:p></table:table-cell>"#,
+                xml_escape(header)
+            ));
+        }
+        xml.push_str("</table:table-row>");
+        for row in &sheet.rows {
+            xml.push_str("<table:table-row>");
+            for cell in row {
+                let cell_xml = match cell {
+                    Cell::Empty => "<table:table-cell/>".to_string(),
+                    Cell::Text(value) => format!(
+                        r#"<table:table-cell office:value-type="string"><text:p>{}</text:p></table:table-cell>"#,
+                        xml_escape(value)
+                    ),
+                    Cell::Number(value) => format!(
+                        r#"<table:table-cell office:value-type="float" office:value="{}"><text:p>{}</text:p></table:table-cell>"#,
+                        value, value
+                    ),
+                    Cell::Money(value, currency) => format!(
+                        r#"<table:table-cell table:style-name="ce_{}" office:value-type="currency" office:currency="{}" office:value="{}"><text:p>{:.*} {}</text:p></table:table-cell>"#,
+                        style_suffix(currency),
+                        xml_escape(currency),
+                        value,
+                        currency_decimals(currency),
+                        value,
+                        xml_escape(currency_symbol(currency))
+                    ),
+                    Cell::Percent(value) => format!(
+                        r#"<table:table-cell table:style-name="ce_pct" office:value-type="percentage" office:value="{}"><text:p>{}%</text:p></table:table-cell>"#,
+                        value / 100.0,
+                        value
+                    ),
+                    Cell::Date(value) => format!(
+                        r#"<table:table-cell table:style-name="ce_date" office:value-type="date" office:date-value="{}"><text:p>{}</text:p></table:table-cell>"#,
+                        value.format("%Y-%m-%d"),
+                        value.format("%Y-%m-%d")
+                    ),
+                    Cell::DateTime(value) => format!(
+                        r#"<table:table-cell table:style-name="ce_datetime" office:value-type="date" office:date-value="{}"><text:p>{}</text:p></table:table-cell>"#,
+                        value.format("%Y-%m-%dT%H:%M:%S"),
+                        value.format("%Y-%m-%d %H:%M")
+                    ),
+                };
+                xml.push_str(&cell_xml);
+            }
+            xml.push_str("</table:table-row>");
+        }
+        xml.push_str("</table:table>");
+    }
+    xml.push_str("</office:spreadsheet></office:body></office:document-content>");
+    xml
+}
+
+const ODS_MIMETYPE: &str = "application/vnd.oasis.opendocument.spreadsheet";
+
+fn write_ods(sheets: &[Sheet], path: &Path) -> Result<(), String> {
+    let manifest = format!(
+        concat!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+            r#"<manifest:manifest xmlns:manifest="urn:oasis:names:tc:opendocument:xmlns:manifest:1.0" manifest:version="1.2">"#,
+            r#"<manifest:file-entry manifest:full-path="/" manifest:version="1.2" manifest:media-type="{}"/>"#,
+            r#"<manifest:file-entry manifest:full-path="content.xml" manifest:media-type="text/xml"/>"#,
+            "</manifest:manifest>"
+        ),
+        ODS_MIMETYPE
+    );
+    let content = ods_content(sheets);
+
+    // A mimetype bejegyzésnek elsőnek és tömörítetlennek kell lennie (ODF követelmény)
+    let stored = SimpleFileOptions::default().compression_method(CompressionMethod::Stored);
+    let compressed = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    let build = |zip: &mut ZipWriter<Cursor<Vec<u8>>>| -> io::Result<()> {
+        zip.start_file("mimetype", stored).map_err(io::Error::other)?;
+        zip.write_all(ODS_MIMETYPE.as_bytes())?;
+        zip.start_file("META-INF/manifest.xml", compressed).map_err(io::Error::other)?;
+        zip.write_all(manifest.as_bytes())?;
+        zip.start_file("content.xml", compressed).map_err(io::Error::other)?;
+        zip.write_all(content.as_bytes())?;
+        Ok(())
+    };
+    build(&mut zip).map_err(|e| format!("ODS írási hiba: {}", e))?;
+    let bytes = zip.finish().map_err(|e| format!("ODS írási hiba: {}", e))?.into_inner();
+    crate::atomic_write::write_atomic(path, bytes).map_err(|e| format!("Nem sikerült menteni a táblázatot: {}", e))
+}
+
+/// CSV-nél több adatkör esetén adatkörönként külön fájl: {név}_{adatkör}.csv
+fn csv_path(base: &Path, dataset: ExportDataset, multiple: bool) -> PathBuf {
+    if !multiple {
+        return base.to_path_buf();
+    }
+    let stem = base.file_stem().and_then(|s| s.to_str()).unwrap_or("export");
+    base.with_file_name(format!("{}_{}.csv", stem, dataset.key()))
+}
+
+/// Árajánlatok, ügyfelek, projektek, feladatok és havi pénzügyi összesítő exportja XLSX, ODS vagy CSV formátumba
+/// A számok valódi szám cellaként, a pénzösszegek az árajánlat pénznemének formátumával kerülnek ki
+/// Az árajánlat szűrő az árajánlatokra, az ügyfél statisztikákra és az összesítőre is vonatkozik
+#[tauri::command]
+pub fn export_spreadsheet(request: SpreadsheetExportRequest) -> Result<SpreadsheetExportResult, String> {
+    if request.datasets.is_empty() {
+        return Err("Nincs kiválasztva exportálandó adat".to_string());
+    }
+    if crate::vault::is_locked() {
+        return Err("Az adattároló zárolva van, előbb oldja fel".to_string());
+    }
+
+    let store = data_store::read_store()?.unwrap_or_default();
+    let list = |key: &str| store.get(key).and_then(|v| v.as_array()).cloned().unwrap_or_default();
+    let all_offers = list("offers");
+    let filter = request.filter.clone().unwrap_or_default();
+    let mut offers: Vec<&Value> = all_offers.iter().filter(|offer| filter.matches(offer)).collect();
+    offers.sort_by(|a, b| text(a, "date").cmp(text(b, "date")));
+
+    let labels = Labels {
+        language: request.language.as_deref().unwrap_or("en"),
+    };
+    let mut datasets: Vec<ExportDataset> = Vec::new();
+    for dataset in &request.datasets {
+        if !datasets.contains(dataset) {
+            datasets.push(*dataset);
+        }
+    }
+    let sheets: Vec<Sheet> = datasets
+        .iter()
+        .map(|dataset| match dataset {
+            ExportDataset::Offers => offers_sheet(&offers, request.offer_layout, &labels),
+            ExportDataset::Customers => customers_sheet(&list("customers"), &offers, &labels),
+            ExportDataset::Projects => projects_sheet(&list("projects"), &labels),
+            ExportDataset::Tasks => tasks_sheet(&list("tasks"), &list("projects"), &labels),
+            ExportDataset::FinancialSummary => financial_summary_sheet(&offers, &labels),
+        })
+        .collect();
+
+    let base_path = PathBuf::from(&request.file_path);
+    let mut files = Vec::new();
+    let mut exported = Vec::new();
+    match request.format {
+        ExportFormat::Csv => {
+            let delimiter = match request.csv_delimiter.as_deref() {
+                Some("\\t") | Some("\t") => b'\t',
+                Some(value) if value.len() == 1 && value.is_ascii() => value.as_bytes()[0],
+                Some(value) => return Err(format!("Érvénytelen elválasztó karakter: {}", value)),
+                None if request.decimal_comma => b';',
+                None => b',',
+            };
+            for sheet in &sheets {
+                let path = csv_path(&base_path, sheet.dataset, sheets.len() > 1);
+                write_csv(sheet, &path, delimiter, request.decimal_comma)?;
+                files.push(path.to_string_lossy().to_string());
+            }
+        }
+        ExportFormat::Xlsx => {
+            write_xlsx(&sheets, &base_path)?;
+            files.push(request.file_path.clone());
+        }
+        ExportFormat::Ods => {
+            write_ods(&sheets, &base_path)?;
+            files.push(request.file_path.clone());
+        }
+    }
+    for (index, sheet) in sheets.iter().enumerate() {
+        exported.push(ExportedSheet {
+            dataset: sheet.dataset.key().to_string(),
+            name: sheet.name.clone(),
+            rows: sheet.rows.len(),
+            file_path: files.get(index).or(files.first()).cloned().unwrap_or_default(),
+        });
+    }
+
+    logger::log_info(&format!(
+        "Táblázat export ({}): {} munkalap, {} árajánlat - {}",
+        request.format.extension(),
+        exported.len(),
+        offers.len(),
+        files.join(", ")
+    ));
+    Ok(SpreadsheetExportResult {
+        files,
+        sheets: exported,
+        offer_count: offers.len(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use calamine::Reader;
+    use serde_json::json;
+    use std::fs;
+
+    fn sample_store() -> Value {
+        json!({
+            "offers": [
+                {
+                    "id": 1,
+                    "date": "2024-05-01",
+                    "status": "accepted",
+                    "customerName": "Kovács Éva",
+                    "currency": "HUF",
+                    "profitPercentage": 50,
+                    "costs": { "totalCost": 1000, "filamentCost": 600 },
+                    "filaments": [{ "brand": "Prusa", "type": "PLA", "color": "fekete", "usedGrams": 120, "pricePerKg": 25 }]
+                },
+                { "id": 2, "date": "2024-05-02", "status": "draft", "customerName": "Nagy Péter", "costs": { "totalCost": 10 } }
+            ]
+        })
+    }
+
+    fn request(file_path: &Path, format: ExportFormat) -> SpreadsheetExportRequest {
+        SpreadsheetExportRequest {
+            file_path: file_path.to_string_lossy().to_string(),
+            format,
+            datasets: vec![ExportDataset::Offers],
+            offer_layout: OfferLayout::PerFilament,
+            filter: Some(OfferFilter {
+                statuses: Some(vec!["accepted".to_string()]),
+                ..OfferFilter::default()
+            }),
+            language: Some("hu".to_string()),
+            csv_delimiter: None,
+            decimal_comma: true,
+        }
+    }
+
+    #[test]
+    fn filtered_offers_are_exported_to_csv_and_xlsx() {
+        let root = crate::paths::test_support::data_root();
+        let _guard = crate::paths::test_support::lock();
+        let store = data_store::store_file_path().unwrap();
+        fs::write(&store, serde_json::to_string(&sample_store()).unwrap()).unwrap();
+        let dir = root.join("exporter");
+        fs::create_dir_all(&dir).unwrap();
+
+        let csv_file = dir.join("ajanlatok.csv");
+        let result = export_spreadsheet(request(&csv_file, ExportFormat::Csv)).unwrap();
+        assert_eq!(result.offer_count, 1, "a szűrő csak az elfogadott ajánlatot engedi át");
+        assert_eq!(result.sheets[0].rows, 1);
+
+        // UTF-8 BOM, magyar fejlécek, tizedesvessző mellett pontosvessző elválasztó, HUF tizedesjegyek nélkül
+        let bytes = fs::read(&csv_file).unwrap();
+        assert_eq!(bytes[..3], [0xEF, 0xBB, 0xBF]);
+        let content = String::from_utf8(bytes[3..].to_vec()).unwrap();
+        let lines: Vec<&str> = content.lines().collect();
+        assert!(lines[0].starts_with("Azonosító;Dátum;Státusz;Ügyfél;Nyomtató;Eladási ár;Tétel;Márka"));
+        assert_eq!(lines[1], "1;2024-05-01;Elfogadva;Kovács Éva;;1500;1;Prusa;PLA;fekete;120;25,00;3,00;");
+
+        let xlsx_file = dir.join("ajanlatok.xlsx");
+        export_spreadsheet(request(&xlsx_file, ExportFormat::Xlsx)).unwrap();
+        let mut workbook = calamine::open_workbook_auto(&xlsx_file).unwrap();
+        let range = workbook.worksheet_range("Árajánlat tételek").unwrap();
+        assert_eq!(range.get_value((0, 3)), Some(&calamine::Data::String("Ügyfél".to_string())));
+        // A pénzösszeg valódi szám cella
+        assert_eq!(range.get_value((1, 5)), Some(&calamine::Data::Float(1500.0)));
+
+        fs::remove_file(&store).unwrap();
+    }
+}
@@ -0,0 +1,822 @@
+use crate::data_store;
+use crate::logger;
+use encoding_rs::Encoding;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io::Cursor;
+use std::path::Path;
+use tauri::AppHandle;
+
+/// Az előnézetben visszaadott sorok maximális száma (a validálás minden sorra lefut)
+const PREVIEW_ROW_LIMIT: usize = 200;
+
+/// Az automatikus elválasztó felismeréshez vizsgált sorok száma
+const DELIMITER_SAMPLE_LINES: usize = 20;
+
+/// Az elválasztó jelöltek (sorrend = egyenlő pontszámnál a preferencia)
+const DELIMITER_CANDIDATES: [u8; 4] = [b';', b',', b'\t', b'|'];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FieldKind {
+    Text,
+    Number,
+    Integer,
+    Color,
+}
+
+/// Egy importálható mező: kulcs a Store rekordban, típus, kötelező-e, és a fejléc felismeréshez használt nevek
+struct FieldSpec {
+    key: &'static str,
+    kind: FieldKind,
+    required: bool,
+    /// Kisbetűs, ékezet nélküli fejléc nevek (magyar / angol / német)
+    aliases: &'static [&'static str],
+}
+
+const FILAMENT_FIELDS: &[FieldSpec] = &[
+    FieldSpec {
+        key: "brand",
+        kind: FieldKind::Text,
+        required: true,
+        aliases: &["brand", "marka", "gyarto", "manufacturer", "marke", "hersteller"],
+    },
+    FieldSpec {
+        key: "type",
+        kind: FieldKind::Text,
+        required: true,
+        aliases: &["type", "tipus", "material", "anyag", "typ", "filament"],
+    },
+    FieldSpec {
+        key: "weight",
+        kind: FieldKind::Number,
+        required: true,
+        aliases: &["weight", "suly", "tomeg", "gewicht", "weight (g)", "suly (g)", "gramm", "g"],
+    },
+    FieldSpec {
+        key: "pricePerKg",
+        kind: FieldKind::Number,
+        required: true,
+        aliases: &["priceperkg", "price per kg", "price/kg", "ar/kg", "kilogrammonkenti ar", "ar", "price", "preis", "preis/kg"],
+    },
+    FieldSpec {
+        key: "density",
+        kind: FieldKind::Number,
+        required: false,
+        aliases: &["density", "suruseg", "dichte"],
+    },
+    FieldSpec {
+        key: "color",
+        kind: FieldKind::Text,
+        required: false,
+        aliases: &["color", "colour", "szin", "farbe", "color name", "szin neve"],
+    },
+    FieldSpec {
+        key: "colorHex",
+        kind: FieldKind::Color,
+        required: false,
+        aliases: &["colorhex", "color hex", "hex", "szinkod", "hex kod", "farbcode"],
+    },
+];
+
+const PRINTER_FIELDS: &[FieldSpec] = &[
+    FieldSpec {
+        key: "name",
+        kind: FieldKind::Text,
+        required: true,
+        aliases: &["name", "nev", "printer", "nyomtato", "drucker"],
+    },
+    FieldSpec {
+        key: "type",
+        kind: FieldKind::Text,
+        required: true,
+        aliases: &["type", "tipus", "model", "modell", "typ"],
+    },
+    FieldSpec {
+        key: "power",
+        kind: FieldKind::Number,
+        required: true,
+        aliases: &["power", "teljesitmeny", "watt", "w", "leistung", "power (w)"],
+    },
+    FieldSpec {
+        key: "usageCost",
+        kind: FieldKind::Number,
+        required: true,
+        aliases: &["usagecost", "usage cost", "uzemeltetesi koltseg", "koltseg", "kosten", "cost", "cost/h"],
+    },
+    FieldSpec {
+        key: "amsCount",
+        kind: FieldKind::Integer,
+        required: false,
+        aliases: &["amscount", "ams count", "ams", "ams szama", "ams anzahl"],
+    },
+];
+
+const CUSTOMER_FIELDS: &[FieldSpec] = &[
+    FieldSpec {
+        key: "name",
+        kind: FieldKind::Text,
+        required: true,
+        aliases: &["name", "nev", "customer", "ugyfel", "vevo", "kunde", "full name"],
+    },
+    FieldSpec {
+        key: "contact",
+        kind: FieldKind::Text,
+        required: false,
+        aliases: &["contact", "kapcsolat", "email", "e-mail", "phone", "telefon", "elerhetoseg", "kontakt"],
+    },
+    FieldSpec {
+        key: "company",
+        kind: FieldKind::Text,
+        required: false,
+        aliases: &["company", "ceg", "cegnev", "firma", "unternehmen"],
+    },
+    FieldSpec {
+        key: "address",
+        kind: FieldKind::Text,
+        required: false,
+        aliases: &["address", "cim", "adresse", "lakcim", "szekhely"],
+    },
+    FieldSpec {
+        key: "notes",
+        kind: FieldKind::Text,
+        required: false,
+        aliases: &["notes", "note", "megjegyzes", "megjegyzesek", "notizen", "bemerkung"],
+    },
+];
+
+fn field_specs(entity_type: &str) -> Result<&'static [FieldSpec], String> {
+    match entity_type {
+        "filaments" => Ok(FILAMENT_FIELDS),
+        "printers" => Ok(PRINTER_FIELDS),
+        "customers" => Ok(CUSTOMER_FIELDS),
+        _ => Err(format!("Nem importálható entitás típus: {}", entity_type)),
+    }
+}
+
+/// A fájl beolvasásának beállításai (minden mező opcionális, alapból automatikus felismerés)
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ImportOptions {
+    /// Kényszerített karakterkódolás (pl. "windows-1250", "utf-8")
+    pub encoding: Option<String>,
+    /// Kényszerített elválasztó karakter (CSV)
+    pub delimiter: Option<String>,
+    /// A beolvasandó munkalap neve (XLSX / ODS, alapból az első)
+    pub sheet: Option<String>,
+    /// Az első sor fejléc-e (alapból igen)
+    pub has_header: Option<bool>,
+    /// Mező kulcs -> oszlop index hozzárendelés (hiányában a fejlécből javasolt)
+    pub mapping: Option<HashMap<String, usize>>,
+}
+
+/// Egy importálható mező leírása a frontend számára
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportField {
+    pub key: String,
+    pub kind: String,
+    pub required: bool,
+}
+
+/// Egy cella validálási hibája
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportFieldError {
+    pub field: String,
+    pub message: String,
+}
+
+/// Egy beolvasott sor: nyers cellák, az elkészült rekord (ha érvényes) és a hibák
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportRow {
+    /// 1-től számozott sor a fájlban (fejléccel együtt)
+    pub row_number: usize,
+    pub cells: Vec<String>,
+    pub record: Option<Value>,
+    pub errors: Vec<ImportFieldError>,
+    /// Már létező rekorddal egyezik (filament: márka|típus|szín, egyébként név)
+    pub duplicate: bool,
+}
+
+/// Az import előnézete
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportPreview {
+    pub entity_type: String,
+    /// csv, xlsx, xls, ods
+    pub format: String,
+    /// Felismert (vagy kényszerített) karakterkódolás, táblázatnál None
+    pub encoding: Option<String>,
+    pub delimiter: Option<String>,
+    pub sheets: Vec<String>,
+    pub sheet: Option<String>,
+    pub headers: Vec<String>,
+    pub fields: Vec<ImportField>,
+    /// A ténylegesen használt hozzárendelés (mező kulcs -> oszlop index)
+    pub mapping: HashMap<String, usize>,
+    pub rows: Vec<ImportRow>,
+    pub total_rows: usize,
+    pub valid_rows: usize,
+    pub invalid_rows: usize,
+    pub duplicate_rows: usize,
+}
+
+/// Az import eredménye
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportResult {
+    pub entity_type: String,
+    pub imported: usize,
+    pub skipped_invalid: usize,
+    pub skipped_duplicates: usize,
+    pub safety_backup: Option<String>,
+}
+
+/// A beolvasott nyers táblázat
+struct RawTable {
+    format: String,
+    encoding: Option<String>,
+    delimiter: Option<String>,
+    sheets: Vec<String>,
+    sheet: Option<String>,
+    rows: Vec<Vec<String>>,
+}
+
+/// Bájtok szöveggé alakítása: érvényes UTF-8 (BOM-mal vagy anélkül) marad, egyébként kényszerített vagy felismert kódolás
+/// A magyar Excel CSV exportok jellemzően Windows-1250 kódolásúak
+fn decode_text(bytes: &[u8], forced: Option<&str>) -> Result<(String, String), String> {
+    if let Some(label) = forced.filter(|label| !label.trim().is_empty()) {
+        let encoding = Encoding::for_label(label.trim().as_bytes())
+            .ok_or_else(|| format!("Ismeretlen karakterkódolás: {}", label))?;
+        let (text, _, _) = encoding.decode(bytes);
+        return Ok((text.into_owned(), encoding.name().to_string()));
+    }
+
+    if let Some((encoding, bom_length)) = Encoding::for_bom(bytes) {
+        let (text, _) = encoding.decode_without_bom_handling(&bytes[bom_length..]);
+        return Ok((text.into_owned(), encoding.name().to_string()));
+    }
+    if let Ok(text) = std::str::from_utf8(bytes) {
+        return Ok((text.to_string(), encoding_rs::UTF_8.name().to_string()));
+    }
+
+    let mut detector = chardetng::EncodingDetector::new();
+    detector.feed(bytes, true);
+    let encoding = detector.guess(Some(b"hu"), true);
+    let (text, _, _) = encoding.decode(bytes);
+    Ok((text.into_owned(), encoding.name().to_string()))
+}
+
+/// Elválasztó felismerése: az a jelölt nyer, amelyik az első sorokban (idézőjeleken kívül) legtöbbször, egyenletesen szerepel
+fn detect_delimiter(text: &str) -> u8 {
+    let lines: Vec<&str> = text
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .take(DELIMITER_SAMPLE_LINES)
+        .collect();
+
+    let mut best = (b',', 0usize);
+    for candidate in DELIMITER_CANDIDATES {
+        let counts: Vec<usize> = lines
+            .iter()
+            .map(|line| {
+                let mut in_quotes = false;
+                line.bytes()
+                    .filter(|&b| {
+                        if b == b'"' {
+                            in_quotes = !in_quotes;
+                        }
+                        b == candidate && !in_quotes
+                    })
+                    .count()
+            })
+            .collect();
+        let Some(&first) = counts.first() else {
+            continue;
+        };
+        if first == 0 {
+            continue;
+        }
+        // Pontszám: az első sor oszlopszáma, ha a többi sor is ugyanennyi elválasztót tartalmaz
+        let consistent = counts.iter().filter(|&&count| count == first).count();
+        let score = first * consistent;
+        if score > best.1 {
+            best = (candidate, score);
+        }
+    }
+    best.0
+}
+
+fn delimiter_label(delimiter: u8) -> String {
+    match delimiter {
+        b'\t' => "\\t".to_string(),
+        other => (other as char).to_string(),
+    }
+}
+
+fn parse_delimiter(value: &str) -> Result<u8, String> {
+    match value {
+        "\\t" | "\t" | "tab" => Ok(b'\t'),
+        other if other.len() == 1 && other.is_ascii() => Ok(other.as_bytes()[0]),
+        other => Err(format!("Érvénytelen elválasztó karakter: {}", other)),
+    }
+}
+
+fn read_csv(bytes: &[u8], options: &ImportOptions) -> Result<RawTable, String> {
+    let (text, encoding) = decode_text(bytes, options.encoding.as_deref())?;
+    let delimiter = match options.delimiter.as_deref().filter(|d| !d.is_empty()) {
+        Some(forced) => parse_delimiter(forced)?,
+        None => detect_delimiter(&text),
+    };
+
+    let mut reader = csv::ReaderBuilder::new()
+        .delimiter(delimiter)
+        .has_headers(false)
+        .flexible(true)
+        .from_reader(text.as_bytes());
+    let mut rows = Vec::new();
+    for (index, record) in reader.records().enumerate() {
+        let record = record.map_err(|e| format!("CSV feldolgozási hiba a(z) {}. sorban: {}", index + 1, e))?;
+        rows.push(record.iter().map(|cell| cell.trim().to_string()).collect());
+    }
+
+    Ok(RawTable {
+        format: "csv".to_string(),
+        encoding: Some(encoding),
+        delimiter: Some(delimiter_label(delimiter)),
+        sheets: Vec::new(),
+        sheet: None,
+        rows,
+    })
+}
+
+fn read_spreadsheet(bytes: Vec<u8>, format: &str, options: &ImportOptions) -> Result<RawTable, String> {
+    use calamine::Reader;
+
+    let mut workbook = calamine::open_workbook_auto_from_rs(Cursor::new(bytes))
+        .map_err(|e| format!("Nem sikerült megnyitni a táblázatot: {}", e))?;
+    let sheets = workbook.sheet_names().to_vec();
+    let sheet = match options.sheet.as_ref().filter(|s| !s.is_empty()) {
+        Some(name) if sheets.contains(name) => name.clone(),
+        Some(name) => return Err(format!("A munkalap nem található: {}", name)),
+        None => sheets
+            .first()
+            .cloned()
+            .ok_or_else(|| "A táblázat nem tartalmaz munkalapot".to_string())?,
+    };
+    let range = workbook
+        .worksheet_range(&sheet)
+        .map_err(|e| format!("Nem sikerült beolvasni a munkalapot ({}): {}", sheet, e))?;
+
+    let rows = range
+        .rows()
+        .map(|row| row.iter().map(|cell| cell.to_string().trim().to_string()).collect())
+        .collect();
+
+    Ok(RawTable {
+        format: format.to_string(),
+        encoding: None,
+        delimiter: None,
+        sheets,
+        sheet: Some(sheet),
+        rows,
+    })
+}
+
+fn read_table(file_path: &Path, options: &ImportOptions) -> Result<RawTable, String> {
+    let bytes = fs::read(file_path).map_err(|e| format!("Nem sikerült beolvasni a fájlt: {}", e))?;
+    let extension = file_path
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or_default()
+        .to_lowercase();
+    match extension.as_str() {
+        "xlsx" | "xlsm" | "xls" | "ods" => read_spreadsheet(bytes, &extension, options),
+        _ => read_csv(&bytes, options),
+    }
+}
+
+/// Fejléc normalizálása a felismeréshez: kisbetű, ékezetek nélkül, egyszerűsített szóközök
+fn normalize_header(header: &str) -> String {
+    crate::search::fold_diacritics(header)
+        .to_lowercase()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Hozzárendelés javaslata a fejléc alapján (pontos egyezés, majd a mező kulcsa szerint)
+fn suggest_mapping(specs: &[FieldSpec], headers: &[String]) -> HashMap<String, usize> {
+    let normalized: Vec<String> = headers.iter().map(|h| normalize_header(h)).collect();
+    let mut used = HashSet::new();
+    let mut mapping = HashMap::new();
+    for spec in specs {
+        let key = spec.key.to_lowercase();
+        let found = normalized.iter().enumerate().find(|(index, header)| {
+            !used.contains(index) && (spec.aliases.contains(&header.as_str()) || **header == key)
+        });
+        if let Some((index, _)) = found {
+            used.insert(index);
+            mapping.insert(spec.key.to_string(), index);
+        }
+    }
+    mapping
+}
+
+/// Szám értelmezése magyar és angol formátumban is: "1 234,5", "1.234,5", "1,234.5", "25 €", "8 990 Ft"
+fn parse_number(raw: &str) -> Option<f64> {
+    let cleaned: String = raw
+        .chars()
+        .filter(|c| c.is_ascii_digit() || matches!(c, ',' | '.' | '-'))
+        .collect();
+    if cleaned.is_empty() || !cleaned.chars().any(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    let normalized = match (cleaned.rfind(','), cleaned.rfind('.')) {
+        // Mindkettő szerepel: a későbbi a tizedesjel, a másik ezres elválasztó
+        (Some(comma), Some(dot)) if comma > dot => cleaned.replace('.', "").replace(',', "."),
+        (Some(_), Some(_)) => cleaned.replace(',', ""),
+        // Csak vessző: több vessző ezres elválasztó, egy vessző tizedesjel (magyar formátum)
+        (Some(_), None) if cleaned.matches(',').count() > 1 => cleaned.replace(',', ""),
+        (Some(_), None) => cleaned.replace(',', "."),
+        // Csak pont: több pont ezres elválasztó (német formátum)
+        (None, Some(_)) if cleaned.matches('.').count() > 1 => cleaned.replace('.', ""),
+        _ => cleaned,
+    };
+    normalized.parse::<f64>().ok().filter(|n| n.is_finite())
+}
+
+fn parse_color_hex(raw: &str) -> Option<String> {
+    let hex = raw.trim().trim_start_matches('#');
+    let expanded = match hex.len() {
+        3 => hex.chars().flat_map(|c| [c, c]).collect::<String>(),
+        6 => hex.to_string(),
+        _ => return None,
+    };
+    expanded
+        .chars()
+        .all(|c| c.is_ascii_hexdigit())
+        .then(|| format!("#{}", expanded.to_uppercase()))
+}
+
+fn number_value(value: f64) -> Value {
+    serde_json::Number::from_f64(value).map(Value::Number).unwrap_or(Value::Null)
+}
+
+/// Egy cella értelmezése a mező típusa szerint (None = üres cella)
+fn convert_cell(spec: &FieldSpec, raw: &str) -> Result<Option<Value>, String> {
+    let raw = raw.trim();
+    if raw.is_empty() {
+        return Ok(None);
+    }
+    match spec.kind {
+        FieldKind::Text => Ok(Some(Value::String(raw.to_string()))),
+        FieldKind::Number => {
+            let number = parse_number(raw).ok_or_else(|| format!("Nem szám: \"{}\"", raw))?;
+            if number < 0.0 {
+                return Err(format!("Nem lehet negatív: {}", raw));
+            }
+            Ok(Some(number_value(number)))
+        }
+        FieldKind::Integer => {
+            let number = parse_number(raw).ok_or_else(|| format!("Nem szám: \"{}\"", raw))?;
+            if number.fract() != 0.0 || number < 0.0 {
+                return Err(format!("Nem nemnegatív egész szám: {}", raw));
+            }
+            Ok(Some(Value::from(number as u64)))
+        }
+        FieldKind::Color => parse_color_hex(raw)
+            .map(|hex| Some(Value::String(hex)))
+            .ok_or_else(|| format!("Érvénytelen színkód (#RRGGBB): \"{}\"", raw)),
+    }
+}
+
+/// Entitás-specifikus szabályok a mező szintű ellenőrzés után
+fn validate_record(entity_type: &str, record: &Map<String, Value>, errors: &mut Vec<ImportFieldError>) {
+    let number = |key: &str| record.get(key).and_then(|v| v.as_f64());
+    let mut error = |field: &str, message: &str| {
+        errors.push(ImportFieldError {
+            field: field.to_string(),
+            message: message.to_string(),
+        })
+    };
+    match entity_type {
+        "filaments" => {
+            if number("weight").is_some_and(|w| w <= 0.0) {
+                error("weight", "A súlynak pozitívnak kell lennie");
+            }
+            if number("density").is_some_and(|d| d <= 0.0 || d > 10.0) {
+                error("density", "A sűrűség 0 és 10 g/cm³ közé essen");
+            }
+        }
+        "printers" if number("amsCount").is_some_and(|n| n > 4.0) => {
+            error("amsCount", "Legfeljebb 4 AMS adható meg");
+        }
+        _ => {}
+    }
+}
+
+/// Egy sor átalakítása rekorddá a hozzárendelés szerint
+fn build_record(
+    entity_type: &str,
+    specs: &[FieldSpec],
+    mapping: &HashMap<String, usize>,
+    cells: &[String],
+) -> (Option<Map<String, Value>>, Vec<ImportFieldError>) {
+    let mut record = Map::new();
+    let mut errors = Vec::new();
+    for spec in specs {
+        let raw = mapping
+            .get(spec.key)
+            .and_then(|&column| cells.get(column))
+            .map(|cell| cell.as_str())
+            .unwrap_or_default();
+        match convert_cell(spec, raw) {
+            Ok(Some(value)) => {
+                record.insert(spec.key.to_string(), value);
+            }
+            Ok(None) if spec.required => errors.push(ImportFieldError {
+                field: spec.key.to_string(),
+                message: "Kötelező mező, de üres".to_string(),
+            }),
+            Ok(None) => {}
+            Err(message) => errors.push(ImportFieldError {
+                field: spec.key.to_string(),
+                message,
+            }),
+        }
+    }
+    validate_record(entity_type, &record, &mut errors);
+    if errors.is_empty() {
+        (Some(record), errors)
+    } else {
+        (None, errors)
+    }
+}
+
+/// Duplikátum kulcs: filamentnél márka|típus|szín, nyomtatónál és ügyfélnél a név
+fn duplicate_key(entity_type: &str, record: &Value) -> Option<String> {
+    if entity_type == "filaments" {
+        return data_store::record_key(entity_type, record);
+    }
+    record
+        .get("name")
+        .and_then(|v| v.as_str())
+        .map(|name| name.trim().to_lowercase())
+        .filter(|name| !name.is_empty())
+}
+
+fn existing_keys(entity_type: &str, store: &Map<String, Value>) -> HashSet<String> {
+    store
+        .get(entity_type)
+        .and_then(|v| v.as_array())
+        .map(|records| records.iter().filter_map(|r| duplicate_key(entity_type, r)).collect())
+        .unwrap_or_default()
+}
+
+/// A teljes fájl feldolgozása: beolvasás, hozzárendelés, soronkénti validálás és duplikátum jelölés
+fn analyze(file_path: &str, entity_type: &str, options: &ImportOptions) -> Result<ImportPreview, String> {
+    let specs = field_specs(entity_type)?;
+    let table = read_table(Path::new(file_path), options)?;
+
+    // Teljesen üres sorok kihagyása, a sorszámok a fájlbeli pozíciót követik
+    let mut rows = table
+        .rows
+        .into_iter()
+        .enumerate()
+        .map(|(index, cells)| (index + 1, cells))
+        .filter(|(_, cells)| cells.iter().any(|cell| !cell.is_empty()));
+
+    let (headers, data_rows): (Vec<String>, Vec<(usize, Vec<String>)>) = if options.has_header.unwrap_or(true) {
+        let headers = rows.next().map(|(_, cells)| cells).unwrap_or_default();
+        (headers, rows.collect())
+    } else {
+        let data_rows: Vec<(usize, Vec<String>)> = rows.collect();
+        let width = data_rows.iter().map(|(_, cells)| cells.len()).max().unwrap_or(0);
+        ((1..=width).map(|n| format!("#{}", n)).collect(), data_rows)
+    };
+
+    let mapping = match &options.mapping {
+        Some(mapping) => {
+            for key in mapping.keys() {
+                if !specs.iter().any(|spec| spec.key == key) {
+                    return Err(format!("Ismeretlen mező a hozzárendelésben: {}", key));
+                }
+            }
+            mapping.clone()
+        }
+        None => suggest_mapping(specs, &headers),
+    };
+
+    let store = data_store::read_store()?.unwrap_or_default();
+    let mut seen = existing_keys(entity_type, &store);
+
+    let mut preview = ImportPreview {
+        entity_type: entity_type.to_string(),
+        format: table.format,
+        encoding: table.encoding,
+        delimiter: table.delimiter,
+        sheets: table.sheets,
+        sheet: table.sheet,
+        headers,
+        fields: specs
+            .iter()
+            .map(|spec| ImportField {
+                key: spec.key.to_string(),
+                kind: format!("{:?}", spec.kind).to_lowercase(),
+                required: spec.required,
+            })
+            .collect(),
+        mapping,
+        rows: Vec::new(),
+        total_rows: data_rows.len(),
+        valid_rows: 0,
+        invalid_rows: 0,
+        duplicate_rows: 0,
+    };
+
+    for (row_number, cells) in data_rows {
+        let (record, errors) = build_record(entity_type, specs, &preview.mapping, &cells);
+        let record = record.map(Value::Object);
+        // A fájlon belüli ismétlődés is duplikátumnak számít
+        let duplicate = record
+            .as_ref()
+            .and_then(|r| duplicate_key(entity_type, r))
+            .is_some_and(|key| !seen.insert(key));
+
+        if record.is_some() {
+            preview.valid_rows += 1;
+        } else {
+            preview.invalid_rows += 1;
+        }
+        if duplicate {
+            preview.duplicate_rows += 1;
+        }
+        preview.rows.push(ImportRow {
+            row_number,
+            cells,
+            record,
+            errors,
+            duplicate,
+        });
+    }
+    Ok(preview)
+}
+
+/// Import előnézete: felismert kódolás / elválasztó, fejléc, javasolt vagy megadott hozzárendelés és soronkénti hibák
+/// Csak az első PREVIEW_ROW_LIMIT sor kerül vissza, a számlálók a teljes fájlra vonatkoznak
+#[tauri::command]
+pub fn preview_import(file_path: String, entity_type: String, options: Option<ImportOptions>) -> Result<ImportPreview, String> {
+    let mut preview = analyze(&file_path, &entity_type, &options.unwrap_or_default())?;
+    preview.rows.truncate(PREVIEW_ROW_LIMIT);
+    Ok(preview)
+}
+
+/// Az érvényes sorok tömeges felvétele a Store-ba (biztonsági mentés után)
+/// Az érvénytelen sorok mindig kimaradnak, a duplikátumok `skip_duplicates` esetén
+/// Nyomtatók és ügyfelek új, egyedi numerikus azonosítót kapnak, az ügyfelek létrehozási időt is
+#[tauri::command]
+pub fn apply_import(
+    app: AppHandle,
+    file_path: String,
+    entity_type: String,
+    options: Option<ImportOptions>,
+    skip_duplicates: bool,
+) -> Result<ImportResult, String> {
+    let preview = analyze(&file_path, &entity_type, &options.unwrap_or_default())?;
+    let mut store = data_store::read_store()?.unwrap_or_default();
+
+    let existing = store
+        .get(&entity_type)
+        .and_then(|v| v.as_array())
+        .cloned()
+        .unwrap_or_default();
+    // Az azonosítók a frontendhez hasonlóan időbélyeg alapúak, de mindig a legnagyobb meglévő fölött
+    let mut next_id = existing
+        .iter()
+        .filter_map(|r| r.get("id").and_then(|id| id.as_i64()))
+        .max()
+        .map(|max| max + 1)
+        .unwrap_or(0)
+        .max(chrono::Utc::now().timestamp_millis());
+    let now = chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true);
+
+    let mut records = existing;
+    let mut result = ImportResult {
+        entity_type: entity_type.clone(),
+        imported: 0,
+        skipped_invalid: preview.invalid_rows,
+        skipped_duplicates: 0,
+        safety_backup: None,
+    };
+    for row in preview.rows {
+        let Some(Value::Object(mut record)) = row.record else {
+            continue;
+        };
+        if row.duplicate && skip_duplicates {
+            result.skipped_duplicates += 1;
+            continue;
+        }
+        match entity_type.as_str() {
+            "printers" => {
+                record.insert("id".to_string(), Value::from(next_id));
+                next_id += 1;
+            }
+            "customers" => {
+                record.insert("id".to_string(), Value::from(next_id));
+                record.insert("createdAt".to_string(), Value::String(now.clone()));
+                record.insert("updatedAt".to_string(), Value::String(now.clone()));
+                next_id += 1;
+            }
+            _ => {}
+        }
+        records.push(Value::Object(record));
+        result.imported += 1;
+    }
+
+    if result.imported == 0 {
+        return Ok(result);
+    }
+    store.insert(entity_type.clone(), Value::Array(records));
+    let safety_backup = crate::restore::apply_store_data(&app, &store, "import")?;
+    result.safety_backup = safety_backup.map(|path| path.to_string_lossy().to_string());
+
+    logger::log_info(&format!(
+        "Import ({}): {} rekord felvéve, {} érvénytelen, {} duplikátum kihagyva - {}",
+        entity_type, result.imported, result.skipped_invalid, result.skipped_duplicates, file_path
+    ));
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    /// Magyar Excel CSV export: Windows-1250 kódolás, pontosvessző elválasztó, magyar fejlécek és számformátum
+    const SAMPLE_CSV: &str = "Márka;Típus;Súly (g);Ár/kg;Szín;Színkód\n\
+                              Prusa;PLA;1 000;8 990 Ft;Fekete;#0a0\n\
+                              ;PETG;sok;5000;Kék;\n\
+                              prusa;pla;1000;8990;fekete;\n";
+
+    #[test]
+    fn sample_csv_is_mapped_and_parsed() {
+        crate::paths::test_support::data_root();
+        let _guard = crate::paths::test_support::lock();
+        let dir = crate::paths::test_support::data_root().join("importer");
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("filamentek.csv");
+        let (bytes, _, _) = encoding_rs::WINDOWS_1250.encode(SAMPLE_CSV);
+        fs::write(&path, &bytes).unwrap();
+
+        let preview = analyze(path.to_str().unwrap(), "filaments", &ImportOptions::default()).unwrap();
+        // A felismert egybájtos közép-európai kódolással az ékezetes fejlécek helyesen olvashatók
+        assert_ne!(preview.encoding.as_deref(), Some("UTF-8"));
+        assert_eq!(preview.delimiter.as_deref(), Some(";"));
+        assert_eq!(preview.headers[..3], ["Márka", "Típus", "Súly (g)"]);
+        let expected_mapping = [("brand", 0), ("type", 1), ("weight", 2), ("pricePerKg", 3), ("color", 4), ("colorHex", 5)];
+        for (key, column) in expected_mapping {
+            assert_eq!(preview.mapping.get(key), Some(&column), "hozzárendelés: {}", key);
+        }
+
+        assert_eq!((preview.total_rows, preview.valid_rows, preview.invalid_rows, preview.duplicate_rows), (3, 2, 1, 1));
+        let first = &preview.rows[0];
+        assert_eq!(first.row_number, 2);
+        assert_eq!(
+            first.record,
+            Some(json!({
+                "brand": "Prusa",
+                "type": "PLA",
+                "weight": 1000.0,
+                "pricePerKg": 8990.0,
+                "color": "Fekete",
+                "colorHex": "#00AA00"
+            }))
+        );
+
+        let invalid = &preview.rows[1];
+        assert!(invalid.record.is_none());
+        let fields: Vec<&str> = invalid.errors.iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(fields, vec!["brand", "weight"]);
+        assert!(preview.rows[2].duplicate, "kis- és nagybetűtől függetlenül ismétlődő filament");
+    }
+
+    #[test]
+    fn numbers_are_parsed_in_hungarian_and_english_formats() {
+        let cases = [
+            ("1 234,5", 1234.5),
+            ("1.234,5", 1234.5),
+            ("1,234.5", 1234.5),
+            ("25 €", 25.0),
+            ("1.234.567", 1234567.0),
+        ];
+        for (raw, expected) in cases {
+            assert_eq!(parse_number(raw), Some(expected), "szám: {}", raw);
+        }
+        assert_eq!(parse_number("n/a"), None);
+    }
+}
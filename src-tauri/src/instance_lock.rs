@@ -0,0 +1,290 @@
+use crate::logger;
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, System};
+use tauri::{AppHandle, Emitter, Manager};
+use tauri_plugin_dialog::{DialogExt, MessageDialogKind};
+use tauri_plugin_fs::FsExt;
+
+/// A frontendnek küldött esemény, ha egy második indítás átadta a parancssori argumentumait
+pub const SECOND_INSTANCE_EVENT: &str = "second-instance";
+
+/// A lock fájl neve az adatkönyvtárban
+pub const LOCK_FILE_NAME: &str = "instance.lock";
+
+/// Ennyiszer próbáljuk újraolvasni a lock fájlt, ha épp most jött létre és még üres
+const LOCK_READ_ATTEMPTS: u32 = 5;
+const LOCK_READ_RETRY_DELAY: Duration = Duration::from_millis(100);
+
+/// Az indítás meghiúsult (pl. foglalt adatkönyvtár): a hibaüzenet bezárásáig nem lépünk ki, utána igen
+static STARTUP_FAILED: AtomicBool = AtomicBool::new(false);
+
+lazy_static! {
+    /// A futó példány által birtokolt lock fájl (kilépéskor töröljük)
+    static ref HELD_LOCK: Mutex<Option<PathBuf>> = Mutex::new(None);
+    /// Az indításkor / második indításból kapott, még fel nem dolgozott megnyitási kérések
+    static ref PENDING_LAUNCH_REQUESTS: Mutex<Vec<LaunchRequest>> = Mutex::new(Vec::new());
+}
+
+/// A lock fájl tartalma: melyik folyamat birtokolja az adatkönyvtárat
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LockInfo {
+    pub pid: u32,
+    /// A folyamat indulási ideje (unix mp) - ezzel szűrjük ki az újrahasznosított PID-eket
+    pub process_start_time: u64,
+    pub executable: Option<String>,
+    pub acquired_at: String,
+}
+
+/// Megnyitási kérés: parancssori argumentumok és a belőlük kiolvasott létező fájlok
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LaunchRequest {
+    pub args: Vec<String>,
+    pub cwd: String,
+    pub files: Vec<String>,
+    /// Második indításból érkezett-e (false = a saját indítási argumentumaink)
+    pub forwarded: bool,
+    pub received_at: String,
+}
+
+fn lock_path() -> Result<PathBuf, String> {
+    Ok(crate::paths::installation_root()?.join(LOCK_FILE_NAME))
+}
+
+fn current_process_info() -> Result<LockInfo, String> {
+    let pid = std::process::id();
+    let mut system = System::new();
+    system.refresh_processes_specifics(ProcessesToUpdate::Some(&[Pid::from_u32(pid)]), ProcessRefreshKind::new());
+    let process_start_time = system
+        .process(Pid::from_u32(pid))
+        .map(|process| process.start_time())
+        .unwrap_or(0);
+    Ok(LockInfo {
+        pid,
+        process_start_time,
+        executable: std::env::current_exe().ok().map(|p| p.to_string_lossy().to_string()),
+        acquired_at: chrono::Utc::now().to_rfc3339(),
+    })
+}
+
+/// Él-e még a lock fájlban megadott folyamat (ugyanazzal az indulási idővel)
+fn is_lock_owner_alive(info: &LockInfo) -> bool {
+    let pid = Pid::from_u32(info.pid);
+    let mut system = System::new();
+    system.refresh_processes_specifics(ProcessesToUpdate::Some(&[pid]), ProcessRefreshKind::new());
+    match system.process(pid) {
+        Some(process) => info.process_start_time == 0 || process.start_time() == info.process_start_time,
+        None => false,
+    }
+}
+
+/// A lock fájl beolvasása; a frissen létrehozott (még üres) fájlt néhányszor újraolvassuk
+fn read_lock(path: &Path) -> Option<LockInfo> {
+    for attempt in 0..LOCK_READ_ATTEMPTS {
+        if attempt > 0 {
+            std::thread::sleep(LOCK_READ_RETRY_DELAY);
+        }
+        match fs::read_to_string(path) {
+            Ok(content) => {
+                if let Ok(info) = serde_json::from_str::<LockInfo>(&content) {
+                    return Some(info);
+                }
+            }
+            // Közben eltűnt: nincs tulajdonos
+            Err(_) => return None,
+        }
+    }
+    None
+}
+
+/// Lock fájl létrehozása (csak ha még nem létezik - két folyamat közül csak az egyik nyerhet)
+fn try_create_lock(path: &Path, info: &LockInfo) -> std::io::Result<()> {
+    let mut file = fs::OpenOptions::new().write(true).create_new(true).open(path)?;
+    let content = serde_json::to_vec_pretty(info).map_err(std::io::Error::other)?;
+    file.write_all(&content)?;
+    file.sync_all()
+}
+
+/// Az adatkönyvtár zárolása erre a folyamatra
+/// Ha egy másik futó példány birtokolja, hibát ad; egy összeomlott folyamat elárvult lockját átvesszük
+pub fn acquire() -> Result<LockInfo, String> {
+    let path = lock_path()?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("Nem sikerült létrehozni az adatkönyvtárat: {}", e))?;
+    }
+    let info = current_process_info()?;
+
+    // Legfeljebb két kör: ha elárvult lockot találunk, félretesszük és újra próbálkozunk
+    for _ in 0..2 {
+        match try_create_lock(&path, &info) {
+            Ok(()) => {
+                *HELD_LOCK.lock().unwrap_or_else(|e| e.into_inner()) = Some(path.clone());
+                logger::log_info(&format!("Adatkönyvtár zárolva (PID {}): {}", info.pid, path.display()));
+                return Ok(info);
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {}
+            Err(e) => return Err(format!("Nem sikerült létrehozni a lock fájlt: {}", e)),
+        }
+
+        match read_lock(&path) {
+            Some(owner) if owner.pid != info.pid && is_lock_owner_alive(&owner) => {
+                return Err(format!(
+                    "Az alkalmazás már fut (PID {}, indítva: {}), ugyanazt az adatkönyvtárat két példány nem használhatja",
+                    owner.pid, owner.acquired_at
+                ));
+            }
+            owner => {
+                // Elárvult lock (összeomlott folyamat, vagy olvashatatlan fájl): átnevezéssel vesszük el,
+                // így ha két példány egyszerre találja meg, csak az egyiknek sikerül
+                let stale_path = path.with_file_name(format!("{}.stale-{}", LOCK_FILE_NAME, info.pid));
+                if fs::rename(&path, &stale_path).is_ok() {
+                    let _ = fs::remove_file(&stale_path);
+                    logger::log_warn(&format!(
+                        "Elárvult lock fájl eltávolítva{}",
+                        owner
+                            .map(|o| format!(" (PID {}, {})", o.pid, o.acquired_at))
+                            .unwrap_or_default()
+                    ));
+                }
+            }
+        }
+    }
+
+    Err("Nem sikerült zárolni az adatkönyvtárat: a lock fájlt egy másik példány foglalja".to_string())
+}
+
+/// A lock feloldása kilépéskor (csak a saját lockunkat töröljük)
+pub fn release() {
+    let Some(path) = HELD_LOCK.lock().unwrap_or_else(|e| e.into_inner()).take() else {
+        return;
+    };
+    let owned = read_lock(&path).is_some_and(|owner| owner.pid == std::process::id());
+    if owned {
+        match fs::remove_file(&path) {
+            Ok(_) => logger::log_info("Adatkönyvtár lock feloldva"),
+            Err(e) => logger::log_warn(&format!("Nem sikerült törölni a lock fájlt: {}", e)),
+        }
+    }
+}
+
+/// Meghiúsult indítás: hibaüzenet megjelenítése, majd kilépés (a setup hibájával a Tauri pánikolna)
+/// A fő ablakot bezárjuk, hogy a frontend ne nyúljon egy másik példány adataihoz; a setup a fő szálon fut,
+/// ahol a blokkoló dialógus nem használható, ezért az üzenet külön szálon jelenik meg
+pub fn exit_with_startup_error(app: &AppHandle, message: &str) {
+    logger::log_error(&format!("Az alkalmazás nem indítható: {}", message));
+    STARTUP_FAILED.store(true, Ordering::SeqCst);
+    if let Some(window) = app.get_webview_window("main") {
+        if let Err(e) = window.destroy() {
+            logger::log_warn(&format!("Nem sikerült bezárni az ablakot: {}", e));
+        }
+    }
+
+    let handle = app.clone();
+    let message = message.to_string();
+    let spawned = std::thread::Builder::new()
+        .name("startup-error".to_string())
+        .spawn(move || {
+            handle
+                .dialog()
+                .message(message)
+                .title("Az alkalmazás nem indítható")
+                .kind(MessageDialogKind::Error)
+                .blocking_show();
+            handle.exit(1);
+        });
+    if let Err(e) = spawned {
+        logger::log_error(&format!("Nem sikerült megjeleníteni a hibaüzenetet: {}", e));
+        app.exit(1);
+    }
+}
+
+/// Meghiúsult-e az indítás (lásd exit_with_startup_error)
+pub fn startup_failed() -> bool {
+    STARTUP_FAILED.load(Ordering::SeqCst)
+}
+
+/// Parancssori argumentumokból megnyitási kérés (a program neve és a kapcsolók nélkül)
+fn launch_request(argv: Vec<String>, cwd: String, forwarded: bool) -> LaunchRequest {
+    let files = argv
+        .iter()
+        .skip(1)
+        .filter(|arg| !arg.starts_with('-'))
+        .map(|arg| {
+            let path = Path::new(arg);
+            if path.is_absolute() {
+                path.to_path_buf()
+            } else {
+                Path::new(&cwd).join(path)
+            }
+        })
+        .filter(|path| path.is_file())
+        .map(|path| path.to_string_lossy().to_string())
+        .collect();
+    LaunchRequest {
+        args: argv,
+        cwd,
+        files,
+        forwarded,
+        received_at: chrono::Utc::now().to_rfc3339(),
+    }
+}
+
+/// A saját indítási argumentumok sorba állítása (pl. fájlra kattintva indított alkalmazás)
+pub fn queue_startup_args() {
+    let cwd = std::env::current_dir()
+        .map(|p| p.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let request = launch_request(std::env::args().collect(), cwd, false);
+    if !request.files.is_empty() {
+        logger::log_info(&format!("Megnyitandó fájlok indításkor: {}", request.files.join(", ")));
+        PENDING_LAUNCH_REQUESTS.lock().unwrap_or_else(|e| e.into_inner()).push(request);
+    }
+}
+
+/// Második indítás: az argumentumai a futó példányhoz kerülnek, és az ablakát előtérbe hozzuk
+/// (a tauri-plugin-single-instance hívja, a második folyamat ezután kilép)
+pub fn handle_second_instance(app: &AppHandle, argv: Vec<String>, cwd: String) {
+    let request = launch_request(argv, cwd, true);
+    logger::log_info(&format!(
+        "Második indítás átirányítva a futó példányhoz ({} argumentum, {} fájl)",
+        request.args.len().saturating_sub(1),
+        request.files.len()
+    ));
+
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.unminimize();
+        let _ = window.show();
+        if let Err(e) = window.set_focus() {
+            logger::log_warn(&format!("Nem sikerült előtérbe hozni az ablakot: {}", e));
+        }
+    }
+
+    PENDING_LAUNCH_REQUESTS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .push(request.clone());
+    if let Err(e) = app.emit(SECOND_INSTANCE_EVENT, &request) {
+        logger::log_warn(&format!("Nem sikerült értesíteni a frontendet a második indításról: {}", e));
+    }
+}
+
+/// A még fel nem dolgozott megnyitási kérések átvétele (a sor kiürül)
+/// Az átadott fájlokat a frontend olvashatja (a fájlválasztóval kiválasztott fájlokhoz hasonlóan)
+#[tauri::command]
+pub fn take_pending_launch_requests(app: AppHandle) -> Vec<LaunchRequest> {
+    let requests = std::mem::take(&mut *PENDING_LAUNCH_REQUESTS.lock().unwrap_or_else(|e| e.into_inner()));
+    for file in requests.iter().flat_map(|request| &request.files) {
+        if let Err(e) = app.fs_scope().allow_file(file) {
+            logger::log_warn(&format!("Nem sikerült engedélyezni a fájl olvasását: {} - {}", file, e));
+        }
+    }
+    requests
+}
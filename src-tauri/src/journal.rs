@@ -0,0 +1,605 @@
+use crate::data_store;
+use crate::logger;
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, HashMap};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use tauri::AppHandle;
+
+lazy_static! {
+    /// A napló alapján ismert utolsó állapot (entitás típus -> rekord kulcs -> rekord)
+    /// Első használatkor a kiindulási pillanatképből és a naplóból épül fel
+    static ref JOURNAL_STATE: Mutex<Option<JournalState>> = Mutex::new(None);
+}
+
+/// A naplózott entitás típusok (az árelőzmények származtatott adatok, azokat nem naplózzuk)
+pub const JOURNALED_ENTITY_TYPES: [&str; 7] = [
+    "printers",
+    "filaments",
+    "offers",
+    "customers",
+    "projects",
+    "tasks",
+    "templates",
+];
+
+/// A kiindulási pillanatkép: a napló ettől az állapottól kezdve rögzíti a változásokat
+const CHECKPOINT_FILE: &str = "checkpoint.json";
+
+/// A naplófájlok előtagja (havonta új fájl: journal-2024-05.jsonl)
+const SEGMENT_PREFIX: &str = "journal-";
+const SEGMENT_EXTENSION: &str = "jsonl";
+
+/// A böngészéskor visszaadott bejegyzések alapértelmezett maximuma
+const DEFAULT_ENTRY_LIMIT: usize = 500;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum JournalOperation {
+    Create,
+    Update,
+    Delete,
+}
+
+/// Egy rekord változása a korábbi és az új értékkel
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JournalEntry {
+    pub seq: u64,
+    pub timestamp: String,
+    pub entity_type: String,
+    pub record_key: String,
+    pub operation: JournalOperation,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+    /// Honnan származik a változás: frontend mentés, restore, import, journal-restore...
+    pub source: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Checkpoint {
+    created_at: String,
+    /// Az utolsó, a pillanatképben már benne lévő bejegyzés sorszáma
+    seq: u64,
+    data: Map<String, Value>,
+}
+
+struct JournalState {
+    next_seq: u64,
+    checkpoint_at: String,
+    records: HashMap<String, BTreeMap<String, Value>>,
+}
+
+/// A napló összefoglalója a frontend számára
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JournalStatus {
+    /// A legkorábbi időpont, amelyre az adatok visszaállíthatók
+    pub checkpoint_at: String,
+    pub entry_count: u64,
+    pub segments: Vec<String>,
+    pub total_size: u64,
+}
+
+/// Point-in-time visszaállítás eredménye
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JournalRestoreResult {
+    pub timestamp: String,
+    pub records: HashMap<String, usize>,
+    pub safety_backup: Option<String>,
+}
+
+fn checkpoint_path() -> Result<PathBuf, String> {
+    Ok(crate::paths::journal_dir()?.join(CHECKPOINT_FILE))
+}
+
+fn segment_path(timestamp: &chrono::DateTime<chrono::Utc>) -> Result<PathBuf, String> {
+    Ok(crate::paths::journal_dir()?.join(format!(
+        "{}{}.{}",
+        SEGMENT_PREFIX,
+        timestamp.format("%Y-%m"),
+        SEGMENT_EXTENSION
+    )))
+}
+
+/// A naplófájlok időrendben (a fájlnév a hónapot tartalmazza)
+fn segment_files() -> Result<Vec<PathBuf>, String> {
+    let dir = crate::paths::journal_dir()?;
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    let mut files: Vec<PathBuf> = fs::read_dir(&dir)
+        .map_err(|e| format!("Nem sikerült olvasni a napló könyvtárat: {}", e))?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|n| n.starts_with(SEGMENT_PREFIX) && n.ends_with(SEGMENT_EXTENSION))
+        })
+        .collect();
+    files.sort();
+    Ok(files)
+}
+
+/// Az összes, rekord tartalmat hordozó naplófájl (a vault ezeket is titkosítja / visszafejti)
+pub fn journal_files() -> Result<Vec<PathBuf>, String> {
+    let mut files = segment_files()?;
+    let checkpoint = checkpoint_path()?;
+    if checkpoint.exists() {
+        files.push(checkpoint);
+    }
+    Ok(files)
+}
+
+fn now_timestamp() -> String {
+    chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true)
+}
+
+/// Időbélyeg egységes (UTC, ezredmásodperces) alakra hozása az összehasonlításhoz
+/// Csak dátum esetén a nap vége számít ("mi volt kedden" = kedd este)
+fn normalize_timestamp(value: &str) -> Result<String, String> {
+    if let Ok(parsed) = chrono::DateTime::parse_from_rfc3339(value) {
+        return Ok(parsed
+            .with_timezone(&chrono::Utc)
+            .to_rfc3339_opts(chrono::SecondsFormat::Millis, true));
+    }
+    let date = chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .map_err(|_| format!("Érvénytelen időpont: {}", value))?;
+    let end_of_day = date
+        .and_hms_milli_opt(23, 59, 59, 999)
+        .and_then(|local| local.and_local_timezone(chrono::Local).earliest())
+        .ok_or_else(|| format!("Érvénytelen időpont: {}", value))?;
+    Ok(end_of_day
+        .with_timezone(&chrono::Utc)
+        .to_rfc3339_opts(chrono::SecondsFormat::Millis, true))
+}
+
+pub fn index_records(entity_type: &str, records: &[Value]) -> BTreeMap<String, Value> {
+    records
+        .iter()
+        .filter_map(|record| data_store::record_key(entity_type, record).map(|key| (key, record.clone())))
+        .collect()
+}
+
+/// Rekordok listává alakítása: numerikus kulcsok (időbélyeg alapú azonosítók) szám szerint, a többi betűrendben
+fn ordered_records(records: &BTreeMap<String, Value>) -> Vec<Value> {
+    let mut entries: Vec<(&String, &Value)> = records.iter().collect();
+    entries.sort_by(|(a, _), (b, _)| match (a.parse::<f64>(), b.parse::<f64>()) {
+        (Ok(a), Ok(b)) => a.total_cmp(&b),
+        _ => a.cmp(b),
+    });
+    entries.into_iter().map(|(_, record)| record.clone()).collect()
+}
+
+fn apply_entry(records: &mut HashMap<String, BTreeMap<String, Value>>, entry: &JournalEntry) {
+    let entity = records.entry(entry.entity_type.clone()).or_default();
+    match (&entry.operation, &entry.after) {
+        (JournalOperation::Delete, _) | (_, None) => {
+            entity.remove(&entry.record_key);
+        }
+        (_, Some(after)) => {
+            entity.insert(entry.record_key.clone(), after.clone());
+        }
+    }
+}
+
+fn read_checkpoint() -> Result<Option<Checkpoint>, String> {
+    let path = checkpoint_path()?;
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content = fs::read_to_string(&path)
+        .map_err(|e| format!("Nem sikerült beolvasni a napló pillanatképét: {}", e))?;
+    let content = crate::vault::open_lines(&content)?;
+    serde_json::from_str(content.trim())
+        .map(Some)
+        .map_err(|e| format!("Érvénytelen napló pillanatkép: {}", e))
+}
+
+/// Kiindulási pillanatkép a jelenlegi Store-ból (egy sorban, hogy a vault soronként titkosíthassa)
+fn write_checkpoint(data: &Map<String, Value>, seq: u64) -> Result<Checkpoint, String> {
+    let checkpoint = Checkpoint {
+        created_at: now_timestamp(),
+        seq,
+        data: JOURNALED_ENTITY_TYPES
+            .iter()
+            .filter_map(|key| data.get(*key).map(|value| (key.to_string(), value.clone())))
+            .collect(),
+    };
+    let line = serde_json::to_string(&checkpoint)
+        .map_err(|e| format!("Napló pillanatkép szerializálási hiba: {}", e))?;
+    let line = crate::vault::seal_line(&line)?;
+    crate::atomic_write::write_atomic(&checkpoint_path()?, format!("{}\n", line))
+        .map_err(|e| format!("Nem sikerült menteni a napló pillanatképét: {}", e))?;
+    Ok(checkpoint)
+}
+
+/// Az összes naplóbejegyzés beolvasása időrendben (a sérült sorokat kihagyja és logolja)
+fn read_entries() -> Result<Vec<JournalEntry>, String> {
+    let mut entries = Vec::new();
+    for path in segment_files()? {
+        let content = fs::read_to_string(&path)
+            .map_err(|e| format!("Nem sikerült beolvasni a naplófájlt: {} - {}", path.display(), e))?;
+        let content = crate::vault::open_lines(&content)?;
+        for line in content.lines().filter(|l| !l.trim().is_empty()) {
+            match serde_json::from_str::<JournalEntry>(line) {
+                Ok(entry) => entries.push(entry),
+                // Összeomláskor félbeírt utolsó sor - a többi bejegyzés ettől még érvényes
+                Err(e) => logger::log_warn(&format!("Sérült naplóbejegyzés kihagyva ({}): {}", path.display(), e)),
+            }
+        }
+    }
+    entries.sort_by_key(|entry| entry.seq);
+    Ok(entries)
+}
+
+/// A napló állapotának betöltése: pillanatkép + az azóta rögzített bejegyzések
+/// Ha még nincs pillanatkép, a jelenlegi Store-ból készül egy (ettől kezdve naplózunk)
+fn load_state() -> Result<JournalState, String> {
+    let checkpoint = match read_checkpoint()? {
+        Some(checkpoint) => checkpoint,
+        None => {
+            let data = data_store::read_store()?.unwrap_or_default();
+            let checkpoint = write_checkpoint(&data, 0)?;
+            logger::log_info("Változásnapló elindítva a jelenlegi adatokból");
+            checkpoint
+        }
+    };
+
+    let mut records: HashMap<String, BTreeMap<String, Value>> = JOURNALED_ENTITY_TYPES
+        .iter()
+        .map(|entity_type| {
+            let list = checkpoint
+                .data
+                .get(*entity_type)
+                .and_then(|v| v.as_array())
+                .map(|a| a.as_slice())
+                .unwrap_or_default();
+            (entity_type.to_string(), index_records(entity_type, list))
+        })
+        .collect();
+
+    let mut next_seq = checkpoint.seq + 1;
+    for entry in read_entries()?.iter().filter(|entry| entry.seq > checkpoint.seq) {
+        apply_entry(&mut records, entry);
+        next_seq = entry.seq + 1;
+    }
+
+    Ok(JournalState {
+        next_seq,
+        checkpoint_at: checkpoint.created_at,
+        records,
+    })
+}
+
+fn with_state<T>(f: impl FnOnce(&mut JournalState) -> Result<T, String>) -> Result<T, String> {
+    if crate::vault::is_locked() {
+        return Err("Az adattároló zárolva van, előbb oldja fel".to_string());
+    }
+    let mut guard = JOURNAL_STATE.lock().map_err(|e| format!("Napló zárolási hiba: {}", e))?;
+    if guard.is_none() {
+        *guard = Some(load_state()?);
+    }
+    match guard.as_mut() {
+        Some(state) => f(state),
+        None => Err("A napló nem érhető el".to_string()),
+    }
+}
+
+/// Bejegyzések hozzáfűzése a havi naplófájlhoz (csak hozzáfűzés, a meglévő sorokat soha nem írjuk át)
+fn append_entries(entries: &[JournalEntry]) -> Result<(), String> {
+    let path = segment_path(&chrono::Utc::now())?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("Nem sikerült létrehozni a napló könyvtárat: {}", e))?;
+    }
+
+    let mut buffer = String::new();
+    for entry in entries {
+        let line = serde_json::to_string(entry).map_err(|e| format!("Naplóbejegyzés szerializálási hiba: {}", e))?;
+        buffer.push_str(&crate::vault::seal_line(&line)?);
+        buffer.push('\n');
+    }
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .map_err(|e| format!("Nem sikerült megnyitni a naplófájlt: {}", e))?;
+    file.write_all(buffer.as_bytes())
+        .and_then(|_| file.sync_data())
+        .map_err(|e| format!("Nem sikerült írni a naplófájlt: {}", e))
+}
+
+/// Egy entitás típus teljes listájának összevetése az ismert állapottal, a különbségek naplózása
+fn record_in_state(
+    state: &mut JournalState,
+    entity_type: &str,
+    records: &[Value],
+    source: &str,
+) -> Result<usize, String> {
+    let previous = state.records.entry(entity_type.to_string()).or_default();
+    let current = index_records(entity_type, records);
+    let timestamp = now_timestamp();
+
+    let mut entries = Vec::new();
+    let mut push = |key: &String, operation, before: Option<&Value>, after: Option<&Value>| {
+        entries.push(JournalEntry {
+            seq: 0,
+            timestamp: timestamp.clone(),
+            entity_type: entity_type.to_string(),
+            record_key: key.clone(),
+            operation,
+            before: before.cloned(),
+            after: after.cloned(),
+            source: source.to_string(),
+        });
+    };
+    for (key, record) in &current {
+        match previous.get(key) {
+            None => push(key, JournalOperation::Create, None, Some(record)),
+            Some(before) if before != record => push(key, JournalOperation::Update, Some(before), Some(record)),
+            Some(_) => {}
+        }
+    }
+    for (key, before) in previous.iter() {
+        if !current.contains_key(key) {
+            push(key, JournalOperation::Delete, Some(before), None);
+        }
+    }
+
+    if entries.is_empty() {
+        return Ok(0);
+    }
+    for entry in entries.iter_mut() {
+        entry.seq = state.next_seq;
+        state.next_seq += 1;
+    }
+    append_entries(&entries)?;
+    *previous = current;
+    Ok(entries.len())
+}
+
+/// A teljes Store változásainak naplózása (backend írások: visszaállítás, import, point-in-time restore)
+pub fn record_store(data: &Map<String, Value>, source: &str) -> Result<usize, String> {
+    with_state(|state| {
+        let mut total = 0;
+        for entity_type in JOURNALED_ENTITY_TYPES {
+            if let Some(records) = data.get(entity_type).and_then(|v| v.as_array()) {
+                total += record_in_state(state, entity_type, records, source)?;
+            }
+        }
+        Ok(total)
+    })
+}
+
+/// Indításkor: a napló betöltése (és első alkalommal a kiindulási pillanatkép elkészítése)
+/// a frontend első mentése előtt, hogy az első változás is a korábbi értékkel kerüljön a naplóba
+pub fn initialize() -> Result<(), String> {
+    if crate::vault::is_locked() {
+        return Ok(());
+    }
+    with_state(|_| Ok(()))
+}
+
+/// A memóriában tartott állapot eldobása (munkaterület váltáskor a következő használat az új naplóból tölt)
+pub fn reset() {
+    if let Ok(mut guard) = JOURNAL_STATE.lock() {
+        *guard = None;
+    }
+}
+
+/// Az adatok felépítése egy adott időpontra: pillanatkép + az addig rögzített bejegyzések
+fn rebuild_as_of(timestamp: &str) -> Result<HashMap<String, BTreeMap<String, Value>>, String> {
+    let target = normalize_timestamp(timestamp)?;
+    // A zárolás alatt olvasunk, hogy egy közben futó mentés ne keveredjen bele
+    with_state(|state| {
+        if target < state.checkpoint_at {
+            return Err(format!(
+                "A napló csak {} óta tartalmaz adatokat, korábbi állapot nem állítható elő",
+                state.checkpoint_at
+            ));
+        }
+        let checkpoint = read_checkpoint()?.ok_or_else(|| "Hiányzik a napló pillanatképe".to_string())?;
+        let mut records: HashMap<String, BTreeMap<String, Value>> = JOURNALED_ENTITY_TYPES
+            .iter()
+            .map(|entity_type| {
+                let list = checkpoint
+                    .data
+                    .get(*entity_type)
+                    .and_then(|v| v.as_array())
+                    .map(|a| a.as_slice())
+                    .unwrap_or_default();
+                (entity_type.to_string(), index_records(entity_type, list))
+            })
+            .collect();
+        for entry in read_entries()?
+            .iter()
+            .filter(|entry| entry.seq > checkpoint.seq && entry.timestamp <= target)
+        {
+            apply_entry(&mut records, entry);
+        }
+        Ok(records)
+    })
+}
+
+fn validate_entity_type(entity_type: &str) -> Result<(), String> {
+    if JOURNALED_ENTITY_TYPES.contains(&entity_type) {
+        Ok(())
+    } else {
+        Err(format!("Nem naplózott entitás típus: {}", entity_type))
+    }
+}
+
+/// A frontend mentés utáni értesítése: a teljes lista összevetése az előző állapottal
+/// Új, módosított és törölt rekordonként egy-egy bejegyzés kerül a naplóba (korábbi és új értékkel)
+#[tauri::command]
+pub fn record_entity_changes(entity_type: String, records: Vec<Value>) -> Result<usize, String> {
+    if !JOURNALED_ENTITY_TYPES.contains(&entity_type.as_str()) {
+        return Ok(0);
+    }
+    with_state(|state| record_in_state(state, &entity_type, &records, "frontend"))
+}
+
+/// Egy rekord előzményei (legújabb elöl)
+/// A kulcs numerikus azonosítónál az id, filamentnél "márka|típus|szín" kisbetűvel (lásd data_store::record_key)
+#[tauri::command]
+pub fn get_record_history(entity_type: String, record_key: String, limit: Option<usize>) -> Result<Vec<JournalEntry>, String> {
+    validate_entity_type(&entity_type)?;
+    with_state(|_| Ok(()))?;
+    let mut entries: Vec<JournalEntry> = read_entries()?
+        .into_iter()
+        .filter(|entry| entry.entity_type == entity_type && entry.record_key == record_key)
+        .collect();
+    entries.reverse();
+    entries.truncate(limit.unwrap_or(DEFAULT_ENTRY_LIMIT));
+    Ok(entries)
+}
+
+/// Naplóbejegyzések böngészése időszak és entitás típus szerint (legújabb elöl)
+#[tauri::command]
+pub fn get_journal_entries(
+    entity_type: Option<String>,
+    since: Option<String>,
+    until: Option<String>,
+    limit: Option<usize>,
+) -> Result<Vec<JournalEntry>, String> {
+    with_state(|_| Ok(()))?;
+    let since = since.as_deref().map(normalize_timestamp).transpose()?;
+    let until = until.as_deref().map(normalize_timestamp).transpose()?;
+    let mut entries: Vec<JournalEntry> = read_entries()?
+        .into_iter()
+        .filter(|entry| entity_type.as_ref().map_or(true, |t| &entry.entity_type == t))
+        .filter(|entry| since.as_ref().map_or(true, |s| &entry.timestamp >= s))
+        .filter(|entry| until.as_ref().map_or(true, |u| &entry.timestamp <= u))
+        .collect();
+    entries.reverse();
+    entries.truncate(limit.unwrap_or(DEFAULT_ENTRY_LIMIT));
+    Ok(entries)
+}
+
+/// Egy rekord állapota egy adott időpontban ("hogy nézett ki ez az árajánlat kedden")
+/// None, ha akkor még nem létezett vagy már törölve volt
+#[tauri::command]
+pub fn get_record_as_of(entity_type: String, record_key: String, timestamp: String) -> Result<Option<Value>, String> {
+    validate_entity_type(&entity_type)?;
+    let mut records = rebuild_as_of(&timestamp)?;
+    Ok(records
+        .get_mut(&entity_type)
+        .and_then(|entity| entity.remove(&record_key)))
+}
+
+/// A teljes adatkészlet (vagy egy entitás típus) egy adott időpontban, a Store formátumában
+#[tauri::command]
+pub fn get_data_as_of(timestamp: String, entity_type: Option<String>) -> Result<Map<String, Value>, String> {
+    if let Some(entity_type) = &entity_type {
+        validate_entity_type(entity_type)?;
+    }
+    let records = rebuild_as_of(&timestamp)?;
+    Ok(records
+        .iter()
+        .filter(|(key, _)| entity_type.as_ref().map_or(true, |t| t == *key))
+        .map(|(key, entity)| (key.clone(), Value::Array(ordered_records(entity))))
+        .collect())
+}
+
+/// Point-in-time visszaállítás: a naplózott entitások a megadott időpont szerinti állapotra állnak vissza
+/// Előtte biztonsági mentés készül; maga a visszaállítás is a naplóba kerül, így visszavonható
+#[tauri::command]
+pub fn restore_data_as_of(app: AppHandle, timestamp: String) -> Result<JournalRestoreResult, String> {
+    let records = rebuild_as_of(&timestamp)?;
+    let mut data = data_store::read_store()?.unwrap_or_default();
+    let mut counts = HashMap::new();
+    for (entity_type, entity) in &records {
+        counts.insert(entity_type.clone(), entity.len());
+        data.insert(entity_type.clone(), Value::Array(ordered_records(entity)));
+    }
+
+    let safety_backup = crate::restore::apply_store_data(&app, &data, "journal-restore")?;
+    logger::log_info(&format!("Adatok visszaállítva a napló alapján erre az időpontra: {}", timestamp));
+    Ok(JournalRestoreResult {
+        timestamp,
+        records: counts,
+        safety_backup: safety_backup.map(|path| path.to_string_lossy().to_string()),
+    })
+}
+
+/// A napló állapota: legkorábbi visszaállítható időpont, bejegyzések száma, fájlok mérete
+#[tauri::command]
+pub fn get_journal_status() -> Result<JournalStatus, String> {
+    let (checkpoint_at, next_seq) = with_state(|state| Ok((state.checkpoint_at.clone(), state.next_seq)))?;
+    let checkpoint_seq = read_checkpoint()?.map(|c| c.seq).unwrap_or(0);
+    let files = segment_files()?;
+    let total_size = files
+        .iter()
+        .filter_map(|path| fs::metadata(path).ok())
+        .map(|meta| meta.len())
+        .sum();
+    Ok(JournalStatus {
+        checkpoint_at,
+        entry_count: next_seq.saturating_sub(checkpoint_seq + 1),
+        segments: files
+            .iter()
+            .filter_map(|path| path.file_name().map(|n| n.to_string_lossy().to_string()))
+            .collect(),
+        total_size,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    /// Időpont két naplózott változás között (a bejegyzések ezredmásodperces időbélyeget kapnak)
+    fn pause_and_mark() -> String {
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        let mark = now_timestamp();
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        mark
+    }
+
+    fn offers(records: &HashMap<String, BTreeMap<String, Value>>) -> Vec<Value> {
+        ordered_records(&records["offers"])
+    }
+
+    fn store(value: Value) -> Map<String, Value> {
+        value.as_object().cloned().unwrap()
+    }
+
+    #[test]
+    fn rebuild_replays_entries_up_to_the_timestamp() {
+        crate::paths::test_support::data_root();
+        let _guard = crate::paths::test_support::lock();
+        let _ = fs::remove_dir_all(crate::paths::journal_dir().unwrap());
+        reset();
+        let store_file = data_store::store_file_path().unwrap();
+        fs::write(&store_file, r#"{ "offers": [{ "id": 1, "price": 100 }] }"#).unwrap();
+        initialize().unwrap();
+
+        let before_update = pause_and_mark();
+        let updated = store(json!({ "offers": [{ "id": 1, "price": 200 }, { "id": 2, "price": 50 }] }));
+        assert_eq!(record_store(&updated, "teszt").unwrap(), 2);
+        let before_delete = pause_and_mark();
+        assert_eq!(record_store(&store(json!({ "offers": [{ "id": 2, "price": 50 }] })), "teszt").unwrap(), 1);
+
+        assert_eq!(offers(&rebuild_as_of(&before_update).unwrap()), vec![json!({ "id": 1, "price": 100 })]);
+        assert_eq!(offers(&rebuild_as_of(&before_delete).unwrap()), updated["offers"].as_array().unwrap().clone());
+        assert_eq!(offers(&rebuild_as_of(&now_timestamp()).unwrap()), vec![json!({ "id": 2, "price": 50 })]);
+        assert!(rebuild_as_of("2000-01-01").is_err(), "a pillanatkép előtti állapot nem állítható elő");
+
+        // A memóriában tartott állapot eldobása után a naplófájlokból ugyanaz az állapot áll elő
+        reset();
+        assert_eq!(offers(&rebuild_as_of(&before_delete).unwrap()).len(), 2);
+
+        reset();
+        fs::remove_dir_all(crate::paths::journal_dir().unwrap()).unwrap();
+        fs::remove_file(&store_file).unwrap();
+    }
+}
@@ -0,0 +1,270 @@
+use crate::crypto;
+use crate::logger;
+use crate::paths;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A régi bundle ID könyvtárból áthozott alkönyvtárak
+/// A könyvtár gyökeréből csak a Store fájlok (data.json és titkosított párja) költöznek; a többi fájlt
+/// (pl. filamentLibrary.json) az fs plugin a régi helyen használja, azokat nem mozgatjuk
+const MIGRATED_SUBDIRS: [&str; 3] = ["backups", "logs", "audit_logs"];
+
+/// Jelölőfájl: a migráció lefutott, a régi könyvtárat többé nem kell vizsgálni
+const MARKER_FILE: &str = "legacy_migration.done";
+
+/// A migráció részletes jelentése
+const REPORT_FILE: &str = "legacy_migration_report.json";
+
+/// Egy áthozott (vagy kihagyott) fájl
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MigratedFile {
+    pub source: String,
+    pub target: String,
+    pub size: u64,
+    pub sha256: String,
+}
+
+/// Sikertelen vagy ütköző fájl
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MigrationIssue {
+    pub source: String,
+    pub reason: String,
+}
+
+/// A régi könyvtár migrációjának jelentése
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LegacyMigrationReport {
+    pub started_at: String,
+    pub finished_at: String,
+    pub source: String,
+    pub target: String,
+    /// Áthelyezett fájlok (ellenőrzőösszeg egyezett, a forrás törölve)
+    pub moved: Vec<MigratedFile>,
+    /// Az új helyen már megvolt ugyanazzal a tartalommal (a régi példány törölve)
+    pub duplicates: Vec<MigratedFile>,
+    /// Az új helyen más tartalommal létezik - a régi példány a helyén maradt
+    pub conflicts: Vec<MigrationIssue>,
+    /// Hiba miatt nem sikerült (a következő indításkor újra próbáljuk)
+    pub failed: Vec<MigrationIssue>,
+    /// A régi könyvtár gyökerében hagyott fájlok (az fs plugin használja őket, pl. filamentLibrary.json)
+    pub left_in_place: Vec<String>,
+}
+
+/// A jelölőfájl tartalma
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MigrationMarker {
+    completed_at: String,
+    moved: usize,
+    conflicts: usize,
+}
+
+fn marker_path() -> Result<PathBuf, String> {
+    Ok(paths::installation_root()?.join(MARKER_FILE))
+}
+
+fn report_path() -> Result<PathBuf, String> {
+    Ok(paths::installation_root()?.join(REPORT_FILE))
+}
+
+/// Lefutott-e már a migráció (ilyenkor a régi könyvtárat nem kell vizsgálni)
+pub fn is_completed() -> bool {
+    marker_path().map(|p| p.exists()).unwrap_or(false)
+}
+
+fn path_string(path: &Path) -> String {
+    path.to_string_lossy().to_string()
+}
+
+/// Egy fájl áthelyezése ellenőrzéssel: atomikus másolás, visszaolvasott SHA-256 összevetése, majd a forrás törlése
+fn move_verified(source: &Path, target: &Path, report: &mut LegacyMigrationReport) {
+    let issue = |reason: String| MigrationIssue {
+        source: path_string(source),
+        reason,
+    };
+
+    let bytes = match fs::read(source) {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            report.failed.push(issue(format!("Nem sikerült beolvasni: {}", e)));
+            return;
+        }
+    };
+    let entry = MigratedFile {
+        source: path_string(source),
+        target: path_string(target),
+        size: bytes.len() as u64,
+        sha256: crypto::sha256_hex(&bytes),
+    };
+
+    if target.exists() {
+        match fs::read(target) {
+            Ok(existing) if crypto::sha256_hex(&existing) == entry.sha256 => {
+                if let Err(e) = fs::remove_file(source) {
+                    report.failed.push(issue(format!("Nem sikerült törölni a régi példányt: {}", e)));
+                } else {
+                    report.duplicates.push(entry);
+                }
+            }
+            Ok(_) => report
+                .conflicts
+                .push(issue(format!("Az új helyen más tartalommal létezik: {}", target.display()))),
+            Err(e) => report.failed.push(issue(format!("Nem sikerült beolvasni a célfájlt: {}", e))),
+        }
+        return;
+    }
+
+    if let Err(e) = crate::atomic_write::write_atomic(target, &bytes) {
+        report.failed.push(issue(e));
+        return;
+    }
+    let verified = fs::read(target).is_ok_and(|copied| crypto::sha256_hex(&copied) == entry.sha256);
+    if !verified {
+        let _ = fs::remove_file(target);
+        report
+            .failed
+            .push(issue("A másolat ellenőrzőösszege nem egyezik az eredetivel".to_string()));
+        return;
+    }
+
+    // Az eredeti módosítási idő megtartása (a kor alapú takarítás ez alapján is dönthet)
+    if let Ok(modified) = fs::metadata(source).and_then(|m| m.modified()) {
+        let _ = fs::File::options()
+            .write(true)
+            .open(target)
+            .and_then(|file| file.set_modified(modified));
+    }
+    if let Err(e) = fs::remove_file(source) {
+        report.failed.push(issue(format!("Átmásolva, de a régi példány nem törölhető: {}", e)));
+        return;
+    }
+    report.moved.push(entry);
+}
+
+fn migrate_dir(source: &Path, target: &Path, report: &mut LegacyMigrationReport) {
+    let entries = match fs::read_dir(source) {
+        Ok(entries) => entries,
+        Err(e) => {
+            report.failed.push(MigrationIssue {
+                source: path_string(source),
+                reason: format!("Nem sikerült olvasni a könyvtárat: {}", e),
+            });
+            return;
+        }
+    };
+    for path in entries.flatten().map(|entry| entry.path()) {
+        let Some(name) = path.file_name() else {
+            continue;
+        };
+        if path.is_dir() {
+            migrate_dir(&path, &target.join(name), report);
+        } else {
+            move_verified(&path, &target.join(name), report);
+        }
+    }
+    // Kiürült könyvtár eltávolítása (ha maradt benne valami, a hiba szándékosan figyelmen kívül marad)
+    let _ = fs::remove_dir(source);
+}
+
+/// A régi bundle ID könyvtár (Store, backupok, logok, audit logok) áthelyezése az alkalmazás adatkönyvtárába
+/// Csak egyszer fut: ha minden fájl átkerült, jelölőfájl készül, és a régi könyvtárat többé nem vizsgáljuk
+/// Hiba vagy ütközés esetén nincs jelölő: a régi könyvtár (és az ott maradt backupok) továbbra is látszik,
+/// és a következő indításkor újra próbálkozik
+pub fn run_startup_migration() -> Result<Option<LegacyMigrationReport>, String> {
+    if is_completed() {
+        return Ok(None);
+    }
+    let Some(legacy_root) = paths::legacy_root().filter(|root| root.is_dir()) else {
+        return Ok(None);
+    };
+    // A régi adatok az alapértelmezett munkaterülethez tartoznak
+    let target_root = paths::workspace_root(crate::workspaces::DEFAULT_WORKSPACE_ID)?;
+
+    let mut report = LegacyMigrationReport {
+        started_at: chrono::Utc::now().to_rfc3339(),
+        finished_at: String::new(),
+        source: path_string(&legacy_root),
+        target: path_string(&target_root),
+        moved: Vec::new(),
+        duplicates: Vec::new(),
+        conflicts: Vec::new(),
+        failed: Vec::new(),
+        left_in_place: Vec::new(),
+    };
+
+    // A Store fájlok mindkét példánya (titkosítatlan és titkosított) ugyanoda kerül; amíg bármelyik a régi helyen van,
+    // a paths::workspace_store_file onnan olvas
+    let legacy_store = legacy_root.join(paths::STORE_FILE_NAME);
+    for source in [crate::vault::encrypted_store_file(&legacy_store), legacy_store] {
+        if source.is_file() {
+            let target = target_root.join(source.file_name().unwrap_or_default());
+            move_verified(&source, &target, &mut report);
+        }
+    }
+
+    for subdir in MIGRATED_SUBDIRS {
+        let source = legacy_root.join(subdir);
+        if source.is_dir() {
+            migrate_dir(&source, &target_root.join(subdir), &mut report);
+        }
+    }
+    if let Ok(entries) = fs::read_dir(&legacy_root) {
+        report.left_in_place = entries
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.is_file())
+            .map(|path| path_string(&path))
+            .collect();
+    }
+    report.finished_at = chrono::Utc::now().to_rfc3339();
+
+    let content = serde_json::to_string_pretty(&report)
+        .map_err(|e| format!("Migrációs jelentés szerializálási hiba: {}", e))?;
+    crate::atomic_write::write_atomic(&report_path()?, content)
+        .map_err(|e| format!("Nem sikerült menteni a migrációs jelentést: {}", e))?;
+
+    if !report.failed.is_empty() || !report.conflicts.is_empty() {
+        logger::log_warn(&format!(
+            "Régi adatkönyvtár migrációja részben sikertelen ({} hiba, {} ütközés), a régi könyvtár látható marad, a következő indításkor újra próbáljuk",
+            report.failed.len(),
+            report.conflicts.len()
+        ));
+        return Ok(Some(report));
+    }
+
+    let marker = MigrationMarker {
+        completed_at: report.finished_at.clone(),
+        moved: report.moved.len(),
+        conflicts: report.conflicts.len(),
+    };
+    let content = serde_json::to_string_pretty(&marker)
+        .map_err(|e| format!("Migrációs jelölő szerializálási hiba: {}", e))?;
+    crate::atomic_write::write_atomic(&marker_path()?, content)
+        .map_err(|e| format!("Nem sikerült menteni a migrációs jelölőt: {}", e))?;
+
+    logger::log_info(&format!(
+        "Régi adatkönyvtár migrálva: {} fájl áthelyezve, {} duplikátum ({})",
+        report.moved.len(),
+        report.duplicates.len(),
+        legacy_root.display()
+    ));
+    Ok(Some(report))
+}
+
+/// A legutóbbi migrációs jelentés (None, ha nem volt mit migrálni)
+#[tauri::command]
+pub fn get_legacy_migration_report() -> Result<Option<LegacyMigrationReport>, String> {
+    let path = report_path()?;
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content = fs::read_to_string(&path)
+        .map_err(|e| format!("Nem sikerült beolvasni a migrációs jelentést: {}", e))?;
+    serde_json::from_str(&content)
+        .map(Some)
+        .map_err(|e| format!("Érvénytelen migrációs jelentés: {}", e))
+}
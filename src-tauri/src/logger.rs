@@ -16,12 +16,8 @@ lazy_static! {
 /// - Windows: %LOCALAPPDATA%\3DPrinterCalcApp\logs\ (pl. C:\Users\<username>\AppData\Local\3DPrinterCalcApp\logs\)
 /// - Linux: ~/.local/share/3DPrinterCalcApp/logs/
 pub fn init_log_file() -> Result<PathBuf, String> {
-    use dirs;
     
-    let log_dir = dirs::data_local_dir()
-        .ok_or_else(|| "Nem található data directory".to_string())?
-        .join("3DPrinterCalcApp")
-        .join("logs");
+    let log_dir = crate::paths::logs_dir()?;
     
     // Létrehozzuk a log könyvtárat, ha nem létezik
     std::fs::create_dir_all(&log_dir)
@@ -32,6 +28,8 @@ pub fn init_log_file() -> Result<PathBuf, String> {
     let log_file_path = log_dir.join(format!("backend-{}.log", today));
     
     // Megnyitjuk a log fájlt append módban
+    // A log soronként bővül (nem íródik újra), összeomláskor legfeljebb az utolsó sor lehet csonka,
+    // ezért itt nincs szükség az atomic_write ideiglenes fájl + átnevezés megoldására
     let file = OpenOptions::new()
         .create(true)
         .append(true)
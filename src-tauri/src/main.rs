@@ -1,13 +1,80 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+mod atomic_write;
+mod backup_archive;
+mod backup_destinations;
+mod backup_format;
+mod backup_manifest;
+mod backup_retention;
+mod backup_scheduler;
+mod backup_snapshots;
 mod commands;
+mod consistency;
+mod crypto;
+mod data_store;
+mod exporter;
+mod importer;
+mod instance_lock;
+mod journal;
+mod legacy_migration;
 mod logger;
+mod migrations;
+mod paths;
+mod pdf;
+mod restore;
+mod search;
+mod sync;
+mod trash;
+mod vault;
+mod webdav;
+mod workspaces;
 
-use tauri::{generate_context, Builder};
+use tauri::{generate_context, Builder, RunEvent};
 use commands::*;
 
+/// Indításkor a Store-ra épülő lépések, mielőtt a frontend betöltené a Store-t
+/// Zárolt titkosított adattárolónál egyik sem fut: a kulcs nélkül a Store nem olvasható, a feloldás
+/// (unlock_vault) futtatja le ugyanezeket. Hibát csak a séma migráció adhat, a többi csak figyelmeztet
+fn open_store_data() -> Result<(), String> {
+    if vault::is_locked() {
+        logger::log_info("Titkosított adattároló zárolva - a migráció és az index építés a feloldáskor fut");
+        return Ok(());
+    }
+
+    if let Some(outcome) = migrations::run_startup_migrations()? {
+        logger::log_info(&format!(
+            "Adatok migrálva: v{} → v{} ({} lépés)",
+            outcome.from_version,
+            outcome.to_version,
+            outcome.applied_steps.len()
+        ));
+    }
+
+    // Változásnapló betöltése (első indításkor kiindulási pillanatkép a jelenlegi adatokból)
+    if let Err(e) = journal::initialize() {
+        logger::log_warn(&format!("Változásnapló inicializálása sikertelen: {}", e));
+    }
+
+    // A megőrzési időnél régebben törölt lomtár elemek végleges törlése
+    if let Err(e) = trash::purge_expired() {
+        logger::log_warn(&format!("Lejárt lomtár elemek törlése sikertelen: {}", e));
+    }
+
+    // Keresési index felépítése a mentett adatokból
+    match search::rebuild_from_store() {
+        Ok(count) => logger::log_info(&format!("Keresési index felépítve: {} dokumentum", count)),
+        Err(e) => logger::log_warn(&format!("Keresési index felépítése sikertelen: {}", e)),
+    }
+    Ok(())
+}
+
 fn main() {
     Builder::default()
+        // Egyetlen futó példány: a második indítás argumentumai a futó példányhoz kerülnek, majd kilép
+        // (a single-instance pluginnek elsőként kell regisztrálva lennie)
+        .plugin(tauri_plugin_single_instance::init(|app, argv, cwd| {
+            instance_lock::handle_second_instance(app, argv, cwd);
+        }))
         .plugin(tauri_plugin_store::Builder::default().build())
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_fs::init())
@@ -41,12 +108,82 @@ fn main() {
             read_audit_log_file,
             get_audit_log_directory_path,
             delete_old_audit_logs,
+            migrations::get_schema_info,
+            search::search,
+            search::update_search_index,
+            search::rebuild_search_index,
+            vault::get_vault_status,
+            vault::enable_vault,
+            vault::unlock_vault,
+            vault::lock_vault,
+            vault::change_vault_passphrase,
+            vault::disable_vault,
+            vault::read_vault_store,
+            vault::write_vault_store,
+            backup_format::set_backup_passphrase,
+            backup_format::get_backup_encryption_status,
+            backup_format::encrypt_backup_content,
+            backup_archive::read_backup_file,
+            restore::restore_backup,
+            backup_destinations::get_backup_destinations,
+            backup_destinations::save_backup_destination,
+            backup_destinations::remove_backup_destination,
+            backup_destinations::replicate_latest_backup,
+            backup_retention::preview_backup_retention,
+            backup_retention::apply_backup_retention,
+            backup_retention::set_backup_pinned,
+            backup_retention::get_pinned_backups,
+            backup_scheduler::get_backup_scheduler_status,
+            backup_scheduler::run_scheduled_backup_now,
+            restore::list_backup_contents,
+            restore::restore_backup_selection,
+            backup_manifest::verify_backup,
+            backup_manifest::verify_all_backups,
+            backup_snapshots::create_backup_snapshot,
+            backup_snapshots::list_backup_snapshots,
+            backup_snapshots::get_backup_snapshot_data,
+            backup_snapshots::delete_backup_snapshot,
+            backup_snapshots::cleanup_old_backup_snapshots,
+            backup_snapshots::gc_backup_snapshots,
+            instance_lock::take_pending_launch_requests,
+            paths::get_data_layout,
+            paths::set_data_root,
+            legacy_migration::get_legacy_migration_report,
+            importer::preview_import,
+            importer::apply_import,
+            exporter::export_spreadsheet,
+            pdf::export_offer_pdf,
+            journal::record_entity_changes,
+            journal::get_record_history,
+            journal::get_journal_entries,
+            journal::get_record_as_of,
+            journal::get_data_as_of,
+            journal::restore_data_as_of,
+            journal::get_journal_status,
+            trash::move_to_trash,
+            trash::list_trash,
+            trash::restore_from_trash,
+            trash::purge_trash,
+            consistency::check_data_consistency,
+            consistency::repair_data_consistency,
+            workspaces::list_workspaces,
+            workspaces::create_workspace,
+            workspaces::duplicate_workspace,
+            workspaces::rename_workspace,
+            workspaces::switch_workspace,
+            workspaces::delete_workspace,
+            sync::get_sync_status,
+            sync::set_sync_config,
+            sync::sync_now,
+            sync::get_sync_conflicts,
+            sync::clear_sync_conflicts,
+            webdav::test_webdav_connection,
             #[cfg(target_os = "macos")]
             set_dock_badge,
             #[cfg(target_os = "windows")]
             set_taskbar_progress,
         ])
-        .setup(|_app| {
+        .setup(|app| {
             // Log fájl inicializálása
             match logger::init_log_file() {
                 Ok(path) => {
@@ -57,6 +194,39 @@ fn main() {
                     eprintln!("⚠️ Backend log fájl inicializálási hiba: {}", e);
                 }
             }
+
+            // Adatkönyvtár zárolása - két példány ne írja egyszerre a Store-t és a backupokat
+            // (pl. ha egy másik telepítés ugyanazt az adatkönyvtárat használja)
+            if let Err(e) = instance_lock::acquire() {
+                instance_lock::exit_with_startup_error(app.handle(), &e);
+                return Ok(());
+            }
+            instance_lock::queue_startup_args();
+
+            // Előző összeomlásból maradt ideiglenes fájlok feltakarítása, mielőtt bármit beolvasnánk
+            let recovered = atomic_write::recover_temp_files();
+            if recovered > 0 {
+                logger::log_info(&format!("{} félbemaradt ideiglenes fájl feldolgozva", recovered));
+            }
+
+            // A régi bundle ID könyvtár (backupok, logok) egyszeri áthelyezése az adatkönyvtárba
+            if let Err(e) = legacy_migration::run_startup_migration() {
+                logger::log_warn(&format!("Régi adatkönyvtár migrációja sikertelen: {}", e));
+            }
+
+            // Séma migrációk, változásnapló, lomtár és keresési index (zárolt adattárolónál a feloldáskor)
+            // Újabb alkalmazás verzió által írt adatokat nem nyitunk meg
+            if let Err(e) = open_store_data() {
+                logger::log_error(&format!("Séma migráció sikertelen, az alkalmazás nem indul el: {}", e));
+                instance_lock::exit_with_startup_error(app.handle(), &e);
+                return Ok(());
+            }
+            
+            // Háttér backup ütemező (kimaradt backup pótlása indításkor, utána intervallum szerint)
+            backup_scheduler::start(app.handle().clone());
+
+            // Mappa alapú szinkronizálás a többi eszközzel (ha be van állítva szinkronizálási mappa)
+            sync::start(app.handle().clone());
             
             // Platform specifikus inicializálás
             #[cfg(target_os = "macos")]
@@ -85,6 +255,40 @@ fn main() {
             
             Ok(())
         })
-        .run(generate_context!())
-        .expect("error while running tauri application");
+        .build(generate_context!())
+        .expect("error while building tauri application")
+        .run(|_app, event| match event {
+            // Meghiúsult indításnál a bezárt ablak miatt ne lépjünk ki, amíg a hibaüzenet látszik
+            RunEvent::ExitRequested { code: None, api, .. } if instance_lock::startup_failed() => {
+                api.prevent_exit();
+            }
+            // Kilépéskor a titkosított adattárolót zároljuk (a kulcs nem marad a memóriában)
+            // Meghiúsult indításnál az adattárolóhoz nem nyúlunk (lehet, hogy egy másik példányé)
+            RunEvent::Exit => {
+                if !instance_lock::startup_failed() {
+                    vault::lock_on_exit();
+                }
+                instance_lock::release();
+            }
+            _ => {}
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn startup_with_locked_vault_does_not_touch_the_store() {
+        let _guard = paths::test_support::lock();
+        let root = paths::test_support::data_root();
+        let metadata = root.join("vault.json");
+        fs::write(&metadata, "{}").unwrap();
+        assert!(vault::is_locked(), "vault.json mellett kulcs nélkül az adattárolónak zároltnak kell lennie");
+
+        let result = open_store_data();
+        fs::remove_file(&metadata).unwrap();
+        assert!(result.is_ok(), "Zárolt adattárolóval is el kell indulnia az alkalmazásnak: {:?}", result);
+    }
 }
@@ -0,0 +1,444 @@
+use crate::data_store;
+use crate::logger;
+use serde_json::{Map, Value};
+use std::path::PathBuf;
+
+/// A Store-ban tárolt séma verzió kulcsa
+pub const SCHEMA_VERSION_KEY: &str = "schemaVersion";
+
+/// Az alkalmazás verziója, amelyik utoljára migrálta a Store-t
+pub const SCHEMA_APP_VERSION_KEY: &str = "schemaAppVersion";
+
+/// A jelenlegi alkalmazás által ismert legújabb séma verzió
+/// Új migrációs lépés hozzáadásakor ezt is növelni kell
+pub const CURRENT_SCHEMA_VERSION: u32 = 3;
+
+/// Egy migrációs lépés: `from` verzióról `from + 1` verzióra alakítja az adatokat
+struct Migration {
+    from: u32,
+    description: &'static str,
+    apply: fn(&mut Map<String, Value>),
+}
+
+/// A migrációs lépések, verzió szerint növekvő sorrendben
+/// Minden lépésnek idempotensnek kell lennie, mert a verzió nélküli (0) adatok
+/// bármelyik korábbi alkalmazás verzióból származhatnak
+const MIGRATIONS: [Migration; 3] = [
+    Migration {
+        from: 0,
+        description: "Nyomtatók: amsCount és ams lista összehangolása",
+        apply: migrate_printers_ams,
+    },
+    Migration {
+        from: 1,
+        description: "Árajánlatok: alapértelmezett státusz és fizetési státusz",
+        apply: migrate_offer_statuses,
+    },
+    Migration {
+        from: 2,
+        description: "Árajánlatok: filament összesítők (súly, hossz) kiegészítése",
+        apply: migrate_offer_filament_summaries,
+    },
+];
+
+/// Migráció eredménye (logoláshoz és a frontend számára)
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MigrationOutcome {
+    pub from_version: u32,
+    pub to_version: u32,
+    pub applied_steps: Vec<String>,
+    pub backup_path: Option<String>,
+}
+
+/// Kiolvassa a séma verziót egy Store tartalomból (verzió nélküli adat = 0)
+pub fn schema_version_of(data: &Map<String, Value>) -> u32 {
+    data.get(SCHEMA_VERSION_KEY)
+        .and_then(|v| v.as_u64())
+        .map(|v| v as u32)
+        .unwrap_or(0)
+}
+
+/// Hibát ad vissza, ha az adatot újabb alkalmazás verzió írta, mint amit ez a verzió ismer
+pub fn ensure_supported_version(data: &Map<String, Value>) -> Result<(), String> {
+    let version = schema_version_of(data);
+
+    if version > CURRENT_SCHEMA_VERSION {
+        let written_by = data
+            .get(SCHEMA_APP_VERSION_KEY)
+            .and_then(|v| v.as_str())
+            .unwrap_or("ismeretlen");
+        return Err(format!(
+            "Az adatokat egy újabb alkalmazás verzió írta (séma: {}, alkalmazás: {}). Ez a verzió csak a {}. sémáig támogatott, kérjük frissítse az alkalmazást.",
+            version, written_by, CURRENT_SCHEMA_VERSION
+        ));
+    }
+
+    Ok(())
+}
+
+/// Lefuttatja a szükséges migrációs lépéseket egy Store tartalmon (memóriában)
+/// Hibát ad vissza, ha az adatot újabb alkalmazás verzió írta
+pub fn migrate_data(data: &mut Map<String, Value>) -> Result<Vec<String>, String> {
+    ensure_supported_version(data)?;
+    let version = schema_version_of(data);
+
+    let mut applied = Vec::new();
+    for migration in MIGRATIONS.iter().filter(|m| m.from >= version) {
+        (migration.apply)(data);
+        applied.push(format!("v{} → v{}: {}", migration.from, migration.from + 1, migration.description));
+    }
+
+    data.insert(SCHEMA_VERSION_KEY.to_string(), Value::from(CURRENT_SCHEMA_VERSION));
+    data.insert(
+        SCHEMA_APP_VERSION_KEY.to_string(),
+        Value::String(env!("CARGO_PKG_VERSION").to_string()),
+    );
+
+    Ok(applied)
+}
+
+/// Indításkori migráció a data.json-on
+/// - ha nincs data.json (első indítás), nem csinál semmit
+/// - ha a séma naprakész, nem ír a fájlba
+/// - migráció előtt automatikus mentést készít a backups/pre_migration könyvtárba
+pub fn run_startup_migrations() -> Result<Option<MigrationOutcome>, String> {
+    let mut data = match data_store::read_store()? {
+        Some(data) => data,
+        None => {
+            logger::log_info("Séma migráció kihagyva: data.json még nem létezik");
+            return Ok(None);
+        }
+    };
+
+    let from_version = schema_version_of(&data);
+    if from_version == CURRENT_SCHEMA_VERSION {
+        logger::log_info(&format!("Séma verzió naprakész: v{}", from_version));
+        return Ok(None);
+    }
+
+    // Újabb verzió esetén a mentés előtt hibát adunk, hogy ne nyúljunk az adatokhoz
+    ensure_supported_version(&data)?;
+
+    let backup_path = create_pre_migration_backup(from_version)?;
+    logger::log_info(&format!(
+        "Migráció előtti mentés létrehozva: {}",
+        backup_path.display()
+    ));
+
+    let applied_steps = migrate_data(&mut data)?;
+    for step in &applied_steps {
+        logger::log_info(&format!("Migrációs lépés lefutott: {}", step));
+    }
+
+    data_store::write_store(&data)?;
+    logger::log_info(&format!(
+        "Séma migráció kész: v{} → v{}",
+        from_version, CURRENT_SCHEMA_VERSION
+    ));
+
+    Ok(Some(MigrationOutcome {
+        from_version,
+        to_version: CURRENT_SCHEMA_VERSION,
+        applied_steps,
+        backup_path: Some(backup_path.to_string_lossy().to_string()),
+    }))
+}
+
+/// Migráció előtti mentés a data.json-ról (változatlan másolat; titkosított adattárolónál a titkosított fájlról)
+/// Hely: 3DPrinterCalcApp/backups/pre_migration/pre_migration_v{verzió}_{időbélyeg}.json
+fn create_pre_migration_backup(from_version: u32) -> Result<PathBuf, String> {
+    let backup_dir = crate::paths::backups_dir()?.join("pre_migration");
+    let timestamp = chrono::Local::now().format("%Y-%m-%d_%H-%M-%S").to_string();
+
+    data_store::copy_stored_file(&backup_dir, &format!("pre_migration_v{}_{}", from_version, timestamp))
+        .map_err(|e| format!("Nem sikerült létrehozni a migráció előtti mentést: {}", e))?
+        .ok_or_else(|| "Nem sikerült létrehozni a migráció előtti mentést: a Store fájl nem található".to_string())
+}
+
+/// v0 → v1: az `amsCount` mező és az `ams` lista összehangolása
+/// Régebbi verziók csak `amsCount`-ot mentettek, újabbak az `ams` listát is
+fn migrate_printers_ams(data: &mut Map<String, Value>) {
+    let Some(Value::Array(printers)) = data.get_mut("printers") else {
+        return;
+    };
+
+    for printer in printers.iter_mut().filter_map(|p| p.as_object_mut()) {
+        let ams_len = printer.get("ams").and_then(|v| v.as_array()).map(|a| a.len());
+        let ams_count = printer.get("amsCount").and_then(|v| v.as_u64());
+
+        match (ams_count, ams_len) {
+            // Van kitöltött AMS lista, de hiányzik vagy kevesebb a darabszám
+            (count, Some(len)) if len > 0 && count.unwrap_or(0) < len as u64 => {
+                printer.insert("amsCount".to_string(), Value::from(len as u64));
+            }
+            // Van darabszám, de nincs lista - üres listát adunk hozzá (a frontend ugyanígy hozza létre)
+            (Some(count), None) if count > 0 => {
+                printer.insert("ams".to_string(), Value::Array(Vec::new()));
+            }
+            // 0 darab AMS: a frontend `undefined`-ként kezeli, eltávolítjuk
+            (Some(0), _) => {
+                printer.remove("amsCount");
+            }
+            _ => {}
+        }
+    }
+}
+
+/// v1 → v2: árajánlat státuszok kiegészítése
+/// - hiányzó `status` → "draft"
+/// - befejezett árajánlat `paymentStatus` nélkül → "paid" (a Home statisztika eddig is így számolta)
+fn migrate_offer_statuses(data: &mut Map<String, Value>) {
+    let Some(Value::Array(offers)) = data.get_mut("offers") else {
+        return;
+    };
+
+    for offer in offers.iter_mut().filter_map(|o| o.as_object_mut()) {
+        if !offer.get("status").is_some_and(|s| s.is_string()) {
+            offer.insert("status".to_string(), Value::String("draft".to_string()));
+        }
+
+        let is_completed = offer.get("status").and_then(|s| s.as_str()) == Some("completed");
+        if is_completed && !offer.get("paymentStatus").is_some_and(|s| s.is_string()) {
+            offer.insert("paymentStatus".to_string(), Value::String("paid".to_string()));
+        }
+    }
+}
+
+/// v2 → v3: filament összesítők kiegészítése
+/// - `totalFilamentWeightSummary.total` a felhasznált grammok összegéből
+/// - `totalFilamentLengthSummary.totalMeters` az extruderenkénti mm értékekből
+fn migrate_offer_filament_summaries(data: &mut Map<String, Value>) {
+    let Some(Value::Array(offers)) = data.get_mut("offers") else {
+        return;
+    };
+
+    for offer in offers.iter_mut().filter_map(|o| o.as_object_mut()) {
+        let used_grams: Option<f64> = offer.get("filaments").and_then(|f| f.as_array()).map(|filaments| {
+            filaments
+                .iter()
+                .filter_map(|f| f.get("usedGrams").and_then(|g| g.as_f64()))
+                .sum()
+        });
+
+        if let Some(total_grams) = used_grams.filter(|g| *g > 0.0) {
+            let summary = offer
+                .entry("totalFilamentWeightSummary")
+                .or_insert_with(|| Value::Object(Map::new()));
+            if let Some(summary) = summary.as_object_mut() {
+                if !summary.get("total").is_some_and(|t| t.is_number()) {
+                    summary.insert("total".to_string(), Value::from(total_grams));
+                }
+            }
+        }
+
+        if let Some(summary) = offer
+            .get_mut("totalFilamentLengthSummary")
+            .and_then(|s| s.as_object_mut())
+        {
+            let has_total = summary.get("totalMeters").is_some_and(|t| t.is_number());
+            let per_extruder_mm: Option<f64> = summary
+                .get("perExtruderMm")
+                .and_then(|p| p.as_array())
+                .map(|values| values.iter().filter_map(|v| v.as_f64()).sum());
+
+            if let (false, Some(total_mm)) = (has_total, per_extruder_mm) {
+                summary.insert("totalMeters".to_string(), Value::from(total_mm / 1000.0));
+            }
+        }
+    }
+}
+
+/// Séma információk lekérése (diagnosztikához)
+#[tauri::command]
+pub fn get_schema_info() -> Result<serde_json::Value, String> {
+    let stored_version = data_store::read_store()?.map(|data| schema_version_of(&data));
+
+    Ok(serde_json::json!({
+        "storedSchemaVersion": stored_version,
+        "currentSchemaVersion": CURRENT_SCHEMA_VERSION,
+        "appVersion": env!("CARGO_PKG_VERSION"),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::fs;
+
+    fn object(value: Value) -> Map<String, Value> {
+        value.as_object().cloned().expect("JSON objektum")
+    }
+
+    #[test]
+    fn v0_to_v1_aligns_ams_count_and_list() {
+        let mut data = object(json!({
+            "printers": [
+                { "id": 1, "ams": [{}, {}] },
+                { "id": 2, "amsCount": 1, "ams": [{}, {}, {}] },
+                { "id": 3, "amsCount": 2 },
+                { "id": 4, "amsCount": 0 },
+                { "id": 5, "amsCount": 4, "ams": [{}] }
+            ]
+        }));
+        migrate_printers_ams(&mut data);
+
+        let printers = data["printers"].as_array().unwrap();
+        assert_eq!(printers[0]["amsCount"], json!(2));
+        assert_eq!(printers[1]["amsCount"], json!(3));
+        assert_eq!(printers[2]["ams"], json!([]));
+        assert!(printers[3].get("amsCount").is_none());
+        assert_eq!(printers[4]["amsCount"], json!(4));
+    }
+
+    #[test]
+    fn v1_to_v2_fills_offer_statuses() {
+        let mut data = object(json!({
+            "offers": [
+                { "id": 1 },
+                { "id": 2, "status": "completed" },
+                { "id": 3, "status": "completed", "paymentStatus": "pending" },
+                { "id": 4, "status": "sent" }
+            ]
+        }));
+        migrate_offer_statuses(&mut data);
+
+        let offers = data["offers"].as_array().unwrap();
+        assert_eq!(offers[0]["status"], json!("draft"));
+        assert!(offers[0].get("paymentStatus").is_none());
+        assert_eq!(offers[1]["paymentStatus"], json!("paid"));
+        assert_eq!(offers[2]["paymentStatus"], json!("pending"));
+        assert!(offers[3].get("paymentStatus").is_none());
+    }
+
+    #[test]
+    fn v2_to_v3_fills_filament_summaries() {
+        let mut data = object(json!({
+            "offers": [
+                {
+                    "id": 1,
+                    "filaments": [{ "usedGrams": 12.5 }, { "usedGrams": 7.5 }],
+                    "totalFilamentLengthSummary": { "perExtruderMm": [1500.0, 500.0] }
+                },
+                {
+                    "id": 2,
+                    "filaments": [{ "usedGrams": 10.0 }],
+                    "totalFilamentWeightSummary": { "total": 99.0 },
+                    "totalFilamentLengthSummary": { "totalMeters": 4.0, "perExtruderMm": [1000.0] }
+                },
+                { "id": 3, "filaments": [] }
+            ]
+        }));
+        migrate_offer_filament_summaries(&mut data);
+
+        let offers = data["offers"].as_array().unwrap();
+        assert_eq!(offers[0]["totalFilamentWeightSummary"]["total"], json!(20.0));
+        assert_eq!(offers[0]["totalFilamentLengthSummary"]["totalMeters"], json!(2.0));
+        assert_eq!(offers[1]["totalFilamentWeightSummary"]["total"], json!(99.0));
+        assert_eq!(offers[1]["totalFilamentLengthSummary"]["totalMeters"], json!(4.0));
+        assert!(offers[2].get("totalFilamentWeightSummary").is_none());
+    }
+
+    #[test]
+    fn migrate_data_runs_every_step_from_v0() {
+        let mut data = object(json!({
+            "printers": [{ "id": 1, "ams": [{}] }],
+            "offers": [{ "id": 1, "filaments": [{ "usedGrams": 5.0 }] }]
+        }));
+        let applied = migrate_data(&mut data).unwrap();
+
+        assert_eq!(applied.len(), MIGRATIONS.len());
+        assert_eq!(schema_version_of(&data), CURRENT_SCHEMA_VERSION);
+        assert_eq!(data["printers"][0]["amsCount"], json!(1));
+        assert_eq!(data["offers"][0]["status"], json!("draft"));
+        assert_eq!(data["offers"][0]["totalFilamentWeightSummary"]["total"], json!(5.0));
+    }
+
+    #[test]
+    fn migrate_data_only_runs_missing_steps() {
+        let mut data = object(json!({
+            "schemaVersion": 2,
+            "printers": [{ "id": 1, "ams": [{}] }],
+            "offers": [{ "id": 1, "filaments": [{ "usedGrams": 5.0 }] }]
+        }));
+        let applied = migrate_data(&mut data).unwrap();
+
+        assert_eq!(applied.len(), 1);
+        assert!(applied[0].starts_with("v2 → v3"));
+        // A v0 → v1 és v1 → v2 lépések már nem futnak le
+        assert!(data["printers"][0].get("amsCount").is_none());
+        assert!(data["offers"][0].get("status").is_none());
+    }
+
+    #[test]
+    fn migrate_data_is_idempotent_at_current_version() {
+        let mut data = object(json!({
+            "printers": [{ "id": 1, "amsCount": 2 }],
+            "offers": [{ "id": 1, "status": "completed", "filaments": [{ "usedGrams": 5.0 }] }]
+        }));
+        migrate_data(&mut data).unwrap();
+        let migrated = data.clone();
+
+        let applied = migrate_data(&mut data).unwrap();
+        assert!(applied.is_empty());
+        assert_eq!(data, migrated);
+
+        // A lépések önmagukban is idempotensek (verzió nélküli adat többször is átmehet rajtuk)
+        for migration in MIGRATIONS.iter() {
+            (migration.apply)(&mut data);
+        }
+        assert_eq!(data, migrated);
+    }
+
+    #[test]
+    fn newer_schema_version_is_refused() {
+        let mut data = object(json!({ "schemaVersion": CURRENT_SCHEMA_VERSION + 1, "schemaAppVersion": "9.9.9" }));
+        let error = migrate_data(&mut data).unwrap_err();
+        assert!(error.contains("9.9.9"));
+        assert_eq!(schema_version_of(&data), CURRENT_SCHEMA_VERSION + 1);
+    }
+
+    #[test]
+    fn startup_migration_refuses_newer_store_without_touching_it() {
+        let root = crate::paths::test_support::data_root();
+        let _guard = crate::paths::test_support::lock();
+
+        let store = data_store::store_file_path().unwrap();
+        assert!(store.starts_with(&root));
+        let content = serde_json::to_string_pretty(&json!({
+            "schemaVersion": CURRENT_SCHEMA_VERSION + 1,
+            "offers": [{ "id": 1 }]
+        }))
+        .unwrap();
+        fs::write(&store, &content).unwrap();
+        let pre_migration_dir = crate::paths::backups_dir().unwrap().join("pre_migration");
+        let _ = fs::remove_dir_all(&pre_migration_dir);
+
+        assert!(run_startup_migrations().is_err());
+        assert_eq!(fs::read_to_string(&store).unwrap(), content);
+        assert!(!pre_migration_dir.exists());
+
+        fs::remove_file(&store).unwrap();
+    }
+
+    #[test]
+    fn startup_migration_backs_up_and_upgrades_old_store() {
+        crate::paths::test_support::data_root();
+        let _guard = crate::paths::test_support::lock();
+
+        let store = data_store::store_file_path().unwrap();
+        fs::write(&store, r#"{ "offers": [{ "id": 1 }] }"#).unwrap();
+
+        let outcome = run_startup_migrations().unwrap().expect("migráció");
+        assert_eq!(outcome.from_version, 0);
+        assert_eq!(outcome.to_version, CURRENT_SCHEMA_VERSION);
+        assert!(outcome.backup_path.is_some_and(|p| PathBuf::from(p).exists()));
+
+        let migrated = data_store::read_store().unwrap().unwrap();
+        assert_eq!(schema_version_of(&migrated), CURRENT_SCHEMA_VERSION);
+        assert!(run_startup_migrations().unwrap().is_none());
+
+        fs::remove_file(&store).unwrap();
+    }
+}
@@ -0,0 +1,506 @@
+use crate::logger;
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tauri::AppHandle;
+use tauri_plugin_fs::FsExt;
+
+/// Az alkalmazás saját adatkönyvtárának neve (logok, backupok, audit logok, konfigurációk)
+pub const APP_DIR_NAME: &str = "3DPrinterCalcApp";
+
+/// A Tauri bundle azonosító - a tauri-plugin-store ez alá a könyvtár alá menti a data.json-t,
+/// és a régebbi verziók backupjai is ide kerültek (macOS / Linux)
+pub const BUNDLE_IDENTIFIER: &str = "com.lekszikov.3dprintercalcapp";
+
+/// A frontend Store fájl neve
+pub const STORE_FILE_NAME: &str = "data.json";
+
+/// Környezeti változó: az adatkönyvtár felülbírálása (minden más beállítást megelőz)
+pub const DATA_DIR_ENV: &str = "PRINTERCALC_DATA_DIR";
+
+/// Hordozható mód: ha ez a fájl a futtatható állomány mellett van, az adatok az `data` alkönyvtárba kerülnek
+pub const PORTABLE_MARKER_FILE: &str = "portable.txt";
+const PORTABLE_DATA_DIR: &str = "data";
+
+/// A felhasználó által választott adatkönyvtár beállítása - mindig az alapértelmezett helyen van,
+/// hogy indításkor megtaláljuk
+const LOCATION_CONFIG_FILE: &str = "data_location.json";
+
+/// A további munkaterületek könyvtára a telepítés adatkönyvtárán belül
+pub const WORKSPACES_DIR: &str = "workspaces";
+
+/// Ezeket a fájlokat nem másoljuk át új adatkönyvtárba (a futó példányhoz / a régi helyhez tartoznak)
+const NON_MIGRATED_FILES: [&str; 2] = [crate::instance_lock::LOCK_FILE_NAME, LOCATION_CONFIG_FILE];
+
+lazy_static! {
+    /// Az indításkor feloldott adatkönyvtár - futás közben nem változik (a módosítás újraindítás után él)
+    static ref RESOLVED_ROOT: Mutex<Option<(DataLocationMode, PathBuf)>> = Mutex::new(None);
+}
+
+/// Honnan származik az adatkönyvtár
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DataLocationMode {
+    /// Alapértelmezett: az operációs rendszer helyi adatkönyvtára
+    Default,
+    /// Hordozható: a futtatható állomány melletti `data` könyvtár
+    Portable,
+    /// A felhasználó által a beállításokban választott könyvtár
+    Custom,
+    /// A PRINTERCALC_DATA_DIR környezeti változó
+    Environment,
+}
+
+/// A felhasználó által választott adatkönyvtár beállítás
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LocationConfig {
+    #[serde(default)]
+    custom_root: Option<String>,
+}
+
+/// A feloldott könyvtárszerkezet a frontend számára
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DataLayout {
+    pub mode: DataLocationMode,
+    pub root: String,
+    pub store_file: String,
+    pub logs_dir: String,
+    pub backups_dir: String,
+    pub automatic_backups_dir: String,
+    pub audit_logs_dir: String,
+    /// Az aktív munkaterület azonosítója és könyvtára
+    pub workspace_id: String,
+    pub workspace_root: String,
+    /// A régi bundle ID alatti backup könyvtár (csak alapértelmezett módban, macOS / Linux)
+    pub legacy_backups_dir: Option<String>,
+    /// A beállításokban választott könyvtár (környezeti változó / hordozható mód felülbírálhatja)
+    pub custom_root: Option<String>,
+    /// A beállítás megváltozott, az új könyvtár újraindítás után lesz használatban
+    pub restart_required: bool,
+    pub env_variable: String,
+    pub portable_marker: String,
+}
+
+/// Az operációs rendszer szerinti alapértelmezett adatkönyvtár
+/// - macOS: ~/Library/Application Support/3DPrinterCalcApp/
+/// - Windows: %LOCALAPPDATA%\3DPrinterCalcApp\
+/// - Linux: ~/.local/share/3DPrinterCalcApp/
+fn default_root() -> Result<PathBuf, String> {
+    Ok(dirs::data_local_dir()
+        .ok_or_else(|| "Nem található data directory".to_string())?
+        .join(APP_DIR_NAME))
+}
+
+fn location_config_path() -> Result<PathBuf, String> {
+    Ok(default_root()?.join(LOCATION_CONFIG_FILE))
+}
+
+fn read_location_config() -> LocationConfig {
+    let Ok(path) = location_config_path() else {
+        return LocationConfig::default();
+    };
+    match fs::read_to_string(&path) {
+        Ok(content) => serde_json::from_str(&content).unwrap_or_else(|e| {
+            logger::log_warn(&format!("Érvénytelen adatkönyvtár beállítás, az alapértelmezettet használjuk: {}", e));
+            LocationConfig::default()
+        }),
+        Err(_) => LocationConfig::default(),
+    }
+}
+
+/// A hordozható mód adatkönyvtára, ha a jelölőfájl a futtatható állomány mellett van
+fn portable_root() -> Option<PathBuf> {
+    let exe_dir = std::env::current_exe().ok()?.parent()?.to_path_buf();
+    exe_dir
+        .join(PORTABLE_MARKER_FILE)
+        .is_file()
+        .then(|| exe_dir.join(PORTABLE_DATA_DIR))
+}
+
+/// Az adatkönyvtár feloldása: környezeti változó > hordozható mód > választott könyvtár > alapértelmezett
+fn resolve_root() -> Result<(DataLocationMode, PathBuf), String> {
+    if let Some(value) = std::env::var_os(DATA_DIR_ENV).filter(|v| !v.is_empty()) {
+        return Ok((DataLocationMode::Environment, PathBuf::from(value)));
+    }
+    if let Some(root) = portable_root() {
+        return Ok((DataLocationMode::Portable, root));
+    }
+    if let Some(root) = read_location_config().custom_root.filter(|r| !r.trim().is_empty()) {
+        return Ok((DataLocationMode::Custom, PathBuf::from(root)));
+    }
+    Ok((DataLocationMode::Default, default_root()?))
+}
+
+fn resolved() -> Result<(DataLocationMode, PathBuf), String> {
+    let mut guard = RESOLVED_ROOT.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(resolved) = guard.as_ref() {
+        return Ok(resolved.clone());
+    }
+    let resolved = resolve_root()?;
+    *guard = Some(resolved.clone());
+    Ok(resolved)
+}
+
+/// Az adatkönyvtár feloldásának módja
+pub fn data_location_mode() -> Result<DataLocationMode, String> {
+    Ok(resolved()?.0)
+}
+
+/// A telepítés adatkönyvtára (a munkaterületektől független: logok, példányzár, munkaterület lista)
+pub fn installation_root() -> Result<PathBuf, String> {
+    Ok(resolved()?.1)
+}
+
+/// Egy munkaterület könyvtára - az alapértelmezett munkaterület maga a telepítés adatkönyvtára
+/// (így a munkaterületek előtti adatok változatlan helyen maradnak), a többi a `workspaces` alatt van
+pub fn workspace_root(workspace_id: &str) -> Result<PathBuf, String> {
+    let root = installation_root()?;
+    if workspace_id == crate::workspaces::DEFAULT_WORKSPACE_ID {
+        return Ok(root);
+    }
+    Ok(root.join(WORKSPACES_DIR).join(workspace_id))
+}
+
+/// Az aktív munkaterület adatkönyvtára (backupok, audit logok, napló, lomtár, konfigurációk)
+pub fn data_root() -> Result<PathBuf, String> {
+    workspace_root(&crate::workspaces::active_workspace_id())
+}
+
+/// Egy munkaterület data.json útvonala
+///
+/// Az alapértelmezett munkaterület alapértelmezett módban:
+/// - macOS / Linux: a telepítés adatkönyvtárában, a legacy_migration áthelyezéséig a régi bundle ID helyen
+///   (~/Library/Application Support/com.lekszikov.3dprintercalcapp/data.json, ~/.local/share/com.lekszikov.3dprintercalcapp/data.json)
+/// - Windows: a tauri-plugin-store helyén (%APPDATA%\com.lekszikov.3dprintercalcapp\data.json)
+///
+/// Hordozható / választott / környezeti változós módban és a további munkaterületeknél a munkaterület könyvtárában van
+pub fn workspace_store_file(workspace_id: &str) -> Result<PathBuf, String> {
+    let mode = data_location_mode()?;
+    if mode != DataLocationMode::Default || workspace_id != crate::workspaces::DEFAULT_WORKSPACE_ID {
+        return Ok(workspace_root(workspace_id)?.join(STORE_FILE_NAME));
+    }
+    if cfg!(any(target_os = "macos", target_os = "linux")) {
+        if let Some(legacy_store) = legacy_store_file() {
+            return Ok(legacy_store);
+        }
+    }
+    default_store_file_in(&installation_root()?, mode)
+}
+
+/// Az alapértelmezett munkaterület data.json-ja egy adott telepítési gyökérben és módban
+/// (a legacy_migration előtti régi hely nélkül - új helyre mindig a végleges útvonalra írunk)
+fn default_store_file_in(root: &Path, mode: DataLocationMode) -> Result<PathBuf, String> {
+    if mode == DataLocationMode::Default && cfg!(target_os = "windows") {
+        return Ok(dirs::data_dir()
+            .ok_or_else(|| "Nem található data directory".to_string())?
+            .join(BUNDLE_IDENTIFIER)
+            .join(STORE_FILE_NAME));
+    }
+    Ok(root.join(STORE_FILE_NAME))
+}
+
+/// Az aktív munkaterület data.json útvonala
+pub fn store_file_path() -> Result<PathBuf, String> {
+    workspace_store_file(&crate::workspaces::active_workspace_id())
+}
+
+/// A logok a telepítéshez tartoznak, minden munkaterület ugyanoda naplóz
+pub fn logs_dir() -> Result<PathBuf, String> {
+    Ok(installation_root()?.join("logs"))
+}
+
+pub fn audit_logs_dir() -> Result<PathBuf, String> {
+    Ok(data_root()?.join("audit_logs"))
+}
+
+pub fn journal_dir() -> Result<PathBuf, String> {
+    Ok(data_root()?.join("journal"))
+}
+
+pub fn trash_dir() -> Result<PathBuf, String> {
+    Ok(data_root()?.join("trash"))
+}
+
+pub fn sync_dir() -> Result<PathBuf, String> {
+    Ok(data_root()?.join("sync"))
+}
+
+pub fn backups_dir() -> Result<PathBuf, String> {
+    Ok(data_root()?.join("backups"))
+}
+
+pub fn automatic_backups_dir() -> Result<PathBuf, String> {
+    Ok(backups_dir()?.join("automatic"))
+}
+
+/// Egy munkaterület alkalmazás által kezelt alkönyvtárai (backupok, snapshotok, audit logok, napló, lomtár, szinkron)
+/// Ezeken kívül a munkaterület könyvtárában a felhasználó saját fájljai is lehetnek (választott adatkönyvtár)
+pub fn workspace_owned_dirs(workspace_id: &str) -> Result<Vec<PathBuf>, String> {
+    let root = workspace_root(workspace_id)?;
+    Ok(["backups", "audit_logs", "journal", "trash", "sync"]
+        .iter()
+        .map(|dir| root.join(dir))
+        .collect())
+}
+
+/// A régebbi verziók adatkönyvtára a bundle ID alatt (az aktív munkaterülettől függetlenül)
+/// Csak macOS / Linux alatt és alapértelmezett módban létezik (Windows-on a bundle ID könyvtár másként néz ki),
+/// és a legacy_migration áthelyezése után már nem vizsgáljuk
+fn legacy_bundle_dir() -> Option<PathBuf> {
+    if !cfg!(any(target_os = "macos", target_os = "linux")) {
+        return None;
+    }
+    if data_location_mode().ok()? != DataLocationMode::Default || crate::legacy_migration::is_completed() {
+        return None;
+    }
+    Some(dirs::data_local_dir()?.join(BUNDLE_IDENTIFIER))
+}
+
+/// A régebbi verziók adatkönyvtára, ha az alapértelmezett munkaterület az aktív
+pub fn legacy_root() -> Option<PathBuf> {
+    // A régi adatok csak az alapértelmezett munkaterülethez tartoznak
+    if crate::workspaces::active_workspace_id() != crate::workspaces::DEFAULT_WORKSPACE_ID {
+        return None;
+    }
+    legacy_bundle_dir()
+}
+
+/// A régi helyen maradt Store fájl (titkosítatlan vagy titkosított), amíg a migráció át nem helyezi
+fn legacy_store_file() -> Option<PathBuf> {
+    let store_file = legacy_bundle_dir()?.join(STORE_FILE_NAME);
+    (store_file.exists() || crate::vault::encrypted_store_file(&store_file).exists()).then_some(store_file)
+}
+
+/// A régebbi verziók automatikus backup könyvtára (kompatibilitás miatt olvassuk, amíg a migráció le nem fut)
+pub fn legacy_automatic_backups_dir() -> Option<PathBuf> {
+    legacy_root().map(|root| root.join("backups").join("automatic"))
+}
+
+/// Az automatikus backupok összes lehetséges könyvtára (új hely elöl, utána a régi bundle ID hely)
+pub fn automatic_backup_dirs() -> Result<Vec<PathBuf>, String> {
+    let mut dirs = vec![automatic_backups_dir()?];
+    dirs.extend(legacy_automatic_backups_dir());
+    Ok(dirs)
+}
+
+/// Backupot megnyitó parancsokhoz a webview által küldött útvonal ellenőrzése (kanonikus útvonallal)
+/// Csak a backup könyvtárakban (munkaterület backupjai, régi automatikus backup hely, mappa backup célok)
+/// lévő, vagy a felhasználó által a fájlválasztóban kiválasztott fájl nyitható meg
+pub fn resolve_backup_file(app: &AppHandle, file_path: &str) -> Result<PathBuf, String> {
+    let path = Path::new(file_path)
+        .canonicalize()
+        .map_err(|e| format!("A backup fájl nem létezik: {} - {}", file_path, e))?;
+    if !path.is_file() {
+        return Err(format!("A backup fájl nem létezik: {}", file_path));
+    }
+
+    let mut roots = vec![backups_dir()?];
+    roots.extend(legacy_automatic_backups_dir());
+    roots.extend(crate::backup_destinations::local_destination_dirs());
+    let in_backup_dir = roots
+        .iter()
+        .filter_map(|root| root.canonicalize().ok())
+        .any(|root| path.starts_with(root));
+    // A fájlválasztó (dialog plugin) a kiválasztott fájlt felveszi az fs hatókörbe
+    if in_backup_dir || app.fs_scope().is_allowed(&path) {
+        Ok(path)
+    } else {
+        Err(format!("A fájl nem a backup könyvtárban található: {}", file_path))
+    }
+}
+
+/// A jelenlegi könyvtárszerkezet
+#[tauri::command]
+pub fn get_data_layout() -> Result<DataLayout, String> {
+    let (mode, root) = resolved()?;
+    let custom_root = read_location_config().custom_root;
+    // Választott könyvtár módosítása (vagy törlése) csak akkor jár újraindítással, ha épp az határozza meg a helyet
+    let restart_required = match mode {
+        DataLocationMode::Custom => custom_root.as_deref() != Some(root.to_string_lossy().as_ref()),
+        DataLocationMode::Default => custom_root.is_some(),
+        DataLocationMode::Portable | DataLocationMode::Environment => false,
+    };
+    let path_string = |p: PathBuf| p.to_string_lossy().to_string();
+
+    Ok(DataLayout {
+        mode,
+        root: path_string(root),
+        store_file: path_string(store_file_path()?),
+        logs_dir: path_string(logs_dir()?),
+        backups_dir: path_string(backups_dir()?),
+        automatic_backups_dir: path_string(automatic_backups_dir()?),
+        audit_logs_dir: path_string(audit_logs_dir()?),
+        workspace_id: crate::workspaces::active_workspace_id(),
+        workspace_root: path_string(data_root()?),
+        legacy_backups_dir: legacy_automatic_backups_dir().map(path_string),
+        custom_root,
+        restart_required,
+        env_variable: DATA_DIR_ENV.to_string(),
+        portable_marker: PORTABLE_MARKER_FILE.to_string(),
+    })
+}
+
+/// Könyvtár tartalmának átmásolása (a célban már meglévő fájlokat nem írjuk felül)
+fn copy_dir_contents(source: &Path, target: &Path, copied: &mut usize) -> Result<(), String> {
+    let entries = fs::read_dir(source)
+        .map_err(|e| format!("Nem sikerült olvasni a könyvtárat: {} - {}", source.display(), e))?;
+    for path in entries.flatten().map(|entry| entry.path()) {
+        let Some(name) = path.file_name() else {
+            continue;
+        };
+        let target_path = target.join(name);
+        if path.is_dir() {
+            // A régi adatkönyvtár ne másolódjon önmagába, ha az új hely a régi alatt van
+            if target.starts_with(&path) {
+                continue;
+            }
+            copy_dir_contents(&path, &target_path, copied)?;
+        } else if !NON_MIGRATED_FILES.iter().any(|f| name == *f) && !target_path.exists() {
+            crate::atomic_write::copy_atomic(&path, &target_path)?;
+            *copied += 1;
+        }
+    }
+    Ok(())
+}
+
+/// A data.json és a titkosított párja (data.json.vault) átmásolása; a célban meglévőt nem írja felül
+fn copy_store_files(current_store: &Path, target_store: &Path) -> Result<usize, String> {
+    let mut copied = 0;
+    let store_files = [
+        (current_store.to_path_buf(), target_store.to_path_buf()),
+        (
+            crate::vault::encrypted_store_file(current_store),
+            crate::vault::encrypted_store_file(target_store),
+        ),
+    ];
+    for (source, destination) in store_files {
+        if source.exists() && source != destination && !destination.exists() {
+            crate::atomic_write::copy_atomic(&source, &destination)?;
+            copied += 1;
+        }
+    }
+    Ok(copied)
+}
+
+/// Az adatkönyvtár kiválasztása (None = vissza az alapértelmezetthez); újraindítás után lép életbe
+/// `copy_existing` esetén a jelenlegi adatok (Store, backupok, logok, konfigurációk) átmásolódnak
+#[tauri::command]
+pub fn set_data_root(root: Option<String>, copy_existing: bool) -> Result<DataLayout, String> {
+    let root = root.filter(|r| !r.trim().is_empty());
+
+    let target = match &root {
+        Some(root) => {
+            let path = PathBuf::from(root);
+            if !path.is_absolute() {
+                return Err("Az adatkönyvtár útvonalának abszolútnak kell lennie".to_string());
+            }
+            fs::create_dir_all(&path).map_err(|e| format!("Nem sikerült létrehozni az adatkönyvtárat: {}", e))?;
+            // Írhatóság ellenőrzése, mielőtt elmentjük
+            let probe = path.join(format!(".write_test_{}", std::process::id()));
+            fs::write(&probe, b"ok").map_err(|e| format!("Az adatkönyvtár nem írható: {}", e))?;
+            let _ = fs::remove_file(&probe);
+            path
+        }
+        None => default_root()?,
+    };
+
+    if copy_existing {
+        // A teljes telepítés (minden munkaterület) átkerül
+        let current_root = installation_root()?;
+        if current_root != target {
+            let mut copied = 0;
+            if current_root.exists() {
+                copy_dir_contents(&current_root, &target, &mut copied)?;
+            }
+            // Az alapértelmezett munkaterület Store-ja (és titkosított párja) a telepítés gyökerén kívül is lehet
+            // (Windows alapértelmezett mód, régi bundle ID hely), ezért külön másoljuk az új hely végleges útvonalára
+            let current_store = workspace_store_file(crate::workspaces::DEFAULT_WORKSPACE_ID)?;
+            let target_mode = if root.is_some() { DataLocationMode::Custom } else { DataLocationMode::Default };
+            let target_store = default_store_file_in(&target, target_mode)?;
+            copied += copy_store_files(&current_store, &target_store)?;
+            logger::log_info(&format!(
+                "Adatok átmásolva az új adatkönyvtárba: {} ({} fájl)",
+                target.display(),
+                copied
+            ));
+        }
+    }
+
+    let config = LocationConfig { custom_root: root };
+    let content = serde_json::to_string_pretty(&config)
+        .map_err(|e| format!("Adatkönyvtár beállítás szerializálási hiba: {}", e))?;
+    crate::atomic_write::write_atomic(&location_config_path()?, content)
+        .map_err(|e| format!("Nem sikerült menteni az adatkönyvtár beállítást: {}", e))?;
+    logger::log_info(&format!(
+        "Adatkönyvtár beállítva (újraindítás után): {}",
+        target.display()
+    ));
+
+    get_data_layout()
+}
+
+/// Tesztekhez: ideiglenes adatkönyvtár (a feloldott gyökér folyamatonként egyszer rögzül)
+/// A Store-t vagy az adatkönyvtárat író tesztek a `lock` zárolás alatt futnak
+#[cfg(test)]
+pub mod test_support {
+    use std::path::PathBuf;
+    use std::sync::{Mutex, MutexGuard, Once};
+
+    static INIT: Once = Once::new();
+    static LOCK: Mutex<()> = Mutex::new(());
+
+    pub fn data_root() -> PathBuf {
+        let root = std::env::temp_dir().join(format!("printercalc-test-{}", std::process::id()));
+        INIT.call_once(|| {
+            let _ = std::fs::remove_dir_all(&root);
+            std::fs::create_dir_all(&root).expect("teszt adatkönyvtár");
+            std::env::set_var(super::DATA_DIR_ENV, &root);
+        });
+        root
+    }
+
+    pub fn lock() -> MutexGuard<'static, ()> {
+        LOCK.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn store_copy_includes_the_encrypted_store() {
+        let root = test_support::data_root().join("store-copy");
+        let (source, target) = (root.join("regi"), root.join("uj"));
+        fs::create_dir_all(&source).unwrap();
+        fs::create_dir_all(&target).unwrap();
+        let current_store = source.join(STORE_FILE_NAME);
+        fs::write(crate::vault::encrypted_store_file(&current_store), b"titkositott").unwrap();
+
+        let target_store = default_store_file_in(&target, DataLocationMode::Custom).unwrap();
+        assert_eq!(target_store, target.join(STORE_FILE_NAME));
+        assert_eq!(copy_store_files(&current_store, &target_store).unwrap(), 1);
+        assert_eq!(
+            fs::read(crate::vault::encrypted_store_file(&target_store)).unwrap(),
+            b"titkositott",
+            "Bekapcsolt adattárolónál a data.json.vault-nak is át kell kerülnie"
+        );
+        assert!(!target_store.exists(), "Nem létező data.json nem jöhet létre");
+
+        // Második futás nem írja felül a célban már meglévő fájlt
+        fs::write(crate::vault::encrypted_store_file(&current_store), b"masik").unwrap();
+        assert_eq!(copy_store_files(&current_store, &target_store).unwrap(), 0);
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[cfg(any(target_os = "macos", target_os = "linux"))]
+    #[test]
+    fn default_mode_store_is_in_the_installation_root() {
+        let root = PathBuf::from("/adat/3DPrinterCalc");
+        assert_eq!(default_store_file_in(&root, DataLocationMode::Default).unwrap(), root.join(STORE_FILE_NAME));
+    }
+}
@@ -0,0 +1,1547 @@
+use crate::atomic_write;
+use crate::exporter;
+use crate::logger;
+use base64::Engine;
+use chrono::{Datelike, Timelike};
+use pdf_writer::types::{CidFontType, FontFlags, SystemInfo, UnicodeCmap};
+use pdf_writer::{Content, Date, Filter, Finish, Name, Pdf, Rect, Ref, Str, TextStr};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Manager};
+use ttf_parser::{Face, GlyphId};
+
+/// A4 álló, pontban
+const PAGE_WIDTH: f32 = 595.28;
+const PAGE_HEIGHT: f32 = 841.89;
+const MARGIN: f32 = 48.0;
+const CONTENT_WIDTH: f32 = PAGE_WIDTH - 2.0 * MARGIN;
+/// A lábléc a alsó margóba kerül, a tartalom eddig tart
+const CONTENT_BOTTOM: f32 = MARGIN;
+const FOOTER_BASELINE: f32 = 28.0;
+
+/// Latin és cirill írás - a binárisba fordítva, így minden platformon ugyanaz a betűkép
+static DEJAVU_SANS: &[u8] = include_bytes!("../fonts/DejaVuSans.ttf");
+static DEJAVU_SANS_BOLD: &[u8] = include_bytes!("../fonts/DejaVuSans-Bold.ttf");
+
+/// Kínai (CJK) betűkészletek helye az erőforrások között (méretük miatt nem a binárisban vannak)
+const CJK_FONT_DIR: &str = "fonts/cjk";
+
+/// Egy CSS pixel pontban (96 dpi), a logó természetes méretéhez
+const PX_TO_PT: f32 = 0.75;
+
+/// Sablon (Beállítások → PDF sablon)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PdfTemplate {
+    /// Színes kiemelések, kártyás elrendezés
+    #[default]
+    Modern,
+    /// Monokróm, halvány keretek
+    Minimal,
+    /// Hivatalos elrendezés aláírás blokkal és elérhetőségi lábléccel
+    Professional,
+}
+
+/// Cégadatok (a frontend `CompanyInfo` típusával egyező mezők)
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PdfCompanyInfo {
+    pub name: Option<String>,
+    pub address: Option<String>,
+    pub tax_number: Option<String>,
+    pub bank_account: Option<String>,
+    pub email: Option<String>,
+    pub phone: Option<String>,
+    pub website: Option<String>,
+    /// data URL (PNG vagy JPEG)
+    pub logo_base64: Option<String>,
+}
+
+/// Árajánlat filament tétele (a frontend `OfferFilament` típusával egyező mezők)
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PdfFilament {
+    pub brand: String,
+    #[serde(rename = "type")]
+    pub filament_type: String,
+    pub color: Option<String>,
+    /// A frontend a színnévből feloldott értéket küldi, ha nincs megadva
+    pub color_hex: Option<String>,
+    pub used_grams: f64,
+    pub price_per_kg: f64,
+    pub needs_drying: bool,
+    pub drying_time: Option<f64>,
+    pub drying_power: Option<f64>,
+    pub image_base64: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PdfCosts {
+    pub filament_cost: f64,
+    pub electricity_cost: f64,
+    pub drying_cost: f64,
+    pub usage_cost: f64,
+    pub total_cost: f64,
+}
+
+/// Árajánlat (a frontend `Offer` típusának a PDF-hez szükséges mezői)
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PdfOffer {
+    pub id: Value,
+    pub printer_name: String,
+    pub printer_type: String,
+    pub printer_power: f64,
+    pub print_time_hours: f64,
+    pub print_time_minutes: f64,
+    pub print_time_seconds: f64,
+    pub filaments: Vec<PdfFilament>,
+    pub costs: PdfCosts,
+    pub currency: Option<String>,
+    pub customer_name: Option<String>,
+    pub customer_contact: Option<String>,
+    pub description: Option<String>,
+    pub profit_percentage: Option<f64>,
+}
+
+/// A felhasználó nyelvén lefordított feliratok (offers.pdf.* kulcsok, a frontend tölti ki)
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct OfferPdfLabels {
+    pub title: String,
+    pub date: String,
+    pub customer_name: String,
+    pub customer_contact: String,
+    pub description: String,
+    pub headquarters: String,
+    pub tax: String,
+    pub bank: String,
+    pub email: String,
+    pub phone: String,
+    pub website: String,
+    pub printer: String,
+    pub print_time: String,
+    pub filaments: String,
+    pub costs: String,
+    pub image: String,
+    pub brand: String,
+    #[serde(rename = "type")]
+    pub filament_type: String,
+    pub color: String,
+    pub used: String,
+    /// "{currency}" helyőrzővel
+    pub price_per_kg: String,
+    pub drying: String,
+    pub cost_filament: String,
+    pub cost_electricity: String,
+    pub cost_drying: String,
+    pub cost_usage: String,
+    pub cost_total: String,
+    /// "{profit}" helyőrzővel
+    pub revenue: String,
+    pub profit: String,
+    pub signature: String,
+    /// "{page}" és "{pages}" helyőrzővel
+    pub page: String,
+}
+
+/// PDF export kérés
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OfferPdfRequest {
+    pub file_path: String,
+    #[serde(default)]
+    pub template: PdfTemplate,
+    pub offer: PdfOffer,
+    #[serde(default)]
+    pub company_info: PdfCompanyInfo,
+    /// Megjelenítési pénznem (a beállításokból), erre váltjuk át az összegeket
+    pub currency: String,
+    /// A nyelvnek megfelelően formázott dátum
+    pub formatted_date: String,
+    pub labels: OfferPdfLabels,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OfferPdfResult {
+    pub file_path: String,
+    pub page_count: usize,
+    /// Karakterek, amelyekhez egyik betöltött betűkészletben sincs jel (pl. hiányzó CJK betűkészlet)
+    pub missing_characters: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Rgb(f32, f32, f32);
+
+impl Rgb {
+    const WHITE: Rgb = Rgb(1.0, 1.0, 1.0);
+
+    fn hex(value: &str) -> Option<Rgb> {
+        let digits = value.trim().trim_start_matches('#');
+        let expanded: String = match digits.len() {
+            3 => digits.chars().flat_map(|c| [c, c]).collect(),
+            6 => digits.to_string(),
+            _ => return None,
+        };
+        let channel = |index: usize| u8::from_str_radix(&expanded[index..index + 2], 16).ok().map(|v| v as f32 / 255.0);
+        Some(Rgb(channel(0)?, channel(2)?, channel(4)?))
+    }
+}
+
+/// Sablononkénti megjelenés
+struct TemplateStyle {
+    accent: Rgb,
+    text: Rgb,
+    muted: Rgb,
+    border: Rgb,
+    /// Bevétel sor kiemelése
+    positive: Rgb,
+    /// Az infó doboz kitöltése (modern: kártya)
+    card_fill: Option<Rgb>,
+    table_header_fill: Option<Rgb>,
+    table_header_text: Rgb,
+    /// Minden második sor háttere
+    stripe_fill: Option<Rgb>,
+    /// Teljes rács (professional), egyébként csak vízszintes elválasztók
+    grid: bool,
+    highlight_fill: Option<Rgb>,
+    corner_radius: f32,
+    /// Színes sáv a lap tetején (modern)
+    top_band: bool,
+    /// Teli sáv a cím mögött (professional)
+    title_band: bool,
+    signature_block: bool,
+    contact_footer: bool,
+}
+
+impl TemplateStyle {
+    fn of(template: PdfTemplate) -> Self {
+        match template {
+            PdfTemplate::Modern => TemplateStyle {
+                accent: Rgb(0.145, 0.388, 0.922),
+                text: Rgb(0.12, 0.16, 0.22),
+                muted: Rgb(0.39, 0.45, 0.55),
+                border: Rgb(0.89, 0.91, 0.94),
+                positive: Rgb(0.09, 0.64, 0.29),
+                card_fill: Some(Rgb(0.95, 0.96, 0.98)),
+                table_header_fill: Some(Rgb(0.86, 0.92, 1.0)),
+                table_header_text: Rgb(0.12, 0.23, 0.54),
+                stripe_fill: Some(Rgb(0.97, 0.98, 0.99)),
+                grid: false,
+                highlight_fill: Some(Rgb(0.93, 0.95, 1.0)),
+                corner_radius: 6.0,
+                top_band: true,
+                title_band: false,
+                signature_block: false,
+                contact_footer: false,
+            },
+            PdfTemplate::Minimal => TemplateStyle {
+                accent: Rgb(0.07, 0.09, 0.15),
+                text: Rgb(0.07, 0.09, 0.15),
+                muted: Rgb(0.42, 0.45, 0.50),
+                border: Rgb(0.82, 0.84, 0.86),
+                positive: Rgb(0.07, 0.09, 0.15),
+                card_fill: None,
+                table_header_fill: None,
+                table_header_text: Rgb(0.07, 0.09, 0.15),
+                stripe_fill: None,
+                grid: false,
+                highlight_fill: None,
+                corner_radius: 0.0,
+                top_band: false,
+                title_band: false,
+                signature_block: false,
+                contact_footer: false,
+            },
+            PdfTemplate::Professional => TemplateStyle {
+                accent: Rgb(0.12, 0.23, 0.37),
+                text: Rgb(0.10, 0.10, 0.12),
+                muted: Rgb(0.40, 0.42, 0.46),
+                border: Rgb(0.72, 0.75, 0.80),
+                positive: Rgb(0.10, 0.45, 0.25),
+                card_fill: None,
+                table_header_fill: Some(Rgb(0.12, 0.23, 0.37)),
+                table_header_text: Rgb::WHITE,
+                stripe_fill: None,
+                grid: true,
+                highlight_fill: Some(Rgb(0.93, 0.94, 0.96)),
+                corner_radius: 0.0,
+                top_band: false,
+                title_band: true,
+                signature_block: true,
+                contact_footer: true,
+            },
+        }
+    }
+}
+
+/// Betöltött betűkészlet fájl (a beépített DejaVu vagy az erőforrások közül)
+struct FontSource {
+    data: Cow<'static, [u8]>,
+    index: u32,
+    bold: bool,
+}
+
+/// A beépített betűkészletek és az erőforrás mappákban talált CJK betűkészletek (fájlnév szerint rendezve)
+fn load_font_sources(font_dirs: &[PathBuf]) -> Vec<FontSource> {
+    let mut sources = vec![
+        FontSource {
+            data: Cow::Borrowed(DEJAVU_SANS),
+            index: 0,
+            bold: false,
+        },
+        FontSource {
+            data: Cow::Borrowed(DEJAVU_SANS_BOLD),
+            index: 0,
+            bold: true,
+        },
+    ];
+    for dir in font_dirs {
+        let Ok(entries) = fs::read_dir(dir) else {
+            continue;
+        };
+        let mut files: Vec<PathBuf> = entries
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.extension()
+                    .and_then(|ext| ext.to_str())
+                    .is_some_and(|ext| matches!(ext.to_ascii_lowercase().as_str(), "ttf" | "otf" | "ttc" | "otc"))
+            })
+            .collect();
+        files.sort();
+        for path in files {
+            let data = match fs::read(&path) {
+                Ok(data) => data,
+                Err(e) => {
+                    logger::log_warn(&format!("Betűkészlet nem olvasható ({}): {}", path.display(), e));
+                    continue;
+                }
+            };
+            // Gyűjteményből (.ttc) az első betűkészletet használjuk
+            let index = 0;
+            if Face::parse(&data, index).is_err() {
+                logger::log_warn(&format!("Érvénytelen betűkészlet: {}", path.display()));
+                continue;
+            }
+            let bold = path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .is_some_and(|stem| stem.to_ascii_lowercase().contains("bold"));
+            sources.push(FontSource {
+                data: Cow::Owned(data),
+                index,
+                bold,
+            });
+        }
+    }
+    sources
+}
+
+struct FontSlot<'a> {
+    face: Face<'a>,
+    data: &'a [u8],
+    index: u32,
+    /// Használt glyph azonosítók és a hozzájuk tartozó karakter (ToUnicode térképhez)
+    used: BTreeMap<u16, char>,
+}
+
+impl FontSlot<'_> {
+    fn is_cff(&self) -> bool {
+        self.face.tables().cff.is_some()
+    }
+
+    /// 1000 egységes em-re skálázva (PDF betűméret egység)
+    fn to_pdf_units(&self, value: f32) -> f32 {
+        value * 1000.0 / self.face.units_per_em() as f32
+    }
+}
+
+/// Egy kirakott jel: melyik betűkészletből, és mekkora előtolással (em egységben)
+#[derive(Debug, Clone, Copy)]
+struct Glyph {
+    slot: usize,
+    gid: u16,
+    advance: f32,
+    ch: char,
+}
+
+/// Betűkészlet lánc karakterenkénti visszaeséssel: DejaVu → CJK
+struct FontSet<'a> {
+    slots: Vec<FontSlot<'a>>,
+    regular: Vec<usize>,
+    bold: Vec<usize>,
+    missing: BTreeSet<char>,
+}
+
+impl<'a> FontSet<'a> {
+    fn new(sources: &'a [FontSource]) -> Result<Self, String> {
+        let mut slots = Vec::new();
+        let mut regular = Vec::new();
+        let mut bold = Vec::new();
+        for source in sources {
+            let face = Face::parse(&source.data, source.index).map_err(|e| format!("Betűkészlet hiba: {}", e))?;
+            if source.bold {
+                bold.push(slots.len());
+            } else {
+                regular.push(slots.len());
+            }
+            slots.push(FontSlot {
+                face,
+                data: &source.data,
+                index: source.index,
+                used: BTreeMap::new(),
+            });
+        }
+        // Félkövér változat hiányában (pl. csak normál CJK betűkészlet) a normál jelet használjuk
+        bold.extend(regular.iter().copied());
+        Ok(FontSet {
+            slots,
+            regular,
+            bold,
+            missing: BTreeSet::new(),
+        })
+    }
+
+    fn shape(&mut self, text: &str, bold: bool) -> Vec<Glyph> {
+        let chain = if bold { &self.bold } else { &self.regular };
+        let mut glyphs = Vec::new();
+        for ch in text.chars() {
+            let ch = if ch == '\t' { ' ' } else { ch };
+            if ch.is_control() {
+                continue;
+            }
+            let found = chain
+                .iter()
+                .find_map(|&slot| self.slots[slot].face.glyph_index(ch).map(|gid| (slot, gid)));
+            let (slot, gid) = match found {
+                Some(found) => found,
+                None => {
+                    if !ch.is_whitespace() {
+                        self.missing.insert(ch);
+                    }
+                    (chain[0], GlyphId(0))
+                }
+            };
+            let font = &mut self.slots[slot];
+            let advance = font.face.glyph_hor_advance(gid).unwrap_or(0) as f32 / font.face.units_per_em() as f32;
+            font.used.entry(gid.0).or_insert(if gid.0 == 0 { '\u{FFFD}' } else { ch });
+            glyphs.push(Glyph {
+                slot,
+                gid: gid.0,
+                advance,
+                ch,
+            });
+        }
+        glyphs
+    }
+
+    /// Vegyes (félkövér / normál) szakaszokból bekezdések; a szövegen belüli sortörés új bekezdést kezd
+    fn shape_rich(&mut self, segments: &[(&str, bool)]) -> Vec<Vec<Glyph>> {
+        let mut paragraphs = vec![Vec::new()];
+        for (text, bold) in segments {
+            for (index, part) in text.split('\n').enumerate() {
+                if index > 0 {
+                    paragraphs.push(Vec::new());
+                }
+                let glyphs = self.shape(part.trim_end_matches('\r'), *bold);
+                if let Some(paragraph) = paragraphs.last_mut() {
+                    paragraph.extend(glyphs);
+                }
+            }
+        }
+        paragraphs
+    }
+
+    fn wrap_rich(&mut self, segments: &[(&str, bool)], size: f32, max_width: f32) -> Vec<Vec<Glyph>> {
+        self.shape_rich(segments)
+            .into_iter()
+            .flat_map(|paragraph| break_lines(paragraph, size, max_width))
+            .collect()
+    }
+
+    fn wrap(&mut self, text: &str, bold: bool, size: f32, max_width: f32) -> Vec<Vec<Glyph>> {
+        self.wrap_rich(&[(text, bold)], size, max_width)
+    }
+}
+
+fn measure(glyphs: &[Glyph], size: f32) -> f32 {
+    glyphs.iter().map(|glyph| glyph.advance).sum::<f32>() * size
+}
+
+/// Széles (CJK) karakter - ezek között szóköz nélkül is törhető a sor
+fn is_wide(ch: char) -> bool {
+    matches!(ch as u32,
+        0x2E80..=0x303F | 0x3040..=0x9FFF | 0xAC00..=0xD7AF | 0xF900..=0xFAFF | 0xFF00..=0xFFEF | 0x20000..=0x2FA1F)
+}
+
+/// Mohó sortörés: szóközöknél és CJK karakterek között, túl hosszú szónál karakterenként
+fn break_lines(glyphs: Vec<Glyph>, size: f32, max_width: f32) -> Vec<Vec<Glyph>> {
+    let mut lines = Vec::new();
+    let mut line: Vec<Glyph> = Vec::new();
+    let mut width = 0.0;
+    let mut break_at: Option<usize> = None;
+    for glyph in glyphs {
+        let advance = glyph.advance * size;
+        if width + advance > max_width && !line.is_empty() && !glyph.ch.is_whitespace() {
+            let split = break_at.filter(|&index| index > 0).unwrap_or(line.len());
+            let rest: Vec<Glyph> = line.split_off(split).into_iter().skip_while(|g| g.ch.is_whitespace()).collect();
+            lines.push(trim_end(line));
+            line = rest;
+            width = measure(&line, size);
+            break_at = None;
+        }
+        let wide = is_wide(glyph.ch);
+        if wide && !line.is_empty() {
+            break_at = Some(line.len());
+        }
+        width += advance;
+        line.push(glyph);
+        if glyph.ch.is_whitespace() || wide {
+            break_at = Some(line.len());
+        }
+    }
+    lines.push(trim_end(line));
+    lines
+}
+
+fn trim_end(mut line: Vec<Glyph>) -> Vec<Glyph> {
+    while line.last().is_some_and(|glyph| glyph.ch.is_whitespace()) {
+        line.pop();
+    }
+    line
+}
+
+/// Jelek kiírása betűkészletenkénti szakaszokban (Identity-H: 2 bájtos glyph azonosítók)
+fn draw_glyphs(content: &mut Content, glyphs: &[Glyph], x: f32, baseline: f32, size: f32, color: Rgb) {
+    if glyphs.is_empty() {
+        return;
+    }
+    content.begin_text();
+    content.set_fill_rgb(color.0, color.1, color.2);
+    let mut cursor = x;
+    for run in glyphs.chunk_by(|a, b| a.slot == b.slot) {
+        let font_name = format!("F{}", run[0].slot);
+        content.set_font(Name(font_name.as_bytes()), size);
+        content.set_text_matrix([1.0, 0.0, 0.0, 1.0, cursor, baseline]);
+        let bytes: Vec<u8> = run.iter().flat_map(|glyph| glyph.gid.to_be_bytes()).collect();
+        content.show(Str(&bytes));
+        cursor += measure(run, size);
+    }
+    content.end_text();
+}
+
+/// Lekerekített téglalap útvonal (bal alsó sarok + méret)
+fn rounded_rect(content: &mut Content, x: f32, y: f32, width: f32, height: f32, radius: f32) {
+    let r = radius.min(width / 2.0).min(height / 2.0);
+    if r <= 0.0 {
+        content.rect(x, y, width, height);
+        return;
+    }
+    // Bézier közelítés negyedkörre
+    let k = r * 0.552_284_8;
+    content.move_to(x + r, y);
+    content.line_to(x + width - r, y);
+    content.cubic_to(x + width - r + k, y, x + width, y + r - k, x + width, y + r);
+    content.line_to(x + width, y + height - r);
+    content.cubic_to(x + width, y + height - r + k, x + width - r + k, y + height, x + width - r, y + height);
+    content.line_to(x + r, y + height);
+    content.cubic_to(x + r - k, y + height, x, y + height - r + k, x, y + height - r);
+    content.line_to(x, y + r);
+    content.cubic_to(x, y + r - k, x + r - k, y, x + r, y);
+    content.close_path();
+}
+
+fn fill_rect(content: &mut Content, x: f32, y: f32, width: f32, height: f32, radius: f32, color: Rgb) {
+    content.set_fill_rgb(color.0, color.1, color.2);
+    rounded_rect(content, x, y, width, height, radius);
+    content.fill_nonzero();
+}
+
+/// A keret színe és vastagsága együtt (`stroke`)
+fn stroke_rect(content: &mut Content, x: f32, y: f32, width: f32, height: f32, radius: f32, stroke: (Rgb, f32)) {
+    let (color, line_width) = stroke;
+    content.set_stroke_rgb(color.0, color.1, color.2);
+    content.set_line_width(line_width);
+    rounded_rect(content, x, y, width, height, radius);
+    content.stroke();
+}
+
+fn line(content: &mut Content, x1: f32, y1: f32, x2: f32, y2: f32, color: Rgb, line_width: f32) {
+    content.set_stroke_rgb(color.0, color.1, color.2);
+    content.set_line_width(line_width);
+    content.move_to(x1, y1);
+    content.line_to(x2, y2);
+    content.stroke();
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ImageColor {
+    Rgb,
+    Gray,
+    Cmyk,
+}
+
+/// Beágyazható kép: PNG-ből tömörített nyers képpontok (+ alfa maszk), JPEG változatlanul
+struct PdfImage {
+    width: u32,
+    height: u32,
+    color: ImageColor,
+    data: Vec<u8>,
+    filter: Filter,
+    alpha: Option<Vec<u8>>,
+}
+
+fn compress(data: &[u8]) -> Vec<u8> {
+    miniz_oxide::deflate::compress_to_vec_zlib(data, 6)
+}
+
+/// data URL → kép; a nem támogatott formátumokat (SVG, WebP, GIF) kihagyjuk
+fn decode_image(data_url: &str) -> Result<PdfImage, String> {
+    let (header, payload) = data_url.split_once(',').ok_or("Érvénytelen kép (nem data URL)")?;
+    if !header.ends_with(";base64") {
+        return Err("Csak base64 kódolt kép támogatott".to_string());
+    }
+    let bytes = base64::engine::general_purpose::STANDARD
+        .decode(payload.trim())
+        .map_err(|e| format!("Érvénytelen base64 kép: {}", e))?;
+    if bytes.starts_with(&[0x89, b'P', b'N', b'G']) {
+        decode_png(&bytes)
+    } else if bytes.starts_with(&[0xFF, 0xD8]) {
+        decode_jpeg(bytes)
+    } else {
+        Err(format!("Nem támogatott képformátum: {}", header))
+    }
+}
+
+fn decode_png(bytes: &[u8]) -> Result<PdfImage, String> {
+    let mut decoder = png::Decoder::new(bytes);
+    decoder.set_transformations(png::Transformations::normalize_to_color8());
+    let mut reader = decoder.read_info().map_err(|e| format!("PNG hiba: {}", e))?;
+    let mut buffer = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut buffer).map_err(|e| format!("PNG hiba: {}", e))?;
+    buffer.truncate(info.buffer_size());
+    let (color, channels, has_alpha) = match info.color_type {
+        png::ColorType::Rgb => (ImageColor::Rgb, 3, false),
+        png::ColorType::Rgba => (ImageColor::Rgb, 3, true),
+        png::ColorType::Grayscale => (ImageColor::Gray, 1, false),
+        png::ColorType::GrayscaleAlpha => (ImageColor::Gray, 1, true),
+        png::ColorType::Indexed => return Err("PNG hiba: palettás kép nem bontható ki".to_string()),
+    };
+    let (pixels, alpha) = if has_alpha {
+        let stride = channels + 1;
+        let mut pixels = Vec::with_capacity(buffer.len() / stride * channels);
+        let mut alpha = Vec::with_capacity(buffer.len() / stride);
+        for pixel in buffer.chunks_exact(stride) {
+            pixels.extend_from_slice(&pixel[..channels]);
+            alpha.push(pixel[channels]);
+        }
+        // Teljesen átlátszatlan képhez nem kell maszk
+        let alpha = alpha.iter().any(|&a| a != 255).then(|| compress(&alpha));
+        (pixels, alpha)
+    } else {
+        (buffer, None)
+    };
+    Ok(PdfImage {
+        width: info.width,
+        height: info.height,
+        color,
+        data: compress(&pixels),
+        filter: Filter::FlateDecode,
+        alpha,
+    })
+}
+
+/// JPEG méret és színcsatornák a SOF szegmensből; a tartalmat a PDF olvasó bontja ki (DCTDecode)
+fn decode_jpeg(bytes: Vec<u8>) -> Result<PdfImage, String> {
+    let mut position = 2;
+    while position + 9 < bytes.len() {
+        if bytes[position] != 0xFF {
+            position += 1;
+            continue;
+        }
+        let marker = bytes[position + 1];
+        let length = u16::from_be_bytes([bytes[position + 2], bytes[position + 3]]) as usize;
+        let is_sof = matches!(marker, 0xC0..=0xCF) && !matches!(marker, 0xC4 | 0xC8 | 0xCC);
+        if is_sof {
+            let height = u16::from_be_bytes([bytes[position + 5], bytes[position + 6]]) as u32;
+            let width = u16::from_be_bytes([bytes[position + 7], bytes[position + 8]]) as u32;
+            let color = match bytes[position + 9] {
+                1 => ImageColor::Gray,
+                4 => ImageColor::Cmyk,
+                _ => ImageColor::Rgb,
+            };
+            return Ok(PdfImage {
+                width,
+                height,
+                color,
+                data: bytes,
+                filter: Filter::DctDecode,
+                alpha: None,
+            });
+        }
+        position += 2 + length;
+    }
+    Err("JPEG hiba: a képméret nem olvasható".to_string())
+}
+
+/// Oldalakra tördelő, felülről lefelé haladó kiíró
+struct PdfDocument<'a> {
+    fonts: FontSet<'a>,
+    style: TemplateStyle,
+    pages: Vec<Content>,
+    content: Content,
+    /// A következő elem felső széle (PDF koordináta, alulról mérve)
+    y: f32,
+    images: Vec<PdfImage>,
+    image_cache: HashMap<String, Option<usize>>,
+}
+
+/// Táblázat cella
+enum TableCell {
+    Text(String),
+    /// Filament kép vagy annak hiányában színes négyzet
+    Thumbnail(Option<usize>, Rgb),
+    /// Színminta kör + felirat
+    Swatch(Rgb, String),
+}
+
+const TEXT_SIZE: f32 = 9.5;
+const SMALL_SIZE: f32 = 8.0;
+const LINE_FACTOR: f32 = 1.4;
+const CELL_PADDING: f32 = 5.0;
+const THUMBNAIL_SIZE: f32 = 26.0;
+
+impl<'a> PdfDocument<'a> {
+    fn new(fonts: FontSet<'a>, style: TemplateStyle) -> Self {
+        let mut document = PdfDocument {
+            fonts,
+            style,
+            pages: Vec::new(),
+            content: Content::new(),
+            y: PAGE_HEIGHT - MARGIN,
+            images: Vec::new(),
+            image_cache: HashMap::new(),
+        };
+        document.decorate_page();
+        document
+    }
+
+    fn decorate_page(&mut self) {
+        if self.style.top_band {
+            fill_rect(&mut self.content, 0.0, PAGE_HEIGHT - 8.0, PAGE_WIDTH, 8.0, 0.0, self.style.accent);
+        }
+    }
+
+    fn new_page(&mut self) {
+        let finished = std::mem::replace(&mut self.content, Content::new());
+        self.pages.push(finished);
+        self.y = PAGE_HEIGHT - MARGIN;
+        self.decorate_page();
+    }
+
+    /// Új oldalt kezd, ha a megadott magasság nem fér el; igaz, ha lapot váltott
+    fn ensure_space(&mut self, height: f32) -> bool {
+        if self.y - height < CONTENT_BOTTOM && self.y < PAGE_HEIGHT - MARGIN {
+            self.new_page();
+            return true;
+        }
+        false
+    }
+
+    fn image(&mut self, data_url: &str) -> Option<usize> {
+        if let Some(cached) = self.image_cache.get(data_url) {
+            return *cached;
+        }
+        let index = match decode_image(data_url) {
+            Ok(image) => {
+                self.images.push(image);
+                Some(self.images.len() - 1)
+            }
+            Err(e) => {
+                logger::log_warn(&format!("PDF: a kép kimarad - {}", e));
+                None
+            }
+        };
+        self.image_cache.insert(data_url.to_string(), index);
+        index
+    }
+
+    /// Kép a dobozba illesztve (contain); a kirajzolt méretet adja vissza
+    fn draw_image_fit(&mut self, index: usize, x: f32, top: f32, max_width: f32, max_height: f32, align_right: bool) -> (f32, f32) {
+        let image = &self.images[index];
+        let natural_width = image.width as f32 * PX_TO_PT;
+        let natural_height = image.height as f32 * PX_TO_PT;
+        let scale = (max_width / natural_width).min(max_height / natural_height).min(1.0);
+        let width = natural_width * scale;
+        let height = natural_height * scale;
+        let left = if align_right { x + max_width - width } else { x };
+        self.content.save_state();
+        self.content.transform([width, 0.0, 0.0, height, left, top - height]);
+        let name = format!("Im{}", index);
+        self.content.x_object(Name(name.as_bytes()));
+        self.content.restore_state();
+        (width, height)
+    }
+
+    /// Kép négyzetbe vágva (cover), lekerekített sarkokkal
+    fn draw_thumbnail(&mut self, index: usize, x: f32, top: f32, size: f32) {
+        let image = &self.images[index];
+        let aspect = image.width as f32 / image.height.max(1) as f32;
+        let (width, height) = if aspect > 1.0 { (size * aspect, size) } else { (size, size / aspect) };
+        self.content.save_state();
+        rounded_rect(&mut self.content, x, top - size, size, size, 4.0);
+        self.content.clip_nonzero();
+        self.content.end_path();
+        self.content.transform([width, 0.0, 0.0, height, x - (width - size) / 2.0, top - size - (height - size) / 2.0]);
+        let name = format!("Im{}", index);
+        self.content.x_object(Name(name.as_bytes()));
+        self.content.restore_state();
+    }
+
+    /// Sorok kiírása a kurzortól, szükség esetén új oldallal
+    fn draw_lines(&mut self, lines: &[Vec<Glyph>], x: f32, size: f32, color: Rgb) {
+        let line_height = size * LINE_FACTOR;
+        for glyphs in lines {
+            self.ensure_space(line_height);
+            draw_glyphs(&mut self.content, glyphs, x, self.y - size, size, color);
+            self.y -= line_height;
+        }
+    }
+
+    fn paragraph(&mut self, text: &str, bold: bool, size: f32, color: Rgb) {
+        let lines = self.fonts.wrap(text, bold, size, CONTENT_WIDTH);
+        self.draw_lines(&lines, MARGIN, size, color);
+    }
+
+    fn heading(&mut self, text: &str) {
+        let size = 13.0;
+        // A cím ne maradjon egyedül a lap alján
+        self.ensure_space(size * LINE_FACTOR + 60.0);
+        self.y -= 14.0;
+        let glyphs = self.fonts.shape(text, true);
+        draw_glyphs(&mut self.content, &glyphs, MARGIN, self.y - size, size, self.style.accent);
+        self.y -= size * LINE_FACTOR;
+        if self.style.contact_footer {
+            line(&mut self.content, MARGIN, self.y + 2.0, MARGIN + CONTENT_WIDTH, self.y + 2.0, self.style.accent, 0.75);
+            self.y -= 4.0;
+        }
+        self.y -= 2.0;
+    }
+
+    fn company_header(&mut self, company: &PdfCompanyInfo, labels: &OfferPdfLabels) {
+        let value = |field: &Option<String>| field.as_deref().map(str::trim).filter(|v| !v.is_empty()).map(str::to_string);
+        let name = value(&company.name);
+        let details: Vec<(String, String)> = [
+            (&labels.headquarters, value(&company.address)),
+            (&labels.tax, value(&company.tax_number)),
+            (&labels.bank, value(&company.bank_account)),
+            (&labels.email, value(&company.email)),
+            (&labels.phone, value(&company.phone)),
+            (&labels.website, value(&company.website)),
+        ]
+        .into_iter()
+        .filter_map(|(label, value)| value.map(|value| (label.clone(), value)))
+        .collect();
+        let logo = company.logo_base64.as_deref().filter(|v| !v.is_empty()).and_then(|url| self.image(url));
+        if name.is_none() && details.is_empty() && logo.is_none() {
+            return;
+        }
+
+        let logo_box = (150.0, 80.0);
+        let text_width = if logo.is_some() { CONTENT_WIDTH - logo_box.0 - 16.0 } else { CONTENT_WIDTH };
+        let top = self.y;
+        if let Some(name) = &name {
+            let size = 18.0;
+            let lines = self.fonts.wrap(name, true, size, text_width);
+            self.draw_lines(&lines, MARGIN, size, self.style.text);
+            self.y -= 2.0;
+        }
+        for (label, value) in &details {
+            let prefix = format!("{}: ", label);
+            let lines = self.fonts.wrap_rich(&[(&prefix, true), (value, false)], TEXT_SIZE, text_width);
+            self.draw_lines(&lines, MARGIN, TEXT_SIZE, self.style.muted);
+        }
+        let mut bottom = self.y;
+        if let Some(index) = logo {
+            let (_, height) = self.draw_image_fit(index, MARGIN + CONTENT_WIDTH - logo_box.0, top, logo_box.0, logo_box.1, true);
+            bottom = bottom.min(top - height);
+        }
+        self.y = bottom - 10.0;
+        let (color, width) = if self.style.top_band { (self.style.accent, 1.5) } else { (self.style.border, 1.0) };
+        line(&mut self.content, MARGIN, self.y, MARGIN + CONTENT_WIDTH, self.y, color, width);
+        self.y -= 18.0;
+    }
+
+    fn title(&mut self, title: &str, offer_id: &str) {
+        let id_glyphs = self.fonts.shape(&format!("#{}", offer_id), false);
+        let id_width = measure(&id_glyphs, TEXT_SIZE);
+        if self.style.title_band {
+            let size = 16.0;
+            let height = 34.0;
+            self.ensure_space(height);
+            fill_rect(&mut self.content, MARGIN, self.y - height, CONTENT_WIDTH, height, 0.0, self.style.accent);
+            let lines = self.fonts.wrap(title, true, size, CONTENT_WIDTH - id_width - 36.0);
+            if let Some(first) = lines.first() {
+                draw_glyphs(&mut self.content, first, MARGIN + 12.0, self.y - height / 2.0 - size * 0.35, size, Rgb::WHITE);
+            }
+            draw_glyphs(
+                &mut self.content,
+                &id_glyphs,
+                MARGIN + CONTENT_WIDTH - 12.0 - id_width,
+                self.y - height / 2.0 - TEXT_SIZE * 0.35,
+                TEXT_SIZE,
+                Rgb::WHITE,
+            );
+            self.y -= height + 14.0;
+            return;
+        }
+        let size = if self.style.top_band { 22.0 } else { 20.0 };
+        let lines = self.fonts.wrap(title, true, size, CONTENT_WIDTH - id_width - 12.0);
+        let top = self.y;
+        self.draw_lines(&lines, MARGIN, size, self.style.accent);
+        draw_glyphs(&mut self.content, &id_glyphs, MARGIN + CONTENT_WIDTH - id_width, top - size, TEXT_SIZE, self.style.muted);
+        let (color, width) = if self.style.top_band { (self.style.accent, 2.0) } else { (self.style.border, 0.75) };
+        line(&mut self.content, MARGIN, self.y, MARGIN + CONTENT_WIDTH, self.y, color, width);
+        self.y -= 16.0;
+    }
+
+    /// Dátum, ügyfél, elérhetőség, leírás - modern sablonnál kártyában, egyébként keretben
+    fn info_box(&mut self, rows: &[(String, String)]) {
+        let padding = 12.0;
+        let inner_width = CONTENT_WIDTH - 2.0 * padding;
+        let mut lines = Vec::new();
+        for (label, value) in rows {
+            let prefix = format!("{}: ", label);
+            lines.extend(self.fonts.wrap_rich(&[(&prefix, true), (value, false)], TEXT_SIZE, inner_width));
+        }
+        let height = lines.len() as f32 * TEXT_SIZE * LINE_FACTOR + 2.0 * padding - TEXT_SIZE * (LINE_FACTOR - 1.0);
+        let full_page = PAGE_HEIGHT - MARGIN - CONTENT_BOTTOM;
+        if height <= full_page {
+            self.ensure_space(height);
+            let bottom = self.y - height;
+            match self.style.card_fill {
+                Some(fill) => fill_rect(&mut self.content, MARGIN, bottom, CONTENT_WIDTH, height, self.style.corner_radius, fill),
+                None => stroke_rect(&mut self.content, MARGIN, bottom, CONTENT_WIDTH, height, self.style.corner_radius, (self.style.border, 0.75)),
+            }
+            if self.style.contact_footer {
+                fill_rect(&mut self.content, MARGIN, bottom, 3.0, height, 0.0, self.style.accent);
+            }
+        }
+        self.y -= padding;
+        self.draw_lines(&lines, MARGIN + padding, TEXT_SIZE, self.style.text);
+        self.y -= padding - TEXT_SIZE * (LINE_FACTOR - 1.0);
+        self.y -= 8.0;
+    }
+
+    fn cell_lines(&mut self, cell: &TableCell, bold: bool, size: f32, width: f32) -> Vec<Vec<Glyph>> {
+        match cell {
+            TableCell::Text(text) => self.fonts.wrap(text, bold, size, width),
+            TableCell::Swatch(_, text) => self.fonts.wrap(text, bold, size, width - 14.0),
+            TableCell::Thumbnail(..) => Vec::new(),
+        }
+    }
+
+    fn table_row(&mut self, widths: &[f32], cells: &[TableCell], header: bool, stripe: bool) {
+        let size = if header { SMALL_SIZE } else { TEXT_SIZE - 0.5 };
+        let line_height = size * LINE_FACTOR;
+        let wrapped: Vec<Vec<Vec<Glyph>>> = cells
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| self.cell_lines(cell, header, size, width - 2.0 * CELL_PADDING))
+            .collect();
+        let has_thumbnail = cells.iter().any(|cell| matches!(cell, TableCell::Thumbnail(..)));
+        let text_height = wrapped.iter().map(|lines| lines.len()).max().unwrap_or(1) as f32 * line_height;
+        let content_height = if has_thumbnail { text_height.max(THUMBNAIL_SIZE) } else { text_height };
+        let height = content_height + 2.0 * CELL_PADDING;
+        let top = self.y;
+        let bottom = top - height;
+
+        let fill = if header { self.style.table_header_fill } else { self.style.stripe_fill.filter(|_| stripe) };
+        if let Some(fill) = fill {
+            fill_rect(&mut self.content, MARGIN, bottom, CONTENT_WIDTH, height, 0.0, fill);
+        }
+        let text_color = if header { self.style.table_header_text } else { self.style.text };
+        let mut x = MARGIN;
+        for ((cell, lines), width) in cells.iter().zip(&wrapped).zip(widths) {
+            let inner_top = top - CELL_PADDING - (content_height - lines.len() as f32 * line_height).max(0.0) / 2.0;
+            match cell {
+                TableCell::Text(_) => {
+                    for (index, glyphs) in lines.iter().enumerate() {
+                        let baseline = inner_top - size - index as f32 * line_height;
+                        draw_glyphs(&mut self.content, glyphs, x + CELL_PADDING, baseline, size, text_color);
+                    }
+                }
+                TableCell::Swatch(color, _) => {
+                    let radius = 4.5;
+                    let center_y = inner_top - line_height / 2.0 + 1.0;
+                    self.content.set_fill_rgb(color.0, color.1, color.2);
+                    self.content.set_stroke_rgb(0.0, 0.0, 0.0);
+                    self.content.set_line_width(0.3);
+                    rounded_rect(&mut self.content, x + CELL_PADDING, center_y - radius, 2.0 * radius, 2.0 * radius, radius);
+                    self.content.fill_nonzero_and_stroke();
+                    for (index, glyphs) in lines.iter().enumerate() {
+                        let baseline = inner_top - size - index as f32 * line_height;
+                        draw_glyphs(&mut self.content, glyphs, x + CELL_PADDING + 14.0, baseline, size, text_color);
+                    }
+                }
+                TableCell::Thumbnail(image, color) => {
+                    let thumb_top = top - CELL_PADDING - (content_height - THUMBNAIL_SIZE) / 2.0;
+                    let thumb_x = x + (width - THUMBNAIL_SIZE) / 2.0;
+                    match image {
+                        Some(index) => self.draw_thumbnail(*index, thumb_x, thumb_top, THUMBNAIL_SIZE),
+                        None => fill_rect(&mut self.content, thumb_x, thumb_top - THUMBNAIL_SIZE, THUMBNAIL_SIZE, THUMBNAIL_SIZE, 4.0, *color),
+                    }
+                    stroke_rect(&mut self.content, thumb_x, thumb_top - THUMBNAIL_SIZE, THUMBNAIL_SIZE, THUMBNAIL_SIZE, 4.0, (self.style.border, 0.5));
+                }
+            }
+            x += width;
+        }
+
+        if self.style.grid {
+            stroke_rect(&mut self.content, MARGIN, bottom, CONTENT_WIDTH, height, 0.0, (self.style.border, 0.5));
+            let mut x = MARGIN;
+            for width in &widths[..widths.len().saturating_sub(1)] {
+                x += width;
+                line(&mut self.content, x, bottom, x, top, self.style.border, 0.5);
+            }
+        } else {
+            let (color, width) = if header && self.style.table_header_fill.is_none() { (self.style.text, 0.8) } else { (self.style.border, 0.5) };
+            line(&mut self.content, MARGIN, bottom, MARGIN + CONTENT_WIDTH, bottom, color, width);
+        }
+        self.y = bottom;
+    }
+
+    /// Táblázat; lapváltáskor a fejléc megismétlődik
+    fn table(&mut self, weights: &[f32], header: &[String], rows: &[Vec<TableCell>]) {
+        let total: f32 = weights.iter().sum();
+        let widths: Vec<f32> = weights.iter().map(|weight| weight / total * CONTENT_WIDTH).collect();
+        let header_cells: Vec<TableCell> = header.iter().map(|text| TableCell::Text(text.clone())).collect();
+        self.y -= 4.0;
+        self.ensure_space(60.0);
+        self.table_row(&widths, &header_cells, true, false);
+        for (index, row) in rows.iter().enumerate() {
+            if self.ensure_space(THUMBNAIL_SIZE + 2.0 * CELL_PADDING) {
+                self.table_row(&widths, &header_cells, true, false);
+            }
+            self.table_row(&widths, row, false, index % 2 == 1);
+        }
+        self.y -= 8.0;
+    }
+
+    fn cost_rows(&mut self, rows: &[(String, String, CostRowKind)]) {
+        let label_width = CONTENT_WIDTH * 0.65;
+        self.y -= 4.0;
+        for (label, value, kind) in rows {
+            let (size, bold) = match kind {
+                CostRowKind::Normal => (TEXT_SIZE, false),
+                CostRowKind::Total | CostRowKind::Revenue => (TEXT_SIZE + 0.5, true),
+                CostRowKind::Profit => (12.0, true),
+            };
+            let label_lines = self.fonts.wrap(label, bold, size, label_width - 2.0 * CELL_PADDING);
+            let value_glyphs = self.fonts.shape(value, true);
+            let height = label_lines.len().max(1) as f32 * size * LINE_FACTOR + 2.0 * CELL_PADDING;
+            self.ensure_space(height);
+            let top = self.y;
+            let bottom = top - height;
+            if *kind == CostRowKind::Profit {
+                if let Some(fill) = self.style.highlight_fill {
+                    fill_rect(&mut self.content, MARGIN, bottom, CONTENT_WIDTH, height, self.style.corner_radius.min(4.0), fill);
+                }
+            }
+            if matches!(kind, CostRowKind::Total | CostRowKind::Revenue) {
+                let width = if *kind == CostRowKind::Revenue { 1.2 } else { 0.75 };
+                line(&mut self.content, MARGIN, top, MARGIN + CONTENT_WIDTH, top, self.style.text, width);
+            }
+            let value_color = match kind {
+                CostRowKind::Revenue => self.style.positive,
+                CostRowKind::Profit => self.style.accent,
+                _ => self.style.text,
+            };
+            for (index, glyphs) in label_lines.iter().enumerate() {
+                let baseline = top - CELL_PADDING - size - index as f32 * size * LINE_FACTOR;
+                draw_glyphs(&mut self.content, glyphs, MARGIN + CELL_PADDING, baseline, size, self.style.text);
+            }
+            let value_x = MARGIN + CONTENT_WIDTH - CELL_PADDING - measure(&value_glyphs, size);
+            draw_glyphs(&mut self.content, &value_glyphs, value_x, top - CELL_PADDING - size, size, value_color);
+            if self.style.grid {
+                stroke_rect(&mut self.content, MARGIN, bottom, CONTENT_WIDTH, height, 0.0, (self.style.border, 0.5));
+                line(&mut self.content, MARGIN + label_width, bottom, MARGIN + label_width, top, self.style.border, 0.5);
+            } else if *kind != CostRowKind::Profit {
+                line(&mut self.content, MARGIN, bottom, MARGIN + CONTENT_WIDTH, bottom, self.style.border, 0.5);
+            }
+            self.y = bottom;
+        }
+        self.y -= 8.0;
+    }
+
+    /// Aláírás blokk (professional): dátum és aláírás vonal
+    fn signature_block(&mut self, date_label: &str, formatted_date: &str, signature_label: &str, company_name: Option<&str>) {
+        self.ensure_space(100.0);
+        self.y -= 48.0;
+        let width = 200.0;
+        let right_x = MARGIN + CONTENT_WIDTH - width;
+        let date_glyphs = self.fonts.shape(formatted_date, false);
+        draw_glyphs(&mut self.content, &date_glyphs, MARGIN + 4.0, self.y + 6.0, TEXT_SIZE, self.style.text);
+        line(&mut self.content, MARGIN, self.y, MARGIN + width, self.y, self.style.text, 0.6);
+        line(&mut self.content, right_x, self.y, right_x + width, self.y, self.style.text, 0.6);
+        let date_label = self.fonts.shape(date_label, false);
+        draw_glyphs(&mut self.content, &date_label, MARGIN, self.y - 12.0, SMALL_SIZE, self.style.muted);
+        let signature = self.fonts.shape(signature_label, false);
+        draw_glyphs(&mut self.content, &signature, right_x, self.y - 12.0, SMALL_SIZE, self.style.muted);
+        if let Some(name) = company_name {
+            let name_glyphs = self.fonts.shape(name, true);
+            draw_glyphs(&mut self.content, &name_glyphs, right_x, self.y - 24.0, SMALL_SIZE, self.style.text);
+        }
+        self.y -= 30.0;
+    }
+
+    /// Oldalszám (és professional sablonnál elérhetőségi sor) minden oldal aljára
+    fn footers(&mut self, page_label: &str, contact_line: Option<&str>) {
+        let total = self.pages.len();
+        for (index, page) in self.pages.iter_mut().enumerate() {
+            let text = page_label
+                .replace("{page}", &(index + 1).to_string())
+                .replace("{pages}", &total.to_string());
+            let glyphs = self.fonts.shape(&text, false);
+            let width = measure(&glyphs, SMALL_SIZE);
+            draw_glyphs(page, &glyphs, MARGIN + CONTENT_WIDTH - width, FOOTER_BASELINE, SMALL_SIZE, self.style.muted);
+            if let Some(contact) = contact_line {
+                line(page, MARGIN, FOOTER_BASELINE + 12.0, MARGIN + CONTENT_WIDTH, FOOTER_BASELINE + 12.0, self.style.border, 0.5);
+                let lines = self.fonts.wrap(contact, false, SMALL_SIZE, CONTENT_WIDTH - width - 16.0);
+                if let Some(first) = lines.first() {
+                    draw_glyphs(page, first, MARGIN, FOOTER_BASELINE, SMALL_SIZE, self.style.muted);
+                }
+            }
+        }
+    }
+
+    /// A PDF fájl összeállítása: betűkészletek (subset), képek, oldalak
+    fn finish_pages(self, title: &str) -> Result<Vec<u8>, String> {
+        let mut pdf = Pdf::new();
+        let mut next_ref = Ref::new(1);
+        let catalog_id = next_ref.bump();
+        let page_tree_id = next_ref.bump();
+        let info_id = next_ref.bump();
+
+        let used_fonts: Vec<usize> = (0..self.fonts.slots.len())
+            .filter(|&slot| !self.fonts.slots[slot].used.is_empty())
+            .collect();
+        let mut font_refs = Vec::new();
+        for &slot in &used_fonts {
+            let font_ref = next_ref.bump();
+            write_font(&mut pdf, &mut next_ref, font_ref, &self.fonts.slots[slot])?;
+            font_refs.push((slot, font_ref));
+        }
+
+        let mut image_refs = Vec::new();
+        for image in &self.images {
+            let image_ref = next_ref.bump();
+            let mask_ref = image.alpha.as_ref().map(|_| next_ref.bump());
+            let mut xobject = pdf.image_xobject(image_ref, &image.data);
+            xobject.filter(image.filter);
+            xobject.width(image.width as i32);
+            xobject.height(image.height as i32);
+            match image.color {
+                ImageColor::Rgb => xobject.color_space().device_rgb(),
+                ImageColor::Gray => xobject.color_space().device_gray(),
+                ImageColor::Cmyk => xobject.color_space().device_cmyk(),
+            }
+            xobject.bits_per_component(8);
+            if let Some(mask_ref) = mask_ref {
+                xobject.s_mask(mask_ref);
+            }
+            xobject.finish();
+            if let (Some(mask_ref), Some(alpha)) = (mask_ref, &image.alpha) {
+                let mut mask = pdf.image_xobject(mask_ref, alpha);
+                mask.filter(Filter::FlateDecode);
+                mask.width(image.width as i32);
+                mask.height(image.height as i32);
+                mask.color_space().device_gray();
+                mask.bits_per_component(8);
+            }
+            image_refs.push(image_ref);
+        }
+
+        let mut page_ids = Vec::new();
+        for content in self.pages {
+            let page_id = next_ref.bump();
+            let content_id = next_ref.bump();
+            page_ids.push(page_id);
+            let mut page = pdf.page(page_id);
+            page.media_box(Rect::new(0.0, 0.0, PAGE_WIDTH, PAGE_HEIGHT));
+            page.parent(page_tree_id);
+            page.contents(content_id);
+            let mut resources = page.resources();
+            let mut fonts = resources.fonts();
+            for (slot, font_ref) in &font_refs {
+                let name = format!("F{}", slot);
+                fonts.pair(Name(name.as_bytes()), *font_ref);
+            }
+            fonts.finish();
+            let mut xobjects = resources.x_objects();
+            for (index, image_ref) in image_refs.iter().enumerate() {
+                let name = format!("Im{}", index);
+                xobjects.pair(Name(name.as_bytes()), *image_ref);
+            }
+            xobjects.finish();
+            resources.finish();
+            page.finish();
+            let compressed = compress(&content.finish());
+            pdf.stream(content_id, &compressed).filter(Filter::FlateDecode);
+        }
+
+        pdf.catalog(catalog_id).pages(page_tree_id);
+        pdf.pages(page_tree_id).kids(page_ids.iter().copied()).count(page_ids.len() as i32);
+        let now = chrono::Local::now();
+        let offset_minutes = now.offset().local_minus_utc() / 60;
+        let date = Date::new(now.year() as u16)
+            .month(now.month() as u8)
+            .day(now.day() as u8)
+            .hour(now.hour() as u8)
+            .minute(now.minute() as u8)
+            .second(now.second() as u8)
+            .utc_offset_hour((offset_minutes / 60) as i8)
+            .utc_offset_minute((offset_minutes % 60).unsigned_abs() as u8);
+        pdf.document_info(info_id)
+            .title(TextStr(title))
+            .creator(TextStr("3DPrinterCalcApp"))
+            .producer(TextStr("3DPrinterCalcApp"))
+            .creation_date(date);
+        Ok(pdf.finish())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CostRowKind {
+    Normal,
+    Total,
+    Revenue,
+    Profit,
+}
+
+/// Type0 / CID betűkészlet beágyazása subsettel és ToUnicode térképpel (a szöveg kijelölhető, kereshető)
+fn write_font(pdf: &mut Pdf, next_ref: &mut Ref, font_ref: Ref, font: &FontSlot) -> Result<(), String> {
+    let cid_ref = next_ref.bump();
+    let descriptor_ref = next_ref.bump();
+    let cmap_ref = next_ref.bump();
+    let data_ref = next_ref.bump();
+
+    let mut glyph_ids: Vec<u16> = font.used.keys().copied().collect();
+    if !glyph_ids.contains(&0) {
+        glyph_ids.insert(0, 0);
+    }
+    let subset = match subsetter::subset(font.data, font.index, subsetter::Profile::pdf(&glyph_ids)) {
+        Ok(subset) => Cow::Owned(subset),
+        Err(e) => {
+            logger::log_warn(&format!("PDF: a betűkészlet subset nem sikerült, teljes beágyazás ({:?})", e));
+            Cow::Borrowed(font.data)
+        }
+    };
+
+    // Subset címke: a használt jelekből számolva, így azonos tartalomhoz azonos név tartozik
+    let digest = Sha256::digest(glyph_ids.iter().flat_map(|gid| gid.to_be_bytes()).collect::<Vec<u8>>());
+    let tag: String = digest.iter().take(6).map(|byte| (b'A' + byte % 26) as char).collect();
+    let postscript_name = font
+        .face
+        .names()
+        .into_iter()
+        .filter(|name| name.name_id == ttf_parser::name_id::POST_SCRIPT_NAME)
+        .find_map(|name| name.to_string())
+        .unwrap_or_else(|| "Font".to_string());
+    let postscript_name: String = postscript_name.chars().filter(|c| c.is_ascii_graphic() && !"[](){}<>/%".contains(*c)).collect();
+    let base_font = format!("{}+{}", tag, postscript_name);
+    let system_info = SystemInfo {
+        registry: Str(b"Adobe"),
+        ordering: Str(b"Identity"),
+        supplement: 0,
+    };
+
+    pdf.type0_font(font_ref)
+        .base_font(Name(base_font.as_bytes()))
+        .encoding_predefined(Name(b"Identity-H"))
+        .descendant_font(cid_ref)
+        .to_unicode(cmap_ref);
+
+    let cff = font.is_cff();
+    let mut cid = pdf.cid_font(cid_ref);
+    cid.subtype(if cff { CidFontType::Type0 } else { CidFontType::Type2 });
+    cid.base_font(Name(base_font.as_bytes()));
+    cid.system_info(system_info);
+    cid.font_descriptor(descriptor_ref);
+    cid.default_width(0.0);
+    if !cff {
+        cid.cid_to_gid_map_predefined(Name(b"Identity"));
+    }
+    let mut widths = cid.widths();
+    for &gid in &glyph_ids {
+        let advance = font.face.glyph_hor_advance(GlyphId(gid)).unwrap_or(0) as f32;
+        widths.consecutive(gid, [font.to_pdf_units(advance)]);
+    }
+    widths.finish();
+    cid.finish();
+
+    let bbox = font.face.global_bounding_box();
+    let ascender = font.to_pdf_units(font.face.ascender() as f32);
+    let descender = font.to_pdf_units(font.face.descender() as f32);
+    let cap_height = font.face.capital_height().map(|h| font.to_pdf_units(h as f32)).unwrap_or(ascender);
+    let stem_v = 10.0 + 0.244 * (font.face.weight().to_number() as f32 - 50.0);
+    let mut descriptor = pdf.font_descriptor(descriptor_ref);
+    descriptor
+        .name(Name(base_font.as_bytes()))
+        .flags(FontFlags::SYMBOLIC)
+        .bbox(Rect::new(
+            font.to_pdf_units(bbox.x_min as f32),
+            font.to_pdf_units(bbox.y_min as f32),
+            font.to_pdf_units(bbox.x_max as f32),
+            font.to_pdf_units(bbox.y_max as f32),
+        ))
+        .italic_angle(0.0)
+        .ascent(ascender)
+        .descent(descender)
+        .cap_height(cap_height)
+        .stem_v(stem_v);
+    if cff {
+        descriptor.font_file3(data_ref);
+    } else {
+        descriptor.font_file2(data_ref);
+    }
+    descriptor.finish();
+
+    let mut cmap = UnicodeCmap::new(Name(b"Custom"), system_info);
+    for (&gid, &ch) in &font.used {
+        if gid != 0 {
+            cmap.pair(gid, ch);
+        }
+    }
+    pdf.cmap(cmap_ref, &cmap.finish());
+
+    let compressed = compress(&subset);
+    let mut stream = pdf.stream(data_ref, &compressed);
+    stream.filter(Filter::FlateDecode);
+    if cff {
+        stream.pair(Name(b"Subtype"), Name(b"OpenType"));
+    }
+    Ok(())
+}
+
+/// Egész számok tizedesek nélkül (pl. 250 W, 120 g)
+fn plain_number(value: f64) -> String {
+    if value.fract() == 0.0 {
+        format!("{:.0}", value)
+    } else {
+        format!("{}", (value * 100.0).round() / 100.0)
+    }
+}
+
+/// Nyomtatási idő a frontenddel egyezően ("2h 30m", üresen "0")
+fn format_print_time(hours: f64, minutes: f64, seconds: f64) -> String {
+    let parts: Vec<String> = [(hours, "h"), (minutes, "m"), (seconds, "s")]
+        .iter()
+        .filter(|(value, _)| *value > 0.0)
+        .map(|(value, unit)| format!("{}{}", plain_number(*value), unit))
+        .collect();
+    if parts.is_empty() {
+        "0".to_string()
+    } else {
+        parts.join(" ")
+    }
+}
+
+/// A feliratok végéről a kettőspont (az offers.* kulcsok egy része "Dátum:" alakú)
+fn bare_label(label: &str) -> String {
+    label.trim().trim_end_matches(':').trim_end().to_string()
+}
+
+fn non_empty(value: &Option<String>) -> Option<&str> {
+    value.as_deref().map(str::trim).filter(|v| !v.is_empty())
+}
+
+pub struct RenderedPdf {
+    pub bytes: Vec<u8>,
+    pub page_count: usize,
+    pub missing_characters: String,
+}
+
+/// Az árajánlat PDF tartalma; a CJK betűkészleteket a megadott mappákban keresi
+pub fn render_offer_pdf(request: &OfferPdfRequest, font_dirs: &[PathBuf]) -> Result<RenderedPdf, String> {
+    let sources = load_font_sources(font_dirs);
+    let fonts = FontSet::new(&sources)?;
+    let mut document = PdfDocument::new(fonts, TemplateStyle::of(request.template));
+    let offer = &request.offer;
+    let labels = &request.labels;
+    let company = &request.company_info;
+
+    let offer_currency = offer.currency.clone().unwrap_or_else(|| "EUR".to_string());
+    let display_currency = request.currency.as_str();
+    let currency_label = if display_currency == "HUF" { "Ft" } else { display_currency };
+    let money = |amount: f64| format!("{:.2} {}", exporter::convert_currency(amount, &offer_currency, display_currency), currency_label);
+    let offer_id = match &offer.id {
+        Value::String(id) => id.clone(),
+        Value::Null => String::new(),
+        other => other.to_string(),
+    };
+
+    document.company_header(company, labels);
+    document.title(&labels.title, &offer_id);
+
+    let mut info = vec![(bare_label(&labels.date), request.formatted_date.clone())];
+    for (label, value) in [
+        (&labels.customer_name, &offer.customer_name),
+        (&labels.customer_contact, &offer.customer_contact),
+        (&labels.description, &offer.description),
+    ] {
+        if let Some(value) = non_empty(value) {
+            info.push((bare_label(label), value.to_string()));
+        }
+    }
+    document.info_box(&info);
+
+    document.heading(&labels.printer);
+    let style_text = document.style.text;
+    document.paragraph(
+        &format!("{} ({}) - {}W", offer.printer_name, offer.printer_type, plain_number(offer.printer_power)),
+        true,
+        TEXT_SIZE + 0.5,
+        style_text,
+    );
+    let print_time = format_print_time(offer.print_time_hours, offer.print_time_minutes, offer.print_time_seconds);
+    let lines = document.fonts.wrap_rich(&[(&format!("{}: ", bare_label(&labels.print_time)), true), (&print_time, false)], TEXT_SIZE, CONTENT_WIDTH);
+    document.draw_lines(&lines, MARGIN, TEXT_SIZE, style_text);
+
+    document.heading(&labels.filaments);
+    let has_drying = offer.filaments.iter().any(|f| f.needs_drying);
+    let mut header = vec![
+        labels.image.clone(),
+        labels.brand.clone(),
+        labels.filament_type.clone(),
+        labels.color.clone(),
+        labels.used.clone(),
+        labels.price_per_kg.replace("{currency}", currency_label),
+    ];
+    let mut weights = vec![38.0, 92.0, 60.0, 98.0, 76.0, 92.0];
+    if has_drying {
+        header.push(labels.drying.clone());
+        weights.push(78.0);
+    }
+    let mut rows = Vec::new();
+    for filament in &offer.filaments {
+        let color = non_empty(&filament.color_hex).and_then(Rgb::hex).unwrap_or(Rgb(0.6, 0.6, 0.6));
+        let image = non_empty(&filament.image_base64).and_then(|url| document.image(url));
+        let color_label = non_empty(&filament.color)
+            .map(str::to_string)
+            .or_else(|| non_empty(&filament.color_hex).map(str::to_string))
+            .unwrap_or_default();
+        let mut row = vec![
+            TableCell::Thumbnail(image, color),
+            TableCell::Text(filament.brand.clone()),
+            TableCell::Text(filament.filament_type.clone()),
+            TableCell::Swatch(color, color_label),
+            TableCell::Text(format!("{} g", plain_number(filament.used_grams))),
+            TableCell::Text(money(filament.price_per_kg)),
+        ];
+        if has_drying {
+            row.push(TableCell::Text(if filament.needs_drying {
+                format!(
+                    "{}h @ {}W",
+                    plain_number(filament.drying_time.unwrap_or(0.0)),
+                    plain_number(filament.drying_power.unwrap_or(0.0))
+                )
+            } else {
+                "-".to_string()
+            }));
+        }
+        rows.push(row);
+    }
+    document.table(&weights, &header, &rows);
+
+    document.heading(&labels.costs);
+    let costs = &offer.costs;
+    let profit_percentage = offer.profit_percentage.unwrap_or(30.0);
+    let mut cost_rows = vec![
+        (labels.cost_filament.clone(), money(costs.filament_cost), CostRowKind::Normal),
+        (labels.cost_electricity.clone(), money(costs.electricity_cost), CostRowKind::Normal),
+    ];
+    if costs.drying_cost > 0.0 {
+        cost_rows.push((labels.cost_drying.clone(), money(costs.drying_cost), CostRowKind::Normal));
+    }
+    cost_rows.push((labels.cost_usage.clone(), money(costs.usage_cost), CostRowKind::Normal));
+    cost_rows.push((labels.cost_total.clone(), money(costs.total_cost), CostRowKind::Total));
+    cost_rows.push((
+        labels.revenue.replace("{profit}", &plain_number(profit_percentage)),
+        money(costs.total_cost * (1.0 + profit_percentage / 100.0)),
+        CostRowKind::Revenue,
+    ));
+    cost_rows.push((
+        labels.profit.clone(),
+        money(costs.total_cost * (profit_percentage / 100.0)),
+        CostRowKind::Profit,
+    ));
+    document.cost_rows(&cost_rows);
+
+    if document.style.signature_block {
+        document.signature_block(&bare_label(&labels.date), &request.formatted_date, &labels.signature, non_empty(&company.name));
+    }
+
+    let contact_line = document.style.contact_footer.then(|| {
+        [&company.name, &company.email, &company.phone, &company.website]
+            .into_iter()
+            .filter_map(non_empty)
+            .collect::<Vec<_>>()
+            .join(" · ")
+    });
+    let last = std::mem::replace(&mut document.content, Content::new());
+    document.pages.push(last);
+    document.footers(&labels.page, contact_line.as_deref().filter(|line| !line.is_empty()));
+
+    let page_count = document.pages.len();
+    let missing_characters: String = document.fonts.missing.iter().collect();
+    let title = format!("{} - {}", labels.title, offer_id);
+    let bytes = document.finish_pages(&title)?;
+    Ok(RenderedPdf {
+        bytes,
+        page_count,
+        missing_characters,
+    })
+}
+
+/// Natív PDF export - a webview nyomtatási útvonalától függetlenül minden platformon azonos kimenet
+#[tauri::command]
+pub fn export_offer_pdf(app: AppHandle, request: OfferPdfRequest) -> Result<OfferPdfResult, String> {
+    let mut font_dirs = Vec::new();
+    if let Ok(resource_dir) = app.path().resource_dir() {
+        font_dirs.push(resource_dir.join(CJK_FONT_DIR));
+    }
+    // Fejlesztői futtatáskor a forrásfában lévő betűkészletek is elérhetők
+    if cfg!(debug_assertions) {
+        font_dirs.push(PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(CJK_FONT_DIR));
+    }
+
+    let rendered = render_offer_pdf(&request, &font_dirs)?;
+    atomic_write::write_atomic(Path::new(&request.file_path), &rendered.bytes)?;
+    if !rendered.missing_characters.is_empty() {
+        logger::log_warn(&format!(
+            "PDF export: {} karakterhez nincs betűkészlet (CJK betűkészlet: {})",
+            rendered.missing_characters.chars().count(),
+            CJK_FONT_DIR
+        ));
+    }
+    logger::log_info(&format!(
+        "PDF árajánlat exportálva: {} ({} oldal, {} bájt)",
+        request.file_path,
+        rendered.page_count,
+        rendered.bytes.len()
+    ));
+    Ok(OfferPdfResult {
+        file_path: request.file_path,
+        page_count: rendered.page_count,
+        missing_characters: rendered.missing_characters,
+    })
+}
@@ -0,0 +1,858 @@
+use crate::backup_archive;
+use crate::backup_format;
+use crate::backup_manifest::{self, IntegrityStatus, MANIFEST_KEY};
+use crate::data_store::{self, ENTITY_KEYS};
+use crate::logger;
+use crate::migrations;
+use crate::search;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Emitter};
+
+/// Esemény a frontend felé, ha a backend átírta a data.json-t (a Store-t újra kell tölteni)
+pub const DATA_STORE_CHANGED_EVENT: &str = "data-store-changed";
+
+/// A backup fájl metaadat kulcsai, amelyek nem kerülnek a Store-ba
+const BACKUP_META_KEYS: [&str; 3] = ["version", "timestamp", MANIFEST_KEY];
+
+/// Részleges visszaállításkor kiválasztható entitás típusok
+pub const SELECTABLE_ENTITY_TYPES: [&str; 6] = ["printers", "filaments", "offers", "customers", "projects", "tasks"];
+
+/// Rekordok közötti hivatkozások: (hivatkozó típus, mező, hivatkozott típus)
+/// Átszámozáskor a vele együtt visszaállított hivatkozó rekordok is az új azonosítót kapják
+pub const RECORD_REFERENCES: [(&str, &str, &str); 4] = [
+    ("offers", "printerId", "printers"),
+    ("projects", "offerIds", "offers"),
+    ("tasks", "relatedOfferId", "offers"),
+    ("tasks", "relatedProjectId", "projects"),
+];
+
+/// Egy entitás típus változásai a visszaállítás során
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntityDiff {
+    pub entity_type: String,
+    pub added: Vec<String>,
+    pub changed: Vec<String>,
+    pub removed: Vec<String>,
+    pub unchanged: usize,
+}
+
+/// Visszaállítás eredménye (dry-run esetén csak az előnézet)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RestoreReport {
+    pub file_path: String,
+    pub dry_run: bool,
+    pub applied: bool,
+    pub backup_timestamp: Option<String>,
+    pub backup_schema_version: u32,
+    pub integrity: IntegrityStatus,
+    pub migration_steps: Vec<String>,
+    pub warnings: Vec<String>,
+    pub entities: Vec<EntityDiff>,
+    pub settings_changed: bool,
+    pub safety_backup_path: Option<String>,
+}
+
+/// Backup fájl megnyitása és integritás ellenőrzése: (dokumentum, integritás)
+/// Sérült backupból nem állítunk vissza; régi, manifest nélküli backup figyelmeztetéssel elfogadott
+pub fn open_verified_backup(path: &Path, passphrase: Option<&str>) -> Result<(Value, IntegrityStatus), String> {
+    let raw = backup_archive::read_backup_document(path)?;
+    let (integrity, message, _) = backup_manifest::check_document(&raw);
+    if integrity == IntegrityStatus::Corrupted {
+        return Err(format!(
+            "A backup sérült, nem állítható vissza: {}",
+            message.unwrap_or_default()
+        ));
+    }
+    Ok((backup_format::open_backup(raw, passphrase)?, integrity))
+}
+
+/// A backup adat ellenőrzése a jelenlegi sémához: az entitás listáknak objektum tömböknek kell lenniük
+/// Visszatérés: figyelmeztetések (pl. azonosító nélküli rekordok)
+pub fn validate_backup_data(data: &Map<String, Value>) -> Result<Vec<String>, String> {
+    let mut warnings = Vec::new();
+
+    for key in ENTITY_KEYS {
+        let Some(value) = data.get(key) else {
+            continue;
+        };
+        let records = value
+            .as_array()
+            .ok_or_else(|| format!("Érvénytelen backup: a(z) '{}' nem lista", key))?;
+        for (index, record) in records.iter().enumerate() {
+            if !record.is_object() {
+                return Err(format!("Érvénytelen backup: {}[{}] nem objektum", key, index));
+            }
+            if data_store::record_key(key, record).is_none() {
+                warnings.push(format!("{}[{}]: hiányzó azonosító", key, index));
+            }
+        }
+    }
+
+    if data.get("settings").is_some_and(|s| !s.is_object()) {
+        return Err("Érvénytelen backup: a 'settings' nem objektum".to_string());
+    }
+    if !ENTITY_KEYS.iter().chain(["settings"].iter()).any(|key| data.contains_key(*key)) {
+        return Err("A backup nem tartalmaz visszaállítható adatot".to_string());
+    }
+
+    Ok(warnings)
+}
+
+/// Migrált backup adat, a Store formátumában
+pub struct PreparedBackup {
+    pub data: Map<String, Value>,
+    pub schema_version: u32,
+    pub migration_steps: Vec<String>,
+}
+
+/// Backup dokumentumból Store adat: metaadatok eltávolítása, séma verzió megállapítása és migráció
+/// A régi backupok nem tartalmaznak séma verziót; ilyenkor a manifest, végül a 0. verzió az alap
+pub fn prepare_backup_data(document: Value) -> Result<PreparedBackup, String> {
+    let Value::Object(mut data) = document else {
+        return Err("Érvénytelen backup: a gyökér nem JSON objektum".to_string());
+    };
+
+    let manifest_schema = data
+        .get(MANIFEST_KEY)
+        .and_then(|m| m.get("schemaVersion"))
+        .and_then(|v| v.as_u64());
+    for key in BACKUP_META_KEYS {
+        data.remove(key);
+    }
+    if !data.contains_key(migrations::SCHEMA_VERSION_KEY) {
+        data.insert(
+            migrations::SCHEMA_VERSION_KEY.to_string(),
+            Value::from(manifest_schema.unwrap_or(0)),
+        );
+    }
+
+    let schema_version = migrations::schema_version_of(&data);
+    let migration_steps = migrations::migrate_data(&mut data)?;
+    Ok(PreparedBackup {
+        data,
+        schema_version,
+        migration_steps,
+    })
+}
+
+fn keyed_records<'a>(entity_type: &str, records: &'a [Value]) -> HashMap<String, &'a Value> {
+    records
+        .iter()
+        .enumerate()
+        .map(|(index, record)| {
+            let key = data_store::record_key(entity_type, record).unwrap_or_else(|| format!("#{}", index));
+            (key, record)
+        })
+        .collect()
+}
+
+/// Rekord szintű különbség a jelenlegi és az új lista között
+pub fn diff_records(entity_type: &str, current: &[Value], incoming: &[Value]) -> EntityDiff {
+    let current_by_key = keyed_records(entity_type, current);
+    let incoming_by_key = keyed_records(entity_type, incoming);
+
+    let mut diff = EntityDiff {
+        entity_type: entity_type.to_string(),
+        added: Vec::new(),
+        changed: Vec::new(),
+        removed: Vec::new(),
+        unchanged: 0,
+    };
+    for (key, record) in &incoming_by_key {
+        match current_by_key.get(key) {
+            None => diff.added.push(key.clone()),
+            Some(existing) if existing != record => diff.changed.push(key.clone()),
+            Some(_) => diff.unchanged += 1,
+        }
+    }
+    diff.removed = current_by_key
+        .keys()
+        .filter(|key| !incoming_by_key.contains_key(*key))
+        .cloned()
+        .collect();
+
+    diff.added.sort();
+    diff.changed.sort();
+    diff.removed.sort();
+    diff
+}
+
+fn entity_list<'a>(data: &'a Map<String, Value>, key: &str) -> &'a [Value] {
+    data.get(key).and_then(|v| v.as_array()).map(|a| a.as_slice()).unwrap_or_default()
+}
+
+/// Visszaállítás előtti biztonsági mentés a data.json-ról (változatlan másolat; titkosított adattárolónál a titkosított fájlról)
+/// Hely: 3DPrinterCalcApp/backups/pre_restore/pre_restore_{időbélyeg}.json
+pub fn create_safety_backup() -> Result<Option<PathBuf>, String> {
+    let backup_dir = crate::paths::backups_dir()?.join("pre_restore");
+    let timestamp = chrono::Local::now().format("%Y-%m-%d_%H-%M-%S").to_string();
+    data_store::copy_stored_file(&backup_dir, &format!("pre_restore_{}", timestamp))
+        .map_err(|e| format!("Nem sikerült létrehozni a visszaállítás előtti mentést: {}", e))
+}
+
+/// Az új Store tartalom kiírása biztonsági mentés után, majd a keresési index és a frontend értesítése
+pub fn apply_store_data(app: &AppHandle, data: &Map<String, Value>, source: &str) -> Result<Option<PathBuf>, String> {
+    if crate::vault::is_locked() {
+        return Err("Az adattároló zárolva van, előbb oldja fel".to_string());
+    }
+
+    let safety_backup = create_safety_backup()?;
+    if let Some(path) = &safety_backup {
+        logger::log_info(&format!("Visszaállítás előtti mentés létrehozva: {}", path.display()));
+    }
+
+    commit_store_data(app, data, source)?;
+    Ok(safety_backup)
+}
+
+/// Az új Store tartalom kiírása biztonsági mentés nélkül (a szinkronizálás gyakori, rekord szintű változásaihoz,
+/// ezeket a változásnapló rögzíti), majd a keresési index és a frontend értesítése
+pub fn commit_store_data(app: &AppHandle, data: &Map<String, Value>, source: &str) -> Result<(), String> {
+    if crate::vault::is_locked() {
+        return Err("Az adattároló zárolva van, előbb oldja fel".to_string());
+    }
+
+    data_store::write_store(data)?;
+    if let Err(e) = crate::journal::record_store(data, source) {
+        logger::log_warn(&format!("Nem sikerült naplózni a Store változásait: {}", e));
+    }
+    if let Err(e) = search::rebuild_from_store() {
+        logger::log_warn(&format!("Keresési index újraépítése sikertelen: {}", e));
+    }
+    if let Err(e) = app.emit(DATA_STORE_CHANGED_EVENT, serde_json::json!({ "source": source })) {
+        logger::log_warn(&format!("Nem sikerült értesíteni a frontend-et a Store változásról: {}", e));
+    }
+
+    Ok(())
+}
+
+/// Teljes visszaállítás backupból
+/// - ellenőrzi a backup integritását és szerkezetét, a régi formátumokat a jelenlegi sémára migrálja
+/// - `dry_run` esetén csak az entitásonkénti előnézetet adja vissza (hozzáadott / módosított / törölt)
+/// - alkalmazás előtt biztonsági mentést készít a jelenlegi adatokról
+///
+/// A backupban nem szereplő kulcsok (pl. régi backupnál customers, projects) változatlanok maradnak
+#[tauri::command]
+pub fn restore_backup(
+    app: AppHandle,
+    file_path: String,
+    passphrase: Option<String>,
+    dry_run: bool,
+) -> Result<RestoreReport, String> {
+    let path = crate::paths::resolve_backup_file(&app, &file_path)?;
+    restore_file(&path, file_path, passphrase.as_deref(), dry_run, |merged| {
+        apply_store_data(&app, merged, "restore")
+    })
+}
+
+/// A teljes visszaállítás a már ellenőrzött útvonalú fájlból; az `apply` írja ki az új Store tartalmat
+fn restore_file(
+    path: &Path,
+    file_path: String,
+    passphrase: Option<&str>,
+    dry_run: bool,
+    apply: impl FnOnce(&Map<String, Value>) -> Result<Option<PathBuf>, String>,
+) -> Result<RestoreReport, String> {
+    let (document, integrity) = open_verified_backup(path, passphrase)?;
+    let backup_timestamp = document.get("timestamp").and_then(|v| v.as_str()).map(|v| v.to_string());
+
+    let PreparedBackup {
+        data: incoming,
+        schema_version: backup_schema_version,
+        migration_steps,
+    } = prepare_backup_data(document)?;
+    let mut warnings = validate_backup_data(&incoming)?;
+    if integrity == IntegrityStatus::Unverified {
+        warnings.insert(0, "Régi formátumú backup, az integritás nem ellenőrizhető".to_string());
+    }
+
+    let current = data_store::read_store()?.unwrap_or_default();
+    let entities = ENTITY_KEYS
+        .iter()
+        .filter(|key| incoming.contains_key(**key))
+        .map(|key| diff_records(key, entity_list(&current, key), entity_list(&incoming, key)))
+        .collect();
+    let settings_changed = incoming.get("settings").is_some_and(|s| current.get("settings") != Some(s));
+
+    let mut report = RestoreReport {
+        file_path,
+        dry_run,
+        applied: false,
+        backup_timestamp,
+        backup_schema_version,
+        integrity,
+        migration_steps,
+        warnings,
+        entities,
+        settings_changed,
+        safety_backup_path: None,
+    };
+    if dry_run {
+        return Ok(report);
+    }
+
+    let mut merged = current;
+    merged.extend(incoming);
+    let safety_backup = apply(&merged)?;
+
+    report.applied = true;
+    report.safety_backup_path = safety_backup.map(|p| p.to_string_lossy().to_string());
+    logger::log_info(&format!(
+        "Backup visszaállítva: {} (séma v{} → v{}, {} migrációs lépés)",
+        report.file_path,
+        report.backup_schema_version,
+        migrations::CURRENT_SCHEMA_VERSION,
+        report.migration_steps.len()
+    ));
+    Ok(report)
+}
+
+/// Egy backup rekord állapota a jelenlegi adatokhoz képest
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RecordState {
+    /// Nincs a jelenlegi adatok között (pl. azóta törölt rekord)
+    New,
+    /// Azonos azonosítóval létezik, de eltérő tartalommal
+    Changed,
+    /// Azonos a jelenlegi rekorddal
+    Unchanged,
+}
+
+/// Egy backup rekord rövid leírása a kiválasztáshoz
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupRecordSummary {
+    pub key: String,
+    pub label: String,
+    pub state: RecordState,
+}
+
+/// Egy entitás típus rekordjai a backupban
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupEntityContents {
+    pub entity_type: String,
+    pub records: Vec<BackupRecordSummary>,
+}
+
+/// A backup tartalma részleges visszaállításhoz
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupContents {
+    pub file_path: String,
+    pub backup_timestamp: Option<String>,
+    pub backup_schema_version: u32,
+    pub integrity: IntegrityStatus,
+    pub warnings: Vec<String>,
+    pub entities: Vec<BackupEntityContents>,
+}
+
+/// Kiválasztás: egy teljes entitás típus (`keys: None`) vagy annak megadott rekordjai
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RestoreSelection {
+    pub entity_type: String,
+    pub keys: Option<Vec<String>>,
+}
+
+/// Azonosító ütközés kezelése, ha a rekord a jelenlegi adatok között is létezik (eltérő tartalommal)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConflictMode {
+    /// A jelenlegi rekord marad
+    Skip,
+    /// A backup rekord felülírja a jelenlegit
+    Overwrite,
+    /// A backup rekord új azonosítóval, másolatként kerül vissza
+    Renumber,
+}
+
+/// Átszámozott rekord (régi → új azonosító)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RenumberedRecord {
+    pub from: i64,
+    pub to: i64,
+}
+
+/// Egy entitás típus részleges visszaállításának eredménye
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SelectiveRestoreResult {
+    pub entity_type: String,
+    pub added: Vec<String>,
+    pub overwritten: Vec<String>,
+    pub skipped: Vec<String>,
+    pub renumbered: Vec<RenumberedRecord>,
+}
+
+/// Részleges visszaállítás eredménye (dry-run esetén csak az előnézet)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SelectiveRestoreReport {
+    pub file_path: String,
+    pub dry_run: bool,
+    pub applied: bool,
+    pub conflict_mode: ConflictMode,
+    pub results: Vec<SelectiveRestoreResult>,
+    pub warnings: Vec<String>,
+    pub safety_backup_path: Option<String>,
+}
+
+/// Megnyitott, ellenőrzött és migrált backup
+struct OpenedBackup {
+    data: Map<String, Value>,
+    backup_timestamp: Option<String>,
+    schema_version: u32,
+    integrity: IntegrityStatus,
+    warnings: Vec<String>,
+}
+
+fn open_prepared_backup(path: &Path, passphrase: Option<&str>) -> Result<OpenedBackup, String> {
+    let (document, integrity) = open_verified_backup(path, passphrase)?;
+    let backup_timestamp = document.get("timestamp").and_then(|v| v.as_str()).map(|v| v.to_string());
+    let prepared = prepare_backup_data(document)?;
+    let mut warnings = validate_backup_data(&prepared.data)?;
+    if integrity == IntegrityStatus::Unverified {
+        warnings.insert(0, "Régi formátumú backup, az integritás nem ellenőrizhető".to_string());
+    }
+    Ok(OpenedBackup {
+        data: prepared.data,
+        backup_timestamp,
+        schema_version: prepared.schema_version,
+        integrity,
+        warnings,
+    })
+}
+
+/// Emberi olvasásra szánt megnevezés egy rekordhoz (a kiválasztó listában)
+fn record_label(entity_type: &str, record: &Value, key: &str) -> String {
+    let field = |name: &str| {
+        record
+            .get(name)
+            .and_then(|v| v.as_str())
+            .map(|v| v.trim())
+            .filter(|v| !v.is_empty())
+            .map(|v| v.to_string())
+    };
+
+    let label = match entity_type {
+        "filaments" => {
+            let parts: Vec<String> = ["brand", "type", "color"].iter().filter_map(|name| field(name)).collect();
+            (!parts.is_empty()).then(|| parts.join(" "))
+        }
+        "offers" => {
+            let customer = field("customerName").unwrap_or_else(|| "-".to_string());
+            let date = field("date").map(|d| d.chars().take(10).collect::<String>());
+            Some(match date {
+                Some(date) => format!("#{} {} ({})", key, customer, date),
+                None => format!("#{} {}", key, customer),
+            })
+        }
+        "tasks" => field("title"),
+        _ => field("name"),
+    };
+    label.unwrap_or_else(|| format!("#{}", key))
+}
+
+/// Backup megnyitása részleges visszaállításhoz: a kiválasztható entitások rekordjai,
+/// a jelenlegi adatokhoz viszonyított állapottal (új / módosult / változatlan)
+#[tauri::command]
+pub fn list_backup_contents(app: AppHandle, file_path: String, passphrase: Option<String>) -> Result<BackupContents, String> {
+    let path = crate::paths::resolve_backup_file(&app, &file_path)?;
+    let backup = open_prepared_backup(&path, passphrase.as_deref())?;
+    let current = data_store::read_store()?.unwrap_or_default();
+
+    let entities = SELECTABLE_ENTITY_TYPES
+        .iter()
+        .filter(|entity_type| backup.data.contains_key(**entity_type))
+        .map(|entity_type| {
+            let current_by_key = keyed_records(entity_type, entity_list(&current, entity_type));
+            let records = entity_list(&backup.data, entity_type)
+                .iter()
+                .filter_map(|record| {
+                    let key = data_store::record_key(entity_type, record)?;
+                    let state = match current_by_key.get(&key) {
+                        None => RecordState::New,
+                        Some(existing) if *existing != record => RecordState::Changed,
+                        Some(_) => RecordState::Unchanged,
+                    };
+                    Some(BackupRecordSummary {
+                        label: record_label(entity_type, record, &key),
+                        key,
+                        state,
+                    })
+                })
+                .collect();
+            BackupEntityContents {
+                entity_type: entity_type.to_string(),
+                records,
+            }
+        })
+        .collect();
+
+    Ok(BackupContents {
+        file_path,
+        backup_timestamp: backup.backup_timestamp,
+        backup_schema_version: backup.schema_version,
+        integrity: backup.integrity,
+        warnings: backup.warnings,
+        entities,
+    })
+}
+
+/// Visszaállítandó rekord: felülírandó index a jelenlegi listában (None = hozzáadás) és a rekord
+type PendingRecord = (Option<usize>, Value);
+
+fn max_numeric_id(records: &[Value]) -> i64 {
+    records
+        .iter()
+        .filter_map(|record| record.get("id").and_then(|id| id.as_i64()))
+        .max()
+        .unwrap_or(0)
+}
+
+/// Hivatkozások átírása az átszámozott rekordokra (csak a visszaállított rekordokban)
+fn remap_references(entity_type: &str, record: &mut Value, renumbered: &HashMap<String, HashMap<i64, i64>>) {
+    for (source, field, target) in RECORD_REFERENCES {
+        if source != entity_type {
+            continue;
+        }
+        let Some(mapping) = renumbered.get(target) else {
+            continue;
+        };
+        let Some(value) = record.get_mut(field) else {
+            continue;
+        };
+        let remap = |id: &mut Value| {
+            if let Some(new_id) = id.as_i64().and_then(|old| mapping.get(&old)) {
+                *id = Value::from(*new_id);
+            }
+        };
+        match value {
+            Value::Array(ids) => ids.iter_mut().for_each(remap),
+            id => remap(id),
+        }
+    }
+}
+
+/// Kiválasztott entitás típusok vagy rekordok visszaállítása backupból, a jelenlegi adatokba fésülve
+/// - a jelenlegi adatok között nem szereplő rekordok hozzáadódnak
+/// - azonos azonosítójú, eltérő rekordoknál a `conflict_mode` dönt (kihagyás / felülírás / átszámozás)
+/// - átszámozáskor a vele együtt visszaállított hivatkozó rekordok (pl. feladat → projekt) is az új azonosítót kapják
+/// - a kiválasztáson kívüli adatok és a beállítások változatlanok maradnak
+#[tauri::command]
+pub fn restore_backup_selection(
+    app: AppHandle,
+    file_path: String,
+    passphrase: Option<String>,
+    selection: Vec<RestoreSelection>,
+    conflict_mode: ConflictMode,
+    dry_run: bool,
+) -> Result<SelectiveRestoreReport, String> {
+    let path = crate::paths::resolve_backup_file(&app, &file_path)?;
+    restore_selection_from_file(
+        &path,
+        file_path,
+        passphrase.as_deref(),
+        &selection,
+        conflict_mode,
+        dry_run,
+        |merged| apply_store_data(&app, merged, "selective-restore"),
+    )
+}
+
+/// A részleges visszaállítás a már ellenőrzött útvonalú fájlból; az `apply` írja ki az új Store tartalmat
+fn restore_selection_from_file(
+    path: &Path,
+    file_path: String,
+    passphrase: Option<&str>,
+    selection: &[RestoreSelection],
+    conflict_mode: ConflictMode,
+    dry_run: bool,
+    apply: impl FnOnce(&Map<String, Value>) -> Result<Option<PathBuf>, String>,
+) -> Result<SelectiveRestoreReport, String> {
+    if selection.is_empty() {
+        return Err("Nincs kiválasztva visszaállítandó adat".to_string());
+    }
+    for item in selection {
+        if !SELECTABLE_ENTITY_TYPES.contains(&item.entity_type.as_str()) {
+            return Err(format!("Nem visszaállítható entitás típus: {}", item.entity_type));
+        }
+    }
+
+    let backup = open_prepared_backup(path, passphrase)?;
+    let mut warnings = backup.warnings;
+    let mut merged = data_store::read_store()?.unwrap_or_default();
+
+    // A kiválasztott rekordok a backupból, a jelenlegi adatokkal összevetve
+    let mut results = Vec::new();
+    let mut pending: Vec<(String, Vec<PendingRecord>)> = Vec::new();
+    let mut renumbered_ids: HashMap<String, HashMap<i64, i64>> = HashMap::new();
+
+    for entity_type in SELECTABLE_ENTITY_TYPES {
+        let Some(item) = selection.iter().find(|item| item.entity_type == entity_type) else {
+            continue;
+        };
+        let incoming = entity_list(&backup.data, entity_type);
+        let current = entity_list(&merged, entity_type);
+
+        let chosen: Vec<(String, &Value)> = match &item.keys {
+            None => incoming
+                .iter()
+                .filter_map(|record| Some((data_store::record_key(entity_type, record)?, record)))
+                .collect(),
+            Some(keys) => {
+                let incoming_by_key = keyed_records(entity_type, incoming);
+                keys.iter()
+                    .map(|key| {
+                        incoming_by_key
+                            .get(key)
+                            .map(|record| (key.clone(), *record))
+                            .ok_or_else(|| format!("A rekord nem található a backupban: {} {}", entity_type, key))
+                    })
+                    .collect::<Result<_, _>>()?
+            }
+        };
+
+        let current_index: HashMap<String, usize> = current
+            .iter()
+            .enumerate()
+            .filter_map(|(index, record)| Some((data_store::record_key(entity_type, record)?, index)))
+            .collect();
+        let mut next_id = max_numeric_id(current).max(max_numeric_id(incoming));
+
+        let mut result = SelectiveRestoreResult {
+            entity_type: entity_type.to_string(),
+            added: Vec::new(),
+            overwritten: Vec::new(),
+            skipped: Vec::new(),
+            renumbered: Vec::new(),
+        };
+        let mut actions = Vec::new();
+        for (key, record) in chosen {
+            let Some(&index) = current_index.get(&key) else {
+                result.added.push(key);
+                actions.push((None, record.clone()));
+                continue;
+            };
+            if &current[index] == record {
+                result.skipped.push(key);
+                continue;
+            }
+            match conflict_mode {
+                ConflictMode::Skip => result.skipped.push(key),
+                ConflictMode::Overwrite => {
+                    result.overwritten.push(key);
+                    actions.push((Some(index), record.clone()));
+                }
+                ConflictMode::Renumber => {
+                    let Some(old_id) = record.get("id").and_then(|id| id.as_i64()) else {
+                        warnings.push(format!(
+                            "{} {}: nincs numerikus azonosító, nem számozható át (kihagyva)",
+                            entity_type, key
+                        ));
+                        result.skipped.push(key);
+                        continue;
+                    };
+                    next_id += 1;
+                    let mut copy = record.clone();
+                    copy["id"] = Value::from(next_id);
+                    renumbered_ids
+                        .entry(entity_type.to_string())
+                        .or_default()
+                        .insert(old_id, next_id);
+                    result.renumbered.push(RenumberedRecord { from: old_id, to: next_id });
+                    actions.push((None, copy));
+                }
+            }
+        }
+        results.push(result);
+        pending.push((entity_type.to_string(), actions));
+    }
+
+    let mut report = SelectiveRestoreReport {
+        file_path,
+        dry_run,
+        applied: false,
+        conflict_mode,
+        results,
+        warnings,
+        safety_backup_path: None,
+    };
+    let changes: usize = pending.iter().map(|(_, actions)| actions.len()).sum();
+    if dry_run || changes == 0 {
+        return Ok(report);
+    }
+
+    for (entity_type, actions) in pending {
+        let list = merged
+            .entry(entity_type.clone())
+            .or_insert_with(|| Value::Array(Vec::new()));
+        let Value::Array(records) = list else {
+            return Err(format!("Érvénytelen jelenlegi adat: a(z) '{}' nem lista", entity_type));
+        };
+        for (index, mut record) in actions {
+            remap_references(&entity_type, &mut record, &renumbered_ids);
+            match index {
+                Some(index) => records[index] = record,
+                None => records.push(record),
+            }
+        }
+    }
+
+    let safety_backup = apply(&merged)?;
+    report.applied = true;
+    report.safety_backup_path = safety_backup.map(|p| p.to_string_lossy().to_string());
+    logger::log_info(&format!(
+        "Részleges visszaállítás: {} ({} rekord, ütközéskezelés: {:?})",
+        report.file_path, changes, conflict_mode
+    ));
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::fs;
+
+    fn object(value: Value) -> Map<String, Value> {
+        value.as_object().cloned().unwrap()
+    }
+
+    /// Jelenlegi Store és egy régi formátumú (manifest és séma verzió nélküli) backup fájl a teszt adatkönyvtárban
+    fn setup(name: &str, current: Value, backup: Value) -> (PathBuf, PathBuf) {
+        let root = crate::paths::test_support::data_root();
+        let store = data_store::store_file_path().unwrap();
+        fs::write(&store, serde_json::to_string_pretty(&current).unwrap()).unwrap();
+        let dir = root.join("restore-tests");
+        fs::create_dir_all(&dir).unwrap();
+        let backup_path = dir.join(format!("{}.json", name));
+        let mut backup = object(backup);
+        backup.insert("version".to_string(), json!("1.9.0"));
+        backup.insert("timestamp".to_string(), json!("2025-06-01T10:00:00Z"));
+        fs::write(&backup_path, serde_json::to_string(&backup).unwrap()).unwrap();
+        (store, backup_path)
+    }
+
+    fn select_all(entity_types: &[&str]) -> Vec<RestoreSelection> {
+        entity_types
+            .iter()
+            .map(|entity_type| RestoreSelection { entity_type: entity_type.to_string(), keys: None })
+            .collect()
+    }
+
+    #[test]
+    fn renumbering_remaps_references_of_restored_records() {
+        let _guard = crate::paths::test_support::lock();
+        let (store, backup) = setup(
+            "renumber",
+            json!({ "offers": [{ "id": 1, "customerName": "Jelenlegi" }] }),
+            json!({
+                "offers": [{ "id": 1, "customerName": "Backup" }],
+                "projects": [{ "id": 10, "name": "Projekt", "offerIds": [1] }],
+                "tasks": [{ "id": 20, "title": "Nyomtatás", "relatedOfferId": 1, "relatedProjectId": 10 }]
+            }),
+        );
+
+        let mut written = None;
+        let report = restore_selection_from_file(
+            &backup,
+            backup.to_string_lossy().to_string(),
+            None,
+            &select_all(&["offers", "projects", "tasks"]),
+            ConflictMode::Renumber,
+            false,
+            |merged| {
+                written = Some(merged.clone());
+                Ok(None)
+            },
+        )
+        .unwrap();
+        assert!(report.applied);
+        let offers = report.results.iter().find(|r| r.entity_type == "offers").unwrap();
+        assert_eq!((offers.renumbered[0].from, offers.renumbered[0].to), (1, 2));
+
+        let merged = written.expect("az új Store tartalom kiírása");
+        assert_eq!(merged["offers"][0]["customerName"], json!("Jelenlegi"));
+        assert_eq!(merged["offers"][1]["id"], json!(2));
+        assert_eq!(merged["offers"][1]["customerName"], json!("Backup"));
+        assert_eq!(merged["projects"][0]["offerIds"], json!([2]));
+        assert_eq!(merged["tasks"][0]["relatedOfferId"], json!(2));
+        assert_eq!(merged["tasks"][0]["relatedProjectId"], json!(10));
+
+        fs::remove_file(&store).unwrap();
+    }
+
+    #[test]
+    fn dry_run_writes_nothing() {
+        let _guard = crate::paths::test_support::lock();
+        let (store, backup) = setup(
+            "dry-run",
+            json!({ "offers": [{ "id": 1, "customerName": "Jelenlegi" }] }),
+            json!({ "offers": [{ "id": 1, "customerName": "Backup" }, { "id": 2, "customerName": "Új" }] }),
+        );
+        let before = fs::read(&store).unwrap();
+        let file_path = backup.to_string_lossy().to_string();
+
+        let report = restore_file(&backup, file_path.clone(), None, true, |_| panic!("dry-run nem írhat")).unwrap();
+        assert!(!report.applied);
+        let offers = report.entities.iter().find(|e| e.entity_type == "offers").unwrap();
+        assert_eq!((offers.added.len(), offers.changed.len()), (1, 1));
+
+        let report = restore_selection_from_file(
+            &backup,
+            file_path,
+            None,
+            &select_all(&["offers"]),
+            ConflictMode::Overwrite,
+            true,
+            |_| panic!("dry-run nem írhat"),
+        )
+        .unwrap();
+        assert!(!report.applied);
+        assert_eq!(report.results[0].overwritten, vec!["1".to_string()]);
+        assert_eq!(fs::read(&store).unwrap(), before);
+
+        fs::remove_file(&store).unwrap();
+    }
+
+    #[test]
+    fn keys_missing_from_an_old_backup_stay_unchanged() {
+        let _guard = crate::paths::test_support::lock();
+        let current = json!({
+            "offers": [{ "id": 1, "customerName": "Jelenlegi" }],
+            "customers": [{ "id": 7, "name": "Kovács Éva" }],
+            "projects": [{ "id": 10, "name": "Projekt", "offerIds": [1] }],
+            "settings": { "language": "hu" }
+        });
+        let (store, backup) = setup("old", current.clone(), json!({ "offers": [{ "id": 3, "customerName": "Régi" }] }));
+
+        let mut written = None;
+        let report = restore_file(&backup, backup.to_string_lossy().to_string(), None, false, |merged| {
+            written = Some(merged.clone());
+            Ok(None)
+        })
+        .unwrap();
+        assert_eq!(report.integrity, IntegrityStatus::Unverified);
+        assert!(!report.settings_changed);
+        assert!(report.entities.iter().all(|e| e.entity_type == "offers"));
+
+        let merged = written.expect("az új Store tartalom kiírása");
+        // A régi backup rekordjai a jelenlegi sémára migrálva kerülnek vissza
+        assert_eq!(merged["offers"][0]["customerName"], json!("Régi"));
+        assert_eq!(merged["offers"].as_array().unwrap().len(), 1);
+        for key in ["customers", "projects", "settings"] {
+            assert_eq!(merged[key], current[key], "változatlan kulcs: {}", key);
+        }
+
+        fs::remove_file(&store).unwrap();
+    }
+}
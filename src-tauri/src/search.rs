@@ -0,0 +1,603 @@
+use crate::data_store;
+use crate::logger;
+use lazy_static::lazy_static;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+use std::sync::Mutex;
+use unicode_normalization::UnicodeNormalization;
+
+lazy_static! {
+    static ref SEARCH_INDEX: Mutex<SearchIndex> = Mutex::new(SearchIndex::default());
+}
+
+/// Az indexelt entitás típusok (a Store kulcsaival megegyezően)
+pub const INDEXED_ENTITY_TYPES: [&str; 4] = ["offers", "customers", "filaments", "projects"];
+
+/// Alapértelmezett találati limit
+const DEFAULT_LIMIT: usize = 50;
+
+/// Pontszám szorzók egyezés típusa szerint
+const EXACT_MATCH_SCORE: f64 = 3.0;
+const PREFIX_MATCH_SCORE: f64 = 2.0;
+const FUZZY_MATCH_SCORE: f64 = 1.0;
+
+/// Dokumentum azonosító: (entitás típus, azonosító szövegként)
+type DocKey = (String, String);
+
+/// Egy keresési találat a frontend számára
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchHit {
+    pub entity_type: String,
+    /// Az entitás azonosítója (filamenteknél a lista index, mert nincs saját ID-juk)
+    pub id: Value,
+    pub title: String,
+    pub subtitle: Option<String>,
+    pub score: f64,
+    pub matched_fields: Vec<String>,
+}
+
+/// Egy indexelt mező tokenjei és súlya
+struct IndexedField {
+    name: &'static str,
+    weight: f64,
+    tokens: Vec<String>,
+}
+
+/// Egy indexelt dokumentum (egy rekord)
+struct IndexedDocument {
+    id: Value,
+    title: String,
+    subtitle: Option<String>,
+    fields: Vec<IndexedField>,
+    content_hash: u64,
+}
+
+/// Invertált index: token → dokumentumok
+/// A tokenek BTreeMap-ben vannak, így a prefix keresés egy tartomány bejárás
+/// Az elgépelés tűrő kereséshez a tokeneket hossz szerint is nyilvántartjuk,
+/// így csak a legfeljebb a tűréshatárnyival rövidebb / hosszabb tokeneket kell összevetni
+#[derive(Default)]
+struct SearchIndex {
+    documents: HashMap<DocKey, IndexedDocument>,
+    postings: BTreeMap<String, HashSet<DocKey>>,
+    tokens_by_length: BTreeMap<usize, HashSet<String>>,
+}
+
+impl SearchIndex {
+    /// Egy entitás típus összes rekordjának frissítése
+    /// Csak a ténylegesen megváltozott rekordok indexelődnek újra (tartalom hash alapján)
+    /// Visszaadja az újraindexelt + törölt dokumentumok számát
+    fn update_entities(&mut self, entity_type: &str, records: &[Value]) -> usize {
+        let mut changed = 0;
+        let mut seen: HashSet<DocKey> = HashSet::new();
+
+        for (index, record) in records.iter().enumerate() {
+            let Some(document) = build_document(entity_type, index, record) else {
+                continue;
+            };
+            let key = (entity_type.to_string(), id_to_string(&document.id));
+            seen.insert(key.clone());
+
+            let unchanged = self
+                .documents
+                .get(&key)
+                .is_some_and(|existing| existing.content_hash == document.content_hash);
+            if unchanged {
+                continue;
+            }
+
+            self.remove_document(&key);
+            self.insert_document(key, document);
+            changed += 1;
+        }
+
+        let removed: Vec<DocKey> = self
+            .documents
+            .keys()
+            .filter(|key| key.0 == entity_type && !seen.contains(*key))
+            .cloned()
+            .collect();
+        for key in removed {
+            self.remove_document(&key);
+            changed += 1;
+        }
+
+        changed
+    }
+
+    fn insert_document(&mut self, key: DocKey, document: IndexedDocument) {
+        for field in &document.fields {
+            for token in &field.tokens {
+                if !self.postings.contains_key(token) {
+                    self.tokens_by_length
+                        .entry(token.chars().count())
+                        .or_default()
+                        .insert(token.clone());
+                }
+                self.postings.entry(token.clone()).or_default().insert(key.clone());
+            }
+        }
+        self.documents.insert(key, document);
+    }
+
+    fn remove_document(&mut self, key: &DocKey) {
+        let Some(document) = self.documents.remove(key) else {
+            return;
+        };
+        for field in &document.fields {
+            for token in &field.tokens {
+                if let Some(keys) = self.postings.get_mut(token) {
+                    keys.remove(key);
+                    if keys.is_empty() {
+                        self.postings.remove(token);
+                        self.remove_token_length(token);
+                    }
+                }
+            }
+        }
+    }
+
+    fn remove_token_length(&mut self, token: &str) {
+        let length = token.chars().count();
+        if let Some(tokens) = self.tokens_by_length.get_mut(&length) {
+            tokens.remove(token);
+            if tokens.is_empty() {
+                self.tokens_by_length.remove(&length);
+            }
+        }
+    }
+
+    fn clear(&mut self) {
+        self.documents.clear();
+        self.postings.clear();
+        self.tokens_by_length.clear();
+    }
+
+    /// Keresés: minden lekérdezés tokennek illeszkednie kell (ÉS kapcsolat)
+    /// Egyezés típusai: pontos token, prefix, illetve elgépelés tűrő (Levenshtein távolság)
+    fn search(&self, query: &str, limit: usize, entity_types: Option<&[String]>) -> Vec<SearchHit> {
+        let query_tokens = tokenize(query);
+        if query_tokens.is_empty() {
+            return Vec::new();
+        }
+
+        // Dokumentumonkénti pontszám és az illeszkedő lekérdezés tokenek száma
+        let mut scores: HashMap<&DocKey, (f64, usize, HashSet<&'static str>)> = HashMap::new();
+
+        for query_token in &query_tokens {
+            // Token → a legjobb egyezés szorzója ennél a lekérdezés tokennél
+            let mut candidates: HashMap<&String, f64> = HashMap::new();
+
+            for (token, _) in self.postings.range(query_token.clone()..) {
+                if !token.starts_with(query_token.as_str()) {
+                    break;
+                }
+                let score = if token == query_token {
+                    EXACT_MATCH_SCORE
+                } else {
+                    PREFIX_MATCH_SCORE
+                };
+                candidates.insert(token, score);
+            }
+
+            if let Some(max_distance) = fuzzy_tolerance(query_token) {
+                let query_length = query_token.chars().count();
+                let lengths = query_length.saturating_sub(max_distance)..=query_length + max_distance;
+                for token in self.tokens_by_length.range(lengths).flat_map(|(_, tokens)| tokens) {
+                    if candidates.contains_key(token) {
+                        continue;
+                    }
+                    if let Some(distance) = levenshtein_within(query_token, token, max_distance) {
+                        candidates.insert(token, FUZZY_MATCH_SCORE / distance as f64);
+                    }
+                }
+            }
+
+            // Dokumentumonként a legjobb egyezést számoljuk (mező súllyal)
+            let mut best_per_doc: HashMap<&DocKey, (f64, &'static str)> = HashMap::new();
+            for (token, match_score) in candidates {
+                let Some(keys) = self.postings.get(token) else {
+                    continue;
+                };
+                for key in keys {
+                    let Some(document) = self.documents.get(key) else {
+                        continue;
+                    };
+                    for field in document.fields.iter().filter(|f| f.tokens.contains(token)) {
+                        let score = match_score * field.weight;
+                        let best = best_per_doc.entry(key).or_insert((0.0, field.name));
+                        if score > best.0 {
+                            *best = (score, field.name);
+                        }
+                    }
+                }
+            }
+
+            for (key, (score, field_name)) in best_per_doc {
+                let entry = scores.entry(key).or_insert((0.0, 0, HashSet::new()));
+                entry.0 += score;
+                entry.1 += 1;
+                entry.2.insert(field_name);
+            }
+        }
+
+        let mut hits: Vec<SearchHit> = scores
+            .into_iter()
+            .filter(|(_, (_, matched, _))| *matched == query_tokens.len())
+            .filter(|(key, _)| entity_types.map_or(true, |types| types.iter().any(|t| t == &key.0)))
+            .filter_map(|(key, (score, _, fields))| {
+                let document = self.documents.get(key)?;
+                let mut matched_fields: Vec<String> = fields.into_iter().map(|f| f.to_string()).collect();
+                matched_fields.sort();
+                Some(SearchHit {
+                    entity_type: key.0.clone(),
+                    id: document.id.clone(),
+                    title: document.title.clone(),
+                    subtitle: document.subtitle.clone(),
+                    score,
+                    matched_fields,
+                })
+            })
+            .collect();
+
+        hits.sort_by(|a, b| {
+            b.score
+                .partial_cmp(&a.score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.title.cmp(&b.title))
+        });
+        hits.truncate(limit);
+        hits
+    }
+}
+
+/// Ékezetek eltávolítása és kisbetűsítés (pl. "Őrség Kft." → "orseg kft.")
+/// NFD felbontás után elhagyjuk a kombináló jeleket; a fel nem bontható betűket kézzel képezzük le
+pub fn fold_diacritics(text: &str) -> String {
+    let mut folded = String::with_capacity(text.len());
+    for c in text.nfd() {
+        if is_combining_mark(c) {
+            continue;
+        }
+        match c {
+            'ß' => folded.push_str("ss"),
+            'ł' | 'Ł' => folded.push('l'),
+            'đ' | 'Đ' => folded.push('d'),
+            'ø' | 'Ø' => folded.push('o'),
+            'æ' | 'Æ' => folded.push_str("ae"),
+            'œ' | 'Œ' => folded.push_str("oe"),
+            _ => folded.extend(c.to_lowercase()),
+        }
+    }
+    folded
+}
+
+fn is_combining_mark(c: char) -> bool {
+    matches!(c as u32, 0x0300..=0x036F | 0x1AB0..=0x1AFF | 0x1DC0..=0x1DFF | 0x20D0..=0x20FF | 0xFE20..=0xFE2F)
+}
+
+/// Szöveg tokenekre bontása: ékezet mentesítés, majd szétválasztás nem alfanumerikus karaktereknél
+/// A CJK írásjeleket karakterenként indexeljük, mert ott nincs szóköz a szavak között
+pub fn tokenize(text: &str) -> Vec<String> {
+    let folded = fold_diacritics(text);
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+
+    for c in folded.chars() {
+        if is_cjk(c) {
+            if !current.is_empty() {
+                tokens.push(std::mem::take(&mut current));
+            }
+            tokens.push(c.to_string());
+        } else if c.is_alphanumeric() {
+            current.push(c);
+        } else if !current.is_empty() {
+            tokens.push(std::mem::take(&mut current));
+        }
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+
+    tokens
+}
+
+fn is_cjk(c: char) -> bool {
+    matches!(c as u32, 0x3040..=0x30FF | 0x3400..=0x4DBF | 0x4E00..=0x9FFF | 0xAC00..=0xD7AF | 0xF900..=0xFAFF)
+}
+
+/// Elgépelés tűrés a lekérdezés token hossza alapján (rövid tokeneknél nincs)
+fn fuzzy_tolerance(token: &str) -> Option<usize> {
+    match token.chars().count() {
+        0..=3 => None,
+        4..=7 => Some(1),
+        _ => Some(2),
+    }
+}
+
+/// Levenshtein távolság (karakter alapon, Unicode biztos), ha legfeljebb `max_distance`, különben None
+/// Csak a főátló körüli `max_distance` széles sávot számoljuk, és kilépünk, amint egy sor minden
+/// értéke túllépi a határt (a sorok minimuma nem csökkenhet)
+fn levenshtein_within(a: &str, b: &str, max_distance: usize) -> Option<usize> {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    if a.len().abs_diff(b.len()) > max_distance {
+        return None;
+    }
+
+    // A sávon kívüli cellák "végtelenek" (a határnál eggyel nagyobbak)
+    let over = max_distance + 1;
+    let mut previous: Vec<usize> = (0..=b.len()).map(|j| j.min(over)).collect();
+    let mut current = vec![over; b.len() + 1];
+
+    for (i, ca) in a.iter().enumerate() {
+        let from = (i + 1).saturating_sub(max_distance).max(1);
+        let to = (i + 1 + max_distance).min(b.len());
+        current.fill(over);
+        current[0] = (i + 1).min(over);
+        let mut row_min = current[0];
+
+        for j in from..=to {
+            let cost = usize::from(*ca != b[j - 1]);
+            let value = (previous[j] + 1).min(current[j - 1] + 1).min(previous[j - 1] + cost).min(over);
+            current[j] = value;
+            row_min = row_min.min(value);
+        }
+        if row_min > max_distance {
+            return None;
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    Some(previous[b.len()]).filter(|distance| *distance <= max_distance)
+}
+
+fn id_to_string(id: &Value) -> String {
+    match id {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn text_field(record: &Value, name: &str) -> Option<String> {
+    match record.get(name)? {
+        Value::String(s) if !s.trim().is_empty() => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+/// Rekordból index dokumentum készítése entitás típus szerint
+/// (mezőnév a rekordban, mezőnév a találatban, súly)
+fn build_document(entity_type: &str, index: usize, record: &Value) -> Option<IndexedDocument> {
+    let mut hasher = DefaultHasher::new();
+    record.to_string().hash(&mut hasher);
+    let content_hash = hasher.finish();
+
+    let field = |name: &'static str, weight: f64, text: Option<String>| IndexedField {
+        name,
+        weight,
+        tokens: text.map(|t| tokenize(&t)).unwrap_or_default(),
+    };
+
+    let (id, title, subtitle, fields) = match entity_type {
+        "offers" => {
+            let id = record.get("id").cloned()?;
+            let filament_text = record.get("filaments").and_then(|f| f.as_array()).map(|filaments| {
+                filaments
+                    .iter()
+                    .flat_map(|f| ["brand", "type", "color"].map(|k| text_field(f, k).unwrap_or_default()))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            });
+            let title = text_field(record, "customerName")
+                .unwrap_or_else(|| format!("#{}", id_to_string(&id)));
+            let subtitle = text_field(record, "description").or_else(|| text_field(record, "date"));
+            let fields = vec![
+                field("id", 3.0, Some(id_to_string(&id))),
+                field("customerName", 3.0, text_field(record, "customerName")),
+                field("customerContact", 2.0, text_field(record, "customerContact")),
+                field("description", 1.0, text_field(record, "description")),
+                field("printerName", 1.0, text_field(record, "printerName")),
+                field("filaments", 1.0, filament_text),
+                field("status", 1.0, text_field(record, "status")),
+            ];
+            (id, title, subtitle, fields)
+        }
+        "customers" => {
+            let id = record.get("id").cloned()?;
+            let title = text_field(record, "name")?;
+            let subtitle = text_field(record, "company").or_else(|| text_field(record, "contact"));
+            let fields = vec![
+                field("name", 3.0, text_field(record, "name")),
+                field("company", 2.0, text_field(record, "company")),
+                field("contact", 2.0, text_field(record, "contact")),
+                field("address", 1.0, text_field(record, "address")),
+                field("notes", 1.0, text_field(record, "notes")),
+            ];
+            (id, title, subtitle, fields)
+        }
+        "filaments" => {
+            let brand = text_field(record, "brand").unwrap_or_default();
+            let filament_type = text_field(record, "type").unwrap_or_default();
+            let title = format!("{} {}", brand, filament_type).trim().to_string();
+            let fields = vec![
+                field("brand", 3.0, text_field(record, "brand")),
+                field("type", 3.0, text_field(record, "type")),
+                field("color", 2.0, text_field(record, "color")),
+                field("multiColorHint", 1.0, text_field(record, "multiColorHint")),
+            ];
+            (Value::from(index), title, text_field(record, "color"), fields)
+        }
+        "projects" => {
+            let id = record.get("id").cloned()?;
+            let title = text_field(record, "name")?;
+            let tags = record.get("tags").and_then(|t| t.as_array()).map(|tags| {
+                tags.iter().filter_map(|t| t.as_str()).collect::<Vec<_>>().join(" ")
+            });
+            let fields = vec![
+                field("name", 3.0, text_field(record, "name")),
+                field("tags", 2.0, tags),
+                field("description", 1.0, text_field(record, "description")),
+                field("assignee", 1.0, text_field(record, "assignee")),
+            ];
+            (id, title, text_field(record, "description"), fields)
+        }
+        _ => return None,
+    };
+
+    Some(IndexedDocument {
+        id,
+        title,
+        subtitle,
+        fields,
+        content_hash,
+    })
+}
+
+/// Teljes index újraépítése a data.json tartalmából
+/// Indításkor és a Store visszaállítása után hívjuk
+pub fn rebuild_from_store() -> Result<usize, String> {
+    let data = data_store::read_store()?.unwrap_or_default();
+    let mut index = SEARCH_INDEX.lock().map_err(|e| format!("Keresési index zárolási hiba: {}", e))?;
+    index.clear();
+
+    for entity_type in INDEXED_ENTITY_TYPES {
+        let records = data
+            .get(entity_type)
+            .and_then(|v| v.as_array())
+            .cloned()
+            .unwrap_or_default();
+        index.update_entities(entity_type, &records);
+    }
+
+    Ok(index.documents.len())
+}
+
+/// Keresés az offers, customers, filaments és projects entitások között
+/// Visszaadja a találatokat relevancia szerint rendezve
+#[tauri::command]
+pub fn search(
+    query: String,
+    limit: Option<usize>,
+    entity_types: Option<Vec<String>>,
+) -> Result<Vec<SearchHit>, String> {
+    let index = SEARCH_INDEX.lock().map_err(|e| format!("Keresési index zárolási hiba: {}", e))?;
+    Ok(index.search(&query, limit.unwrap_or(DEFAULT_LIMIT), entity_types.as_deref()))
+}
+
+/// Keresési index inkrementális frissítése egy entitás típus mentése után
+/// A frontend a Store mentése után hívja a teljes listával; csak a változott rekordok indexelődnek újra
+#[tauri::command]
+pub fn update_search_index(entity_type: String, records: Vec<Value>) -> Result<usize, String> {
+    if !INDEXED_ENTITY_TYPES.contains(&entity_type.as_str()) {
+        return Ok(0);
+    }
+
+    let mut index = SEARCH_INDEX.lock().map_err(|e| format!("Keresési index zárolási hiba: {}", e))?;
+    Ok(index.update_entities(&entity_type, &records))
+}
+
+/// Keresési index teljes újraépítése (pl. Factory Reset vagy import után)
+#[tauri::command]
+pub fn rebuild_search_index() -> Result<usize, String> {
+    let count = rebuild_from_store()?;
+    logger::log_info(&format!("Keresési index újraépítve: {} dokumentum", count));
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn fold_diacritics_strips_accents_and_lowercases() {
+        assert_eq!(fold_diacritics("Őrség Kft."), "orseg kft.");
+        assert_eq!(fold_diacritics("ÁRVÍZTŰRŐ tükörfúrógép"), "arvizturo tukorfurogep");
+        assert_eq!(fold_diacritics("Straße Łódź Øresund Æble Œuvre Đakovo"), "strasse lodz oresund aeble oeuvre dakovo");
+        assert_eq!(fold_diacritics("Crème Brûlée"), "creme brulee");
+        assert_eq!(fold_diacritics("打印机"), "打印机");
+    }
+
+    #[test]
+    fn tokenize_splits_on_non_alphanumerics() {
+        assert_eq!(tokenize("PLA+ / Bambu-Lab, 1.75mm"), vec!["pla", "bambu", "lab", "1", "75mm"]);
+        assert_eq!(tokenize("  Kovács   Éva  "), vec!["kovacs", "eva"]);
+        assert!(tokenize(" - / ").is_empty());
+    }
+
+    #[test]
+    fn tokenize_indexes_cjk_per_character() {
+        assert_eq!(tokenize("PLA打印机"), vec!["pla", "打", "印", "机"]);
+        assert_eq!(tokenize("東京 Tokyo"), vec!["東", "京", "tokyo"]);
+    }
+
+    #[test]
+    fn levenshtein_within_respects_cutoff() {
+        assert_eq!(levenshtein_within("kovacs", "kovacs", 1), Some(0));
+        assert_eq!(levenshtein_within("kovacs", "kovats", 1), Some(1));
+        assert_eq!(levenshtein_within("kovacs", "kovac", 1), Some(1));
+        assert_eq!(levenshtein_within("kovacs", "kovacsne", 1), None);
+        assert_eq!(levenshtein_within("kovacs", "kovacsne", 2), Some(2));
+        assert_eq!(levenshtein_within("filament", "filamnet", 2), Some(2));
+        assert_eq!(levenshtein_within("filament", "pigment", 2), None);
+        assert_eq!(levenshtein_within("árvíz", "arviz", 2), Some(2));
+        assert_eq!(levenshtein_within("", "ab", 2), Some(2));
+    }
+
+    #[test]
+    fn fuzzy_tolerance_depends_on_token_length() {
+        assert_eq!(fuzzy_tolerance("pla"), None);
+        assert_eq!(fuzzy_tolerance("petg"), Some(1));
+        assert_eq!(fuzzy_tolerance("filament"), Some(2));
+    }
+
+    fn sample_index() -> SearchIndex {
+        let mut index = SearchIndex::default();
+        index.update_entities(
+            "customers",
+            &[
+                json!({ "id": 1, "name": "Kovács Éva", "company": "Őrség Kft." }),
+                json!({ "id": 2, "name": "Nagy Péter", "company": "Printfarm Bt." }),
+            ],
+        );
+        index
+    }
+
+    #[test]
+    fn search_matches_exact_prefix_and_fuzzy_tokens() {
+        let index = sample_index();
+
+        let exact = index.search("kovacs", 10, None);
+        assert_eq!(exact.len(), 1);
+        assert_eq!(exact[0].id, json!(1));
+
+        let prefix = index.search("print", 10, None);
+        assert_eq!(prefix.len(), 1);
+        assert_eq!(prefix[0].id, json!(2));
+        assert!(prefix[0].score < exact[0].score);
+
+        // Elgépelés és ékezet nélküli lekérdezés
+        let fuzzy = index.search("kovats orseg", 10, None);
+        assert_eq!(fuzzy.len(), 1);
+        assert_eq!(fuzzy[0].id, json!(1));
+
+        assert!(index.search("kovats nagy", 10, None).is_empty());
+        assert!(index.search("kovacs", 10, Some(&["offers".to_string()])).is_empty());
+    }
+
+    #[test]
+    fn removed_tokens_leave_the_fuzzy_index() {
+        let mut index = sample_index();
+        index.update_entities("customers", &[json!({ "id": 2, "name": "Nagy Péter" })]);
+
+        assert!(index.search("kovats", 10, None).is_empty());
+        assert!(!index.tokens_by_length.values().any(|tokens| tokens.contains("kovacs")));
+        assert_eq!(index.search("peter", 10, None).len(), 1);
+    }
+}
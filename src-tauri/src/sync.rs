@@ -0,0 +1,1379 @@
+use crate::data_store;
+use crate::journal::{self, JOURNALED_ENTITY_TYPES};
+use crate::logger;
+use crate::webdav::{WebDavClient, WebDavSettings};
+use lazy_static::lazy_static;
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
+use tauri::AppHandle;
+
+lazy_static! {
+    /// Egyszerre csak egy szinkronizálás futhat (kézi és ütemezett)
+    static ref SYNC_LOCK: Mutex<()> = Mutex::new(());
+    static ref SYNC_STATUS: Mutex<SyncRunStatus> = Mutex::new(SyncRunStatus::default());
+}
+
+/// A szinkronizálás beállításai (eszközönként eltérnek, ezért nem a Store-ban vannak)
+const CONFIG_FILE: &str = "sync.json";
+
+/// Az eszköz szinkronizálási állapota: óra, mezőverziók, törlési jelek, utoljára szinkronizált állapot
+const STATE_FILE: &str = "state.json";
+
+/// A valódi ütközések naplója (mindkét eszközön módosult ugyanaz a mező)
+const CONFLICTS_FILE: &str = "conflicts.jsonl";
+
+/// A megosztott mappán (vagy WebDAV gyűjteményen) belül: changes/<eszköz azonosító>/<sorszám>.json
+const CHANGES_DIR: &str = "changes";
+const DEVICE_FILE: &str = "device.json";
+const CHANGE_SET_EXTENSION: &str = "json";
+
+/// Alapértelmezett automatikus szinkronizálási gyakoriság (0 = csak kézi)
+const DEFAULT_INTERVAL_MINUTES: u32 = 15;
+
+/// A lekérdezéskor visszaadott ütközések alapértelmezett maximuma
+const DEFAULT_CONFLICT_LIMIT: usize = 200;
+
+/// Milyen gyakran nézi az ütemező, hogy esedékes-e a szinkronizálás
+const TICK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Késleltetés indításkor, hogy a frontend betöltése ne versenyezzen a szinkronizálással
+const STARTUP_DELAY: Duration = Duration::from_secs(20);
+
+/// Hibrid logikai óra: fali idő (ms) + számláló + eszköz azonosító
+/// A mezőnkénti "utolsó író nyer" döntés ennek a rendezésén alapul (eszközök közötti óraeltérésnél is monoton)
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Hlc {
+    pub wall: i64,
+    pub counter: u32,
+    pub device: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SyncConfig {
+    folder: Option<String>,
+    /// WebDAV gyűjtemény a megosztott mappa helyett (pl. Nextcloud)
+    #[serde(default)]
+    webdav: Option<WebDavSettings>,
+    interval_minutes: u32,
+    device_name: String,
+}
+
+impl Default for SyncConfig {
+    fn default() -> Self {
+        Self {
+            folder: None,
+            webdav: None,
+            interval_minutes: DEFAULT_INTERVAL_MINUTES,
+            device_name: default_device_name(),
+        }
+    }
+}
+
+/// Törölt rekord: a törlés ideje és az utolsó tartalom (egy későbbi távoli módosítás visszaállíthatja)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Tombstone {
+    clock: Hlc,
+    record: Value,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SyncState {
+    device_id: String,
+    /// Az utoljára kiadott / látott óraérték
+    clock: Option<Hlc>,
+    next_seq: u64,
+    /// Eszközönként az utoljára beolvasott változáscsomag sorszáma
+    imported: BTreeMap<String, u64>,
+    /// entitás típus -> rekord kulcs -> mező -> a mező aktuális értékét író óraérték
+    versions: BTreeMap<String, BTreeMap<String, BTreeMap<String, Hlc>>>,
+    tombstones: BTreeMap<String, BTreeMap<String, Tombstone>>,
+    /// Az utolsó szinkronizáláskori állapot, ehhez képest keressük a helyi változásokat
+    snapshot: BTreeMap<String, BTreeMap<String, Value>>,
+    last_sync_at: Option<String>,
+    /// WebDAV-on eszközönként a könyvtár utoljára teljesen beolvasott ETag-je (változatlan ETag esetén nem listázzuk)
+    #[serde(default)]
+    remote_etags: BTreeMap<String, String>,
+}
+
+impl SyncState {
+    fn new() -> Self {
+        let mut bytes = [0u8; 8];
+        rand::thread_rng().fill_bytes(&mut bytes);
+        Self {
+            device_id: hex::encode(bytes),
+            clock: None,
+            next_seq: 1,
+            imported: BTreeMap::new(),
+            versions: BTreeMap::new(),
+            tombstones: BTreeMap::new(),
+            snapshot: BTreeMap::new(),
+            last_sync_at: None,
+            remote_etags: BTreeMap::new(),
+        }
+    }
+
+    /// Új óraérték helyi változáshoz
+    fn tick(&mut self) -> Hlc {
+        let now = chrono::Utc::now().timestamp_millis();
+        let next = match &self.clock {
+            Some(last) if last.wall >= now => Hlc {
+                wall: last.wall,
+                counter: last.counter + 1,
+                device: self.device_id.clone(),
+            },
+            _ => Hlc {
+                wall: now,
+                counter: 0,
+                device: self.device_id.clone(),
+            },
+        };
+        self.clock = Some(next.clone());
+        next
+    }
+
+    /// Távoli óraérték beolvasása: a helyi óra sosem marad le a már látott változások mögött
+    fn receive(&mut self, remote: &Hlc) {
+        let now = chrono::Utc::now().timestamp_millis();
+        let (last_wall, last_counter) = self.clock.as_ref().map_or((i64::MIN, 0), |c| (c.wall, c.counter));
+        let wall = now.max(last_wall).max(remote.wall);
+        let counter = if wall == last_wall && wall == remote.wall {
+            last_counter.max(remote.counter) + 1
+        } else if wall == last_wall {
+            last_counter + 1
+        } else if wall == remote.wall {
+            remote.counter + 1
+        } else {
+            0
+        };
+        self.clock = Some(Hlc {
+            wall,
+            counter,
+            device: self.device_id.clone(),
+        });
+    }
+
+    fn field_version(&self, entity_type: &str, record_key: &str, field: &str) -> Option<Hlc> {
+        self.versions.get(entity_type)?.get(record_key)?.get(field).cloned()
+    }
+
+    /// A rekord legutóbbi mezőverziója (törléskor ehhez képest döntünk)
+    fn latest_version(&self, entity_type: &str, record_key: &str) -> Option<Hlc> {
+        self.versions.get(entity_type)?.get(record_key)?.values().max().cloned()
+    }
+
+    fn set_version(&mut self, entity_type: &str, record_key: &str, field: &str, clock: Hlc) -> Option<Hlc> {
+        self.versions
+            .entry(entity_type.to_string())
+            .or_default()
+            .entry(record_key.to_string())
+            .or_default()
+            .insert(field.to_string(), clock)
+    }
+}
+
+/// Egy mező változása (mező nélkül: a rekord törlése)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FieldChange {
+    entity_type: String,
+    record_key: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    field: Option<String>,
+    #[serde(default)]
+    value: Value,
+    /// A mező törlődött a rekordból (nem null értéket kapott)
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    removed: bool,
+    clock: Hlc,
+    /// A felülírt verzió - ha nem egyezik a helyi verzióval, a két eszköz egymástól függetlenül módosított
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    previous: Option<Hlc>,
+}
+
+/// Egy eszköz egy szinkronizálás alatt összegyűjtött változásai (a megosztott mappába írt fájl)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ChangeSet {
+    device_id: String,
+    device_name: String,
+    seq: u64,
+    created_at: String,
+    changes: Vec<FieldChange>,
+}
+
+/// Egy eszköz bemutatkozása a megosztott mappában
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DeviceInfo {
+    device_id: String,
+    device_name: String,
+    last_seq: u64,
+    last_sync_at: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConflictWinner {
+    Local,
+    Remote,
+}
+
+/// Valódi ütközés: ugyanazt a mezőt (vagy a rekordot törlés és módosítás) két eszköz egymástól függetlenül változtatta
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncConflict {
+    pub timestamp: String,
+    pub entity_type: String,
+    pub record_key: String,
+    /// Mező nélkül: törlés és módosítás ütközése
+    pub field: Option<String>,
+    pub local_value: Option<Value>,
+    pub remote_value: Option<Value>,
+    pub winner: ConflictWinner,
+    pub remote_device: String,
+}
+
+/// Egy szinkronizálás eredménye
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncReport {
+    /// Kiírt helyi mezőváltozások
+    pub exported: usize,
+    /// Beolvasott távoli változáscsomagok
+    pub imported_change_sets: usize,
+    /// Alkalmazott távoli mezőváltozások
+    pub applied: usize,
+    pub conflicts: usize,
+    pub store_changed: bool,
+    pub finished_at: String,
+}
+
+#[derive(Debug, Clone, Default)]
+struct SyncRunStatus {
+    in_progress: bool,
+    last_error: Option<String>,
+    last_report: Option<SyncReport>,
+    last_run: Option<Instant>,
+    /// WebDAV-on a többi eszköz utoljára letöltött adatai (az állapot lekérdezése ne járjon hálózati kéréssel)
+    remote_peers: BTreeMap<String, DeviceInfo>,
+}
+
+/// Egy másik eszköz a megosztott mappában
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncPeer {
+    pub device_id: String,
+    pub device_name: String,
+    pub last_seq: u64,
+    pub imported_seq: u64,
+    pub last_sync_at: Option<String>,
+}
+
+/// A szinkronizálás állapota a frontend számára
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncStatus {
+    pub folder: Option<String>,
+    pub webdav: Option<WebDavSettings>,
+    pub interval_minutes: u32,
+    pub device_id: String,
+    pub device_name: String,
+    pub last_sync_at: Option<String>,
+    pub last_error: Option<String>,
+    pub last_report: Option<SyncReport>,
+    pub in_progress: bool,
+    pub peers: Vec<SyncPeer>,
+    pub conflict_count: usize,
+    /// Titkosított adattárolóval a szinkronizálás nem érhető el (a változáscsomagok titkosítatlanok lennének)
+    pub blocked_by_vault: bool,
+}
+
+fn default_device_name() -> String {
+    std::env::var("COMPUTERNAME")
+        .or_else(|_| std::env::var("HOSTNAME"))
+        .ok()
+        .filter(|name| !name.trim().is_empty())
+        .unwrap_or_else(|| "3DPrinterCalcApp".to_string())
+}
+
+fn config_path() -> Result<PathBuf, String> {
+    Ok(crate::paths::sync_dir()?.join(CONFIG_FILE))
+}
+
+fn state_path() -> Result<PathBuf, String> {
+    Ok(crate::paths::sync_dir()?.join(STATE_FILE))
+}
+
+fn conflicts_path() -> Result<PathBuf, String> {
+    Ok(crate::paths::sync_dir()?.join(CONFLICTS_FILE))
+}
+
+/// A rekord tartalmat hordozó szinkronizálási fájlok (a vault ezeket is titkosítja / visszafejti)
+pub fn sync_files() -> Result<Vec<PathBuf>, String> {
+    Ok([state_path()?, conflicts_path()?].into_iter().filter(|path| path.exists()).collect())
+}
+
+fn read_config() -> Result<SyncConfig, String> {
+    let path = config_path()?;
+    if !path.exists() {
+        return Ok(SyncConfig::default());
+    }
+    let content = fs::read_to_string(&path).map_err(|e| format!("Nem sikerült beolvasni a szinkronizálás beállításait: {}", e))?;
+    serde_json::from_str(&content).map_err(|e| format!("Érvénytelen szinkronizálási beállítások: {}", e))
+}
+
+/// A WebDAV jelszó bekapcsolt adattárolónál titkosítva kerül a fájlba
+fn write_config(config: &SyncConfig) -> Result<(), String> {
+    let config = SyncConfig {
+        webdav: config.webdav.as_ref().map(|settings| settings.sealed()).transpose()?,
+        ..config.clone()
+    };
+    let content = serde_json::to_string_pretty(&config)
+        .map_err(|e| format!("Szinkronizálási beállítások szerializálási hiba: {}", e))?;
+    crate::atomic_write::write_atomic(&config_path()?, content)
+        .map_err(|e| format!("Nem sikerült menteni a szinkronizálás beállításait: {}", e))
+}
+
+/// A mentett WebDAV beállítás (a jelszó üres jelszavas kapcsolatteszthez)
+pub fn stored_webdav_settings() -> Option<WebDavSettings> {
+    read_config().ok()?.webdav
+}
+
+/// A mentett WebDAV jelszó átírása a vault állapotváltásakor (bekapcsolás / jelmondat csere / kikapcsolás)
+pub fn rewrite_webdav_secret(f: &mut dyn FnMut(&str) -> Result<String, String>) -> Result<(), String> {
+    let mut config = read_config()?;
+    let Some(settings) = config.webdav.as_mut() else {
+        return Ok(());
+    };
+    settings.rewrite_password(f)?;
+    let content = serde_json::to_string_pretty(&config)
+        .map_err(|e| format!("Szinkronizálási beállítások szerializálási hiba: {}", e))?;
+    crate::atomic_write::write_atomic(&config_path()?, content)
+        .map_err(|e| format!("Nem sikerült menteni a szinkronizálás beállításait: {}", e))
+}
+
+fn read_state() -> Result<SyncState, String> {
+    let path = state_path()?;
+    if !path.exists() {
+        return Ok(SyncState::new());
+    }
+    let content = fs::read_to_string(&path).map_err(|e| format!("Nem sikerült beolvasni a szinkronizálási állapotot: {}", e))?;
+    let content = crate::vault::open_lines(&content)?;
+    serde_json::from_str(content.trim()).map_err(|e| format!("Sérült szinkronizálási állapot: {}", e))
+}
+
+/// Egy sorba írjuk, hogy a vault soronkénti titkosítása erre is működjön
+fn write_state(state: &SyncState) -> Result<(), String> {
+    let line = serde_json::to_string(state).map_err(|e| format!("Szinkronizálási állapot szerializálási hiba: {}", e))?;
+    let content = format!("{}\n", crate::vault::seal_line(&line)?);
+    crate::atomic_write::write_atomic(&state_path()?, content)
+        .map_err(|e| format!("Nem sikerült menteni a szinkronizálási állapotot: {}", e))
+}
+
+fn read_conflicts() -> Result<Vec<SyncConflict>, String> {
+    let path = conflicts_path()?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content = fs::read_to_string(&path).map_err(|e| format!("Nem sikerült beolvasni az ütközésnaplót: {}", e))?;
+    let content = crate::vault::open_lines(&content)?;
+    Ok(content
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect())
+}
+
+fn append_conflicts(conflicts: &[SyncConflict]) -> Result<(), String> {
+    if conflicts.is_empty() {
+        return Ok(());
+    }
+    let path = conflicts_path()?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("Nem sikerült létrehozni a szinkronizálási könyvtárat: {}", e))?;
+    }
+    let mut content = String::new();
+    for conflict in conflicts {
+        let line = serde_json::to_string(conflict).map_err(|e| format!("Ütközés szerializálási hiba: {}", e))?;
+        content.push_str(&crate::vault::seal_line(&line)?);
+        content.push('\n');
+    }
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .map_err(|e| format!("Nem sikerült megnyitni az ütközésnaplót: {}", e))?;
+    file.write_all(content.as_bytes())
+        .map_err(|e| format!("Nem sikerült írni az ütközésnaplót: {}", e))
+}
+
+fn ensure_available() -> Result<(), String> {
+    if crate::vault::is_enabled() {
+        return Err("Titkosított adattárolóval a szinkronizálás nem érhető el, mert a megosztott mappába titkosítatlan változások kerülnének".to_string());
+    }
+    Ok(())
+}
+
+/// A változáscsomagok közös helye: megosztott mappa vagy WebDAV gyűjtemény
+enum SyncTarget {
+    Folder(PathBuf),
+    WebDav(Box<WebDavClient>),
+}
+
+/// Egy másik eszköz könyvtára; WebDAV-on az ETag-jével
+struct PeerListing {
+    device_id: String,
+    etag: Option<String>,
+}
+
+impl SyncTarget {
+    fn open(config: &SyncConfig) -> Result<Self, String> {
+        if let Some(settings) = &config.webdav {
+            return Ok(Self::WebDav(Box::new(WebDavClient::new(settings)?)));
+        }
+        let folder = config
+            .folder
+            .as_deref()
+            .ok_or_else(|| "Nincs beállítva szinkronizálási mappa".to_string())?;
+        let folder = PathBuf::from(folder);
+        if !folder.is_dir() {
+            return Err(format!("A szinkronizálási mappa nem érhető el: {}", folder.display()));
+        }
+        Ok(Self::Folder(folder))
+    }
+
+    fn device_path(device_id: &str) -> String {
+        format!("{}/{}", CHANGES_DIR, device_id)
+    }
+
+    fn write_file(&self, device_id: &str, file_name: &str, content: String) -> Result<(), String> {
+        match self {
+            Self::Folder(shared) => {
+                let dir = shared.join(CHANGES_DIR).join(device_id);
+                fs::create_dir_all(&dir).map_err(|e| format!("Nem sikerült létrehozni a változáscsomag könyvtárat: {}", e))?;
+                crate::atomic_write::write_atomic(&dir.join(file_name), content).map_err(|e| e.to_string())
+            }
+            Self::WebDav(client) => {
+                let dir = Self::device_path(device_id);
+                client.ensure_collection(&dir)?;
+                client.put(&format!("{}/{}", dir, file_name), content.as_bytes(), None).map(|_| ())
+            }
+        }
+    }
+
+    fn read_file(&self, device_id: &str, file_name: &str) -> Result<Option<String>, String> {
+        match self {
+            Self::Folder(shared) => {
+                let path = shared.join(CHANGES_DIR).join(device_id).join(file_name);
+                match fs::read_to_string(&path) {
+                    Ok(content) => Ok(Some(content)),
+                    Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+                    Err(e) => Err(e.to_string()),
+                }
+            }
+            Self::WebDav(client) => client
+                .get(&format!("{}/{}", Self::device_path(device_id), file_name))?
+                .map(|content| String::from_utf8(content).map_err(|e| e.to_string()))
+                .transpose(),
+        }
+    }
+
+    /// A többi eszköz könyvtára
+    fn peers(&self, own_device_id: &str) -> Result<Vec<PeerListing>, String> {
+        let mut peers: Vec<PeerListing> = match self {
+            Self::Folder(shared) => {
+                let Ok(entries) = fs::read_dir(shared.join(CHANGES_DIR)) else {
+                    return Ok(Vec::new());
+                };
+                entries
+                    .flatten()
+                    .map(|entry| entry.path())
+                    .filter(|path| path.is_dir())
+                    .filter_map(|path| Some(path.file_name()?.to_str()?.to_string()))
+                    .map(|device_id| PeerListing { device_id, etag: None })
+                    .collect()
+            }
+            Self::WebDav(client) => client
+                .list(CHANGES_DIR)?
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|entry| entry.is_collection)
+                .map(|entry| PeerListing {
+                    device_id: entry.name,
+                    etag: entry.etag,
+                })
+                .collect(),
+        };
+        peers.retain(|peer| peer.device_id != own_device_id);
+        peers.sort_by(|a, b| a.device_id.cmp(&b.device_id));
+        Ok(peers)
+    }
+
+    /// Egy eszköz még be nem olvasott változáscsomagjai sorszám szerint (fájlnévvel)
+    fn pending_change_sets(&self, device_id: &str, after_seq: u64) -> Result<Vec<(u64, String)>, String> {
+        let names: Vec<String> = match self {
+            Self::Folder(shared) => {
+                let Ok(entries) = fs::read_dir(shared.join(CHANGES_DIR).join(device_id)) else {
+                    return Ok(Vec::new());
+                };
+                entries.flatten().filter_map(|entry| entry.file_name().to_str().map(|n| n.to_string())).collect()
+            }
+            Self::WebDav(client) => client
+                .list(&Self::device_path(device_id))?
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|entry| !entry.is_collection)
+                .map(|entry| entry.name)
+                .collect(),
+        };
+        let mut files: Vec<(u64, String)> = names
+            .into_iter()
+            .filter_map(|name| {
+                let seq = name.strip_suffix(&format!(".{}", CHANGE_SET_EXTENSION))?.parse::<u64>().ok()?;
+                Some((seq, name))
+            })
+            .filter(|(seq, _)| *seq > after_seq)
+            .collect();
+        files.sort();
+        Ok(files)
+    }
+
+    fn read_device_info(&self, device_id: &str) -> Option<DeviceInfo> {
+        self.read_file(device_id, DEVICE_FILE)
+            .ok()
+            .flatten()
+            .and_then(|content| serde_json::from_str::<DeviceInfo>(&content).ok())
+    }
+}
+
+fn store_modified_at() -> Option<SystemTime> {
+    data_store::stored_file_path().ok().and_then(|path| fs::metadata(path).ok()).and_then(|m| m.modified().ok())
+}
+
+fn entity_records(store: &Map<String, Value>, entity_type: &str) -> BTreeMap<String, Value> {
+    let list = store.get(entity_type).and_then(|v| v.as_array()).map(|a| a.as_slice()).unwrap_or_default();
+    journal::index_records(entity_type, list)
+}
+
+/// A helyi változások összegyűjtése az utolsó szinkronizált állapothoz képest, mezőnként új óraértékkel
+/// Egy rekord változásai egymás után következnek (a beolvasás rekordonként csoportosít)
+fn collect_local_changes(state: &mut SyncState, store: &Map<String, Value>) -> Vec<FieldChange> {
+    let mut changes = Vec::new();
+    for entity_type in JOURNALED_ENTITY_TYPES {
+        let current = entity_records(store, entity_type);
+        let previous = state.snapshot.remove(entity_type).unwrap_or_default();
+
+        let empty = Map::new();
+        for (key, record) in &current {
+            let after = record.as_object().unwrap_or(&empty);
+            let is_new = !previous.contains_key(key);
+            if is_new {
+                // Új (vagy törlés után újra létrehozott) rekord: minden mező változásnak számít
+                if let Some(tombstones) = state.tombstones.get_mut(entity_type) {
+                    tombstones.remove(key);
+                }
+            }
+            let before = previous.get(key).and_then(|r| r.as_object()).unwrap_or(&empty);
+            let fields: Vec<&String> = after.keys().chain(before.keys().filter(|k| !after.contains_key(*k))).collect();
+            for field in fields {
+                let new_value = after.get(field);
+                if !is_new && new_value == before.get(field) {
+                    continue;
+                }
+                let clock = state.tick();
+                let previous_version = state.set_version(entity_type, key, field, clock.clone());
+                changes.push(FieldChange {
+                    entity_type: entity_type.to_string(),
+                    record_key: key.clone(),
+                    field: Some(field.clone()),
+                    value: new_value.cloned().unwrap_or(Value::Null),
+                    removed: new_value.is_none(),
+                    clock,
+                    previous: previous_version,
+                });
+            }
+        }
+
+        for (key, record) in previous.iter().filter(|(key, _)| !current.contains_key(*key)) {
+            let clock = state.tick();
+            let previous_version = state.latest_version(entity_type, key);
+            if let Some(records) = state.versions.get_mut(entity_type) {
+                records.remove(key);
+            }
+            state.tombstones.entry(entity_type.to_string()).or_default().insert(
+                key.clone(),
+                Tombstone {
+                    clock: clock.clone(),
+                    record: record.clone(),
+                },
+            );
+            changes.push(FieldChange {
+                entity_type: entity_type.to_string(),
+                record_key: key.clone(),
+                field: None,
+                value: Value::Null,
+                removed: false,
+                clock,
+                previous: previous_version,
+            });
+        }
+
+        state.snapshot.insert(entity_type.to_string(), current);
+    }
+    changes
+}
+
+fn write_change_set(target: &SyncTarget, config: &SyncConfig, state: &mut SyncState, changes: Vec<FieldChange>) -> Result<(), String> {
+    let change_set = ChangeSet {
+        device_id: state.device_id.clone(),
+        device_name: config.device_name.clone(),
+        seq: state.next_seq,
+        created_at: chrono::Utc::now().to_rfc3339(),
+        changes,
+    };
+    let content = serde_json::to_string(&change_set).map_err(|e| format!("Változáscsomag szerializálási hiba: {}", e))?;
+    let file_name = format!("{:010}.{}", change_set.seq, CHANGE_SET_EXTENSION);
+    target
+        .write_file(&state.device_id, &file_name, content)
+        .map_err(|e| format!("Nem sikerült írni a változáscsomagot: {}", e))?;
+    state.next_seq += 1;
+    Ok(())
+}
+
+fn write_device_info(target: &SyncTarget, config: &SyncConfig, state: &SyncState) -> Result<(), String> {
+    let info = DeviceInfo {
+        device_id: state.device_id.clone(),
+        device_name: config.device_name.clone(),
+        last_seq: state.next_seq - 1,
+        last_sync_at: chrono::Utc::now().to_rfc3339(),
+    };
+    let content = serde_json::to_string_pretty(&info).map_err(|e| format!("Eszköz adatok szerializálási hiba: {}", e))?;
+    target
+        .write_file(&state.device_id, DEVICE_FILE, content)
+        .map_err(|e| format!("Nem sikerült írni az eszköz adatait: {}", e))
+}
+
+struct ImportContext<'a> {
+    state: &'a mut SyncState,
+    conflicts: Vec<SyncConflict>,
+    applied: usize,
+    remote_device: String,
+}
+
+impl ImportContext<'_> {
+    fn conflict(
+        &mut self,
+        change: &FieldChange,
+        local_value: Option<Value>,
+        remote_value: Option<Value>,
+        winner: ConflictWinner,
+    ) {
+        self.conflicts.push(SyncConflict {
+            timestamp: chrono::Utc::now().to_rfc3339(),
+            entity_type: change.entity_type.clone(),
+            record_key: change.record_key.clone(),
+            field: change.field.clone(),
+            local_value,
+            remote_value,
+            winner,
+            remote_device: self.remote_device.clone(),
+        });
+    }
+
+    /// Egy távoli mezőváltozás: ha a helyi verziót írta felül, alkalmazzuk; ha a két eszköz egymástól
+    /// függetlenül módosított, a későbbi óraérték nyer, és eltérő értéknél ütközést naplózunk
+    fn apply_field(&mut self, change: &FieldChange, field: &str, record: &mut Option<Map<String, Value>>) {
+        let remote_value = (!change.removed).then(|| change.value.clone());
+        let (entity_type, key) = (change.entity_type.as_str(), change.record_key.as_str());
+
+        let Some(map) = record else {
+            let tombstone = self.state.tombstones.get(entity_type).and_then(|t| t.get(key)).cloned();
+            match tombstone {
+                // Helyben később törölték: a törlés nyer (új rekord létrehozása nem ütközés)
+                Some(tombstone) if tombstone.clock >= change.clock => {
+                    if change.previous.is_some() {
+                        self.conflict(change, None, remote_value, ConflictWinner::Local);
+                    }
+                    return;
+                }
+                // A távoli módosítás későbbi a helyi törlésnél: a rekord visszaáll az utolsó ismert tartalommal
+                Some(tombstone) => {
+                    if change.previous.is_some() {
+                        self.conflict(change, None, remote_value.clone(), ConflictWinner::Remote);
+                    }
+                    if let Some(tombstones) = self.state.tombstones.get_mut(entity_type) {
+                        tombstones.remove(key);
+                    }
+                    *record = Some(tombstone.record.as_object().cloned().unwrap_or_default());
+                }
+                None => *record = Some(Map::new()),
+            }
+            let Some(map) = record else {
+                return;
+            };
+            set_field(map, field, remote_value);
+            self.state.set_version(entity_type, key, field, change.clock.clone());
+            self.applied += 1;
+            return;
+        };
+
+        let local_version = self.state.field_version(entity_type, key, field);
+        if local_version.as_ref() == Some(&change.clock) {
+            // Már alkalmazott változás
+            return;
+        }
+        let fast_forward = local_version.is_none() || local_version == change.previous;
+        let local_value = map.get(field).cloned();
+        if fast_forward {
+            set_field(map, field, remote_value);
+            self.state.set_version(entity_type, key, field, change.clock.clone());
+            self.applied += 1;
+            return;
+        }
+
+        let local_version = local_version.unwrap_or_else(|| change.clock.clone());
+        if local_value == remote_value {
+            if change.clock > local_version {
+                self.state.set_version(entity_type, key, field, change.clock.clone());
+            }
+            return;
+        }
+        if change.clock > local_version {
+            self.conflict(change, local_value, remote_value.clone(), ConflictWinner::Remote);
+            set_field(map, field, remote_value);
+            self.state.set_version(entity_type, key, field, change.clock.clone());
+            self.applied += 1;
+        } else {
+            self.conflict(change, local_value, remote_value, ConflictWinner::Local);
+        }
+    }
+
+    /// Távoli törlés: ha a törlő eszköz látta a rekord összes helyi módosítását, töröljük;
+    /// egy általa nem látott helyi módosítással szemben a későbbi óraérték nyer
+    fn apply_delete(&mut self, change: &FieldChange, record: &mut Option<Map<String, Value>>) {
+        let (entity_type, key) = (change.entity_type.as_str(), change.record_key.as_str());
+        let Some(map) = record.take() else {
+            // Helyben sincs meg: csak a törlési jelet frissítjük
+            let tombstones = self.state.tombstones.entry(entity_type.to_string()).or_default();
+            let stored = tombstones.get(key).map(|t| t.record.clone()).unwrap_or(Value::Null);
+            if tombstones.get(key).map_or(true, |t| t.clock < change.clock) {
+                tombstones.insert(key.to_string(), Tombstone { clock: change.clock.clone(), record: stored });
+            }
+            return;
+        };
+
+        let latest = self.state.latest_version(entity_type, key);
+        let seen = latest
+            .as_ref()
+            .map_or(true, |local| change.previous.as_ref().is_some_and(|previous| local <= previous));
+        let remote_wins = seen || latest.as_ref().map_or(true, |local| &change.clock > local);
+        if !seen {
+            let winner = if remote_wins { ConflictWinner::Remote } else { ConflictWinner::Local };
+            self.conflict(change, Some(Value::Object(map.clone())), None, winner);
+        }
+        if !remote_wins {
+            *record = Some(map);
+            return;
+        }
+
+        if let Some(records) = self.state.versions.get_mut(entity_type) {
+            records.remove(key);
+        }
+        self.state.tombstones.entry(entity_type.to_string()).or_default().insert(
+            key.to_string(),
+            Tombstone {
+                clock: change.clock.clone(),
+                record: Value::Object(map),
+            },
+        );
+        self.applied += 1;
+    }
+
+    /// Egy rekord egymást követő változásai: a rekordot egyszer keressük meg és egyszer írjuk vissza
+    fn apply_record_changes(&mut self, working: &mut Map<String, Value>, changes: &[FieldChange]) {
+        let Some(first) = changes.first() else {
+            return;
+        };
+        if !JOURNALED_ENTITY_TYPES.contains(&first.entity_type.as_str()) {
+            return;
+        }
+        let list = working
+            .entry(first.entity_type.clone())
+            .or_insert_with(|| Value::Array(Vec::new()));
+        let Some(list) = list.as_array_mut() else {
+            return;
+        };
+        let position = list
+            .iter()
+            .position(|r| data_store::record_key(&first.entity_type, r).as_deref() == Some(first.record_key.as_str()));
+        let mut record = position.map(|i| list[i].as_object().cloned().unwrap_or_default());
+
+        for change in changes {
+            self.state.receive(&change.clock);
+            match &change.field {
+                Some(field) => self.apply_field(change, field, &mut record),
+                None => self.apply_delete(change, &mut record),
+            }
+        }
+
+        let Some(list) = working.get_mut(&first.entity_type).and_then(|v| v.as_array_mut()) else {
+            return;
+        };
+        match (position, record) {
+            (Some(i), Some(record)) => list[i] = Value::Object(record),
+            (Some(i), None) => {
+                list.remove(i);
+            }
+            (None, Some(record)) => list.push(Value::Object(record)),
+            (None, None) => {}
+        }
+    }
+}
+
+fn set_field(record: &mut Map<String, Value>, field: &str, value: Option<Value>) {
+    match value {
+        Some(value) => {
+            record.insert(field.to_string(), value);
+        }
+        None => {
+            record.remove(field);
+        }
+    }
+}
+
+/// Egy beolvasási kör eredménye
+struct ImportOutcome {
+    imported: usize,
+    applied: usize,
+    conflicts: Vec<SyncConflict>,
+    /// WebDAV-on a változott könyvtárú (vagy még nem ismert) eszközök friss adatai
+    peer_infos: Vec<DeviceInfo>,
+}
+
+/// A többi eszköz új változáscsomagjainak alkalmazása a munkapéldányon
+/// Félig szinkronizált (még nem olvasható) fájlnál az adott eszközt a következő körben folytatjuk
+/// WebDAV-on a változatlan ETag-ű eszközkönyvtárakat nem listázzuk újra
+fn import_change_sets(
+    target: &SyncTarget,
+    known_peers: &BTreeSet<String>,
+    state: &mut SyncState,
+    working: &mut Map<String, Value>,
+) -> Result<ImportOutcome, String> {
+    let mut outcome = ImportOutcome {
+        imported: 0,
+        applied: 0,
+        conflicts: Vec::new(),
+        peer_infos: Vec::new(),
+    };
+    for PeerListing { device_id, etag } in target.peers(&state.device_id)? {
+        let unchanged = etag.is_some() && state.remote_etags.get(&device_id) == etag.as_ref();
+        if unchanged && known_peers.contains(&device_id) {
+            continue;
+        }
+        if matches!(target, SyncTarget::WebDav(_)) {
+            outcome.peer_infos.extend(target.read_device_info(&device_id));
+        }
+        if unchanged {
+            continue;
+        }
+
+        let after_seq = state.imported.get(&device_id).copied().unwrap_or(0);
+        let mut complete = true;
+        for (seq, file_name) in target.pending_change_sets(&device_id, after_seq)? {
+            let change_set = match target.read_file(&device_id, &file_name).and_then(|content| {
+                let content = content.ok_or_else(|| "a fájl nem található".to_string())?;
+                serde_json::from_str::<ChangeSet>(&content).map_err(|e| e.to_string())
+            }) {
+                Ok(change_set) => change_set,
+                Err(e) => {
+                    logger::log_warn(&format!(
+                        "Változáscsomag nem olvasható, később újrapróbáljuk: {}/{} - {}",
+                        device_id, file_name, e
+                    ));
+                    complete = false;
+                    break;
+                }
+            };
+
+            let mut context = ImportContext {
+                state: &mut *state,
+                conflicts: Vec::new(),
+                applied: 0,
+                remote_device: if change_set.device_name.is_empty() { device_id.clone() } else { change_set.device_name.clone() },
+            };
+            for group in change_set
+                .changes
+                .chunk_by(|a, b| a.entity_type == b.entity_type && a.record_key == b.record_key)
+            {
+                context.apply_record_changes(working, group);
+            }
+            outcome.applied += context.applied;
+            outcome.conflicts.extend(context.conflicts);
+            state.imported.insert(device_id.clone(), seq);
+            outcome.imported += 1;
+        }
+        if let (true, Some(etag)) = (complete, etag) {
+            state.remote_etags.insert(device_id, etag);
+        }
+    }
+    Ok(outcome)
+}
+
+/// Egy teljes szinkronizálási kör
+/// 1. a helyi változások kiírása változáscsomagként (mezőnként, óraértékkel)
+/// 2. a többi eszköz új változáscsomagjainak összefésülése rekordonként / mezőnként
+/// 3. csak a változott rekordok kerülnek a Store-ba - a teljes fájlt sosem írjuk felül a másik eszköz példányával
+fn run_sync(app: &AppHandle) -> Result<SyncReport, String> {
+    ensure_available()?;
+    let config = read_config()?;
+    let target = SyncTarget::open(&config)?;
+    let _guard = SYNC_LOCK.lock().map_err(|e| format!("Szinkronizálási zárolási hiba: {}", e))?;
+
+    let modified_at = store_modified_at();
+    let store = data_store::read_store()?
+        .ok_or_else(|| "Még nincsenek adatok, a szinkronizálás az első mentés után indítható".to_string())?;
+
+    let mut state = read_state()?;
+    let changes = collect_local_changes(&mut state, &store);
+    let exported = changes.len();
+    if !changes.is_empty() {
+        write_change_set(&target, &config, &mut state, changes)?;
+    }
+    // A kiírt csomag sorszáma ne kerülhessen újra kiosztásra akkor sem, ha a beolvasás megszakad
+    write_state(&state)?;
+
+    let mut working = store.clone();
+    let known_peers: BTreeSet<String> = SYNC_STATUS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .remote_peers
+        .keys()
+        .cloned()
+        .collect();
+    let ImportOutcome {
+        imported: imported_change_sets,
+        applied,
+        conflicts,
+        peer_infos,
+    } = import_change_sets(&target, &known_peers, &mut state, &mut working)?;
+    let store_changed = JOURNALED_ENTITY_TYPES.iter().any(|t| working.get(*t) != store.get(*t));
+    if store_changed {
+        // A frontend közben mentett: nem írjuk felül, a következő kör a friss adatokkal fut
+        if store_modified_at() != modified_at {
+            return Err("Az adatok a szinkronizálás közben módosultak, a következő körben újrapróbáljuk".to_string());
+        }
+        crate::restore::commit_store_data(app, &working, "sync")?;
+    }
+
+    for entity_type in JOURNALED_ENTITY_TYPES {
+        state.snapshot.insert(entity_type.to_string(), entity_records(&working, entity_type));
+    }
+    let finished_at = chrono::Utc::now().to_rfc3339();
+    state.last_sync_at = Some(finished_at.clone());
+    write_state(&state)?;
+    append_conflicts(&conflicts)?;
+    if let Err(e) = write_device_info(&target, &config, &state) {
+        logger::log_warn(&format!("Nem sikerült frissíteni az eszköz adatait a megosztott mappában: {}", e));
+    }
+    update_status(|status| {
+        for info in peer_infos {
+            status.remote_peers.insert(info.device_id.clone(), info);
+        }
+    });
+
+    let report = SyncReport {
+        exported,
+        imported_change_sets,
+        applied,
+        conflicts: conflicts.len(),
+        store_changed,
+        finished_at,
+    };
+    if exported > 0 || imported_change_sets > 0 {
+        logger::log_info(&format!(
+            "Szinkronizálás: {} helyi változás kiírva, {} csomag beolvasva, {} változás alkalmazva, {} ütközés",
+            report.exported, report.imported_change_sets, report.applied, report.conflicts
+        ));
+    }
+    Ok(report)
+}
+
+fn update_status(f: impl FnOnce(&mut SyncRunStatus)) {
+    let mut status = SYNC_STATUS.lock().unwrap_or_else(|e| e.into_inner());
+    f(&mut status);
+}
+
+fn run_and_record(app: &AppHandle) -> Result<SyncReport, String> {
+    update_status(|status| status.in_progress = true);
+    let result = run_sync(app);
+    update_status(|status| {
+        status.in_progress = false;
+        status.last_run = Some(Instant::now());
+        match &result {
+            Ok(report) => {
+                status.last_error = None;
+                status.last_report = Some(report.clone());
+            }
+            Err(e) => status.last_error = Some(e.clone()),
+        }
+    });
+    result
+}
+
+/// Esedékes-e az automatikus szinkronizálás (beállított mappa, nem zárolt / titkosított adattároló)
+fn is_due() -> bool {
+    if crate::vault::is_enabled() {
+        return false;
+    }
+    let Ok(config) = read_config() else {
+        return false;
+    };
+    if (config.folder.is_none() && config.webdav.is_none()) || config.interval_minutes == 0 {
+        return false;
+    }
+    let interval = Duration::from_secs(config.interval_minutes as u64 * 60);
+    let status = SYNC_STATUS.lock().unwrap_or_else(|e| e.into_inner());
+    !status.in_progress && status.last_run.map_or(true, |last| last.elapsed() >= interval)
+}
+
+/// A háttér szinkronizálás indítása (main.rs setup-ból, egyszer)
+/// Percenként ellenőrzi, esedékes-e a szinkronizálás a beállított gyakoriság szerint
+pub fn start(app: AppHandle) {
+    let spawn_result = thread::Builder::new().name("folder-sync".to_string()).spawn(move || {
+        thread::sleep(STARTUP_DELAY);
+        loop {
+            if is_due() {
+                if let Err(e) = run_and_record(&app) {
+                    logger::log_warn(&format!("Automatikus szinkronizálás sikertelen: {}", e));
+                }
+            }
+            thread::sleep(TICK_INTERVAL);
+        }
+    });
+    if let Err(e) = spawn_result {
+        logger::log_error(&format!("Szinkronizálás ütemező indítása sikertelen: {}", e));
+    }
+}
+
+/// A szinkronizálás felfüggesztése a visszaadott zár eldobásáig (a folyamatban lévő futás befejeződik)
+pub fn pause_runs() -> MutexGuard<'static, ()> {
+    SYNC_LOCK.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Munkaterület váltáskor: az előző munkaterület szinkronizálási állapota nem vonatkozik az újra
+pub fn reset_status() {
+    update_status(|status| *status = SyncRunStatus::default());
+}
+
+/// A szinkronizálás beállításai, a többi eszköz és az utolsó futás eredménye
+#[tauri::command]
+pub fn get_sync_status() -> Result<SyncStatus, String> {
+    let config = read_config()?;
+    let blocked_by_vault = crate::vault::is_enabled();
+    // Titkosított állapotfájlt zárolt adattárolónál nem tudunk olvasni
+    let state = if crate::vault::is_locked() { SyncState::new() } else { read_state()? };
+    let status = SYNC_STATUS.lock().map_err(|e| format!("Szinkronizálási zárolási hiba: {}", e))?.clone();
+    // WebDAV-on az utolsó szinkronizáláskor letöltött adatok, mappánál a mappa aktuális tartalma
+    let peer_infos: Vec<(String, Option<DeviceInfo>)> = if config.webdav.is_some() {
+        status.remote_peers.iter().map(|(id, info)| (id.clone(), Some(info.clone()))).collect()
+    } else {
+        match SyncTarget::open(&config) {
+            Ok(target) => target
+                .peers(&state.device_id)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|peer| {
+                    let info = target.read_device_info(&peer.device_id);
+                    (peer.device_id, info)
+                })
+                .collect(),
+            Err(_) => Vec::new(),
+        }
+    };
+    let peers = peer_infos
+        .into_iter()
+        .map(|(device_id, info)| SyncPeer {
+            device_name: info.as_ref().map(|i| i.device_name.clone()).unwrap_or_else(|| device_id.clone()),
+            last_seq: info.as_ref().map(|i| i.last_seq).unwrap_or(0),
+            imported_seq: state.imported.get(&device_id).copied().unwrap_or(0),
+            last_sync_at: info.map(|i| i.last_sync_at),
+            device_id,
+        })
+        .collect();
+    let conflict_count = if crate::vault::is_locked() { 0 } else { read_conflicts()?.len() };
+    Ok(SyncStatus {
+        folder: config.folder,
+        webdav: config.webdav.as_ref().map(WebDavSettings::redacted),
+        interval_minutes: config.interval_minutes,
+        device_id: state.device_id,
+        device_name: config.device_name,
+        last_sync_at: state.last_sync_at,
+        last_error: status.last_error,
+        last_report: status.last_report,
+        in_progress: status.in_progress,
+        peers,
+        conflict_count,
+        blocked_by_vault,
+    })
+}
+
+/// Szinkronizálási mappa vagy WebDAV gyűjtemény és gyakoriság beállítása (mindkettő nélkül a szinkronizálás kikapcsol)
+/// A megosztott mappát a Syncthing / Dropbox / hálózati megosztás viszi át a többi eszközre
+/// WebDAV megadásakor a mappa beállítás törlődik; üres jelszónál azonos címhez és felhasználóhoz a korábbi marad
+#[tauri::command]
+pub fn set_sync_config(
+    folder: Option<String>,
+    webdav: Option<WebDavSettings>,
+    interval_minutes: u32,
+    device_name: Option<String>,
+) -> Result<SyncStatus, String> {
+    let mut config = read_config()?;
+    let folder = folder.map(|f| f.trim().to_string()).filter(|f| !f.is_empty());
+    let mut webdav = webdav.filter(|settings| !settings.url.trim().is_empty());
+    if let Some(settings) = webdav.as_mut() {
+        ensure_available()?;
+        // A cím formátumának ellenőrzése (a kapcsolatot a test_webdav_connection parancs teszteli)
+        WebDavClient::new(settings)?;
+        if let Some(previous) = &config.webdav {
+            settings.keep_password_of(previous);
+        }
+    } else if let Some(folder) = &folder {
+        ensure_available()?;
+        if !Path::new(folder).is_dir() {
+            return Err(format!("A megadott mappa nem létezik: {}", folder));
+        }
+    }
+    config.folder = if webdav.is_some() { None } else { folder };
+    config.webdav = webdav;
+    config.interval_minutes = interval_minutes;
+    if let Some(name) = device_name.map(|n| n.trim().to_string()).filter(|n| !n.is_empty()) {
+        config.device_name = name;
+    }
+    write_config(&config)?;
+    update_status(|status| status.remote_peers.clear());
+    logger::log_info(&format!(
+        "Szinkronizálás beállítva: {} ({} percenként)",
+        config
+            .webdav
+            .as_ref()
+            .map(|settings| settings.url.as_str())
+            .or(config.folder.as_deref())
+            .unwrap_or("kikapcsolva"),
+        config.interval_minutes
+    ));
+    get_sync_status()
+}
+
+/// Azonnali szinkronizálás (a Store változásakor a frontend a "data-store-changed" eseményre újratölti az adatokat)
+/// Hálózati / megosztott mappás művelet, ezért háttérszálon fut (a felület nem áll meg közben)
+#[tauri::command]
+pub async fn sync_now(app: AppHandle) -> Result<SyncReport, String> {
+    crate::vault::run_blocking(move || run_and_record(&app)).await
+}
+
+/// A naplózott ütközések (legújabb elöl)
+#[tauri::command]
+pub fn get_sync_conflicts(limit: Option<usize>) -> Result<Vec<SyncConflict>, String> {
+    let mut conflicts = read_conflicts()?;
+    conflicts.reverse();
+    conflicts.truncate(limit.unwrap_or(DEFAULT_CONFLICT_LIMIT));
+    Ok(conflicts)
+}
+
+/// Az ütközésnapló ürítése (a már feloldott ütközések után)
+#[tauri::command]
+pub fn clear_sync_conflicts() -> Result<(), String> {
+    let path = conflicts_path()?;
+    if path.exists() {
+        fs::remove_file(&path).map_err(|e| format!("Nem sikerült törölni az ütközésnaplót: {}", e))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    /// Egy eszköz a szinkronizálási kör helyi részével (a megosztott mappa nélkül)
+    struct Device {
+        state: SyncState,
+        store: Map<String, Value>,
+    }
+
+    impl Device {
+        fn new(id: &str) -> Self {
+            let mut state = SyncState::new();
+            state.device_id = id.to_string();
+            Self { state, store: Map::new() }
+        }
+
+        /// A helyi óra előreállítása: a következő helyi változás biztosan későbbi a másik eszközénél
+        fn clock_ahead(&mut self) {
+            self.state.clock = Some(Hlc {
+                wall: chrono::Utc::now().timestamp_millis() + 60_000,
+                counter: 0,
+                device: self.state.device_id.clone(),
+            });
+        }
+
+        fn set(&mut self, entity_type: &str, records: Value) {
+            self.store.insert(entity_type.to_string(), records);
+        }
+
+        fn export(&mut self) -> Vec<FieldChange> {
+            collect_local_changes(&mut self.state, &self.store)
+        }
+
+        /// A run_sync beolvasási része: rekordonkénti alkalmazás, majd a pillanatkép frissítése
+        fn import(&mut self, changes: &[FieldChange]) -> (usize, Vec<SyncConflict>) {
+            let mut working = self.store.clone();
+            let mut context = ImportContext {
+                state: &mut self.state,
+                conflicts: Vec::new(),
+                applied: 0,
+                remote_device: "másik".to_string(),
+            };
+            for group in changes.chunk_by(|a, b| a.entity_type == b.entity_type && a.record_key == b.record_key) {
+                context.apply_record_changes(&mut working, group);
+            }
+            let (applied, conflicts) = (context.applied, context.conflicts);
+            for entity_type in JOURNALED_ENTITY_TYPES {
+                self.state.snapshot.insert(entity_type.to_string(), entity_records(&working, entity_type));
+            }
+            self.store = working;
+            (applied, conflicts)
+        }
+    }
+
+    /// Két eszköz, amelyek már szinkronizálták ugyanazt az árajánlatot
+    fn synced_pair() -> (Device, Device) {
+        let (mut a, mut b) = (Device::new("a"), Device::new("b"));
+        a.set("offers", json!([{ "id": 1, "customerName": "Kovács Éva", "price": 1000 }]));
+        let changes = a.export();
+        b.import(&changes);
+        assert_eq!(b.store["offers"], a.store["offers"]);
+        assert!(b.export().is_empty(), "a beolvasott rekord nem helyi változás");
+        (a, b)
+    }
+
+    #[test]
+    fn concurrent_edits_of_the_same_field_use_the_later_clock() {
+        let (mut a, mut b) = synced_pair();
+        a.set("offers", json!([{ "id": 1, "customerName": "Nagy Péter", "price": 1000 }]));
+        b.clock_ahead();
+        b.set("offers", json!([{ "id": 1, "customerName": "Szabó Anna", "price": 1000 }]));
+        let (from_a, from_b) = (a.export(), b.export());
+
+        let (_, conflicts) = a.import(&from_b);
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].winner, ConflictWinner::Remote);
+        let (applied, conflicts) = b.import(&from_a);
+        assert_eq!(applied, 0);
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].winner, ConflictWinner::Local);
+
+        assert_eq!(a.store["offers"][0]["customerName"], json!("Szabó Anna"));
+        assert_eq!(a.store["offers"], b.store["offers"]);
+    }
+
+    #[test]
+    fn delete_loses_against_a_later_unseen_edit() {
+        let (mut a, mut b) = synced_pair();
+        a.set("offers", json!([]));
+        let from_a = a.export();
+        assert!(from_a.iter().any(|c| c.field.is_none()), "a törlés mező nélküli változás");
+        b.clock_ahead();
+        b.set("offers", json!([{ "id": 1, "customerName": "Kovács Éva", "price": 2000 }]));
+        let from_b = b.export();
+
+        let (_, conflicts) = b.import(&from_a);
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].winner, ConflictWinner::Local);
+        assert_eq!(b.store["offers"].as_array().unwrap().len(), 1);
+
+        // A törlő eszközön a rekord az utolsó ismert tartalommal és a távoli módosítással visszaáll
+        let (_, conflicts) = a.import(&from_b);
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].winner, ConflictWinner::Remote);
+        assert_eq!(a.store["offers"], b.store["offers"]);
+        assert_eq!(a.store["offers"][0]["price"], json!(2000));
+    }
+
+    #[test]
+    fn record_recreated_after_delete_is_synced_without_conflict() {
+        let (mut a, mut b) = synced_pair();
+        a.set("offers", json!([]));
+        let deleted = a.export();
+        b.import(&deleted);
+        assert_eq!(b.store["offers"], json!([]));
+        assert!(b.state.tombstones["offers"].contains_key("1"));
+
+        a.set("offers", json!([{ "id": 1, "customerName": "Tóth Béla", "price": 3000 }]));
+        let recreated = a.export();
+        assert!(recreated.iter().all(|c| c.previous.is_none()), "az újra létrehozott rekord nem írja felül a régit");
+        assert!(!a.state.tombstones["offers"].contains_key("1"));
+
+        let (applied, conflicts) = b.import(&recreated);
+        assert!(conflicts.is_empty(), "{:?}", conflicts);
+        assert_eq!(applied, recreated.len());
+        assert_eq!(b.store["offers"], a.store["offers"]);
+        assert!(!b.state.tombstones["offers"].contains_key("1"));
+    }
+
+    #[test]
+    fn reimporting_a_change_set_changes_nothing() {
+        let (mut a, mut b) = synced_pair();
+        a.set("offers", json!([{ "id": 1, "customerName": "Kovács Éva", "price": 1500 }, { "id": 2, "customerName": "Új" }]));
+        let edited = a.export();
+        let (applied, _) = b.import(&edited);
+        assert_eq!(applied, edited.len());
+        let after_first = b.store.clone();
+
+        let (applied, conflicts) = b.import(&edited);
+        assert_eq!(applied, 0);
+        assert!(conflicts.is_empty());
+        assert_eq!(b.store, after_first);
+
+        a.set("offers", json!([{ "id": 2, "customerName": "Új" }]));
+        let deleted = a.export();
+        b.import(&deleted);
+        let (applied, conflicts) = b.import(&deleted);
+        assert_eq!(applied, 0);
+        assert!(conflicts.is_empty());
+        assert_eq!(b.store["offers"], a.store["offers"]);
+    }
+
+    #[test]
+    fn filament_key_change_is_a_delete_and_a_create() {
+        let (mut a, mut b) = (Device::new("a"), Device::new("b"));
+        a.set("filaments", json!([{ "brand": "Prusa", "type": "PLA", "color": "fekete", "pricePerKg": 8000 }]));
+        let created = a.export();
+        b.import(&created);
+
+        a.set("filaments", json!([{ "brand": "Prusa", "type": "PLA", "color": "fehér", "pricePerKg": 8000 }]));
+        let changes = a.export();
+        let deletes: Vec<&str> = changes.iter().filter(|c| c.field.is_none()).map(|c| c.record_key.as_str()).collect();
+        assert_eq!(deletes, vec!["prusa|pla|fekete"]);
+        assert!(changes
+            .iter()
+            .filter(|c| c.field.is_some())
+            .all(|c| c.record_key == "prusa|pla|fehér" && c.previous.is_none()));
+
+        let (_, conflicts) = b.import(&changes);
+        assert!(conflicts.is_empty());
+        assert_eq!(b.store["filaments"], a.store["filaments"]);
+    }
+}
@@ -0,0 +1,332 @@
+use crate::data_store;
+use crate::logger;
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashSet;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use tauri::AppHandle;
+
+lazy_static! {
+    /// A lomtár fájl olvasás-módosítás-írás műveleteinek sorosítása
+    static ref TRASH_LOCK: Mutex<()> = Mutex::new(());
+}
+
+/// Lomtárba helyezhető entitás típusok
+pub const TRASH_ENTITY_TYPES: [&str; 3] = ["offers", "customers", "filaments"];
+
+/// A lomtár tartalma: soronként egy elem (a vault soronként titkosítja, mint az audit logokat)
+const TRASH_FILE: &str = "trash.jsonl";
+
+/// Alapértelmezett megőrzés napokban, ha a beállításokban nincs megadva
+const DEFAULT_RETENTION_DAYS: u32 = 30;
+
+/// Egy törölt rekord a lomtárban
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrashItem {
+    pub id: String,
+    pub entity_type: String,
+    pub record_key: String,
+    /// Megjelenítendő név (ügyfél neve, filament márka / típus / szín)
+    pub label: String,
+    pub record: Value,
+    pub deleted_at: String,
+    /// A törlést végző felhasználó (alapból az operációs rendszer felhasználója)
+    pub deleted_by: String,
+}
+
+/// Visszaállítás eredménye
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrashRestoreResult {
+    pub restored: Vec<String>,
+    /// Azok az elemek, amelyek kulcsa már foglalt a Store-ban (ezek a lomtárban maradnak)
+    pub conflicts: Vec<String>,
+    pub safety_backup: Option<String>,
+}
+
+fn trash_path() -> Result<PathBuf, String> {
+    Ok(crate::paths::trash_dir()?.join(TRASH_FILE))
+}
+
+/// A lomtár fájl (ha létezik) - a vault ezt is titkosítja / visszafejti
+pub fn trash_files() -> Result<Vec<PathBuf>, String> {
+    let path = trash_path()?;
+    Ok(if path.exists() { vec![path] } else { Vec::new() })
+}
+
+fn ensure_unlocked() -> Result<(), String> {
+    if crate::vault::is_locked() {
+        return Err("Az adattároló zárolva van, előbb oldja fel".to_string());
+    }
+    Ok(())
+}
+
+fn read_items() -> Result<Vec<TrashItem>, String> {
+    let path = trash_path()?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content = fs::read_to_string(&path).map_err(|e| format!("Nem sikerült beolvasni a lomtárat: {}", e))?;
+    let content = crate::vault::open_lines(&content)?;
+    let mut items = Vec::new();
+    for line in content.lines().filter(|l| !l.trim().is_empty()) {
+        match serde_json::from_str::<TrashItem>(line) {
+            Ok(item) => items.push(item),
+            Err(e) => logger::log_warn(&format!("Sérült lomtár elem kihagyva: {}", e)),
+        }
+    }
+    Ok(items)
+}
+
+fn write_items(items: &[TrashItem]) -> Result<(), String> {
+    let path = trash_path()?;
+    let mut content = String::new();
+    for item in items {
+        let line = serde_json::to_string(item).map_err(|e| format!("Lomtár szerializálási hiba: {}", e))?;
+        content.push_str(&crate::vault::seal_line(&line)?);
+        content.push('\n');
+    }
+    crate::atomic_write::write_atomic(&path, content).map_err(|e| format!("Nem sikerült menteni a lomtárat: {}", e))
+}
+
+fn default_user() -> String {
+    std::env::var("USERNAME")
+        .or_else(|_| std::env::var("USER"))
+        .ok()
+        .filter(|name| !name.trim().is_empty())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+fn record_label(entity_type: &str, record: &Value) -> String {
+    let field = |name: &str| record.get(name).and_then(|v| v.as_str()).unwrap_or_default().trim();
+    let parts: Vec<&str> = match entity_type {
+        "offers" => vec![field("customerName"), field("description")],
+        "customers" => vec![field("name"), field("company")],
+        "filaments" => vec![field("brand"), field("type"), field("color")],
+        _ => Vec::new(),
+    };
+    parts.into_iter().filter(|p| !p.is_empty()).collect::<Vec<_>>().join(" - ")
+}
+
+/// A megőrzési idő a beállításokból (0 = soha ne törölje automatikusan)
+fn retention_days(store: Option<&Map<String, Value>>) -> u32 {
+    store
+        .and_then(|s| s.get("settings"))
+        .and_then(|s| s.get("trashRetentionDays"))
+        .and_then(|v| v.as_u64())
+        .map(|days| days as u32)
+        .unwrap_or(DEFAULT_RETENTION_DAYS)
+}
+
+/// A megőrzési időnél régebbi elemek végleges törlése (a zárolást a hívó tartja)
+fn purge_expired_locked(days: u32) -> Result<usize, String> {
+    if days == 0 {
+        return Ok(0);
+    }
+    let cutoff = chrono::Utc::now() - chrono::Duration::days(days as i64);
+    let items = read_items()?;
+    let before = items.len();
+    let kept: Vec<TrashItem> = items
+        .into_iter()
+        .filter(|item| {
+            chrono::DateTime::parse_from_rfc3339(&item.deleted_at)
+                .map(|deleted_at| deleted_at.with_timezone(&chrono::Utc) > cutoff)
+                .unwrap_or(true)
+        })
+        .collect();
+    let purged = before - kept.len();
+    if purged > 0 {
+        write_items(&kept)?;
+        logger::log_info(&format!("{} lejárt elem véglegesen törölve a lomtárból ({} nap után)", purged, days));
+    }
+    Ok(purged)
+}
+
+/// Lejárt elemek automatikus törlése (indításkor és a lomtár megnyitásakor)
+pub fn purge_expired() -> Result<usize, String> {
+    ensure_unlocked()?;
+    let days = retention_days(data_store::read_store()?.as_ref());
+    let _guard = TRASH_LOCK.lock().map_err(|e| format!("Lomtár zárolási hiba: {}", e))?;
+    purge_expired_locked(days)
+}
+
+/// Törölt rekordok lomtárba helyezése (a frontend a törlés előtt hívja)
+#[tauri::command]
+pub fn move_to_trash(entity_type: String, records: Vec<Value>, deleted_by: Option<String>) -> Result<Vec<TrashItem>, String> {
+    ensure_unlocked()?;
+    if !TRASH_ENTITY_TYPES.contains(&entity_type.as_str()) {
+        return Err(format!("Ez az entitás típus nem helyezhető lomtárba: {}", entity_type));
+    }
+
+    let deleted_at = chrono::Utc::now();
+    let deleted_by = deleted_by.filter(|name| !name.trim().is_empty()).unwrap_or_else(default_user);
+    let new_items: Vec<TrashItem> = records
+        .into_iter()
+        .filter_map(|record| {
+            let record_key = data_store::record_key(&entity_type, &record)?;
+            Some(TrashItem {
+                id: format!("{}-{}-{}", entity_type, record_key, deleted_at.timestamp_millis()),
+                label: record_label(&entity_type, &record),
+                entity_type: entity_type.clone(),
+                record_key,
+                record,
+                deleted_at: deleted_at.to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+                deleted_by: deleted_by.clone(),
+            })
+        })
+        .collect();
+    if new_items.is_empty() {
+        return Ok(new_items);
+    }
+
+    let _guard = TRASH_LOCK.lock().map_err(|e| format!("Lomtár zárolási hiba: {}", e))?;
+    let mut items = read_items()?;
+    items.extend(new_items.iter().cloned());
+    write_items(&items)?;
+    logger::log_info(&format!("{} {} rekord lomtárba helyezve ({})", new_items.len(), entity_type, deleted_by));
+    Ok(new_items)
+}
+
+/// A lomtár tartalma (legutóbb törölt elöl), előtte a lejárt elemek törlése
+#[tauri::command]
+pub fn list_trash(entity_type: Option<String>) -> Result<Vec<TrashItem>, String> {
+    if let Err(e) = purge_expired() {
+        logger::log_warn(&format!("Lejárt lomtár elemek törlése sikertelen: {}", e));
+    }
+    let _guard = TRASH_LOCK.lock().map_err(|e| format!("Lomtár zárolási hiba: {}", e))?;
+    let mut items: Vec<TrashItem> = read_items()?
+        .into_iter()
+        .filter(|item| entity_type.as_ref().map_or(true, |t| &item.entity_type == t))
+        .collect();
+    items.sort_by(|a, b| b.deleted_at.cmp(&a.deleted_at));
+    Ok(items)
+}
+
+/// Elemek visszaállítása a Store-ba (a foglalt kulcsú elemek a lomtárban maradnak)
+/// A backend írja a Store-t, a frontend a "data-store-changed" eseményre újratölti az adatokat
+#[tauri::command]
+pub fn restore_from_trash(app: AppHandle, ids: Vec<String>) -> Result<TrashRestoreResult, String> {
+    ensure_unlocked()?;
+    let _guard = TRASH_LOCK.lock().map_err(|e| format!("Lomtár zárolási hiba: {}", e))?;
+    let items = read_items()?;
+    let mut store = data_store::read_store()?.unwrap_or_default();
+
+    let wanted: HashSet<&String> = ids.iter().collect();
+    let mut restored_ids = HashSet::new();
+    let mut result = TrashRestoreResult {
+        restored: Vec::new(),
+        conflicts: Vec::new(),
+        safety_backup: None,
+    };
+    for item in items.iter().filter(|item| wanted.contains(&item.id)) {
+        let list = store
+            .entry(item.entity_type.clone())
+            .or_insert_with(|| Value::Array(Vec::new()));
+        let Some(list) = list.as_array_mut() else {
+            return Err(format!("Érvénytelen Store szerkezet: {}", item.entity_type));
+        };
+        let taken = list
+            .iter()
+            .any(|record| data_store::record_key(&item.entity_type, record).as_deref() == Some(item.record_key.as_str()));
+        if taken {
+            result.conflicts.push(item.id.clone());
+            continue;
+        }
+        list.push(item.record.clone());
+        restored_ids.insert(item.id.clone());
+        result.restored.push(item.id.clone());
+    }
+
+    if restored_ids.is_empty() {
+        return Ok(result);
+    }
+
+    let safety_backup = crate::restore::apply_store_data(&app, &store, "trash")?;
+    result.safety_backup = safety_backup.map(|path| path.to_string_lossy().to_string());
+    let remaining: Vec<TrashItem> = items.into_iter().filter(|item| !restored_ids.contains(&item.id)).collect();
+    write_items(&remaining)?;
+    logger::log_info(&format!("{} elem visszaállítva a lomtárból", result.restored.len()));
+    Ok(result)
+}
+
+/// Végleges törlés a lomtárból (azonosítók nélkül a teljes lomtár ürítése)
+#[tauri::command]
+pub fn purge_trash(ids: Option<Vec<String>>) -> Result<usize, String> {
+    ensure_unlocked()?;
+    let _guard = TRASH_LOCK.lock().map_err(|e| format!("Lomtár zárolási hiba: {}", e))?;
+    let items = read_items()?;
+    let before = items.len();
+    let remaining: Vec<TrashItem> = match &ids {
+        Some(ids) => {
+            let ids: HashSet<&String> = ids.iter().collect();
+            items.into_iter().filter(|item| !ids.contains(&item.id)).collect()
+        }
+        None => Vec::new(),
+    };
+    let purged = before - remaining.len();
+    if purged > 0 {
+        write_items(&remaining)?;
+        logger::log_info(&format!("{} elem véglegesen törölve a lomtárból", purged));
+    }
+    Ok(purged)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn item(id: &str, deleted_at: String) -> TrashItem {
+        TrashItem {
+            id: id.to_string(),
+            entity_type: "offers".to_string(),
+            record_key: id.to_string(),
+            label: String::new(),
+            record: json!({ "id": id }),
+            deleted_at,
+            deleted_by: "teszt".to_string(),
+        }
+    }
+
+    fn days_ago(days: i64) -> String {
+        (chrono::Utc::now() - chrono::Duration::days(days)).to_rfc3339()
+    }
+
+    #[test]
+    fn only_items_older_than_the_retention_are_purged() {
+        crate::paths::test_support::data_root();
+        let _guard = crate::paths::test_support::lock();
+        write_items(&[
+            item("regi", days_ago(31)),
+            item("hatar", days_ago(29)),
+            item("friss", days_ago(0)),
+            item("ervenytelen-datum", "tegnap".to_string()),
+        ])
+        .unwrap();
+
+        assert_eq!(purge_expired_locked(0).unwrap(), 0, "0 nap: nincs automatikus törlés");
+        assert_eq!(read_items().unwrap().len(), 4);
+
+        assert_eq!(purge_expired_locked(30).unwrap(), 1);
+        let kept: Vec<String> = read_items().unwrap().into_iter().map(|item| item.id).collect();
+        assert_eq!(kept, vec!["hatar", "friss", "ervenytelen-datum"]);
+
+        assert_eq!(purge_expired_locked(1).unwrap(), 1);
+        assert_eq!(read_items().unwrap().len(), 2);
+
+        fs::remove_file(trash_path().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn retention_comes_from_the_settings() {
+        let store = |settings: Value| json!({ "settings": settings }).as_object().cloned().unwrap();
+        assert_eq!(retention_days(None), DEFAULT_RETENTION_DAYS);
+        assert_eq!(retention_days(Some(&store(json!({ "trashRetentionDays": 7 })))), 7);
+        assert_eq!(retention_days(Some(&store(json!({ "trashRetentionDays": 0 })))), 0);
+        assert_eq!(retention_days(Some(&store(json!({})))), DEFAULT_RETENTION_DAYS);
+    }
+}
@@ -0,0 +1,502 @@
+use crate::atomic_write;
+use crate::crypto::{self, EncryptionKey};
+use crate::data_store;
+use crate::logger;
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+lazy_static! {
+    /// A feloldott adattároló kulcsa (csak memóriában, zároláskor törlődik)
+    static ref VAULT_KEY: Mutex<Option<EncryptionKey>> = Mutex::new(None);
+}
+
+/// Titkosított audit log sorok előtagja
+const ENCRYPTED_LINE_PREFIX: &str = "vault:v1:";
+
+/// A jelmondat ellenőrzéséhez titkosított ismert szöveg
+const VERIFIER_PLAINTEXT: &[u8] = b"3DPrinterCalcApp vault";
+
+/// A titkosított Store fájl kiterjesztése (data.json → data.json.vault)
+const VAULT_STORE_EXTENSION: &str = "vault";
+
+/// Az adattároló metaadatai (vault.json) - titkosítatlan, nem tartalmaz titkot
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct VaultMetadata {
+    version: u32,
+    kdf_algorithm: String,
+    kdf_iterations: u32,
+    salt: String,
+    cipher: String,
+    verifier: String,
+    created_at: String,
+    updated_at: String,
+}
+
+/// Az adattároló állapota a frontend számára
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VaultStatus {
+    pub enabled: bool,
+    pub unlocked: bool,
+    pub store_encrypted: bool,
+    pub plaintext_store_present: bool,
+}
+
+fn metadata_path() -> Result<PathBuf, String> {
+    Ok(crate::paths::data_root()?.join("vault.json"))
+}
+
+fn audit_dir() -> Result<PathBuf, String> {
+    crate::paths::audit_logs_dir()
+}
+
+/// Egy data.json titkosított párja (data.json → data.json.vault)
+pub fn encrypted_store_file(store_file: &Path) -> PathBuf {
+    store_file.with_extension(format!("json.{}", VAULT_STORE_EXTENSION))
+}
+
+/// Az aktív munkaterület titkosított Store fájlja
+pub fn encrypted_store_path() -> Result<PathBuf, String> {
+    Ok(encrypted_store_file(&data_store::store_file_path()?))
+}
+
+fn read_metadata() -> Result<Option<VaultMetadata>, String> {
+    let path = metadata_path()?;
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content = fs::read_to_string(&path)
+        .map_err(|e| format!("Nem sikerült beolvasni a vault metaadatokat: {}", e))?;
+    serde_json::from_str(&content)
+        .map(Some)
+        .map_err(|e| format!("Érvénytelen vault metaadat fájl: {}", e))
+}
+
+fn write_metadata(metadata: &VaultMetadata) -> Result<(), String> {
+    let content = serde_json::to_string_pretty(metadata)
+        .map_err(|e| format!("Nem sikerült JSON-né alakítani a vault metaadatokat: {}", e))?;
+    atomic_write::write_atomic(&metadata_path()?, content)
+        .map_err(|e| format!("Nem sikerült írni a vault metaadatokat: {}", e))
+}
+
+/// Új kulcs és metaadat létrehozása egy jelmondathoz (friss sóval)
+fn new_metadata(passphrase: &str, created_at: Option<String>) -> Result<(VaultMetadata, EncryptionKey), String> {
+    let salt = crypto::generate_salt();
+    let key = crypto::derive_key(passphrase, &salt, crypto::KDF_ITERATIONS);
+    let now = chrono::Utc::now().to_rfc3339();
+
+    let metadata = VaultMetadata {
+        version: 1,
+        kdf_algorithm: crypto::KDF_ALGORITHM.to_string(),
+        kdf_iterations: crypto::KDF_ITERATIONS,
+        salt: crypto::to_base64(&salt),
+        cipher: crypto::CIPHER_ALGORITHM.to_string(),
+        verifier: crypto::to_base64(&crypto::encrypt(&key, VERIFIER_PLAINTEXT)?),
+        created_at: created_at.unwrap_or_else(|| now.clone()),
+        updated_at: now,
+    };
+    Ok((metadata, key))
+}
+
+/// Kulcs levezetése és ellenőrzése a metaadatokban tárolt verifier alapján
+fn unlock_key(metadata: &VaultMetadata, passphrase: &str) -> Result<EncryptionKey, String> {
+    let salt = crypto::from_base64(&metadata.salt)?;
+    let key = crypto::derive_key(passphrase, &salt, metadata.kdf_iterations);
+    let verifier = crypto::from_base64(&metadata.verifier)?;
+
+    match crypto::decrypt(&key, &verifier) {
+        Ok(plaintext) if plaintext == VERIFIER_PLAINTEXT => Ok(key),
+        _ => Err("Hibás jelmondat".to_string()),
+    }
+}
+
+fn current_key() -> Result<Option<EncryptionKey>, String> {
+    VAULT_KEY
+        .lock()
+        .map(|guard| *guard)
+        .map_err(|e| format!("Vault zárolási hiba: {}", e))
+}
+
+fn set_current_key(key: Option<EncryptionKey>) -> Result<(), String> {
+    let mut guard = VAULT_KEY.lock().map_err(|e| format!("Vault zárolási hiba: {}", e))?;
+    *guard = key;
+    Ok(())
+}
+
+/// Be van-e kapcsolva a titkosított adattároló
+pub fn is_enabled() -> bool {
+    metadata_path().map(|p| p.exists()).unwrap_or(false)
+}
+
+/// Zárolt-e az adattároló (bekapcsolt, de nincs kulcs a memóriában)
+pub fn is_locked() -> bool {
+    is_enabled() && !matches!(current_key(), Ok(Some(_)))
+}
+
+/// Fájl titkosítása a megadott kulccsal, atomikus írással (ne maradjon félbe írt fájl)
+fn write_encrypted_file(key: &EncryptionKey, plaintext: &[u8], target: &Path) -> Result<(), String> {
+    let encrypted = crypto::encrypt_file_bytes(key, plaintext)?;
+    atomic_write::write_atomic(target, encrypted)
+        .map_err(|e| format!("Nem sikerült írni a titkosított fájlt: {}", e))
+}
+
+/// A titkosított Store visszafejtése memóriába (a titkosítatlan tartalom nem kerül lemezre)
+/// None, ha még nincs Store; zárolt adattárolónál hibát ad
+pub fn read_sealed_store() -> Result<Option<Vec<u8>>, String> {
+    let key = current_key()?.ok_or_else(|| "Az adattároló zárolva van".to_string())?;
+    read_sealed_store_with(&key)
+}
+
+fn read_sealed_store_with(key: &EncryptionKey) -> Result<Option<Vec<u8>>, String> {
+    let vault_path = encrypted_store_path()?;
+    if !vault_path.exists() {
+        return Ok(None);
+    }
+    let encrypted = fs::read(&vault_path)
+        .map_err(|e| format!("Nem sikerült beolvasni a titkosított Store fájlt: {}", e))?;
+    crypto::decrypt_file_bytes(key, &encrypted).map(Some)
+}
+
+/// A Store tartalmának titkosított kiírása (minden mentés így történik, amíg az adattároló be van kapcsolva)
+pub fn write_sealed_store(plaintext: &[u8]) -> Result<(), String> {
+    let key = current_key()?.ok_or_else(|| "Az adattároló zárolva van, a mentéshez előbb oldja fel".to_string())?;
+    write_encrypted_file(&key, plaintext, &encrypted_store_path()?)
+}
+
+/// A titkosítatlan data.json beolvasztása a titkosított Store-ba, majd törlése
+/// - bekapcsoláskor ez a titkosítandó Store
+/// - feloldáskor régebbi verzió (vagy kilépéskor mentő plugin) által hagyott példány: ha újabb, azt tartjuk meg
+fn seal_plaintext_store(key: &EncryptionKey) -> Result<bool, String> {
+    let store_path = data_store::store_file_path()?;
+    if !store_path.exists() {
+        return Ok(false);
+    }
+    let vault_path = encrypted_store_path()?;
+
+    let modified = |p: &Path| fs::metadata(p).and_then(|m| m.modified()).ok();
+    let plaintext_is_newer = !vault_path.exists() || modified(&store_path) >= modified(&vault_path);
+    if plaintext_is_newer {
+        let plaintext = fs::read(&store_path)
+            .map_err(|e| format!("Nem sikerült beolvasni a Store fájlt: {}", e))?;
+        write_encrypted_file(key, &plaintext, &vault_path)?;
+    } else {
+        logger::log_warn("Elavult titkosítatlan data.json található, a titkosított példányt használjuk");
+    }
+
+    fs::remove_file(&store_path)
+        .map_err(|e| format!("Nem sikerült törölni a titkosítatlan Store fájlt: {}", e))?;
+    Ok(plaintext_is_newer)
+}
+
+/// A titkosított Store visszafejtése data.json-ba (csak a titkosítás kikapcsolásakor)
+fn unseal_store(key: &EncryptionKey) -> Result<(), String> {
+    seal_plaintext_store(key)?;
+    let Some(plaintext) = read_sealed_store_with(key)? else {
+        return Ok(());
+    };
+    atomic_write::write_atomic(&data_store::store_file_path()?, plaintext)
+        .map_err(|e| format!("Nem sikerült visszaírni a Store fájlt: {}", e))
+}
+
+/// Audit log sor titkosítása, ha az adattároló be van kapcsolva
+/// Zárolt adattároló esetén hibát ad, hogy ne kerüljön ki titkosítatlan sor
+pub fn seal_line(line: &str) -> Result<String, String> {
+    if !is_enabled() {
+        return Ok(line.to_string());
+    }
+    let key = current_key()?.ok_or_else(|| "Az adattároló zárolva van".to_string())?;
+    encrypt_line(&key, line)
+}
+
+fn encrypt_line(key: &EncryptionKey, line: &str) -> Result<String, String> {
+    Ok(format!(
+        "{}{}",
+        ENCRYPTED_LINE_PREFIX,
+        crypto::to_base64(&crypto::encrypt(key, line.as_bytes())?)
+    ))
+}
+
+/// Titkosított (seal_line által írt) érték-e
+pub fn is_sealed(line: &str) -> bool {
+    line.starts_with(ENCRYPTED_LINE_PREFIX)
+}
+
+/// Egyetlen seal_line által titkosított érték visszafejtése (a titkosítatlan érték változatlan marad)
+pub fn open_line(line: &str) -> Result<String, String> {
+    if !is_sealed(line) {
+        return Ok(line.to_string());
+    }
+    let key = current_key()?.ok_or_else(|| "Az adattároló zárolva van".to_string())?;
+    decrypt_line(&key, line)
+}
+
+/// A beállításokban tárolt titkok (WebDAV jelszavak) átírása bekapcsoláskor, jelmondat cserekor és kikapcsoláskor
+fn rewrite_secrets(mut f: impl FnMut(&str) -> Result<String, String>) -> Result<(), String> {
+    crate::sync::rewrite_webdav_secret(&mut f)?;
+    crate::backup_destinations::rewrite_webdav_secrets(&mut f)
+}
+
+/// Audit log fájl tartalmának visszafejtése soronként (a titkosítatlan sorok változatlanok maradnak)
+pub fn open_lines(content: &str) -> Result<String, String> {
+    if !content.lines().any(|l| l.starts_with(ENCRYPTED_LINE_PREFIX)) {
+        return Ok(content.to_string());
+    }
+    let key = current_key()?.ok_or_else(|| "Az adattároló zárolva van".to_string())?;
+    transform_lines(content, |line| decrypt_line(&key, line))
+}
+
+fn decrypt_line(key: &EncryptionKey, line: &str) -> Result<String, String> {
+    match line.strip_prefix(ENCRYPTED_LINE_PREFIX) {
+        Some(encoded) => {
+            let plaintext = crypto::decrypt(key, &crypto::from_base64(encoded)?)?;
+            String::from_utf8(plaintext).map_err(|e| format!("Érvénytelen UTF-8 audit sor: {}", e))
+        }
+        None => Ok(line.to_string()),
+    }
+}
+
+fn transform_lines(content: &str, mut f: impl FnMut(&str) -> Result<String, String>) -> Result<String, String> {
+    let mut output = String::with_capacity(content.len());
+    for line in content.lines().filter(|l| !l.trim().is_empty()) {
+        output.push_str(&f(line)?);
+        output.push('\n');
+    }
+    Ok(output)
+}
+
+/// Az összes audit log és változásnapló fájl átírása soronként (titkosítás, visszafejtés vagy kulcscsere)
+fn rewrite_audit_logs(mut f: impl FnMut(&str) -> Result<String, String>) -> Result<u32, String> {
+    let dir = audit_dir()?;
+    let mut files = Vec::new();
+    if dir.exists() {
+        let entries = fs::read_dir(&dir).map_err(|e| format!("Nem sikerült olvasni az audit könyvtárat: {}", e))?;
+        files.extend(entries.flatten().map(|e| e.path()).filter(|path| {
+            path.file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|n| n.starts_with("audit-") && n.ends_with(".json"))
+        }));
+    }
+    // A változásnapló, a lomtár és a szinkronizálási állapot a rekordok teljes tartalmát hordozza, ugyanúgy védeni kell
+    files.extend(crate::journal::journal_files()?);
+    files.extend(crate::trash::trash_files()?);
+    files.extend(crate::sync::sync_files()?);
+
+    let mut rewritten = 0;
+    for path in files {
+        let content = fs::read_to_string(&path)
+            .map_err(|e| format!("Nem sikerült beolvasni az audit log fájlt: {} - {}", path.display(), e))?;
+        let transformed = transform_lines(&content, &mut f)?;
+        atomic_write::write_atomic(&path, transformed)
+            .map_err(|e| format!("Nem sikerült írni az audit log fájlt: {} - {}", path.display(), e))?;
+        rewritten += 1;
+    }
+
+    Ok(rewritten)
+}
+
+/// Kilépéskori zárolás: a kulcs eldobása (és az esetleg mégis lemezre került titkosítatlan Store törlése)
+pub fn lock_on_exit() {
+    if !is_enabled() {
+        return;
+    }
+    match lock_vault() {
+        Ok(_) => logger::log_info("Adattároló zárolva kilépéskor"),
+        Err(e) => logger::log_error(&format!("Nem sikerült zárolni az adattárolót kilépéskor: {}", e)),
+    }
+}
+
+/// Adattároló állapotának lekérése
+#[tauri::command]
+pub fn get_vault_status() -> Result<VaultStatus, String> {
+    Ok(VaultStatus {
+        enabled: is_enabled(),
+        unlocked: current_key()?.is_some(),
+        store_encrypted: encrypted_store_path()?.exists(),
+        plaintext_store_present: data_store::store_file_path()?.exists(),
+    })
+}
+
+/// A PBKDF2 kulcs levezetés (600 000 iteráció) másodpercekig tarthat, ezért a jelmondatos parancsok
+/// háttérszálon futnak, hogy a felület ne álljon meg (a többi hosszan futó parancs is ezt használja)
+pub(crate) async fn run_blocking<T: Send + 'static>(f: impl FnOnce() -> Result<T, String> + Send + 'static) -> Result<T, String> {
+    tauri::async_runtime::spawn_blocking(f)
+        .await
+        .map_err(|e| format!("Háttérszál hiba: {}", e))?
+}
+
+/// Titkosított adattároló bekapcsolása
+/// A Store és az audit log fájlok AES-256-GCM titkosítással, PBKDF2-vel levezetett kulccsal kerülnek mentésre
+/// A titkosítatlan data.json törlődik; ezután a Store csak memóriában létezik visszafejtve
+#[tauri::command]
+pub async fn enable_vault(passphrase: String) -> Result<VaultStatus, String> {
+    run_blocking(move || enable_vault_blocking(&passphrase)).await
+}
+
+fn enable_vault_blocking(passphrase: &str) -> Result<VaultStatus, String> {
+    if is_enabled() {
+        return Err("A titkosított adattároló már be van kapcsolva".to_string());
+    }
+    crypto::validate_passphrase(passphrase)?;
+
+    let (metadata, key) = new_metadata(passphrase, None)?;
+    let audit_files = rewrite_audit_logs(|line| {
+        if line.starts_with(ENCRYPTED_LINE_PREFIX) {
+            Ok(line.to_string())
+        } else {
+            encrypt_line(&key, line)
+        }
+    })?;
+    rewrite_secrets(|secret| if is_sealed(secret) { Ok(secret.to_string()) } else { encrypt_line(&key, secret) })?;
+    seal_plaintext_store(&key)?;
+    write_metadata(&metadata)?;
+    set_current_key(Some(key))?;
+
+    logger::log_info(&format!(
+        "Titkosított adattároló bekapcsolva ({} audit log fájl titkosítva)",
+        audit_files
+    ));
+    get_vault_status()
+}
+
+/// Adattároló feloldása a jelmondattal
+/// A kulcs memóriába kerül (a Store visszafejtése mindig csak memóriában történik), majd lefutnak
+/// a séma migrációk és az index építés
+#[tauri::command]
+pub async fn unlock_vault(passphrase: String) -> Result<VaultStatus, String> {
+    run_blocking(move || unlock_vault_blocking(&passphrase)).await
+}
+
+fn unlock_vault_blocking(passphrase: &str) -> Result<VaultStatus, String> {
+    let metadata = read_metadata()?.ok_or_else(|| "A titkosított adattároló nincs bekapcsolva".to_string())?;
+    let key = unlock_key(&metadata, passphrase)?;
+
+    if seal_plaintext_store(&key)? {
+        logger::log_warn("Titkosítatlan data.json titkosítva és törölve feloldáskor");
+    }
+    set_current_key(Some(key))?;
+    logger::log_info("Adattároló feloldva");
+
+    // Zárolt állapotban indításkor ezek kimaradtak
+    if let Err(e) = crate::migrations::run_startup_migrations() {
+        logger::log_error(&format!("Séma migráció sikertelen feloldás után: {}", e));
+        lock_vault()?;
+        return Err(e);
+    }
+    if let Err(e) = crate::search::rebuild_from_store() {
+        logger::log_warn(&format!("Keresési index felépítése sikertelen: {}", e));
+    }
+
+    get_vault_status()
+}
+
+/// Adattároló zárolása: a kulcs eldobása (a Store lemezen mindig titkosított)
+#[tauri::command]
+pub fn lock_vault() -> Result<VaultStatus, String> {
+    if !is_enabled() {
+        return Err("A titkosított adattároló nincs bekapcsolva".to_string());
+    }
+
+    if let Some(key) = current_key()? {
+        seal_plaintext_store(&key)?;
+    }
+    set_current_key(None)?;
+
+    logger::log_info("Adattároló zárolva");
+    get_vault_status()
+}
+
+/// Jelmondat csere: új sóval és kulccsal újratitkosítja a Store-t és az audit log fájlokat
+#[tauri::command]
+pub async fn change_vault_passphrase(current_passphrase: String, new_passphrase: String) -> Result<VaultStatus, String> {
+    run_blocking(move || change_vault_passphrase_blocking(&current_passphrase, &new_passphrase)).await
+}
+
+fn change_vault_passphrase_blocking(current_passphrase: &str, new_passphrase: &str) -> Result<VaultStatus, String> {
+    let metadata = read_metadata()?.ok_or_else(|| "A titkosított adattároló nincs bekapcsolva".to_string())?;
+    crypto::validate_passphrase(new_passphrase)?;
+    let old_key = unlock_key(&metadata, current_passphrase)?;
+
+    seal_plaintext_store(&old_key)?;
+    let store = read_sealed_store_with(&old_key)?;
+
+    let (new_metadata, new_key) = new_metadata(new_passphrase, Some(metadata.created_at.clone()))?;
+    let audit_files = rewrite_audit_logs(|line| encrypt_line(&new_key, &decrypt_line(&old_key, line)?))?;
+    rewrite_secrets(|secret| encrypt_line(&new_key, &decrypt_line(&old_key, secret)?))?;
+    if let Some(plaintext) = store {
+        write_encrypted_file(&new_key, &plaintext, &encrypted_store_path()?)?;
+    }
+    write_metadata(&new_metadata)?;
+    set_current_key(Some(new_key))?;
+
+    logger::log_info(&format!(
+        "Adattároló jelmondat cserélve ({} audit log fájl újratitkosítva)",
+        audit_files
+    ));
+    get_vault_status()
+}
+
+/// Titkosított adattároló kikapcsolása: minden visszafejtve, a metaadatok törölve
+#[tauri::command]
+pub async fn disable_vault(passphrase: String) -> Result<VaultStatus, String> {
+    run_blocking(move || disable_vault_blocking(&passphrase)).await
+}
+
+fn disable_vault_blocking(passphrase: &str) -> Result<VaultStatus, String> {
+    let metadata = read_metadata()?.ok_or_else(|| "A titkosított adattároló nincs bekapcsolva".to_string())?;
+    let key = unlock_key(&metadata, passphrase)?;
+
+    unseal_store(&key)?;
+    let audit_files = rewrite_audit_logs(|line| decrypt_line(&key, line))?;
+    rewrite_secrets(|secret| decrypt_line(&key, secret))?;
+
+    let vault_path = encrypted_store_path()?;
+    if vault_path.exists() {
+        fs::remove_file(&vault_path)
+            .map_err(|e| format!("Nem sikerült törölni a titkosított Store fájlt: {}", e))?;
+    }
+    fs::remove_file(metadata_path()?)
+        .map_err(|e| format!("Nem sikerült törölni a vault metaadatokat: {}", e))?;
+    set_current_key(None)?;
+
+    logger::log_info(&format!(
+        "Titkosított adattároló kikapcsolva ({} audit log fájl visszafejtve)",
+        audit_files
+    ));
+    get_vault_status()
+}
+
+/// A visszafejtett Store tartalma a frontend számára (bekapcsolt adattárolónál a tauri-plugin-store helyett)
+/// None, ha még nincs mentett adat
+#[tauri::command]
+pub fn read_vault_store() -> Result<Option<Map<String, Value>>, String> {
+    if !is_enabled() {
+        return Err("A titkosított adattároló nincs bekapcsolva".to_string());
+    }
+    data_store::read_store()
+}
+
+/// A frontend Store tartalmának titkosított mentése (a teljes tartalom, mint a tauri-plugin-store mentésnél)
+#[tauri::command]
+pub fn write_vault_store(data: Map<String, Value>) -> Result<(), String> {
+    if !is_enabled() {
+        return Err("A titkosított adattároló nincs bekapcsolva".to_string());
+    }
+    data_store::write_store(&data)
+}
+
+/// Titkosított backupokhoz: a vault kulcsa és a levezetés paraméterei (só, iterációk)
+/// Az így titkosított backup a vault jelmondattal nyitható meg; None, ha nincs bekapcsolva vagy zárolt
+pub fn backup_key() -> Result<Option<(EncryptionKey, Vec<u8>, u32)>, String> {
+    let Some(metadata) = read_metadata()? else {
+        return Ok(None);
+    };
+    let Some(key) = current_key()? else {
+        return Ok(None);
+    };
+    Ok(Some((key, crypto::from_base64(&metadata.salt)?, metadata.kdf_iterations)))
+}
@@ -0,0 +1,1198 @@
+use crate::logger;
+use base64::Engine;
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use sha2::Digest;
+use std::collections::HashMap;
+use std::fs;
+use std::io::Read;
+use std::path::Path;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Ennél nagyobb fájlokat darabokban töltünk fel, hogy megszakadt kapcsolat után folytatható legyen
+/// (a Nextcloud darabolt feltöltése legalább 5 MB-os darabokat vár, az utolsó kivételével)
+const CHUNK_SIZE: usize = 10 * 1024 * 1024;
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(15);
+const IO_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// A Nextcloud / ownCloud felhasználói fájl végpontja (darabolt feltöltésnél az uploads végpontot használjuk)
+const NEXTCLOUD_FILES_PATH: &str = "/remote.php/dav/files/";
+const NEXTCLOUD_UPLOADS_PATH: &str = "/remote.php/dav/uploads/";
+
+const PROPFIND_BODY: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<d:propfind xmlns:d="DAV:">
+  <d:prop>
+    <d:resourcetype/>
+    <d:getetag/>
+    <d:getcontentlength/>
+    <d:getlastmodified/>
+    <d:quota-available-bytes/>
+  </d:prop>
+</d:propfind>"#;
+
+/// Hitelesítés módja (auto: a szerver 401-es válaszában kért módszer, digest előnyben)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WebDavAuth {
+    #[default]
+    Auto,
+    Basic,
+    Digest,
+}
+
+/// Egy WebDAV gyűjtemény (mappa) elérése, pl. Nextcloud:
+/// https://felho.example.com/remote.php/dav/files/<felhasználó>/3DPrinterCalc/
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebDavSettings {
+    pub url: String,
+    #[serde(default)]
+    pub username: String,
+    /// Nextcloud esetén alkalmazásjelszó ajánlott
+    #[serde(default)]
+    pub password: String,
+    #[serde(default)]
+    pub auth: WebDavAuth,
+}
+
+impl WebDavSettings {
+    /// Mentéshez: bekapcsolt titkosított adattárolónál a jelszó titkosítva kerül a fájlba
+    /// (a már titkosított jelszó változatlan marad)
+    pub fn sealed(&self) -> Result<Self, String> {
+        let mut sealed = self.clone();
+        if !sealed.password.is_empty() && !crate::vault::is_sealed(&sealed.password) {
+            sealed.password = crate::vault::seal_line(&sealed.password)?;
+        }
+        Ok(sealed)
+    }
+
+    /// A frontend felé jelszó nélkül adjuk vissza (üres jelszóval mentve a korábbi marad)
+    pub fn redacted(&self) -> Self {
+        Self {
+            password: String::new(),
+            ..self.clone()
+        }
+    }
+
+    /// Üres jelszónál ugyanahhoz a címhez és felhasználóhoz a korábban mentett jelszó marad
+    pub fn keep_password_of(&mut self, previous: &WebDavSettings) {
+        if self.password.is_empty() && previous.url == self.url && previous.username == self.username {
+            self.password = previous.password.clone();
+        }
+    }
+
+    /// A tárolt jelszó átírása (vault bekapcsolás / jelmondat csere / kikapcsolás)
+    pub fn rewrite_password(&mut self, f: &mut dyn FnMut(&str) -> Result<String, String>) -> Result<(), String> {
+        if !self.password.is_empty() {
+            self.password = f(&self.password)?;
+        }
+        Ok(())
+    }
+}
+
+/// Egy elem a PROPFIND válaszból
+#[derive(Debug, Clone, Default)]
+pub struct DavEntry {
+    /// Az elem neve (dekódolva, a gyűjteményeknél záró perjel nélkül)
+    pub name: String,
+    pub href: String,
+    pub is_collection: bool,
+    pub etag: Option<String>,
+    pub size: Option<u64>,
+    pub last_modified: Option<chrono::DateTime<chrono::Utc>>,
+    pub quota_available_bytes: Option<u64>,
+}
+
+/// Feltöltés eredménye
+#[derive(Debug, Clone)]
+pub struct UploadOutcome {
+    pub etag: Option<String>,
+    pub size: u64,
+    /// Egy korábbi, megszakadt feltöltésből már a szerveren lévő bájtok
+    pub resumed_bytes: u64,
+}
+
+/// Kapcsolatteszt eredménye (semmit nem ír a szerverre)
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebDavTestReport {
+    /// Elérhető, a bejelentkezés elfogadott és a gyűjtemény listázható (vagy még nem létezik)
+    pub success: bool,
+    pub url: String,
+    pub reachable: bool,
+    pub authenticated: bool,
+    pub auth_scheme: Option<String>,
+    /// A DAV fejléc (támogatott WebDAV osztályok, pl. "1, 3")
+    pub dav_classes: Option<String>,
+    pub server: Option<String>,
+    pub collection_exists: bool,
+    pub collection_etag: Option<String>,
+    pub entry_count: usize,
+    pub quota_available_bytes: Option<u64>,
+    /// Nextcloud darabolt (folytatható) feltöltés elérhető
+    pub chunked_upload: bool,
+    pub message: String,
+}
+
+#[derive(Debug, Clone)]
+struct DigestChallenge {
+    realm: String,
+    nonce: String,
+    opaque: Option<String>,
+    qop_auth: bool,
+    algorithm: String,
+}
+
+#[derive(Debug, Clone)]
+enum Challenge {
+    Basic,
+    Digest(DigestChallenge),
+}
+
+/// Blokkoló WebDAV kliens (háttérszálakból és parancsokból hívjuk)
+pub struct WebDavClient {
+    /// A gyűjtemény címe, mindig perjelre végződik
+    base: String,
+    settings: WebDavSettings,
+    agent: ureq::Agent,
+    challenge: Mutex<Option<Challenge>>,
+    nonce_count: AtomicU32,
+}
+
+/// Egy útvonal szegmens URL kódolása (RFC 3986 unreserved karakterek maradnak)
+fn encode_segment(segment: &str) -> String {
+    let mut encoded = String::with_capacity(segment.len());
+    for byte in segment.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => encoded.push(byte as char),
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+fn decode_percent(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).unwrap_or("");
+            if let Ok(byte) = u8::from_str_radix(hex, 16) {
+                decoded.push(byte);
+                i += 3;
+                continue;
+            }
+        }
+        decoded.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&decoded).to_string()
+}
+
+/// Relatív útvonal (perjellel elválasztott szegmensek) URL kódolása
+fn encode_path(path: &str) -> String {
+    let trailing = path.ends_with('/');
+    let encoded: Vec<String> = path.split('/').filter(|s| !s.is_empty()).map(encode_segment).collect();
+    let mut result = encoded.join("/");
+    if trailing && !result.is_empty() {
+        result.push('/');
+    }
+    result
+}
+
+/// Az URL útvonal része a lekérdezéssel (a digest hitelesítés "uri" mezője)
+fn request_uri(url: &str) -> &str {
+    let after_scheme = url.find("://").map(|i| i + 3).unwrap_or(0);
+    url[after_scheme..].find('/').map(|i| &url[after_scheme + i..]).unwrap_or("/")
+}
+
+fn random_hex(len: usize) -> String {
+    let mut bytes = vec![0u8; len];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    hex::encode(bytes)
+}
+
+/// `kulcs=érték` / `kulcs="érték"` párok egy WWW-Authenticate fejlécből
+fn parse_auth_params(params: &str) -> HashMap<String, String> {
+    let mut result = HashMap::new();
+    let mut rest = params.trim();
+    while !rest.is_empty() {
+        let Some(eq) = rest.find('=') else {
+            break;
+        };
+        let key = rest[..eq].trim().trim_start_matches(',').trim().to_lowercase();
+        rest = rest[eq + 1..].trim_start();
+        let value;
+        if let Some(stripped) = rest.strip_prefix('"') {
+            let end = stripped.find('"').unwrap_or(stripped.len());
+            value = stripped[..end].to_string();
+            rest = stripped.get(end + 1..).unwrap_or("");
+        } else {
+            let end = rest.find(',').unwrap_or(rest.len());
+            value = rest[..end].trim().to_string();
+            rest = &rest[end..];
+        }
+        rest = rest.trim_start().trim_start_matches(',').trim_start();
+        result.insert(key, value);
+    }
+    result
+}
+
+fn parse_challenges(headers: &[String]) -> (Option<DigestChallenge>, bool) {
+    let mut digest = None;
+    let mut basic = false;
+    for header in headers {
+        let trimmed = header.trim();
+        let (scheme, params) = trimmed.split_once(' ').unwrap_or((trimmed, ""));
+        if scheme.eq_ignore_ascii_case("basic") {
+            basic = true;
+        } else if scheme.eq_ignore_ascii_case("digest") {
+            let params = parse_auth_params(params);
+            let algorithm = params.get("algorithm").cloned().unwrap_or_else(|| "MD5".to_string());
+            let supported = ["MD5", "MD5-SESS", "SHA-256", "SHA-256-SESS"].contains(&algorithm.to_uppercase().as_str());
+            // Több digest ajánlat közül az erősebbet választjuk
+            let better = digest
+                .as_ref()
+                .map_or(true, |current: &DigestChallenge| !current.algorithm.to_uppercase().starts_with("SHA-256"));
+            if supported && better {
+                digest = Some(DigestChallenge {
+                    realm: params.get("realm").cloned().unwrap_or_default(),
+                    nonce: params.get("nonce").cloned().unwrap_or_default(),
+                    opaque: params.get("opaque").cloned(),
+                    qop_auth: params
+                        .get("qop")
+                        .is_some_and(|qop| qop.split(',').any(|q| q.trim().eq_ignore_ascii_case("auth"))),
+                    algorithm,
+                });
+            }
+        }
+    }
+    (digest, basic)
+}
+
+fn hash_hex(algorithm: &str, value: &str) -> String {
+    if algorithm.to_uppercase().starts_with("SHA-256") {
+        hex::encode(sha2::Sha256::digest(value.as_bytes()))
+    } else {
+        hex::encode(md5::Md5::digest(value.as_bytes()))
+    }
+}
+
+/// A digest "response" mező (RFC 7616; qop nélkül RFC 2069)
+fn digest_response(
+    digest: &DigestChallenge,
+    method: &str,
+    uri: &str,
+    username: &str,
+    password: &str,
+    nc: &str,
+    cnonce: &str,
+) -> String {
+    let algorithm = digest.algorithm.as_str();
+    let mut ha1 = hash_hex(algorithm, &format!("{}:{}:{}", username, digest.realm, password));
+    if algorithm.to_uppercase().ends_with("-SESS") {
+        ha1 = hash_hex(algorithm, &format!("{}:{}:{}", ha1, digest.nonce, cnonce));
+    }
+    let ha2 = hash_hex(algorithm, &format!("{}:{}", method, uri));
+    if digest.qop_auth {
+        hash_hex(algorithm, &format!("{}:{}:{}:{}:auth:{}", ha1, digest.nonce, nc, cnonce, ha2))
+    } else {
+        hash_hex(algorithm, &format!("{}:{}:{}", ha1, digest.nonce, ha2))
+    }
+}
+
+fn is_success(status: u16) -> bool {
+    (200..300).contains(&status)
+}
+
+fn header_value(response: &ureq::Response, name: &str) -> Option<String> {
+    response.header(name).map(|v| v.to_string()).filter(|v| !v.is_empty())
+}
+
+/// PROPFIND (207 Multi-Status) válasz feldolgozása, a névtér előtagoktól függetlenül
+fn parse_multistatus(xml: &str) -> Result<Vec<DavEntry>, String> {
+    let mut reader = Reader::from_str(xml);
+    let mut entries = Vec::new();
+    let mut current: Option<DavEntry> = None;
+    let mut element = String::new();
+    // Csak a 200-as propstat értékeit vesszük figyelembe (a 404-es a nem támogatott tulajdonságoké)
+    let mut propstat_values: Vec<(String, String)> = Vec::new();
+    let mut propstat_collection = false;
+    let mut propstat_ok = true;
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(e)) => {
+                let name = String::from_utf8_lossy(e.local_name().as_ref()).to_lowercase();
+                match name.as_str() {
+                    "response" => current = Some(DavEntry::default()),
+                    "propstat" => {
+                        propstat_values.clear();
+                        propstat_collection = false;
+                        propstat_ok = true;
+                    }
+                    _ => {}
+                }
+                element = name;
+            }
+            Ok(Event::Empty(e)) if e.local_name().as_ref().eq_ignore_ascii_case(b"collection") => {
+                propstat_collection = true;
+            }
+            Ok(Event::Text(e)) => {
+                let text = e.unescape().map_err(|e| format!("Hibás WebDAV válasz: {}", e))?.trim().to_string();
+                if text.is_empty() {
+                    continue;
+                }
+                match element.as_str() {
+                    "href" => {
+                        if let Some(entry) = current.as_mut() {
+                            entry.href = text;
+                        }
+                    }
+                    "status" => propstat_ok = text.split_whitespace().nth(1) == Some("200"),
+                    _ => propstat_values.push((element.clone(), text)),
+                }
+            }
+            Ok(Event::End(e)) => {
+                let name = String::from_utf8_lossy(e.local_name().as_ref()).to_lowercase();
+                match name.as_str() {
+                    "propstat" if propstat_ok => {
+                        if let Some(entry) = current.as_mut() {
+                            entry.is_collection |= propstat_collection;
+                            for (key, value) in propstat_values.drain(..) {
+                                match key.as_str() {
+                                    "getetag" => entry.etag = Some(value),
+                                    "getcontentlength" => entry.size = value.parse().ok(),
+                                    "getlastmodified" => {
+                                        entry.last_modified = chrono::DateTime::parse_from_rfc2822(&value)
+                                            .ok()
+                                            .map(|ts| ts.with_timezone(&chrono::Utc))
+                                    }
+                                    "quota-available-bytes" => entry.quota_available_bytes = value.parse().ok(),
+                                    _ => {}
+                                }
+                            }
+                        }
+                    }
+                    "response" => {
+                        if let Some(mut entry) = current.take() {
+                            let path = decode_percent(request_uri(&entry.href));
+                            entry.name = path.trim_end_matches('/').rsplit('/').next().unwrap_or_default().to_string();
+                            entries.push(entry);
+                        }
+                    }
+                    _ => {}
+                }
+                element.clear();
+            }
+            Ok(Event::Eof) => break,
+            Err(e) => return Err(format!("Hibás WebDAV válasz: {}", e)),
+            _ => {}
+        }
+    }
+    Ok(entries)
+}
+
+impl WebDavClient {
+    pub fn new(settings: &WebDavSettings) -> Result<Self, String> {
+        let url = settings.url.trim();
+        if !(url.starts_with("https://") || url.starts_with("http://")) {
+            return Err("A WebDAV címnek http:// vagy https:// előtaggal kell kezdődnie".to_string());
+        }
+        // A beírt címben lehetnek ékezetes vagy szóközt tartalmazó mappanevek: szegmensenként újrakódoljuk
+        let path_start = url.find("://").map(|i| i + 3).unwrap_or(0);
+        let (origin, path) = url.split_at(url[path_start..].find('/').map(|i| path_start + i).unwrap_or(url.len()));
+        let path: Vec<String> = path
+            .split('/')
+            .filter(|s| !s.is_empty())
+            .map(|segment| encode_segment(&decode_percent(segment)))
+            .collect();
+        let base = if path.is_empty() { format!("{}/", origin) } else { format!("{}/{}/", origin, path.join("/")) };
+        let agent = ureq::AgentBuilder::new()
+            .timeout_connect(CONNECT_TIMEOUT)
+            .timeout_read(IO_TIMEOUT)
+            .timeout_write(IO_TIMEOUT)
+            .build();
+        // Basic módban rögtön küldjük a hitelesítést (egy felesleges 401-es kör nélkül)
+        let challenge = (settings.auth == WebDavAuth::Basic).then_some(Challenge::Basic);
+        // A mentett jelszó titkosítva lehet (bekapcsolt adattárolónál)
+        let settings = WebDavSettings {
+            password: crate::vault::open_line(&settings.password)?,
+            ..settings.clone()
+        };
+        Ok(Self {
+            base,
+            settings,
+            agent,
+            challenge: Mutex::new(challenge),
+            nonce_count: AtomicU32::new(0),
+        })
+    }
+
+    /// Egy relatív útvonal teljes URL-je (a gyűjteményekhez perjellel a végén)
+    pub fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base, encode_path(path))
+    }
+
+    fn authorization(&self, method: &str, url: &str) -> Option<String> {
+        let challenge = self.challenge.lock().unwrap_or_else(|e| e.into_inner()).clone()?;
+        let (username, password) = (&self.settings.username, &self.settings.password);
+        match challenge {
+            Challenge::Basic => Some(format!(
+                "Basic {}",
+                base64::engine::general_purpose::STANDARD.encode(format!("{}:{}", username, password))
+            )),
+            Challenge::Digest(digest) => {
+                let uri = request_uri(url);
+                let cnonce = random_hex(8);
+                let nc = format!("{:08x}", self.nonce_count.fetch_add(1, Ordering::SeqCst) + 1);
+                let response = digest_response(&digest, method, uri, username, password, &nc, &cnonce);
+                let mut header = format!(
+                    "Digest username=\"{}\", realm=\"{}\", nonce=\"{}\", uri=\"{}\", algorithm={}, response=\"{}\"",
+                    username, digest.realm, digest.nonce, uri, digest.algorithm, response
+                );
+                if digest.qop_auth {
+                    header.push_str(&format!(", qop=auth, nc={}, cnonce=\"{}\"", nc, cnonce));
+                }
+                if let Some(opaque) = &digest.opaque {
+                    header.push_str(&format!(", opaque=\"{}\"", opaque));
+                }
+                Some(header)
+            }
+        }
+    }
+
+    /// A 401-es válasz hitelesítési ajánlatának elfogadása (digest előnyben, ha a beállítás engedi)
+    fn accept_challenge(&self, headers: &[String]) -> bool {
+        let (digest, basic) = parse_challenges(headers);
+        let mut challenge = self.challenge.lock().unwrap_or_else(|e| e.into_inner());
+        match (digest, self.settings.auth) {
+            (Some(digest), WebDavAuth::Auto | WebDavAuth::Digest) => {
+                *challenge = Some(Challenge::Digest(digest));
+                self.nonce_count.store(0, Ordering::SeqCst);
+                true
+            }
+            _ if basic && self.settings.auth != WebDavAuth::Digest && !matches!(*challenge, Some(Challenge::Basic)) => {
+                if self.base.starts_with("http://") {
+                    logger::log_warn("WebDAV: basic hitelesítés titkosítatlan (http://) kapcsolaton");
+                }
+                *challenge = Some(Challenge::Basic);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Kérés küldése hitelesítéssel; 401-re egyszer újrapróbálja a szerver által kért módszerrel
+    /// A HTTP hibakódokat nem alakítja hibává (a hívó dönt), csak a hálózati hibákat
+    fn send(&self, method: &str, url: &str, headers: &[(&str, String)], body: Option<&[u8]>) -> Result<ureq::Response, String> {
+        let mut retried = false;
+        loop {
+            let mut request = self.agent.request(method, url);
+            for (name, value) in headers {
+                request = request.set(name, value);
+            }
+            if let Some(authorization) = self.authorization(method, url) {
+                request = request.set("Authorization", &authorization);
+            }
+            let result = match body {
+                Some(body) => request.send_bytes(body),
+                None => request.call(),
+            };
+            let response = match result {
+                Ok(response) => response,
+                Err(ureq::Error::Status(_, response)) => response,
+                Err(e) => return Err(format!("A WebDAV szerver nem érhető el: {}", e)),
+            };
+            if response.status() != 401 {
+                return Ok(response);
+            }
+            let challenges: Vec<String> = response.all("www-authenticate").into_iter().map(|h| h.to_string()).collect();
+            if retried || !self.accept_challenge(&challenges) {
+                return Err("A WebDAV szerver elutasította a bejelentkezést (hibás felhasználónév vagy jelszó?)".to_string());
+            }
+            retried = true;
+        }
+    }
+
+    fn status_error(&self, action: &str, url: &str, response: ureq::Response) -> String {
+        format!("WebDAV {} sikertelen ({}): HTTP {} {}", action, url, response.status(), response.status_text())
+    }
+
+    /// A használt hitelesítési mód (kapcsolatteszthez)
+    fn auth_scheme(&self) -> Option<String> {
+        match self.challenge.lock().unwrap_or_else(|e| e.into_inner()).as_ref()? {
+            Challenge::Basic => Some("basic".to_string()),
+            Challenge::Digest(digest) => Some(format!("digest ({})", digest.algorithm)),
+        }
+    }
+
+    /// PROPFIND a megadott mélységgel; nem létező elemnél `None`
+    pub fn propfind(&self, path: &str, depth: u8) -> Result<Option<Vec<DavEntry>>, String> {
+        let url = self.url(path);
+        self.propfind_url(&url, depth)
+    }
+
+    fn propfind_url(&self, url: &str, depth: u8) -> Result<Option<Vec<DavEntry>>, String> {
+        let headers = [
+            ("Depth", depth.to_string()),
+            ("Content-Type", "application/xml; charset=utf-8".to_string()),
+        ];
+        let response = self.send("PROPFIND", url, &headers, Some(PROPFIND_BODY.as_bytes()))?;
+        match response.status() {
+            207 => {
+                let body = response
+                    .into_string()
+                    .map_err(|e| format!("Nem sikerült olvasni a WebDAV választ: {}", e))?;
+                parse_multistatus(&body).map(Some)
+            }
+            404 => Ok(None),
+            _ => Err(self.status_error("listázás", url, response)),
+        }
+    }
+
+    /// Egy elem adatai (ETag, méret, módosítás ideje); nem létező elemnél `None`
+    pub fn stat(&self, path: &str) -> Result<Option<DavEntry>, String> {
+        Ok(self.propfind(path, 0)?.and_then(|entries| entries.into_iter().next()))
+    }
+
+    /// Egy gyűjtemény tartalma (maga a gyűjtemény nélkül); nem létező gyűjteménynél `None`
+    pub fn list(&self, path: &str) -> Result<Option<Vec<DavEntry>>, String> {
+        let collection = format!("{}/", path.trim_end_matches('/'));
+        let Some(mut entries) = self.propfind(&collection, 1)? else {
+            return Ok(None);
+        };
+        // Az első elem maga a lekérdezett gyűjtemény
+        let own_path = decode_percent(request_uri(&self.url(&collection))).trim_end_matches('/').to_string();
+        entries.retain(|entry| decode_percent(request_uri(&entry.href)).trim_end_matches('/') != own_path);
+        Ok(Some(entries))
+    }
+
+    /// A gyűjtemény létrehozása a hiányzó szülő gyűjteményekkel együtt (a meglévők változatlanok)
+    pub fn ensure_collection(&self, path: &str) -> Result<(), String> {
+        let url = self.url(&format!("{}/", path.trim_end_matches('/')));
+        self.make_collection(&url)
+    }
+
+    fn make_collection(&self, url: &str) -> Result<(), String> {
+        let response = self.send("MKCOL", url, &[], None)?;
+        match response.status() {
+            // 405: már létezik
+            status if is_success(status) || status == 405 => Ok(()),
+            // 409: hiányzik a szülő gyűjtemény
+            409 => {
+                let trimmed = url.trim_end_matches('/');
+                let parent = &trimmed[..trimmed.rfind('/').unwrap_or(0) + 1];
+                if request_uri(parent) == "/" || parent.len() >= url.len() {
+                    return Err(self.status_error("mappa létrehozás", url, response));
+                }
+                self.make_collection(parent)?;
+                let response = self.send("MKCOL", url, &[], None)?;
+                if is_success(response.status()) || response.status() == 405 {
+                    Ok(())
+                } else {
+                    Err(self.status_error("mappa létrehozás", url, response))
+                }
+            }
+            _ => Err(self.status_error("mappa létrehozás", url, response)),
+        }
+    }
+
+    /// Fájl feltöltése egy kérésben; `if_match` esetén csak akkor, ha a szerveren lévő ETag nem változott
+    pub fn put(&self, path: &str, content: &[u8], if_match: Option<&str>) -> Result<Option<String>, String> {
+        let url = self.url(path);
+        let mut headers = vec![("Content-Type", "application/octet-stream".to_string())];
+        if let Some(etag) = if_match {
+            headers.push(("If-Match", etag.to_string()));
+        }
+        let response = self.send("PUT", &url, &headers, Some(content))?;
+        match response.status() {
+            status if is_success(status) => Ok(header_value(&response, "ETag")),
+            412 => Err(format!("A fájl időközben megváltozott a szerveren: {}", path)),
+            _ => Err(self.status_error("feltöltés", &url, response)),
+        }
+    }
+
+    /// Fájl letöltése; nem létező fájlnál `None`
+    pub fn get(&self, path: &str) -> Result<Option<Vec<u8>>, String> {
+        let url = self.url(path);
+        let response = self.send("GET", &url, &[], None)?;
+        match response.status() {
+            200 => {
+                let mut content = Vec::new();
+                response
+                    .into_reader()
+                    .read_to_end(&mut content)
+                    .map_err(|e| format!("Nem sikerült letölteni a fájlt: {} - {}", path, e))?;
+                Ok(Some(content))
+            }
+            404 => Ok(None),
+            _ => Err(self.status_error("letöltés", &url, response)),
+        }
+    }
+
+    /// Fájl vagy gyűjtemény törlése (nem létező elem nem hiba)
+    pub fn delete(&self, path: &str) -> Result<(), String> {
+        self.delete_url(&self.url(path))
+    }
+
+    fn delete_url(&self, url: &str) -> Result<(), String> {
+        let response = self.send("DELETE", url, &[], None)?;
+        if is_success(response.status()) || response.status() == 404 {
+            Ok(())
+        } else {
+            Err(self.status_error("törlés", url, response))
+        }
+    }
+
+    fn move_url(&self, source_url: &str, destination_url: &str, extra: &[(&str, String)]) -> Result<Option<String>, String> {
+        let mut headers = vec![
+            ("Destination", destination_url.to_string()),
+            ("Overwrite", "T".to_string()),
+        ];
+        headers.extend(extra.iter().cloned());
+        let response = self.send("MOVE", source_url, &headers, None)?;
+        if is_success(response.status()) {
+            Ok(header_value(&response, "ETag"))
+        } else {
+            Err(self.status_error("áthelyezés", source_url, response))
+        }
+    }
+
+    /// A Nextcloud darabolt feltöltés gyűjteménye (csak /remote.php/dav/files/<felhasználó>/ alatti címnél)
+    fn nextcloud_uploads_url(&self, upload_id: &str) -> Option<String> {
+        let index = self.base.find(NEXTCLOUD_FILES_PATH)?;
+        let user = self.base[index + NEXTCLOUD_FILES_PATH.len()..].split('/').next()?;
+        (!user.is_empty()).then(|| format!("{}{}{}/{}/", &self.base[..index], NEXTCLOUD_UPLOADS_PATH, user, upload_id))
+    }
+
+    /// Folytatható feltöltés
+    /// - kis fájl: egy PUT kérés
+    /// - Nextcloud: darabolt feltöltés az uploads végpontra, a már feltöltött darabokat kihagyja, végül MOVE
+    /// - egyéb szerver: `.part` fájl Content-Range kérésekkel bővítve, végül MOVE; ha a szerver nem támogatja
+    ///   a részleges PUT-ot, a teljes fájlt tölti fel
+    ///
+    /// A feltöltés azonosítója a tartalom hash-éből képződik, így egy megszakadt feltöltést a következő próbálkozás folytat
+    /// (és csak ugyanannak a tartalomnak a maradékát, egy másik fájlét soha)
+    pub fn upload_file(&self, local: &Path, remote_path: &str) -> Result<UploadOutcome, String> {
+        let content = fs::read(local).map_err(|e| format!("Nem sikerült beolvasni a fájlt: {} - {}", local.display(), e))?;
+        let size = content.len() as u64;
+        let content_hash = crate::crypto::sha256_hex(&content);
+        let upload_id = format!("3dpc-{}", &content_hash[..24]);
+
+        let (etag, resumed_bytes) = if content.len() <= CHUNK_SIZE {
+            (self.put(remote_path, &content, None)?, 0)
+        } else if let Some(uploads_url) = self.nextcloud_uploads_url(&upload_id) {
+            self.upload_nextcloud_chunks(&uploads_url, &content, remote_path)?
+        } else {
+            self.upload_ranged(&content, &content_hash, remote_path)?
+        };
+
+        // Ellenőrzés: a szerveren lévő méret egyezik-e
+        let entry = self
+            .stat(remote_path)?
+            .ok_or_else(|| format!("A feltöltött fájl nem található a szerveren: {}", remote_path))?;
+        if entry.size.is_some_and(|remote_size| remote_size != size) {
+            return Err(format!(
+                "A feltöltött fájl mérete eltér: {} (helyi {} bájt, szerveren {} bájt)",
+                remote_path,
+                size,
+                entry.size.unwrap_or_default()
+            ));
+        }
+        Ok(UploadOutcome {
+            etag: entry.etag.or(etag),
+            size,
+            resumed_bytes,
+        })
+    }
+
+    fn upload_nextcloud_chunks(&self, uploads_url: &str, content: &[u8], remote_path: &str) -> Result<(Option<String>, u64), String> {
+        let destination = self.url(remote_path);
+        let total = content.len().to_string();
+        let headers = [("Destination", destination.clone())];
+        let response = self.send("MKCOL", uploads_url, &headers, None)?;
+        if !(is_success(response.status()) || response.status() == 405) {
+            return Err(self.status_error("darabolt feltöltés indítása", uploads_url, response));
+        }
+
+        let existing: HashMap<String, u64> = self
+            .propfind_url(uploads_url, 1)?
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|entry| Some((entry.name, entry.size?)))
+            .collect();
+        let mut resumed = 0u64;
+        for (index, chunk) in content.chunks(CHUNK_SIZE).enumerate() {
+            let name = format!("{:05}", index + 1);
+            if existing.get(&name) == Some(&(chunk.len() as u64)) {
+                resumed += chunk.len() as u64;
+                continue;
+            }
+            let url = format!("{}{}", uploads_url, name);
+            let headers = [("Destination", destination.clone()), ("OC-Total-Length", total.clone())];
+            let response = self.send("PUT", &url, &headers, Some(chunk))?;
+            if !is_success(response.status()) {
+                return Err(self.status_error("darab feltöltés", &url, response));
+            }
+        }
+        if resumed > 0 {
+            logger::log_info(&format!("WebDAV feltöltés folytatva: {} ({} bájt már a szerveren volt)", remote_path, resumed));
+        }
+        let etag = self.move_url(&format!("{}.file", uploads_url), &destination, &[("OC-Total-Length", total)])?;
+        Ok((etag, resumed))
+    }
+
+    /// A `.part` fájl neve a tartalom hash-ét is tartalmazza, így csak ugyanannak a forrásnak a megszakadt
+    /// feltöltése folytatódik; a folytatott feltöltés kész fájlját letöltve a hash-sel is ellenőrizzük,
+    /// eltérésnél a teljes fájl újra feltöltődik
+    fn upload_ranged(&self, content: &[u8], content_hash: &str, remote_path: &str) -> Result<(Option<String>, u64), String> {
+        let part_path = format!("{}.{}.part", remote_path, &content_hash[..16]);
+        self.remove_stale_parts(remote_path, &part_path);
+        let part_url = self.url(&part_path);
+        let total = content.len() as u64;
+        let mut offset = match self.stat(&part_path)?.and_then(|entry| entry.size) {
+            Some(size) if size <= total => size,
+            Some(_) => {
+                self.delete(&part_path)?;
+                0
+            }
+            None => 0,
+        };
+        let resumed = offset;
+        if resumed > 0 {
+            logger::log_info(&format!("WebDAV feltöltés folytatva: {} ({} bájt már a szerveren volt)", remote_path, resumed));
+        }
+
+        while offset < total {
+            let end = (offset + CHUNK_SIZE as u64).min(total);
+            let chunk = &content[offset as usize..end as usize];
+            let accepted = if offset == 0 {
+                self.put(&part_path, chunk, None).map(|_| true)?
+            } else {
+                let headers = [("Content-Range", format!("bytes {}-{}/{}", offset, end - 1, total))];
+                let response = self.send("PUT", &part_url, &headers, Some(chunk))?;
+                is_success(response.status())
+            };
+            // Ha a szerver elutasította vagy figyelmen kívül hagyta a Content-Range-et, a teljes fájlt töltjük fel
+            let stored = self.stat(&part_path)?.and_then(|entry| entry.size);
+            if !accepted || stored != Some(end) {
+                logger::log_info("A WebDAV szerver nem támogatja a részleges feltöltést, a teljes fájlt töltjük fel");
+                self.put(&part_path, content, None)?;
+                break;
+            }
+            offset = end;
+        }
+        let etag = self.move_url(&part_url, &self.url(remote_path), &[])?;
+        if resumed > 0 && !self.content_matches(remote_path, content_hash)? {
+            logger::log_warn(&format!(
+                "WebDAV: a folytatott feltöltés tartalma eltér, a teljes fájlt újra feltöltjük: {}",
+                remote_path
+            ));
+            return Ok((self.put(remote_path, content, None)?, 0));
+        }
+        Ok((etag, resumed))
+    }
+
+    /// A szerveren lévő fájl SHA-256 hash-e egyezik-e a várttal
+    fn content_matches(&self, remote_path: &str, content_hash: &str) -> Result<bool, String> {
+        Ok(self
+            .get(remote_path)?
+            .is_some_and(|remote| crate::crypto::sha256_hex(&remote) == content_hash))
+    }
+
+    /// Ugyanennek a fájlnak más tartalomhoz tartozó, félbemaradt `.part` feltöltései (legjobb igyekezettel)
+    fn remove_stale_parts(&self, remote_path: &str, keep_part_path: &str) {
+        let (dir, file_name) = remote_path.rsplit_once('/').unwrap_or(("", remote_path));
+        let keep_name = keep_part_path.rsplit('/').next().unwrap_or(keep_part_path);
+        let prefix = format!("{}.", file_name);
+        let entries = match self.list(dir) {
+            Ok(entries) => entries.unwrap_or_default(),
+            Err(e) => {
+                logger::log_warn(&format!("WebDAV: a félbemaradt feltöltések nem listázhatók: {}", e));
+                return;
+            }
+        };
+        for entry in entries {
+            if entry.is_collection || entry.name == keep_name || !(entry.name.starts_with(&prefix) && entry.name.ends_with(".part")) {
+                continue;
+            }
+            let stale_path = if dir.is_empty() { entry.name.clone() } else { format!("{}/{}", dir, entry.name) };
+            if let Err(e) = self.delete(&stale_path) {
+                logger::log_warn(&format!("WebDAV: félbemaradt feltöltés nem törölhető: {} - {}", stale_path, e));
+            }
+        }
+    }
+
+    /// Kapcsolatteszt írás nélkül: OPTIONS, majd PROPFIND a gyűjteményre
+    pub fn test_connection(&self) -> WebDavTestReport {
+        let mut report = WebDavTestReport {
+            url: self.base.clone(),
+            chunked_upload: self.nextcloud_uploads_url("test").is_some(),
+            ..Default::default()
+        };
+        match self.send("OPTIONS", &self.base, &[], None) {
+            Ok(response) => {
+                report.reachable = true;
+                report.authenticated = response.status() != 403;
+                report.dav_classes = header_value(&response, "DAV");
+                report.server = header_value(&response, "Server");
+            }
+            Err(e) => {
+                report.reachable = !e.starts_with("A WebDAV szerver nem érhető el");
+                report.message = e;
+                return report;
+            }
+        }
+        match self.propfind("", 1) {
+            Ok(Some(entries)) => {
+                report.authenticated = true;
+                report.collection_exists = true;
+                if let Some(own) = entries.first() {
+                    report.collection_etag = own.etag.clone();
+                    report.quota_available_bytes = own.quota_available_bytes;
+                }
+                report.entry_count = entries.len().saturating_sub(1);
+                report.success = true;
+                report.message = "A kapcsolat rendben".to_string();
+            }
+            Ok(None) => {
+                report.authenticated = true;
+                report.success = true;
+                report.message = "A szerver elérhető, a mappa még nem létezik (az első feltöltés létrehozza)".to_string();
+            }
+            Err(e) => report.message = e,
+        }
+        report.auth_scheme = self.auth_scheme();
+        report
+    }
+}
+
+/// WebDAV kapcsolat tesztelése a beállítások mentése előtt (semmit nem ír és nem töröl a szerveren)
+/// A hálózati időtúllépés percekig is tarthat, ezért háttérszálon fut
+/// Üres jelszónál (a frontend a mentett jelszót nem kapja meg) az azonos címhez mentett jelszóval tesztel
+#[tauri::command]
+pub async fn test_webdav_connection(settings: WebDavSettings) -> Result<WebDavTestReport, String> {
+    crate::vault::run_blocking(move || {
+        let mut settings = settings;
+        for previous in crate::sync::stored_webdav_settings()
+            .into_iter()
+            .chain(crate::backup_destinations::stored_webdav_settings())
+        {
+            settings.keep_password_of(&previous);
+        }
+        let client = WebDavClient::new(&settings)?;
+        let report = client.test_connection();
+        logger::log_info(&format!("WebDAV kapcsolatteszt: {} - {}", report.url, report.message));
+        Ok(report)
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn challenge(algorithm: &str, realm: &str, nonce: &str, qop_auth: bool) -> DigestChallenge {
+        DigestChallenge {
+            realm: realm.to_string(),
+            nonce: nonce.to_string(),
+            opaque: None,
+            qop_auth,
+            algorithm: algorithm.to_string(),
+        }
+    }
+
+    #[test]
+    fn digest_response_matches_rfc_examples() {
+        // RFC 2617 3.5
+        let rfc2617 = challenge("MD5", "testrealm@host.com", "dcd98b7102dd2f0e8b11d0f600bfb0c093", true);
+        assert_eq!(
+            digest_response(&rfc2617, "GET", "/dir/index.html", "Mufasa", "Circle Of Life", "00000001", "0a4f113b"),
+            "6629fae49393a05397450978507c4ef1",
+            "Az MD5 digest válasz eltér az RFC 2617 példától"
+        );
+
+        // RFC 7616 3.9.1 (ugyanaz a kérés MD5 és SHA-256 algoritmussal)
+        let nonce = "7ypf/xlj9XXwfDPEoM4URrv/xwf94BcCAzFZH4GiTo0v";
+        let cnonce = "f2/wE4q74E6zIJEtWaHKaf5wv/H5QzzpXusqGemxURZJ";
+        let md5 = challenge("MD5", "http-auth@example.org", nonce, true);
+        assert_eq!(
+            digest_response(&md5, "GET", "/dir/index.html", "Mufasa", "Circle of Life", "00000001", cnonce),
+            "8ca523f5e9506fed4657c9700eebdbec",
+            "Az MD5 digest válasz eltér az RFC 7616 példától"
+        );
+        let sha256 = challenge("SHA-256", "http-auth@example.org", nonce, true);
+        assert_eq!(
+            digest_response(&sha256, "GET", "/dir/index.html", "Mufasa", "Circle of Life", "00000001", cnonce),
+            "753927fa0e85d155564e2e272a28d1802ca10daf4496794697cf8db5856cb6c1",
+            "A SHA-256 digest válasz eltér az RFC 7616 példától"
+        );
+    }
+
+    #[test]
+    fn digest_response_without_qop_and_with_sess() {
+        let plain = challenge("MD5", "realm", "nonce", false);
+        let ha1 = hash_hex("MD5", "user:realm:pass");
+        let ha2 = hash_hex("MD5", "PUT:/a.txt");
+        assert_eq!(
+            digest_response(&plain, "PUT", "/a.txt", "user", "pass", "00000001", "cn"),
+            hash_hex("MD5", &format!("{}:nonce:{}", ha1, ha2)),
+            "qop nélkül az RFC 2069 szerinti válasz kell"
+        );
+
+        let sess = challenge("MD5-sess", "realm", "nonce", true);
+        let ha1_sess = hash_hex("MD5", &format!("{}:nonce:cn", ha1));
+        assert_eq!(
+            digest_response(&sess, "PUT", "/a.txt", "user", "pass", "00000002", "cn"),
+            hash_hex("MD5", &format!("{}:nonce:00000002:cn:auth:{}", ha1_sess, ha2)),
+            "-sess algoritmusnál a HA1 a nonce-szal és a cnonce-szal is képződik"
+        );
+    }
+
+    #[test]
+    fn saved_password_is_kept_only_for_same_account_and_never_returned() {
+        let saved = WebDavSettings {
+            url: "https://felho.example.com/dav/".to_string(),
+            username: "anna".to_string(),
+            password: "titok".to_string(),
+            auth: WebDavAuth::Auto,
+        };
+        assert_eq!(saved.redacted().password, "", "A frontend felé nem mehet ki a jelszó");
+        assert_eq!(saved.redacted().url, saved.url);
+
+        let mut edited = saved.redacted();
+        edited.keep_password_of(&saved);
+        assert_eq!(edited.password, "titok", "Üres jelszónál a mentett marad");
+
+        let mut other_user = WebDavSettings { username: "bela".to_string(), ..saved.redacted() };
+        other_user.keep_password_of(&saved);
+        assert_eq!(other_user.password, "", "Másik felhasználóhoz nem kerülhet át a jelszó");
+
+        let mut changed = WebDavSettings { password: "uj".to_string(), ..saved.clone() };
+        changed.keep_password_of(&saved);
+        assert_eq!(changed.password, "uj", "A megadott új jelszó nem íródhat felül");
+    }
+
+    #[test]
+    fn parse_challenges_prefers_sha256_digest() {
+        let headers = vec![
+            "Basic realm=\"cloud\"".to_string(),
+            "Digest realm=\"cloud\", nonce=\"n1\", qop=\"auth\", algorithm=MD5".to_string(),
+            "Digest realm=\"cloud\", nonce=\"n2\", qop=\"auth-int, auth\", algorithm=SHA-256, opaque=\"op\"".to_string(),
+        ];
+        let (digest, basic) = parse_challenges(&headers);
+        assert!(basic, "A Basic ajánlatot is fel kell ismerni");
+        let digest = digest.expect("Digest ajánlat várható");
+        assert_eq!(digest.algorithm, "SHA-256", "Több digest ajánlatból a SHA-256 a jobb");
+        assert_eq!(digest.nonce, "n2");
+        assert_eq!(digest.opaque.as_deref(), Some("op"));
+        assert!(digest.qop_auth, "A qop listában szereplő auth-ot fel kell ismerni");
+    }
+
+    #[test]
+    fn parse_challenges_ignores_unsupported_algorithms() {
+        let headers = vec![
+            "Digest realm=\"r\", nonce=\"n\", algorithm=SHA-512-256".to_string(),
+            "Bearer realm=\"r\"".to_string(),
+        ];
+        let (digest, basic) = parse_challenges(&headers);
+        assert!(digest.is_none(), "Nem támogatott algoritmusú digest ajánlat nem fogadható el");
+        assert!(!basic, "Basic ajánlat nem volt a válaszban");
+
+        let (digest, _) = parse_challenges(&["digest realm=\"r\", nonce=\"n\"".to_string()]);
+        let digest = digest.expect("Algoritmus nélkül is elfogadható (alapértelmezés MD5)");
+        assert_eq!(digest.algorithm, "MD5");
+        assert!(!digest.qop_auth);
+    }
+
+    #[test]
+    fn parse_multistatus_reads_entries_regardless_of_prefix() {
+        let xml = r#"<?xml version="1.0"?>
+<d:multistatus xmlns:d="DAV:" xmlns:oc="http://owncloud.org/ns">
+  <d:response>
+    <d:href>/remote.php/dav/files/anna/3DPrinterCalc/</d:href>
+    <d:propstat>
+      <d:prop>
+        <d:resourcetype><d:collection/></d:resourcetype>
+        <d:getetag>"dir-etag"</d:getetag>
+        <d:quota-available-bytes>1048576</d:quota-available-bytes>
+      </d:prop>
+      <d:status>HTTP/1.1 200 OK</d:status>
+    </d:propstat>
+    <d:propstat>
+      <d:prop><d:getcontentlength/></d:prop>
+      <d:status>HTTP/1.1 404 Not Found</d:status>
+    </d:propstat>
+  </d:response>
+  <d:response>
+    <d:href>https://felho.example.com/remote.php/dav/files/anna/3DPrinterCalc/ment%C3%A9s%201.json</d:href>
+    <d:propstat>
+      <d:prop>
+        <d:resourcetype/>
+        <d:getetag>&quot;file-etag&quot;</d:getetag>
+        <d:getcontentlength>2048</d:getcontentlength>
+        <d:getlastmodified>Tue, 06 Oct 2026 10:15:00 GMT</d:getlastmodified>
+      </d:prop>
+      <d:status>HTTP/1.1 200 OK</d:status>
+    </d:propstat>
+  </d:response>
+</d:multistatus>"#;
+        let entries = parse_multistatus(xml).expect("A válasz feldolgozható");
+        assert_eq!(entries.len(), 2);
+
+        let dir = &entries[0];
+        assert!(dir.is_collection, "A resourcetype/collection gyűjteményt jelöl");
+        assert_eq!(dir.name, "3DPrinterCalc");
+        assert_eq!(dir.etag.as_deref(), Some("\"dir-etag\""));
+        assert_eq!(dir.quota_available_bytes, Some(1_048_576));
+        assert_eq!(dir.size, None, "A 404-es propstat értékei nem számítanak");
+
+        let file = &entries[1];
+        assert!(!file.is_collection);
+        assert_eq!(file.name, "mentés 1.json", "A név URL dekódolva, teljes URL-ből is");
+        assert_eq!(file.etag.as_deref(), Some("\"file-etag\""));
+        assert_eq!(file.size, Some(2048));
+        assert_eq!(
+            file.last_modified.map(|ts| ts.to_rfc3339()),
+            Some("2026-10-06T10:15:00+00:00".to_string())
+        );
+    }
+
+    #[test]
+    fn parse_multistatus_accepts_default_namespace_and_rejects_broken_xml() {
+        let xml = r#"<multistatus xmlns="DAV:"><response><href>/dav/a.txt</href><propstat><prop><getcontentlength>5</getcontentlength></prop><status>HTTP/1.1 200 OK</status></propstat></response></multistatus>"#;
+        let entries = parse_multistatus(xml).expect("Előtag nélküli névtér is feldolgozható");
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].name, "a.txt");
+        assert_eq!(entries[0].size, Some(5));
+
+        assert!(parse_multistatus("<d:multistatus xmlns:d=\"DAV:\"><d:response></d:multistatus>").is_err(), "Hibás XML-t el kell utasítani");
+    }
+
+    // Élő szerver elleni tesztek (alapból kihagyva), pl.:
+    // PRINTERCALC_WEBDAV_TEST_URL=https://felho.example.com/remote.php/dav/files/teszt/3dpc-teszt/ \
+    // PRINTERCALC_WEBDAV_TEST_USER=teszt PRINTERCALC_WEBDAV_TEST_PASSWORD=... \
+    // PRINTERCALC_WEBDAV_TEST_AUTH=basic,digest cargo test webdav -- --ignored
+    // A megadott gyűjteménybe egy egyedi almappát ír, majd a végén törli
+
+    fn live_settings(auth: WebDavAuth) -> WebDavSettings {
+        let var = |name: &str| std::env::var(name).unwrap_or_else(|_| panic!("Az élő WebDAV tesztekhez meg kell adni: {}", name));
+        WebDavSettings {
+            url: var("PRINTERCALC_WEBDAV_TEST_URL"),
+            username: var("PRINTERCALC_WEBDAV_TEST_USER"),
+            password: var("PRINTERCALC_WEBDAV_TEST_PASSWORD"),
+            auth,
+        }
+    }
+
+    fn live_auth_modes() -> Vec<WebDavAuth> {
+        std::env::var("PRINTERCALC_WEBDAV_TEST_AUTH")
+            .unwrap_or_else(|_| "auto".to_string())
+            .split(',')
+            .map(|mode| match mode.trim().to_lowercase().as_str() {
+                "basic" => WebDavAuth::Basic,
+                "digest" => WebDavAuth::Digest,
+                _ => WebDavAuth::Auto,
+            })
+            .collect()
+    }
+
+    fn live_dir(name: &str) -> String {
+        format!("3dpc-test-{}-{}", name, random_hex(4))
+    }
+
+    #[test]
+    #[ignore]
+    fn live_authentication_and_propfind() {
+        for auth in live_auth_modes() {
+            let client = WebDavClient::new(&live_settings(auth)).unwrap();
+            let report = client.test_connection();
+            assert!(report.success, "Kapcsolatteszt sikertelen ({:?}): {}", auth, report.message);
+            let scheme = report.auth_scheme.unwrap_or_default();
+            match auth {
+                WebDavAuth::Basic => assert_eq!(scheme, "basic"),
+                WebDavAuth::Digest => assert!(scheme.starts_with("digest"), "Digest hitelesítés várható, ez lett: {}", scheme),
+                WebDavAuth::Auto => {}
+            }
+
+            let dir = live_dir("propfind");
+            client.ensure_collection(&format!("{}/al mappa", dir)).unwrap();
+            client.put(&format!("{}/ékezetes fájl.txt", dir), b"hello", None).unwrap();
+            let mut names: Vec<String> = client
+                .list(&dir)
+                .unwrap()
+                .expect("A létrehozott gyűjtemény listázható")
+                .into_iter()
+                .map(|entry| entry.name)
+                .collect();
+            names.sort();
+            assert_eq!(names, vec!["al mappa".to_string(), "ékezetes fájl.txt".to_string()]);
+            let file = client.stat(&format!("{}/ékezetes fájl.txt", dir)).unwrap().expect("A fájl megtalálható");
+            assert_eq!(file.size, Some(5));
+            assert!(file.etag.is_some(), "A PROPFIND válaszban ETag várható");
+            client.delete(&format!("{}/", dir)).unwrap();
+            assert!(client.list(&dir).unwrap().is_none(), "A törölt gyűjtemény nem létezhet");
+        }
+    }
+
+    #[test]
+    #[ignore]
+    fn live_conditional_put_rejects_stale_etag() {
+        let client = WebDavClient::new(&live_settings(WebDavAuth::Auto)).unwrap();
+        let dir = live_dir("etag");
+        client.ensure_collection(&dir).unwrap();
+        let path = format!("{}/allapot.json", dir);
+        client.put(&path, b"{\"v\":1}", None).unwrap();
+        let etag = client.stat(&path).unwrap().and_then(|entry| entry.etag).expect("ETag várható");
+
+        client.put(&path, b"{\"v\":2}", Some(&etag)).expect("A friss ETag-gel a feltöltés sikerül");
+        let error = client.put(&path, b"{\"v\":3}", Some(&etag)).expect_err("Az elavult ETag-gel a feltöltés nem sikerülhet");
+        assert!(error.contains("megváltozott"), "Váratlan hibaüzenet: {}", error);
+        assert_eq!(client.get(&path).unwrap().as_deref(), Some(&b"{\"v\":2}"[..]));
+        client.delete(&format!("{}/", dir)).unwrap();
+    }
+
+    #[test]
+    #[ignore]
+    fn live_resumed_upload_is_verified() {
+        let client = WebDavClient::new(&live_settings(WebDavAuth::Auto)).unwrap();
+        let dir = live_dir("resume");
+        client.ensure_collection(&dir).unwrap();
+        let remote_path = format!("{}/backup.json", dir);
+
+        let mut content = vec![0u8; CHUNK_SIZE + CHUNK_SIZE / 2];
+        rand::thread_rng().fill_bytes(&mut content);
+        let local = std::env::temp_dir().join(format!("{}.bin", live_dir("local")));
+        fs::write(&local, &content).unwrap();
+
+        if client.nextcloud_uploads_url("x").is_none() {
+            // Egy "megszakadt" feltöltés az első darabbal, de hibás tartalommal: a folytatás után a hash-ellenőrzés
+            // észreveszi, és a teljes fájl újra feltöltődik
+            let hash = crate::crypto::sha256_hex(&content);
+            client.put(&format!("{}.{}.part", remote_path, &hash[..16]), &vec![0xAB; CHUNK_SIZE], None).unwrap();
+            // Egy másik tartalom maradéka: nem folytatható, és a feltöltés eltávolítja
+            client.put(&format!("{}.0000000000000000.part", remote_path), b"regi", None).unwrap();
+        }
+
+        let outcome = client.upload_file(&local, &remote_path).unwrap();
+        fs::remove_file(&local).ok();
+        assert_eq!(outcome.size, content.len() as u64);
+        assert_eq!(client.get(&remote_path).unwrap().as_deref(), Some(&content[..]), "A feltöltött tartalomnak egyeznie kell");
+        let leftovers: Vec<String> = client
+            .list(&dir)
+            .unwrap()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|entry| entry.name)
+            .filter(|name| name.ends_with(".part"))
+            .collect();
+        assert!(leftovers.is_empty(), "Nem maradhat félbemaradt feltöltés: {:?}", leftovers);
+        client.delete(&format!("{}/", dir)).unwrap();
+    }
+}
@@ -0,0 +1,414 @@
+use crate::logger;
+use crate::paths;
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::fs;
+use std::path::Path;
+use std::sync::Mutex;
+
+lazy_static! {
+    /// A munkaterület lista (első használatkor a workspaces.json-ból töltődik)
+    static ref REGISTRY: Mutex<Option<WorkspaceRegistry>> = Mutex::new(None);
+}
+
+/// Az alapértelmezett munkaterület: a munkaterületek bevezetése előtti adatok (változatlan helyen)
+pub const DEFAULT_WORKSPACE_ID: &str = "default";
+
+/// A munkaterület lista és az aktív munkaterület (a telepítés adatkönyvtárában, minden munkaterület közös)
+const REGISTRY_FILE: &str = "workspaces.json";
+
+/// A munkaterület azonosítók maximális hossza (könyvtárnév)
+const MAX_ID_LENGTH: usize = 40;
+
+/// Duplikáláskor és új munkaterületnél ezek a beállítások nem másolódnak (munkaterületenként eltérnek)
+const WORKSPACE_SPECIFIC_SETTINGS: [&str; 1] = ["companyInfo"];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct WorkspaceEntry {
+    id: String,
+    name: String,
+    created_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct WorkspaceRegistry {
+    active_id: String,
+    workspaces: Vec<WorkspaceEntry>,
+}
+
+impl Default for WorkspaceRegistry {
+    fn default() -> Self {
+        Self {
+            active_id: DEFAULT_WORKSPACE_ID.to_string(),
+            workspaces: vec![WorkspaceEntry {
+                id: DEFAULT_WORKSPACE_ID.to_string(),
+                // Üres név: a frontend a nyelvnek megfelelő "Alapértelmezett" feliratot mutatja
+                name: String::new(),
+                created_at: String::new(),
+            }],
+        }
+    }
+}
+
+/// Egy munkaterület a frontend számára
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceInfo {
+    pub id: String,
+    pub name: String,
+    pub created_at: String,
+    pub is_default: bool,
+    pub is_active: bool,
+    pub root: String,
+    pub store_exists: bool,
+    pub vault_enabled: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceList {
+    pub active_id: String,
+    pub workspaces: Vec<WorkspaceInfo>,
+}
+
+fn registry_path() -> Result<std::path::PathBuf, String> {
+    Ok(paths::installation_root()?.join(REGISTRY_FILE))
+}
+
+fn read_registry() -> WorkspaceRegistry {
+    let Ok(path) = registry_path() else {
+        return WorkspaceRegistry::default();
+    };
+    let Ok(content) = fs::read_to_string(&path) else {
+        return WorkspaceRegistry::default();
+    };
+    let mut registry: WorkspaceRegistry = match serde_json::from_str(&content) {
+        Ok(registry) => registry,
+        Err(e) => {
+            logger::log_warn(&format!("Érvénytelen munkaterület lista, az alapértelmezettet használjuk: {}", e));
+            return WorkspaceRegistry::default();
+        }
+    };
+
+    if !registry.workspaces.iter().any(|w| w.id == DEFAULT_WORKSPACE_ID) {
+        registry.workspaces.insert(0, WorkspaceRegistry::default().workspaces.remove(0));
+    }
+    // Kézzel törölt munkaterület könyvtár esetén vissza az alapértelmezetthez
+    let active_exists = registry.active_id == DEFAULT_WORKSPACE_ID
+        || (registry.workspaces.iter().any(|w| w.id == registry.active_id)
+            && paths::workspace_root(&registry.active_id).is_ok_and(|root| root.is_dir()));
+    if !active_exists {
+        logger::log_warn(&format!(
+            "Az aktív munkaterület nem található ({}), az alapértelmezett munkaterület lesz aktív",
+            registry.active_id
+        ));
+        registry.active_id = DEFAULT_WORKSPACE_ID.to_string();
+    }
+    registry
+}
+
+fn write_registry(registry: &WorkspaceRegistry) -> Result<(), String> {
+    let content = serde_json::to_string_pretty(registry)
+        .map_err(|e| format!("Munkaterület lista szerializálási hiba: {}", e))?;
+    crate::atomic_write::write_atomic(&registry_path()?, content)
+        .map_err(|e| format!("Nem sikerült menteni a munkaterület listát: {}", e))
+}
+
+/// A munkaterület lista másolata (a zárolást nem tartjuk meg, mert az útvonalak feloldása is ezt olvassa)
+fn registry() -> WorkspaceRegistry {
+    let mut guard = REGISTRY.lock().unwrap_or_else(|e| e.into_inner());
+    if guard.is_none() {
+        // A zárolás alatt csak a telepítés könyvtárát oldjuk fel, az nem függ az aktív munkaterülettől
+        *guard = Some(read_registry());
+    }
+    guard.clone().unwrap_or_default()
+}
+
+fn save_registry(registry: WorkspaceRegistry) -> Result<(), String> {
+    write_registry(&registry)?;
+    *REGISTRY.lock().unwrap_or_else(|e| e.into_inner()) = Some(registry);
+    Ok(())
+}
+
+/// Az aktív munkaterület azonosítója (hiba esetén az alapértelmezett)
+pub fn active_workspace_id() -> String {
+    registry().active_id
+}
+
+/// Az összes ismert munkaterület azonosítója (az alapértelmezett mindig az első)
+pub fn workspace_ids() -> Vec<String> {
+    registry().workspaces.into_iter().map(|w| w.id).collect()
+}
+
+/// Azonosító a névből: ékezetek nélkül, kisbetűvel, kötőjelekkel; ütközésnél sorszámmal
+fn unique_workspace_id(name: &str, registry: &WorkspaceRegistry) -> String {
+    let folded = crate::search::fold_diacritics(name).to_lowercase();
+    let mut slug = String::new();
+    for c in folded.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c);
+        } else if !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    let mut base: String = slug.trim_matches('-').chars().take(MAX_ID_LENGTH).collect();
+    base = base.trim_end_matches('-').to_string();
+    if base.is_empty() || base == DEFAULT_WORKSPACE_ID {
+        base = "workspace".to_string();
+    }
+
+    let taken = |id: &str| {
+        registry.workspaces.iter().any(|w| w.id == id)
+            || paths::workspace_root(id).is_ok_and(|root| root.exists())
+    };
+    let mut id = base.clone();
+    let mut counter = 2;
+    while taken(&id) {
+        id = format!("{}-{}", base, counter);
+        counter += 1;
+    }
+    id
+}
+
+fn validate_name(name: &str, registry: &WorkspaceRegistry, except_id: Option<&str>) -> Result<String, String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("A munkaterület neve nem lehet üres".to_string());
+    }
+    let duplicate = registry
+        .workspaces
+        .iter()
+        .any(|w| Some(w.id.as_str()) != except_id && w.name.trim().to_lowercase() == name.to_lowercase());
+    if duplicate {
+        return Err(format!("Már létezik ilyen nevű munkaterület: {}", name));
+    }
+    Ok(name.to_string())
+}
+
+fn find_entry<'a>(registry: &'a WorkspaceRegistry, id: &str) -> Result<&'a WorkspaceEntry, String> {
+    registry
+        .workspaces
+        .iter()
+        .find(|w| w.id == id)
+        .ok_or_else(|| format!("Nem található munkaterület: {}", id))
+}
+
+fn workspace_vault_enabled(id: &str) -> bool {
+    paths::workspace_root(id).is_ok_and(|root| root.join("vault.json").exists())
+}
+
+/// Van-e már mentett adata a munkaterületnek (titkosított munkaterületnél a data.json.vault)
+fn workspace_store_exists(id: &str) -> Result<bool, String> {
+    let store_file = paths::workspace_store_file(id)?;
+    if workspace_vault_enabled(id) {
+        return Ok(crate::vault::encrypted_store_file(&store_file).exists());
+    }
+    Ok(store_file.exists())
+}
+
+fn workspace_list() -> Result<WorkspaceList, String> {
+    let registry = registry();
+    let mut workspaces = Vec::new();
+    for entry in &registry.workspaces {
+        workspaces.push(WorkspaceInfo {
+            id: entry.id.clone(),
+            name: entry.name.clone(),
+            created_at: entry.created_at.clone(),
+            is_default: entry.id == DEFAULT_WORKSPACE_ID,
+            is_active: entry.id == registry.active_id,
+            root: paths::workspace_root(&entry.id)?.to_string_lossy().to_string(),
+            store_exists: workspace_store_exists(&entry.id)?,
+            vault_enabled: workspace_vault_enabled(&entry.id),
+        });
+    }
+    Ok(WorkspaceList {
+        active_id: registry.active_id,
+        workspaces,
+    })
+}
+
+/// Egy munkaterület Store-ja (titkosított munkaterületet nem olvasunk)
+fn read_workspace_store(id: &str) -> Result<Option<Map<String, Value>>, String> {
+    if workspace_vault_enabled(id) {
+        return Err("Titkosított munkaterület adatai nem másolhatók, előbb kapcsolja ki a titkosítást".to_string());
+    }
+    let path = paths::workspace_store_file(id)?;
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content = fs::read_to_string(&path).map_err(|e| format!("Nem sikerült beolvasni a Store fájlt: {}", e))?;
+    match serde_json::from_str(&content) {
+        Ok(Value::Object(map)) => Ok(Some(map)),
+        Ok(_) => Err("A Store fájl gyökere nem JSON objektum".to_string()),
+        Err(e) => Err(format!("A Store fájl nem érvényes JSON: {}", e)),
+    }
+}
+
+fn write_workspace_store(path: &Path, data: &Map<String, Value>) -> Result<(), String> {
+    let content = serde_json::to_string_pretty(data)
+        .map_err(|e| format!("Nem sikerült JSON-né alakítani a Store tartalmát: {}", e))?;
+    crate::atomic_write::write_atomic(path, content).map_err(|e| format!("Nem sikerült írni a Store fájlt: {}", e))
+}
+
+fn without_workspace_settings(settings: &Value) -> Value {
+    let mut settings = settings.clone();
+    if let Some(map) = settings.as_object_mut() {
+        for key in WORKSPACE_SPECIFIC_SETTINGS {
+            map.remove(key);
+        }
+    }
+    settings
+}
+
+/// Új munkaterület felvétele: könyvtár, opcionálisan Store a forrás munkaterület adataival / beállításaival
+fn add_workspace(name: &str, store: Option<Map<String, Value>>) -> Result<WorkspaceList, String> {
+    let mut registry = registry();
+    let name = validate_name(name, &registry, None)?;
+    let id = unique_workspace_id(&name, &registry);
+
+    let root = paths::workspace_root(&id)?;
+    fs::create_dir_all(&root).map_err(|e| format!("Nem sikerült létrehozni a munkaterület könyvtárát: {}", e))?;
+    if let Some(store) = store {
+        write_workspace_store(&paths::workspace_store_file(&id)?, &store)?;
+    }
+
+    registry.workspaces.push(WorkspaceEntry {
+        id: id.clone(),
+        name: name.clone(),
+        created_at: chrono::Utc::now().to_rfc3339(),
+    });
+    save_registry(registry)?;
+    logger::log_info(&format!("Munkaterület létrehozva: {} ({})", name, id));
+    workspace_list()
+}
+
+/// A munkaterületek listája és az aktív munkaterület
+#[tauri::command]
+pub fn list_workspaces() -> Result<WorkspaceList, String> {
+    workspace_list()
+}
+
+/// Új, üres munkaterület (saját Store, backupok, audit log, cégadatok és beállítások)
+/// `copy_settings` esetén az aktív munkaterület beállításai (a cégadatok nélkül) átkerülnek
+#[tauri::command]
+pub fn create_workspace(name: String, copy_settings: bool) -> Result<WorkspaceList, String> {
+    let store = if copy_settings {
+        read_workspace_store(&active_workspace_id())?
+            .and_then(|store| store.get("settings").map(without_workspace_settings))
+            .map(|settings| Map::from_iter([("settings".to_string(), settings)]))
+    } else {
+        None
+    };
+    add_workspace(&name, store)
+}
+
+/// Munkaterület másolása új néven: az adatok és a beállítások (a cégadatok nélkül) átkerülnek,
+/// a backupok, az audit log, a változásnapló és a lomtár nem (az új munkaterület előzmények nélkül indul)
+#[tauri::command]
+pub fn duplicate_workspace(source_id: String, name: String) -> Result<WorkspaceList, String> {
+    find_entry(&registry(), &source_id)?;
+    let mut store = read_workspace_store(&source_id)?
+        .ok_or_else(|| "A forrás munkaterületnek még nincsenek adatai".to_string())?;
+    if let Some(settings) = store.get("settings").map(without_workspace_settings) {
+        store.insert("settings".to_string(), settings);
+    }
+    add_workspace(&name, Some(store))
+}
+
+/// Munkaterület átnevezése
+#[tauri::command]
+pub fn rename_workspace(id: String, name: String) -> Result<WorkspaceList, String> {
+    let mut registry = registry();
+    let name = validate_name(&name, &registry, Some(&id))?;
+    find_entry(&registry, &id)?;
+    if let Some(entry) = registry.workspaces.iter_mut().find(|w| w.id == id) {
+        entry.name = name;
+    }
+    save_registry(registry)?;
+    workspace_list()
+}
+
+/// Váltás egy másik munkaterületre (a választás újraindítás után is megmarad)
+/// - feloldott titkosított adattároló esetén előbb zárolja (titkosítja) a jelenlegi munkaterületet
+/// - az új munkaterületen lefutnak a séma migrációk, felépül a keresési index és a változásnapló
+///
+/// A frontend a váltás után újratölti magát, hogy az új Store-t olvassa
+#[tauri::command]
+pub async fn switch_workspace(id: String) -> Result<WorkspaceList, String> {
+    crate::vault::run_blocking(move || switch_workspace_blocking(&id)).await
+}
+
+/// A váltás idejére az ütemezett backup és a szinkronizálás szünetel: a folyamatban lévő futás még
+/// a régi munkaterületen fejeződik be, a következő már az újat látja (félúton sosem vált adattárat)
+fn switch_workspace_blocking(id: &str) -> Result<WorkspaceList, String> {
+    let _backup_guard = crate::backup_scheduler::pause_runs();
+    let _sync_guard = crate::sync::pause_runs();
+
+    let mut registry = registry();
+    find_entry(&registry, id)?;
+    let previous = registry.active_id.clone();
+    if previous == id {
+        return workspace_list();
+    }
+
+    if crate::vault::is_enabled() && !crate::vault::is_locked() {
+        crate::vault::lock_vault()?;
+    }
+
+    registry.active_id = id.to_string();
+    save_registry(registry.clone())?;
+    crate::journal::reset();
+    crate::backup_scheduler::reset_status();
+    crate::sync::reset_status();
+
+    if !crate::vault::is_locked() {
+        // Újabb alkalmazás verzió által írt adatokat itt sem nyitunk meg - vissza az előző munkaterületre
+        if let Err(e) = crate::migrations::run_startup_migrations() {
+            registry.active_id = previous;
+            save_registry(registry)?;
+            crate::journal::reset();
+            if let Err(e) = crate::search::rebuild_from_store() {
+                logger::log_warn(&format!("Keresési index felépítése sikertelen: {}", e));
+            }
+            return Err(format!("A munkaterület nem nyitható meg: {}", e));
+        }
+        if let Err(e) = crate::journal::initialize() {
+            logger::log_warn(&format!("Változásnapló inicializálása sikertelen: {}", e));
+        }
+        if let Err(e) = crate::trash::purge_expired() {
+            logger::log_warn(&format!("Lejárt lomtár elemek törlése sikertelen: {}", e));
+        }
+    }
+    if let Err(e) = crate::search::rebuild_from_store() {
+        logger::log_warn(&format!("Keresési index felépítése sikertelen: {}", e));
+    }
+
+    logger::log_info(&format!("Munkaterület váltás: {} → {}", previous, id));
+    workspace_list()
+}
+
+/// Munkaterület végleges törlése az összes adatával és backupjával
+/// Az alapértelmezett és az aktív munkaterület nem törölhető
+#[tauri::command]
+pub fn delete_workspace(id: String) -> Result<WorkspaceList, String> {
+    let mut registry = registry();
+    find_entry(&registry, &id)?;
+    if id == DEFAULT_WORKSPACE_ID {
+        return Err("Az alapértelmezett munkaterület nem törölhető".to_string());
+    }
+    if id == registry.active_id {
+        return Err("Az aktív munkaterület nem törölhető, előbb váltson másikra".to_string());
+    }
+
+    let root = paths::workspace_root(&id)?;
+    if root.exists() {
+        fs::remove_dir_all(&root).map_err(|e| format!("Nem sikerült törölni a munkaterület könyvtárát: {}", e))?;
+    }
+    registry.workspaces.retain(|w| w.id != id);
+    save_registry(registry)?;
+    logger::log_info(&format!("Munkaterület törölve: {}", id));
+    workspace_list()
+}